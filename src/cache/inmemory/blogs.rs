@@ -1,7 +1,7 @@
 use crate::cache::inmemory::InMemoryCache;
 use crate::model::blogs::*;
 use crate::repo::blogs::{BlogCacheOperationRepo as BlogOperationRepo, BlogDisplayRepo};
-use crate::utils::remove_whitespace;
+use crate::utils::is_tags_matched;
 use async_trait::async_trait;
 use tracing::{debug, info};
 
@@ -14,7 +14,7 @@ impl BlogDisplayRepo for InMemoryCache {
     async fn find(&self, id: i64) -> Option<Blog> {
         debug!("Finding InMemoryCache {BLOG_KEY_PREFIX}-{id}");
         let key = format!("{BLOG_KEY_PREFIX}-{id}");
-        self.blogs_cache.get(&key).await
+        self.record_lookup(self.blogs_cache.get(&key).await)
     }
     /// Find Blogs Caches
     /// Take `BlogsParams` that contain `start`, `end`, and `tags` then
@@ -30,41 +30,25 @@ impl BlogDisplayRepo for InMemoryCache {
         // rev() method to reverse Blog order
         // TODO: Observe the effect of Blog order reversal
         // I forgot why I need to reverse the order in Talk cache
-        'cache_id: for id in (start_seq..=end_seq).rev() {
-            let value = self.find(id).await;
-            if value.is_none() {
+        for id in (start_seq..=end_seq).rev() {
+            let key = format!("{BLOG_KEY_PREFIX}-{id}");
+            let Some(value) = self.blogs_cache.get(&key).await else {
                 debug!("{BLOG_KEY_PREFIX}-{id} cache miss");
                 continue;
-            }
+            };
 
-            // Return early when tags is empty
-            if tags.is_empty() {
+            // Push blog if any of the tags is contained in blog tags
+            if is_tags_matched(&tags, &value.tags.clone().unwrap_or_default()) {
                 debug!("{BLOG_KEY_PREFIX}-{id} cache hit");
-                blogs.push(value.unwrap());
-                continue;
-            }
-
-            for tag in remove_whitespace(&tags).split(",") {
-                // If tag contained in blog tags, push blog and break `cache_id` loop early
-                if value
-                    .clone()
-                    .unwrap()
-                    .tags
-                    .unwrap()
-                    .contains(&tag.to_string())
-                {
-                    debug!("{BLOG_KEY_PREFIX}-{id} cache hit");
-                    blogs.push(value.unwrap());
-                    continue 'cache_id;
-                }
+                blogs.push(value);
             }
         }
 
         // If Cache is still fresh, return None
         if blogs.is_empty() {
-            return None;
+            return self.record_lookup(None);
         }
-        Some(blogs)
+        self.record_lookup(Some(blogs))
     }
 }
 
@@ -0,0 +1,78 @@
+use crate::cache::inmemory::InMemoryCache;
+use crate::model::dashboard::{CacheEntryCount, CacheStats};
+use crate::repo::dashboard::DashboardCacheRepo;
+use async_trait::async_trait;
+use std::sync::atomic::Ordering;
+use tracing::debug;
+
+#[async_trait]
+impl DashboardCacheRepo for InMemoryCache {
+    /// Find InMemoryCache statistics
+    /// Return lookup counters since the application started and entry count per cache.
+    /// Pending evictions are applied first so expired entries aren't counted.
+    async fn find_cache_stats(&self) -> Option<CacheStats> {
+        debug!("Finding InMemoryCache statistics");
+        self.blogs_cache.run_pending_tasks().await;
+        self.talks_cache.run_pending_tasks().await;
+        self.talks_calendar_cache.run_pending_tasks().await;
+        self.tags_cache.run_pending_tasks().await;
+        self.btms_cache.run_pending_tasks().await;
+        self.ttms_cache.run_pending_tasks().await;
+        self.projects_cache.run_pending_tasks().await;
+        self.profiles_cache.run_pending_tasks().await;
+        self.resume_cache.run_pending_tasks().await;
+
+        let entry_count = |name: &str, count: u64| CacheEntryCount {
+            name: name.to_string(),
+            count,
+        };
+        Some(CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: vec![
+                entry_count("Blogs", self.blogs_cache.entry_count()),
+                entry_count("Talks", self.talks_cache.entry_count()),
+                entry_count("Talks calendar", self.talks_calendar_cache.entry_count()),
+                entry_count("Tags", self.tags_cache.entry_count()),
+                entry_count("Blog tag mappings", self.btms_cache.entry_count()),
+                entry_count("Talk tag mappings", self.ttms_cache.entry_count()),
+                entry_count("Projects", self.projects_cache.entry_count()),
+                entry_count("Profiles", self.profiles_cache.entry_count()),
+                entry_count("Resume", self.resume_cache.entry_count()),
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::model::tags::Tag;
+    use crate::repo::tags::{TagCacheOperationRepo, TagDisplayRepo};
+
+    #[tokio::test]
+    async fn test_find_cache_stats_counts_lookups_and_entries() {
+        let cache = InMemoryCache::new(3600);
+        let _ = cache
+            .clone()
+            .insert(Tag {
+                id: 1,
+                name: "rust".to_string(),
+            })
+            .await;
+
+        assert!(TagDisplayRepo::find(&cache, 1).await.is_some());
+        assert!(TagDisplayRepo::find(&cache, 1).await.is_some());
+        assert!(TagDisplayRepo::find(&cache, 2).await.is_none());
+
+        let cache_stats = cache.find_cache_stats().await.unwrap();
+        assert_eq!(cache_stats.hits, 2);
+        assert_eq!(cache_stats.misses, 1);
+        let tags = cache_stats
+            .entries
+            .iter()
+            .find(|entry| entry.name == "Tags")
+            .unwrap();
+        assert_eq!(tags.count, 1);
+    }
+}
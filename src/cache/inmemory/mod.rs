@@ -1,22 +1,41 @@
 pub mod blog_tag_mappings;
 pub mod blogs;
+pub mod dashboard;
+pub mod profiles;
+pub mod projects;
+pub mod resume;
 pub mod tags;
+pub mod talk_tag_mappings;
 pub mod talks;
 
 use moka::future::Cache;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 
 use crate::model::blog_tag_mappings::BlogTagMapping;
 use crate::model::blogs::Blog;
+use crate::model::profiles::Profile;
+use crate::model::projects::Project;
+use crate::model::resume::Resume;
 use crate::model::tags::Tag;
+use crate::model::talk_tag_mappings::TalkTagMapping;
 use crate::model::talks::Talk;
 
 #[derive(Clone)]
 pub struct InMemoryCache {
     blogs_cache: Cache<String, Blog>,
     talks_cache: Cache<String, Talk>,
+    talks_calendar_cache: Cache<String, String>,
     tags_cache: Cache<String, Tag>,
     btms_cache: Cache<String, BlogTagMapping>,
+    ttms_cache: Cache<String, TalkTagMapping>,
+    projects_cache: Cache<String, Project>,
+    profiles_cache: Cache<String, Profile>,
+    resume_cache: Cache<String, Resume>,
+    // Lookup counters shared by every clone of the cache
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
 }
 
 impl InMemoryCache {
@@ -38,6 +57,14 @@ impl InMemoryCache {
             // Set max cache capacity to 32MiB
             .max_capacity(32 * 1024 * 1024)
             .build();
+        let talks_calendar_cache = Cache::builder()
+            // Set time to live from the CACHE_TTL envar
+            .time_to_live(Duration::from_secs(ttl as u64))
+            // Weigher to set K and V varaibles type
+            .weigher(|_key: &String, value: &String| -> u32 { value.len() as u32 })
+            // Set max cache capacity to 1MiB
+            .max_capacity(1024 * 1024)
+            .build();
         let tags_cache = Cache::builder()
             // Set time to live from the CACHE_TTL envar
             .time_to_live(Duration::from_secs(ttl as u64))
@@ -54,13 +81,60 @@ impl InMemoryCache {
             // Set max cache capacity to 32MiB
             .max_capacity(32 * 1024 * 1024)
             .build();
+        let ttms_cache = Cache::builder()
+            // Set time to live from the CACHE_TTL envar
+            .time_to_live(Duration::from_secs(ttl as u64))
+            // Weigher to set K and V varaibles type
+            .weigher(|_key: &String, value: &TalkTagMapping| -> u32 { value.data_size() })
+            // Set max cache capacity to 32MiB
+            .max_capacity(32 * 1024 * 1024)
+            .build();
+        let projects_cache = Cache::builder()
+            // Set time to live from the CACHE_TTL envar
+            .time_to_live(Duration::from_secs(ttl as u64))
+            // Weigher to set K and V varaibles type
+            .weigher(|_key: &String, value: &Project| -> u32 { value.data_size() })
+            // Set max cache capacity to 32MiB
+            .max_capacity(32 * 1024 * 1024)
+            .build();
+        let profiles_cache = Cache::builder()
+            // Set time to live from the CACHE_TTL envar
+            .time_to_live(Duration::from_secs(ttl as u64))
+            // Weigher to set K and V varaibles type
+            .weigher(|_key: &String, value: &Profile| -> u32 { value.data_size() })
+            // Set max cache capacity to 1MiB
+            .max_capacity(1024 * 1024)
+            .build();
+        let resume_cache = Cache::builder()
+            // Set time to live from the CACHE_TTL envar
+            .time_to_live(Duration::from_secs(ttl as u64))
+            // Weigher to set K and V varaibles type
+            .weigher(|_key: &String, value: &Resume| -> u32 { value.data_size() })
+            // Set max cache capacity to 1MiB
+            .max_capacity(1024 * 1024)
+            .build();
         Self {
             blogs_cache,
             talks_cache,
+            talks_calendar_cache,
             tags_cache,
             btms_cache,
+            ttms_cache,
+            projects_cache,
+            profiles_cache,
+            resume_cache,
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
         }
     }
+    /// Count a display lookup as a hit or a miss then return it untouched
+    fn record_lookup<V>(&self, value: Option<V>) -> Option<V> {
+        match value {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+        value
+    }
 }
 
 #[cfg(test)]
@@ -75,10 +149,13 @@ mod test {
         let test_val = Talk {
             id: 1,
             name: "test".to_string(),
-            date: "2025-10-10".to_string(),
+            date: chrono::NaiveDate::from_ymd_opt(2025, 10, 10).unwrap(),
+            invalid_date: None,
             media_link: None,
+            org_id: None,
             org_name: None,
             org_link: None,
+            tags: Vec::new(),
         };
         let talks_cache = cache.talks_cache;
 
@@ -105,10 +182,13 @@ mod test {
         let test_val = Talk {
             id: 1,
             name: "test".to_string(),
-            date: "2025-10-10".to_string(),
+            date: chrono::NaiveDate::from_ymd_opt(2025, 10, 10).unwrap(),
+            invalid_date: None,
             media_link: None,
+            org_id: None,
             org_name: None,
             org_link: None,
+            tags: Vec::new(),
         };
         let talks_cache = cache.talks_cache;
 
@@ -0,0 +1,119 @@
+use crate::cache::inmemory::InMemoryCache;
+use crate::model::profiles::*;
+use crate::repo::profiles::{
+    ProfileCacheOperationRepo as ProfileOperationRepo, ProfileDisplayRepo,
+};
+use async_trait::async_trait;
+use tracing::{debug, info};
+
+const PROFILE_KEY_PREFIX: &str = "profile";
+
+#[async_trait]
+impl ProfileDisplayRepo for InMemoryCache {
+    /// Find a Profile Cache
+    /// Take profile version and return Option of `Profile`. If `None`, no profile was cached
+    async fn find(&self, version: String) -> Option<Profile> {
+        debug!("Finding InMemoryCache {PROFILE_KEY_PREFIX}-{version}");
+        let key = format!("{PROFILE_KEY_PREFIX}-{version}");
+        self.record_lookup(self.profiles_cache.get(&key).await)
+    }
+    /// Find the latest Profile Cache
+    /// Return Option of the cached `Profile` with the newest version.
+    /// If `None`, no profile was cached
+    async fn find_latest(&self) -> Option<Profile> {
+        debug!("Finding latest InMemoryCache {PROFILE_KEY_PREFIX}");
+        let profile = self
+            .profiles_cache
+            .iter()
+            .map(|(_key, profile)| profile)
+            .max_by(|a, b| a.version.cmp(&b.version));
+        self.record_lookup(profile)
+    }
+    /// Find Profile Caches
+    /// Take `ProfilesParams` that contain `start` and `end` then return Option of `Profiles`.
+    /// `profiles` field is ordered from the newest version.
+    /// if `None`, no profiles within `ProfilesParams` was cached
+    async fn find_profiles(&self, params: ProfilesParams) -> Option<Profiles> {
+        let start_seq = params.start.unwrap();
+        let end_seq = params.end.unwrap();
+        debug!("Finding InMemoryCache {PROFILE_KEY_PREFIX} from {start_seq} to {end_seq}");
+
+        let mut profiles: Vec<Profile> = self
+            .profiles_cache
+            .iter()
+            .map(|(_key, profile)| profile)
+            .collect();
+        profiles.sort_by(|a, b| b.version.cmp(&a.version));
+        let profiles: Vec<Profile> = profiles
+            .into_iter()
+            .skip(start_seq as usize)
+            .take((end_seq - start_seq).max(0) as usize)
+            .collect();
+
+        // If Cache is still fresh, return None
+        if profiles.is_empty() {
+            return self.record_lookup(None);
+        }
+        self.record_lookup(Some(Profiles { profiles }))
+    }
+}
+
+#[async_trait]
+impl ProfileOperationRepo for InMemoryCache {
+    /// Insert Profile Cache
+    /// Take a `Profile` object and store it in the `InMemoryCache`
+    /// Return Option of `ProfileCommandStatus`. If `None`, insertion failed
+    async fn insert(&mut self, profile: Profile) -> Option<ProfileCommandStatus> {
+        let key = format!("{PROFILE_KEY_PREFIX}-{}", &profile.version);
+        info!("Inserting {} into InMemoryCache", &key);
+        self.profiles_cache.insert(key, profile).await;
+        Some(ProfileCommandStatus::CacheInserted)
+    }
+    /// Invalidate Profile Cache
+    /// Invalidate (discard value from the cached key) profile cache by profile version
+    /// Return Option of `ProfileCommandStatus`. If `None`, invalidation failed
+    async fn invalidate(&mut self, version: String) -> Option<ProfileCommandStatus> {
+        let key = format!("{PROFILE_KEY_PREFIX}-{version}");
+        info!("Invalidating {} from InMemoryCache", &key);
+        self.profiles_cache.invalidate(&key).await;
+        Some(ProfileCommandStatus::CacheInvalidated)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample_profile(version: &str) -> Profile {
+        Profile {
+            version: version.to_string(),
+            headline: format!("headline {version}"),
+            bio: "bio".to_string(),
+            experiences: Vec::new(),
+            skills: Vec::new(),
+            links: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_insert_find_latest_and_invalidate_profile() {
+        let mut cache = InMemoryCache::new(3600);
+        assert_eq!(cache.find_latest().await, None);
+
+        let old_profile = sample_profile("2026-10-18T00:00:00.000Z");
+        let new_profile = sample_profile("2026-10-19T00:00:00.000Z");
+        let res = cache.insert(new_profile.clone()).await;
+        assert_eq!(res, Some(ProfileCommandStatus::CacheInserted));
+        cache.insert(old_profile.clone()).await;
+
+        assert_eq!(cache.find_latest().await, Some(new_profile.clone()));
+        assert_eq!(
+            cache.find(old_profile.version.clone()).await,
+            Some(old_profile.clone())
+        );
+
+        let res = cache.invalidate(new_profile.version.clone()).await;
+        assert_eq!(res, Some(ProfileCommandStatus::CacheInvalidated));
+        assert_eq!(cache.find_latest().await, Some(old_profile));
+    }
+}
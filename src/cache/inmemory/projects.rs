@@ -0,0 +1,160 @@
+use crate::cache::inmemory::InMemoryCache;
+use crate::model::projects::*;
+use crate::repo::projects::{
+    ProjectCacheOperationRepo as ProjectOperationRepo, ProjectDisplayRepo,
+};
+use async_trait::async_trait;
+use tracing::{debug, info};
+
+const PROJECT_KEY_PREFIX: &str = "project";
+
+#[async_trait]
+impl ProjectDisplayRepo for InMemoryCache {
+    /// Find a Project Cache
+    /// Take project id and return Option of `Project`. If `None`, no project was cached
+    async fn find(&self, id: i64) -> Option<Project> {
+        debug!("Finding InMemoryCache {PROJECT_KEY_PREFIX}-{id}");
+        let key = format!("{PROJECT_KEY_PREFIX}-{id}");
+        self.record_lookup(self.projects_cache.get(&key).await)
+    }
+    /// Find Project Caches
+    /// Take `ProjectsParams` that contain `start`, `end` and optional `status`
+    /// and `tags` filters then return Option of `Projects`.
+    /// `projects` field is ordered by position then id.
+    /// if `None`, no projects within `ProjectsParams` was cached
+    async fn find_projects(&self, params: ProjectsParams) -> Option<Projects> {
+        let start_seq = params.start.unwrap();
+        let end_seq = params.end.unwrap();
+        debug!("Finding InMemoryCache {PROJECT_KEY_PREFIX} from {start_seq} to {end_seq}");
+
+        let mut projects: Vec<Project> = self
+            .projects_cache
+            .iter()
+            .map(|(_key, project)| project)
+            .filter(|project| params.is_matched(project))
+            .collect();
+        projects.sort_by_key(|project| (project.position, project.id));
+        let projects: Vec<Project> = projects
+            .into_iter()
+            .skip(start_seq as usize)
+            .take((end_seq - start_seq).max(0) as usize)
+            .collect();
+
+        // If Cache is still fresh, return None
+        if projects.is_empty() {
+            return self.record_lookup(None);
+        }
+        self.record_lookup(Some(Projects { projects }))
+    }
+}
+
+#[async_trait]
+impl ProjectOperationRepo for InMemoryCache {
+    /// Insert Project Cache
+    /// Take a `Project` object and store it in the `InMemoryCache`
+    /// Return Option of `ProjectCommandStatus`. If `None`, insertion failed
+    async fn insert(&mut self, project: Project) -> Option<ProjectCommandStatus> {
+        let key = format!("{PROJECT_KEY_PREFIX}-{}", &project.id);
+        info!("Inserting {} into InMemoryCache", &key);
+        self.projects_cache.insert(key, project).await;
+        Some(ProjectCommandStatus::CacheInserted)
+    }
+    /// Invalidate Project Cache
+    /// Invalidate (discard value from the cached key) project cache by project id
+    /// Return Option of `ProjectCommandStatus`. If `None`, invalidation failed
+    async fn invalidate(&mut self, id: i64) -> Option<ProjectCommandStatus> {
+        let key = format!("{PROJECT_KEY_PREFIX}-{id}");
+        info!("Invalidating {} from InMemoryCache", &key);
+        self.projects_cache.invalidate(&key).await;
+        Some(ProjectCommandStatus::CacheInvalidated)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample_project(id: i64, position: i64, status: ProjectStatus) -> Project {
+        Project {
+            id,
+            name: format!("project {id}"),
+            summary: "summary".to_string(),
+            body: "body".to_string(),
+            repo_url: None,
+            status,
+            tags: vec!["rust".to_string()],
+            position,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_insert_find_and_invalidate_project() {
+        let mut cache = InMemoryCache::new(3600);
+        let test_val = sample_project(1, 0, ProjectStatus::Active);
+
+        let res = cache.insert(test_val.clone()).await;
+        assert_eq!(res, Some(ProjectCommandStatus::CacheInserted));
+        assert_eq!(cache.find(1).await, Some(test_val));
+
+        let res = cache.invalidate(1).await;
+        assert_eq!(res, Some(ProjectCommandStatus::CacheInvalidated));
+        assert_eq!(cache.find(1).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_find_projects_ordered_and_filtered() {
+        let mut cache = InMemoryCache::new(3600);
+        cache
+            .insert(sample_project(1, 2, ProjectStatus::Active))
+            .await;
+        cache
+            .insert(sample_project(2, 1, ProjectStatus::Archived))
+            .await;
+        cache
+            .insert(sample_project(3, 1, ProjectStatus::Active))
+            .await;
+
+        let params = ProjectsParams {
+            start: None,
+            end: None,
+            status: None,
+            tags: None,
+        }
+        .sanitize();
+        let ids: Vec<i64> = cache
+            .find_projects(params)
+            .await
+            .unwrap()
+            .projects
+            .iter()
+            .map(|project| project.id)
+            .collect();
+        assert_eq!(ids, vec![2, 3, 1]);
+
+        let params = ProjectsParams {
+            start: Some(0),
+            end: Some(1),
+            status: Some("active".to_string()),
+            tags: None,
+        }
+        .sanitize();
+        let ids: Vec<i64> = cache
+            .find_projects(params)
+            .await
+            .unwrap()
+            .projects
+            .iter()
+            .map(|project| project.id)
+            .collect();
+        assert_eq!(ids, vec![3]);
+
+        let params = ProjectsParams {
+            start: None,
+            end: None,
+            status: None,
+            tags: Some("go".to_string()),
+        }
+        .sanitize();
+        assert_eq!(cache.find_projects(params).await, None);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::cache::inmemory::InMemoryCache;
+use crate::model::resume::*;
+use crate::repo::resume::{ResumeCacheOperationRepo as ResumeOperationRepo, ResumeDisplayRepo};
+use async_trait::async_trait;
+use tracing::{debug, info};
+
+/// There is only a single Resume so it's cached under a single key
+const RESUME_KEY: &str = "resume";
+
+#[async_trait]
+impl ResumeDisplayRepo for InMemoryCache {
+    /// Find the Resume Cache
+    /// Return Option of `Resume`. If `None`, the resume was not cached
+    async fn find(&self) -> Option<Resume> {
+        debug!("Finding InMemoryCache {RESUME_KEY}");
+        self.record_lookup(self.resume_cache.get(RESUME_KEY).await)
+    }
+}
+
+#[async_trait]
+impl ResumeOperationRepo for InMemoryCache {
+    /// Insert Resume Cache
+    /// Take a `Resume` object and store it in the `InMemoryCache`
+    /// Return Option of `ResumeCommandStatus`. If `None`, insertion failed
+    async fn insert(&mut self, resume: Resume) -> Option<ResumeCommandStatus> {
+        info!("Inserting {RESUME_KEY} into InMemoryCache");
+        self.resume_cache
+            .insert(RESUME_KEY.to_string(), resume)
+            .await;
+        Some(ResumeCommandStatus::CacheInserted)
+    }
+    /// Invalidate Resume Cache
+    /// Return Option of `ResumeCommandStatus`. If `None`, invalidation failed
+    async fn invalidate(&mut self) -> Option<ResumeCommandStatus> {
+        info!("Invalidating {RESUME_KEY} from InMemoryCache");
+        self.resume_cache.invalidate(RESUME_KEY).await;
+        Some(ResumeCommandStatus::CacheInvalidated)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_insert_find_and_invalidate_resume() {
+        let mut cache = InMemoryCache::new(3600);
+        assert_eq!(cache.find().await, None);
+
+        let resume = Resume {
+            basics: ResumeBasics {
+                name: "Husni Zuhdi".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let res = cache.insert(resume.clone()).await;
+        assert_eq!(res, Some(ResumeCommandStatus::CacheInserted));
+        assert_eq!(cache.find().await, Some(resume));
+
+        let res = cache.invalidate().await;
+        assert_eq!(res, Some(ResumeCommandStatus::CacheInvalidated));
+        assert_eq!(cache.find().await, None);
+    }
+}
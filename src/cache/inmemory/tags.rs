@@ -13,7 +13,7 @@ impl TagDisplayRepo for InMemoryCache {
     async fn find(&self, id: i64) -> Option<Tag> {
         debug!("Finding InMemoryCache {TAG_KEY_PREFIX}-{id}");
         let key = format!("{TAG_KEY_PREFIX}-{id}");
-        self.tags_cache.get(&key).await
+        self.record_lookup(self.tags_cache.get(&key).await)
     }
     /// Find a Tag Caches
     /// Take `TagListParams` that contain `start` and `end` then
@@ -29,7 +29,8 @@ impl TagDisplayRepo for InMemoryCache {
         // TODO: Observe the effect of Tag order reversal
         // I forgot why I need to reverse the order in Talk cache
         for id in (start_seq..=end_seq).rev() {
-            let value = self.find(id).await;
+            let key = format!("{TAG_KEY_PREFIX}-{id}");
+            let value = self.tags_cache.get(&key).await;
             if value.is_none() {
                 debug!("{TAG_KEY_PREFIX}-{id} cache miss");
                 continue;
@@ -41,9 +42,9 @@ impl TagDisplayRepo for InMemoryCache {
 
         // If Cache is still fresh, return None
         if tags.is_empty() {
-            return None;
+            return self.record_lookup(None);
         }
-        Some(Tags { tags })
+        self.record_lookup(Some(Tags { tags }))
     }
     /// Search Tag Caches
     /// Take `TagSearchParams` that contain `start` and `end` then
@@ -60,7 +61,8 @@ impl TagDisplayRepo for InMemoryCache {
         // TODO: Observe the effect of Tag order reversal
         // I forgot why I need to reverse the order in Talk cache
         for id in (start_seq..=end_seq).rev() {
-            let value = self.find(id).await;
+            let key = format!("{TAG_KEY_PREFIX}-{id}");
+            let value = self.tags_cache.get(&key).await;
             if value.is_none() {
                 debug!("{TAG_KEY_PREFIX}-{id} cache miss");
                 continue;
@@ -79,13 +81,13 @@ impl TagDisplayRepo for InMemoryCache {
 
         // If Cache is still fresh, return None
         if tags.is_empty() {
-            return None;
+            return self.record_lookup(None);
         }
 
         // Filter tags to contain the query
         let _ = tags.iter().filter(|tag| tag.name.contains(&query));
 
-        Some(Tags { tags })
+        self.record_lookup(Some(Tags { tags }))
     }
 }
 
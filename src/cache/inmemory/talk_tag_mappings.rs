@@ -0,0 +1,262 @@
+use crate::cache::inmemory::InMemoryCache;
+use crate::model::talk_tag_mappings::*;
+use crate::repo::talk_tag_mappings::{
+    TalkTagMappingCacheOperationRepo as TalkTagMappingOperationRepo, TalkTagMappingDisplayRepo,
+};
+use async_trait::async_trait;
+use tracing::{debug, info};
+
+const TTM_KEY_PREFIX: &str = "ttm";
+
+#[async_trait]
+impl TalkTagMappingDisplayRepo for InMemoryCache {
+    /// Find a TalkTagMapping Caches by talk id
+    async fn find_by_talk_id(&self, talk_id: i64) -> Option<TalkTagMappings> {
+        debug!("Finding InMemoryCache {TTM_KEY_PREFIX} for talk id {talk_id}");
+
+        let mut maps = Vec::new();
+        for (_, v) in self.ttms_cache.iter().filter(|(_, v)| v.talk_id == talk_id) {
+            debug!("{TTM_KEY_PREFIX}-{talk_id}-{} cache hit", v.tag_id);
+            maps.push(v);
+        }
+
+        // If Cache is still fresh, return None
+        if maps.is_empty() {
+            return None;
+        }
+        Some(TalkTagMappings { maps })
+    }
+    /// Find a TalkTagMapping Caches by tag id
+    async fn find_by_tag_id(&self, tag_id: i64) -> Option<TalkTagMappings> {
+        debug!("Finding InMemoryCache {TTM_KEY_PREFIX} for tag id {tag_id}");
+
+        let mut maps = Vec::new();
+        for (_, v) in self.ttms_cache.iter().filter(|(_, v)| v.tag_id == tag_id) {
+            debug!("{TTM_KEY_PREFIX}-{}-{tag_id} cache hit", v.talk_id);
+            maps.push(v);
+        }
+
+        // If Cache is still fresh, return None
+        if maps.is_empty() {
+            return None;
+        }
+        Some(TalkTagMappings { maps })
+    }
+}
+
+#[async_trait]
+impl TalkTagMappingOperationRepo for InMemoryCache {
+    /// Insert TalkTagMapping Cache
+    /// Take a `TalkTagMapping` object and store it in the `InMemoryCache`
+    /// Return Option of `TalkTagMappingCommandStatus`. If `None`, insertion failed
+    async fn insert(&mut self, talk_id: i64, tag_id: i64) -> Option<TalkTagMappingCommandStatus> {
+        let key = format!("{TTM_KEY_PREFIX}-{talk_id}-{tag_id}");
+        info!("Inserting {} into InMemoryCache", &key);
+        self.ttms_cache
+            .insert(key, TalkTagMapping { talk_id, tag_id })
+            .await;
+        Some(TalkTagMappingCommandStatus::CacheInserted)
+    }
+    /// Invalidate TalkTagMapping Cache
+    /// Invalidate (discard value from the cached key) ttm cache by talk id and tag id
+    /// Return Option of `TalkTagMappingCommandStatus`. If `None`, invalidation failed
+    async fn invalidate(
+        &mut self,
+        talk_id: i64,
+        tag_id: i64,
+    ) -> Option<TalkTagMappingCommandStatus> {
+        let key = format!("{TTM_KEY_PREFIX}-{talk_id}-{tag_id}");
+        info!("Invalidating {} from InMemoryCache", &key);
+        self.ttms_cache.invalidate(&key).await;
+        Some(TalkTagMappingCommandStatus::CacheInvalidated)
+    }
+    /// Invalidate TalkTagMappings Cache by Talk id
+    /// Invalidate (discard value from the cached key) ttm cache by talk id
+    /// Return Option of `TalkTagMappingCommandStatus`. If `None`, invalidation failed
+    async fn invalidate_by_talk_id(&mut self, talk_id: i64) -> Option<TalkTagMappingCommandStatus> {
+        let key = format!("{TTM_KEY_PREFIX}-{talk_id}-*");
+        info!("Invalidating {} from InMemoryCache", &key);
+
+        let ttms = self.find_by_talk_id(talk_id).await?;
+
+        for ttm in ttms.maps {
+            let k = format!("{TTM_KEY_PREFIX}-{talk_id}-{}", ttm.tag_id);
+            self.ttms_cache.invalidate(&k).await;
+            debug!("{} invalidated", &k);
+        }
+        Some(TalkTagMappingCommandStatus::CacheInvalidated)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_insert_find_by_talk_id_and_invalidate_ttm() {
+        let cache = InMemoryCache::new(3600);
+        let test_val = TalkTagMapping {
+            talk_id: 1,
+            tag_id: 1,
+        };
+
+        // Insert cache
+        let insert_status = cache
+            .clone()
+            .insert(test_val.talk_id, test_val.tag_id)
+            .await;
+        assert!(insert_status.is_some(), "ttm-1-1 insertion failed");
+        assert_eq!(
+            insert_status.unwrap(),
+            TalkTagMappingCommandStatus::CacheInserted
+        );
+
+        // Find cache
+        let result = cache.clone().find_by_talk_id(test_val.talk_id).await;
+        assert!(result.is_some(), "ttm-1-1 find operation failed");
+        assert_eq!(
+            result.unwrap(),
+            TalkTagMappings {
+                maps: vec![test_val.clone()]
+            }
+        );
+
+        // Invalidate cache
+        let invalidate_status = cache
+            .clone()
+            .invalidate(test_val.talk_id, test_val.tag_id)
+            .await;
+        assert!(invalidate_status.is_some(), "ttm-1-1 invalidation failed");
+        assert_eq!(
+            invalidate_status.unwrap(),
+            TalkTagMappingCommandStatus::CacheInvalidated
+        );
+
+        // Find cache after invalidation
+        let result = cache.clone().find_by_talk_id(test_val.talk_id).await;
+        assert!(result.is_none(), "ttm-1-1 find a cache! it shouldn't be!");
+    }
+
+    #[tokio::test]
+    async fn test_insert_find_by_tag_id_and_invalidate_ttm() {
+        let cache = InMemoryCache::new(3600);
+        let test_val = TalkTagMapping {
+            talk_id: 1,
+            tag_id: 1,
+        };
+
+        // Insert cache
+        let insert_status = cache
+            .clone()
+            .insert(test_val.talk_id, test_val.tag_id)
+            .await;
+        assert!(insert_status.is_some(), "ttm-1-1 insertion failed");
+        assert_eq!(
+            insert_status.unwrap(),
+            TalkTagMappingCommandStatus::CacheInserted
+        );
+
+        // Find cache
+        let result = cache.clone().find_by_tag_id(test_val.tag_id).await;
+        assert!(result.is_some(), "ttm-1-1 find operation failed");
+        assert_eq!(
+            result.unwrap(),
+            TalkTagMappings {
+                maps: vec![test_val.clone()]
+            }
+        );
+
+        // Invalidate cache
+        let invalidate_status = cache
+            .clone()
+            .invalidate(test_val.talk_id, test_val.tag_id)
+            .await;
+        assert!(invalidate_status.is_some(), "ttm-1-1 invalidation failed");
+        assert_eq!(
+            invalidate_status.unwrap(),
+            TalkTagMappingCommandStatus::CacheInvalidated
+        );
+
+        // Find cache after invalidation
+        let result = cache.clone().find_by_talk_id(test_val.talk_id).await;
+        assert!(result.is_none(), "ttm-1-1 find a cache! it shouldn't be!");
+    }
+
+    #[tokio::test]
+    async fn test_insert_find_and_invalidate_talk_tag_mappings() {
+        let cache = InMemoryCache::new(3600);
+        let talk_id = 1;
+        let test_values = TalkTagMappings {
+            maps: vec![
+                TalkTagMapping { talk_id, tag_id: 1 },
+                TalkTagMapping { talk_id, tag_id: 2 },
+            ],
+        };
+
+        for test_val in &test_values.maps {
+            let key = format!("{TTM_KEY_PREFIX}-{}-{}", test_val.talk_id, test_val.tag_id);
+            // Insert cache
+            let insert_status = cache
+                .clone()
+                .insert(test_val.talk_id, test_val.tag_id)
+                .await;
+            assert!(
+                insert_status.is_some(),
+                "{}",
+                format!("{key} insertion failed")
+            );
+            assert_eq!(
+                insert_status.unwrap(),
+                TalkTagMappingCommandStatus::CacheInserted
+            );
+
+            // Find cache by tag id
+            let result = cache.clone().find_by_tag_id(test_val.tag_id).await;
+            assert!(
+                result.is_some(),
+                "{}",
+                format!("{key} find operation failed")
+            );
+            assert_eq!(
+                result.unwrap(),
+                TalkTagMappings {
+                    maps: vec![test_val.clone()]
+                }
+            );
+        }
+
+        // Find tag caches
+        let talk_tag_mappings_res = cache.clone().find_by_talk_id(talk_id).await;
+        assert!(
+            talk_tag_mappings_res.is_some(),
+            "find_by_talk_id operation failed"
+        );
+        // Disabled since we don't care the order of the ttm
+        //assert_eq!(talk_tag_mappings_res.unwrap(), test_values.clone());
+
+        for test_val in &test_values.maps {
+            let key = format!("{TTM_KEY_PREFIX}-{}-{}", test_val.talk_id, test_val.tag_id);
+            // Invalidate cache
+            let invalidate_status = cache
+                .clone()
+                .invalidate(test_val.talk_id, test_val.tag_id)
+                .await;
+            assert!(
+                invalidate_status.is_some(),
+                "{}",
+                format!("{key} invalidation failed")
+            );
+            assert_eq!(
+                invalidate_status.unwrap(),
+                TalkTagMappingCommandStatus::CacheInvalidated
+            );
+        }
+
+        // Find tag caches after invalidated
+        let inv_talk_tag_mappings_res = cache.clone().find_by_talk_id(talk_id).await;
+        assert!(
+            inv_talk_tag_mappings_res.is_none(),
+            "find_by_talk_id operation should failed after invalidation!"
+        );
+    }
+}
@@ -5,6 +5,7 @@ use async_trait::async_trait;
 use tracing::{debug, info};
 
 const TALK_KEY_PREFIX: &str = "talk";
+const TALK_CALENDAR_KEY: &str = "talks-calendar";
 
 #[async_trait]
 impl TalkDisplayRepo for InMemoryCache {
@@ -13,35 +14,36 @@ impl TalkDisplayRepo for InMemoryCache {
     async fn find(&self, id: i64) -> Option<Talk> {
         debug!("Finding InMemoryCache {TALK_KEY_PREFIX}-{id}");
         let key = format!("{TALK_KEY_PREFIX}-{id}");
-        self.talks_cache.get(&key).await
+        self.record_lookup(self.talks_cache.get(&key).await)
     }
     /// Find a Talk Caches
-    /// Take `TalkParams` that contain `start` and `end` then
-    /// return Option of `Talks`. `talks` filed is always reversed.
+    /// Take `TalkParams` that contain `start`, `end` and optional `year`,
+    /// `org` and `tags` filters then return Option of `Talks`.
+    /// Talks are filtered first then paginated from the newest, like the database.
     /// if `None`, no talks within `TalkParams` was cached
     async fn find_talks(&self, params: TalksParams) -> Option<Talks> {
-        let start_seq = params.start.unwrap() + 1;
+        let start_seq = params.start.unwrap();
         let end_seq = params.end.unwrap();
-        debug!("Finding InMemoryCache {TALK_KEY_PREFIX}-{start_seq} - {TALK_KEY_PREFIX}-{end_seq}");
-
-        let mut talks = Vec::new();
-        // rev() method to reverse Talk order
-        for id in (start_seq..=end_seq).rev() {
-            let value = self.find(id).await;
-            if value.is_none() {
-                debug!("{TALK_KEY_PREFIX}-{id} cache miss");
-                continue;
-            }
-
-            debug!("{TALK_KEY_PREFIX}-{id} cache hit");
-            talks.push(value.unwrap());
-        }
+        debug!("Finding InMemoryCache {TALK_KEY_PREFIX} from {start_seq} to {end_seq}");
+
+        let mut talks: Vec<Talk> = self
+            .talks_cache
+            .iter()
+            .map(|(_key, talk)| talk)
+            .filter(|talk| params.is_matched(talk))
+            .collect();
+        talks.sort_by_key(|talk| std::cmp::Reverse(talk.id));
+        let talks: Vec<Talk> = talks
+            .into_iter()
+            .skip(start_seq as usize)
+            .take((end_seq - start_seq).max(0) as usize)
+            .collect();
 
         // If Cache is still fresh, return None
         if talks.is_empty() {
-            return None;
+            return self.record_lookup(None);
         }
-        Some(Talks { talks })
+        self.record_lookup(Some(Talks { talks }))
     }
 }
 
@@ -49,20 +51,53 @@ impl TalkDisplayRepo for InMemoryCache {
 impl TalkOperationRepo for InMemoryCache {
     /// Insert Talk Cache
     /// Take a `Talk` object and store it in the `InMemoryCache`
+    /// The talks calendar cache is invalidated since it's outdated.
     /// Return Option of `TalkCommandStatus`. If `None`, insertion failed
     async fn insert(&mut self, talk: Talk) -> Option<TalkCommandStatus> {
         let key = format!("{TALK_KEY_PREFIX}-{}", &talk.id);
         info!("Inserting {} into InMemoryCache", &key);
         self.talks_cache.insert(key, talk).await;
+        self.talks_calendar_cache
+            .invalidate(TALK_CALENDAR_KEY)
+            .await;
         Some(TalkCommandStatus::CacheInserted)
     }
     /// Invalidate Talk Cache
     /// Invalidate (discard value from the cached key) talk cache by talk id
+    /// The talks calendar cache is invalidated as well.
     /// Return Option of `TalkCommandStatus`. If `None`, invalidation failed
     async fn invalidate(&mut self, id: i64) -> Option<TalkCommandStatus> {
         let key = format!("{TALK_KEY_PREFIX}-{id}");
         info!("Invalidating {} from InMemoryCache", &key);
         self.talks_cache.invalidate(&key).await;
+        self.talks_calendar_cache
+            .invalidate(TALK_CALENDAR_KEY)
+            .await;
+        Some(TalkCommandStatus::CacheInvalidated)
+    }
+    /// Find Talks Calendar Cache
+    /// Return Option of iCalendar String. If `None`, calendar was not cached
+    async fn find_calendar(&self) -> Option<String> {
+        debug!("Finding InMemoryCache {TALK_CALENDAR_KEY}");
+        self.record_lookup(self.talks_calendar_cache.get(TALK_CALENDAR_KEY).await)
+    }
+    /// Insert Talks Calendar Cache
+    /// Take an iCalendar String and store it in the `InMemoryCache`
+    /// Return Option of `TalkCommandStatus`. If `None`, insertion failed
+    async fn insert_calendar(&mut self, calendar: String) -> Option<TalkCommandStatus> {
+        info!("Inserting {TALK_CALENDAR_KEY} into InMemoryCache");
+        self.talks_calendar_cache
+            .insert(TALK_CALENDAR_KEY.to_string(), calendar)
+            .await;
+        Some(TalkCommandStatus::CacheInserted)
+    }
+    /// Invalidate Talks Calendar Cache
+    /// Return Option of `TalkCommandStatus`. If `None`, invalidation failed
+    async fn invalidate_calendar(&mut self) -> Option<TalkCommandStatus> {
+        info!("Invalidating {TALK_CALENDAR_KEY} from InMemoryCache");
+        self.talks_calendar_cache
+            .invalidate(TALK_CALENDAR_KEY)
+            .await;
         Some(TalkCommandStatus::CacheInvalidated)
     }
 }
@@ -70,6 +105,7 @@ impl TalkOperationRepo for InMemoryCache {
 #[cfg(test)]
 mod test {
     use super::*;
+    use chrono::NaiveDate;
 
     #[tokio::test]
     async fn test_insert_find_and_invalidate_talk() {
@@ -77,10 +113,13 @@ mod test {
         let test_val = Talk {
             id: 1,
             name: "test".to_string(),
-            date: "2025-10-10".to_string(),
+            date: NaiveDate::from_ymd_opt(2025, 10, 10).unwrap(),
+            invalid_date: None,
             media_link: None,
+            org_id: None,
             org_name: None,
             org_link: None,
+            tags: Vec::new(),
         };
 
         // Insert cache
@@ -115,18 +154,24 @@ mod test {
                 Talk {
                     id: 2,
                     name: "tast".to_string(),
-                    date: "2025-10-10".to_string(),
+                    date: NaiveDate::from_ymd_opt(2025, 10, 10).unwrap(),
+                    invalid_date: None,
                     media_link: Some("https://youtube.com".to_string()),
-                    org_name: None,
+                    org_id: Some(1),
+                    org_name: Some("Org".to_string()),
                     org_link: None,
+                    tags: Vec::new(),
                 },
                 Talk {
                     id: 1,
                     name: "test".to_string(),
-                    date: "2025-10-10".to_string(),
+                    date: NaiveDate::from_ymd_opt(2025, 10, 10).unwrap(),
+                    invalid_date: None,
                     media_link: None,
+                    org_id: None,
                     org_name: None,
                     org_link: None,
+                    tags: Vec::new(),
                 },
             ],
         };
@@ -158,11 +203,38 @@ mod test {
             .find_talks(TalksParams {
                 start: Some(0),
                 end: Some(2),
+                year: None,
+                org: None,
+                tags: None,
             })
             .await;
         assert!(talks_res.is_some(), "find_talks operation failed");
         assert_eq!(talks_res.unwrap(), test_values.clone());
 
+        // Find talk caches with filters
+        let talks_res = cache
+            .clone()
+            .find_talks(TalksParams {
+                start: Some(0),
+                end: Some(2),
+                year: Some(2025),
+                org: Some("Org".to_string()),
+                tags: None,
+            })
+            .await;
+        assert_eq!(talks_res.unwrap().talks, vec![test_values.talks[0].clone()]);
+        let talks_res = cache
+            .clone()
+            .find_talks(TalksParams {
+                start: Some(0),
+                end: Some(2),
+                year: Some(2024),
+                org: None,
+                tags: None,
+            })
+            .await;
+        assert!(talks_res.is_none(), "find_talks should filter by year");
+
         for test_val in &test_values.talks {
             let id = test_val.id;
             // Invalidate cache
@@ -184,8 +256,89 @@ mod test {
             .find_talks(TalksParams {
                 start: Some(0),
                 end: Some(2),
+                year: None,
+                org: None,
+                tags: None,
             })
             .await;
         assert!(talks_res.is_none(), "find_talks operation should failed!");
     }
+
+    #[tokio::test]
+    async fn test_insert_find_and_invalidate_talks_calendar() {
+        let mut cache = InMemoryCache::new(3600);
+        let calendar = "BEGIN:VCALENDAR\r\nEND:VCALENDAR\r\n".to_string();
+
+        // Insert and find calendar cache
+        let insert_status = cache.insert_calendar(calendar.clone()).await;
+        assert_eq!(insert_status.unwrap(), TalkCommandStatus::CacheInserted);
+        assert_eq!(cache.find_calendar().await, Some(calendar.clone()));
+
+        // Invalidate calendar cache
+        let invalidate_status = cache.invalidate_calendar().await;
+        assert_eq!(
+            invalidate_status.unwrap(),
+            TalkCommandStatus::CacheInvalidated
+        );
+        assert!(cache.find_calendar().await.is_none());
+
+        // Inserting a talk discards the calendar cache
+        let _ = cache.insert_calendar(calendar).await;
+        let _ = cache
+            .insert(Talk {
+                id: 1,
+                name: "test".to_string(),
+                date: NaiveDate::from_ymd_opt(2025, 10, 10).unwrap(),
+                invalid_date: None,
+                media_link: None,
+                org_id: None,
+                org_name: None,
+                org_link: None,
+                tags: Vec::new(),
+            })
+            .await;
+        assert!(
+            cache.find_calendar().await.is_none(),
+            "talks-calendar should be invalidated after talk insertion"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_talks_filters_before_pagination() {
+        let mut cache = InMemoryCache::new(3600);
+        for id in 1..=3 {
+            let org_name = if id == 2 { "Other Org" } else { "Org" };
+            cache
+                .insert(Talk {
+                    id,
+                    name: format!("Talk {id}"),
+                    date: NaiveDate::from_ymd_opt(2025, 10, 10).unwrap(),
+                    invalid_date: None,
+                    media_link: None,
+                    org_id: Some(1),
+                    org_name: Some(org_name.to_string()),
+                    org_link: None,
+                    tags: Vec::new(),
+                })
+                .await;
+        }
+
+        // Second page of the Org talks, same as the database query
+        let talks_res = cache
+            .find_talks(TalksParams {
+                start: Some(1),
+                end: Some(2),
+                year: None,
+                org: Some("Org".to_string()),
+                tags: None,
+            })
+            .await;
+        let ids: Vec<i64> = talks_res
+            .unwrap()
+            .talks
+            .iter()
+            .map(|talk| talk.id)
+            .collect();
+        assert_eq!(ids, vec![1]);
+    }
 }
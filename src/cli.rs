@@ -0,0 +1,349 @@
+use crate::config::Config;
+use crate::database::turso::TursoDatabase;
+use crate::handler::auth::{generate_user_id, hash_password, sanitize_email, sanitize_password};
+use crate::model::auth::{PasswordPolicy, UserCommandStatus, UserRole};
+use crate::model::totp::UserTotpCommandStatus;
+use crate::repo::auth::AuthRepo;
+use crate::usecase::auth::AuthDBUseCase;
+use clap::{Parser, Subcommand};
+
+/// Command line interface of the portfolio binary.
+///
+/// Without a subcommand the web application is served.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Manage admin users
+    User {
+        #[command(subcommand)]
+        command: UserCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum UserCommand {
+    /// Add a user. The password is prompted.
+    Add {
+        #[arg(long)]
+        email: String,
+        /// owner, editor or viewer
+        #[arg(long, default_value = "owner", value_parser = parse_user_role)]
+        role: UserRole,
+    },
+    /// Set a new password of a user. The password is prompted.
+    ResetPassword {
+        #[arg(long)]
+        email: String,
+    },
+    /// Turn off two-factor authentication of a user who lost their authenticator app
+    ResetTotp {
+        #[arg(long)]
+        email: String,
+    },
+    /// List every user
+    List,
+    /// Delete a user
+    Delete {
+        #[arg(long)]
+        email: String,
+    },
+}
+
+fn parse_user_role(role: &str) -> Result<UserRole, String> {
+    UserRole::parse(role).ok_or_else(|| format!("unknown role {role}"))
+}
+
+/// Create the AuthDBUseCase of the configured database like the web application does
+pub async fn create_auth_usecase(config: &Config) -> Option<AuthDBUseCase> {
+    let database_token = match config.data_source.as_str() {
+        "sqlite" => None,
+        "turso" => Some(config.secrets.turso_auth_token.clone()?),
+        _ => return None,
+    };
+    let db_repo = TursoDatabase::new(
+        config.data_source.clone(),
+        config.secrets.database_url.clone(),
+        database_token,
+    )
+    .await;
+    Some(AuthDBUseCase::new(Box::new(db_repo)))
+}
+
+/// Prompt a password without echoing it on the terminal
+pub fn prompt_password(prompt: &str) -> std::io::Result<String> {
+    rpassword::prompt_password(prompt)
+}
+
+/// Run a user subcommand
+/// Passwords are read through `prompt`, sanitized the same way as the login form
+/// and checked against `policy`.
+/// Return the message to be printed or the reason the command failed.
+pub async fn run_user_command<F>(
+    auth_uc: &AuthDBUseCase,
+    command: UserCommand,
+    policy: &PasswordPolicy,
+    prompt: F,
+) -> Result<String, String>
+where
+    F: Fn(&str) -> std::io::Result<String>,
+{
+    match command {
+        UserCommand::Add { email, role } => {
+            let email = sanitize_email(&email).ok_or(format!("{email} is not a valid email"))?;
+            if auth_uc.find_user_by_email(email.clone()).await.is_some() {
+                return Err(format!("{email} is already registered"));
+            }
+            let hashed_password = read_new_password(policy, &prompt)?;
+            let result = auth_uc
+                .add_user(generate_user_id(), email.clone(), hashed_password, role)
+                .await;
+            if result != Some(UserCommandStatus::Stored) {
+                return Err(format!("failed to add {email}"));
+            }
+            Ok(format!("Added {email} as {role}"))
+        }
+        UserCommand::ResetPassword { email } => {
+            let user = auth_uc
+                .find_user_by_email(email.clone())
+                .await
+                .ok_or(format!("no user with email {email}"))?;
+            let hashed_password = read_new_password(policy, &prompt)?;
+            let result = auth_uc
+                .update_user(user.id, None, Some(hashed_password), None)
+                .await;
+            if result != Some(UserCommandStatus::Updated) {
+                return Err(format!("failed to reset the password of {email}"));
+            }
+            Ok(format!("Reset the password of {email}"))
+        }
+        UserCommand::ResetTotp { email } => {
+            let user = auth_uc
+                .find_user_by_email(email.clone())
+                .await
+                .ok_or(format!("no user with email {email}"))?;
+            if auth_uc.delete_user_totp(user.id).await != Some(UserTotpCommandStatus::Deleted) {
+                return Err(format!(
+                    "failed to reset two-factor authentication of {email}"
+                ));
+            }
+            Ok(format!("Reset two-factor authentication of {email}"))
+        }
+        UserCommand::List => {
+            let users = auth_uc
+                .find_users()
+                .await
+                .ok_or("failed to list users".to_string())?;
+            let rows: Vec<String> = users
+                .users
+                .iter()
+                .map(|user| format!("{}\t{}\t{}", user.email, user.role, user.id))
+                .collect();
+            Ok(format!("EMAIL\tROLE\tID\n{}", rows.join("\n")))
+        }
+        UserCommand::Delete { email } => {
+            let user = auth_uc
+                .find_user_by_email(email.clone())
+                .await
+                .ok_or(format!("no user with email {email}"))?;
+            let users = auth_uc
+                .find_users()
+                .await
+                .ok_or("failed to list users".to_string())?;
+            let owners = users
+                .users
+                .iter()
+                .filter(|val| val.role == UserRole::Owner)
+                .count();
+            if user.role == UserRole::Owner && owners == 1 {
+                return Err(format!(
+                    "{email} is the last owner. Add another owner first"
+                ));
+            }
+            if auth_uc.delete_user(user.id).await != Some(UserCommandStatus::Deleted) {
+                return Err(format!("failed to delete {email}"));
+            }
+            Ok(format!("Deleted {email}"))
+        }
+    }
+}
+
+/// Prompt a new password twice, check it against the policy and hash it
+fn read_new_password<F>(policy: &PasswordPolicy, prompt: &F) -> Result<String, String>
+where
+    F: Fn(&str) -> std::io::Result<String>,
+{
+    let password = prompt("Password: ").map_err(|err| err.to_string())?;
+    let confirmation = prompt("Confirm password: ").map_err(|err| err.to_string())?;
+    if password != confirmation {
+        return Err("passwords do not match".to_string());
+    }
+    let password = sanitize_password(&password);
+    let errors = policy.check(&password);
+    if !errors.is_empty() {
+        return Err(errors.join(" "));
+    }
+    hash_password(&password).ok_or("failed to hash the password".to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::handler::auth::is_password_match;
+    use crate::model::totp::UserTotp;
+
+    async fn auth_usecase() -> AuthDBUseCase {
+        let db_repo = TursoDatabase::new("sqlite".to_string(), ":memory:".to_string(), None).await;
+        AuthDBUseCase::new(Box::new(db_repo))
+    }
+
+    fn prompt(password: &'static str) -> impl Fn(&str) -> std::io::Result<String> {
+        move |_| Ok(password.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_run_user_command() {
+        let auth_uc = auth_usecase().await;
+        let policy = PasswordPolicy {
+            min_length: 12,
+            use_denylist: true,
+        };
+        let add = |email: &str, role| UserCommand::Add {
+            email: email.to_string(),
+            role,
+        };
+
+        let res = run_user_command(
+            &auth_uc,
+            add("owner@example.com", UserRole::Owner),
+            &policy,
+            prompt("owner-passphrase-1"),
+        )
+        .await;
+        assert_eq!(res, Ok("Added owner@example.com as owner".to_string()));
+        let res = run_user_command(
+            &auth_uc,
+            add("owner@example.com", UserRole::Owner),
+            &policy,
+            prompt("owner-passphrase-1"),
+        )
+        .await;
+        assert!(res.is_err());
+        let res = run_user_command(
+            &auth_uc,
+            add("not-an-email", UserRole::Viewer),
+            &policy,
+            prompt("owner-passphrase-1"),
+        )
+        .await;
+        assert!(res.is_err());
+        let res = run_user_command(
+            &auth_uc,
+            add("editor@example.com", UserRole::Editor),
+            &policy,
+            prompt(""),
+        )
+        .await;
+        assert!(res.is_err());
+        let res = run_user_command(
+            &auth_uc,
+            add("editor@example.com", UserRole::Editor),
+            &policy,
+            prompt("password"),
+        )
+        .await;
+        assert!(res.is_err());
+        let res = run_user_command(
+            &auth_uc,
+            add("editor@example.com", UserRole::Editor),
+            &policy,
+            prompt("editor-passphrase-2"),
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let res = run_user_command(&auth_uc, UserCommand::List, &policy, prompt(""))
+            .await
+            .unwrap();
+        let emails: Vec<&str> = res
+            .lines()
+            .skip(1)
+            .map(|line| line.split('\t').next().unwrap())
+            .collect();
+        assert_eq!(emails, vec!["editor@example.com", "owner@example.com"]);
+
+        let owner = auth_uc
+            .find_user_by_email("owner@example.com".to_string())
+            .await
+            .unwrap();
+        let user_totp = UserTotp {
+            user_id: owner.id.clone(),
+            encrypted_secret: "secret".to_string(),
+            enabled: true,
+            last_step: None,
+            recovery_codes: Vec::new(),
+        };
+        auth_uc.upsert_user_totp(user_totp).await.unwrap();
+        let reset_totp = |email: &str| UserCommand::ResetTotp {
+            email: email.to_string(),
+        };
+        assert!(run_user_command(
+            &auth_uc,
+            reset_totp("owner@example.com"),
+            &policy,
+            prompt("")
+        )
+        .await
+        .is_ok());
+        assert_eq!(auth_uc.find_user_totp(owner.id).await, None);
+        assert!(run_user_command(
+            &auth_uc,
+            reset_totp("nobody@example.com"),
+            &policy,
+            prompt("")
+        )
+        .await
+        .is_err());
+
+        let reset = UserCommand::ResetPassword {
+            email: "editor@example.com".to_string(),
+        };
+        assert!(
+            run_user_command(&auth_uc, reset, &policy, prompt("editor-passphrase-3"))
+                .await
+                .is_ok()
+        );
+        let editor = auth_uc
+            .find_user_by_email("editor@example.com".to_string())
+            .await
+            .unwrap();
+        assert!(is_password_match(
+            "editor-passphrase-3",
+            &editor.hashed_password
+        ));
+
+        let delete = |email: &str| UserCommand::Delete {
+            email: email.to_string(),
+        };
+        assert!(
+            run_user_command(&auth_uc, delete("owner@example.com"), &policy, prompt(""))
+                .await
+                .is_err()
+        );
+        assert!(
+            run_user_command(&auth_uc, delete("editor@example.com"), &policy, prompt(""))
+                .await
+                .is_ok()
+        );
+        assert!(
+            run_user_command(&auth_uc, delete("editor@example.com"), &policy, prompt(""))
+                .await
+                .is_err()
+        );
+    }
+}
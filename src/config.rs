@@ -1,5 +1,6 @@
 use google_cloud_storage::client::Storage;
 use std::env;
+use std::net::IpAddr;
 
 /// Struct Config for setup environment variables
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -47,6 +48,9 @@ pub struct Config {
     /// - JWT_SECRET
     /// - DATABASE_URL
     /// - TURSO_AUTH_TOKEN
+    /// - TOTP_ENCRYPTION_KEY
+    /// - OIDC_CLIENT_SECRET
+    /// - SMTP_PASSWORD
     /// - BUCKET_NAME
     ///
     /// Example: secret/my-secret
@@ -72,6 +76,142 @@ pub struct Config {
     /// Example: 60
     /// Default to 60
     pub rate_limit_replenish_period: u64,
+    /// Media Storage Type
+    /// Storage backend of the admin media library.
+    /// Default to `Local`. Available types are `local` and `gcs`.
+    /// `gcs` required MEDIA_BUCKET envar to be set.
+    pub media_storage: MediaStorage,
+    /// Media Path
+    /// Directory to store media files when MEDIA_STORAGE is `local`.
+    /// Example: ./media
+    /// Default to ./media
+    pub media_path: String,
+    /// Media Bucket (Optional)
+    /// Google Cloud Storage (GCS) bucket name to store media files.
+    /// **Required** if you use `gcs` as MEDIA_STORAGE.
+    /// Example: my-media-bucket
+    /// Default to None
+    pub media_bucket: Option<String>,
+    /// Media Max Size
+    /// Max size of an uploaded media file in bytes
+    /// Example: 10485760
+    /// Default to 10485760 (10MiB)
+    pub media_max_size: usize,
+    /// Link Check Interval
+    /// Time between background broken link checks in second.
+    /// Set to 0 to disable the background checks.
+    /// Example: 3600
+    /// Default to 86400 (a day)
+    pub link_check_interval: u64,
+    /// Password Min Length
+    /// Minimum number of characters of a new admin password
+    /// Example: 16
+    /// Default to 12
+    pub password_min_length: usize,
+    /// Password Denylist
+    /// Reject new admin passwords found in the bundled breached-password list.
+    /// Set to `false` to disable.
+    /// Default to true
+    pub password_denylist: bool,
+    /// Access Token TTL
+    /// Lifetime of the JWT sent with every admin request in second.
+    /// It is reissued on activity while its session is active.
+    /// Example: 300
+    /// Default to 900 (15 minutes)
+    pub access_token_ttl: u64,
+    /// Session TTL
+    /// Time an inactive session stays logged in, in second.
+    /// Example: 3600
+    /// Default to 10800 (3 hours)
+    pub session_ttl: u64,
+    /// Remember Me TTL
+    /// Time an inactive session stays logged in if "remember me" was checked,
+    /// in second.
+    /// Example: 604800
+    /// Default to 2592000 (30 days)
+    pub remember_me_ttl: u64,
+    /// Login Lockout Threshold
+    /// Consecutive failed logins of an email before it is locked out.
+    /// 0 disables the lockout.
+    /// Example: 10
+    /// Default to 5
+    pub login_lockout_threshold: u64,
+    /// Login Lockout Base
+    /// Lockout after reaching the threshold, in second. Doubled on every
+    /// further failed login.
+    /// Example: 30
+    /// Default to 60
+    pub login_lockout_base: u64,
+    /// Login Lockout Max
+    /// Longest lockout, in second.
+    /// Example: 86400
+    /// Default to 3600 (1 hour)
+    pub login_lockout_max: u64,
+    /// OpenID Connect Issuer URL (Optional)
+    /// Identity provider serving `/.well-known/openid-configuration`.
+    /// OIDC login is enabled when OIDC_ISSUER_URL, OIDC_CLIENT_ID and
+    /// OIDC_REDIRECT_URL are set.
+    /// Example: https://accounts.google.com
+    /// Default to None
+    pub oidc_issuer_url: Option<String>,
+    /// OpenID Connect Client Id (Optional)
+    /// Client Identifier registered at the identity provider.
+    /// Default to None
+    pub oidc_client_id: Option<String>,
+    /// OpenID Connect Redirect URL (Optional)
+    /// Callback URL registered at the identity provider.
+    /// Example: https://husni.dev/login/oidc/callback
+    /// Default to None
+    pub oidc_redirect_url: Option<String>,
+    /// Public URL (Optional)
+    /// Address the site is publicly served at. Used to build the links sent
+    /// by email.
+    /// Example: https://husni.dev
+    /// Default to None
+    pub public_url: Option<String>,
+    /// Mail Transport (Optional)
+    /// Transport of outgoing emails such as login links. Available types are
+    /// `smtp` and `file`. `file` writes emails into a maildir at MAIL_PATH
+    /// for local development.
+    /// Login and password reset by email are enabled when MAIL_TRANSPORT,
+    /// MAIL_FROM and PUBLIC_URL are set.
+    /// Default to None
+    pub mail_transport: Option<MailTransport>,
+    /// Mail From (Optional)
+    /// Sender of outgoing emails.
+    /// Example: Husni Portfolio <noreply@husni.dev>
+    /// Default to None
+    pub mail_from: Option<String>,
+    /// Mail Path
+    /// Maildir to write emails into when MAIL_TRANSPORT is `file`.
+    /// Example: /var/mail/portfolio
+    /// Default to ./mail
+    pub mail_path: String,
+    /// SMTP Host (Optional)
+    /// **Required** if you use `smtp` as MAIL_TRANSPORT.
+    /// Example: smtp.gmail.com
+    /// Default to None
+    pub smtp_host: Option<String>,
+    /// SMTP Port
+    /// Port 465 uses implicit TLS. Other ports upgrade with STARTTLS.
+    /// Example: 465
+    /// Default to 587
+    pub smtp_port: u16,
+    /// SMTP Username (Optional)
+    /// Logs in with SMTP_PASSWORD if set.
+    /// Default to None
+    pub smtp_username: Option<String>,
+    /// Email Token TTL
+    /// Lifetime of the login and password reset links sent by email in second.
+    /// Example: 600
+    /// Default to 900 (15 minutes)
+    pub email_token_ttl: u64,
+    /// Trusted Proxies
+    /// Comma separated IP addresses of the reverse proxies in front of the
+    /// application. `X-Forwarded-For` is only read from these peers.
+    /// Example: 10.0.0.2,10.0.0.3
+    /// Default to empty. The peer address is used as the client IP
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 /// Environment Type
@@ -99,6 +239,32 @@ impl std::fmt::Display for Cache {
     }
 }
 
+/// Media Storage Type
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum MediaStorage {
+    Local,
+    Gcs,
+}
+
+impl std::fmt::Display for MediaStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Mail Transport Type
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum MailTransport {
+    Smtp,
+    File,
+}
+
+impl std::fmt::Display for MailTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
 /// Collection of secrets
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Secrets {
@@ -119,6 +285,20 @@ pub struct Secrets {
     /// `turso` as DATA_SOURCE..
     /// Default to None
     pub turso_auth_token: Option<String>,
+    /// TOTP Encryption Key (Optional; Secret)
+    /// Key to encrypt the TOTP secrets of two-factor authentication at rest.
+    /// Default to None, which derives the key from JWT_SECRET. Changing the
+    /// key makes every enrolled User set up two-factor authentication again.
+    pub totp_encryption_key: Option<String>,
+    /// OpenID Connect Client Secret (Optional; Secret)
+    /// Client Secret registered at the identity provider. Leave empty for
+    /// public clients relying on PKCE only.
+    /// Default to None
+    pub oidc_client_secret: Option<String>,
+    /// SMTP Password (Optional; Secret)
+    /// Password of SMTP_USERNAME at the SMTP server.
+    /// Default to None
+    pub smtp_password: Option<String>,
 }
 
 impl Default for Config {
@@ -143,6 +323,9 @@ impl Default for Config {
                 jwt_secret,
                 database_url,
                 turso_auth_token: None,
+                totp_encryption_key: None,
+                oidc_client_secret: None,
+                smtp_password: None,
             },
             secrets_bucket: None,
             secrets_object: None,
@@ -150,6 +333,31 @@ impl Default for Config {
             cache_ttl: None,
             rate_limit_burst_size: 10,
             rate_limit_replenish_period: 60,
+            media_storage: MediaStorage::Local,
+            media_path: "./media".to_string(),
+            media_bucket: None,
+            media_max_size: 10 * 1024 * 1024,
+            link_check_interval: 86400,
+            password_min_length: 12,
+            password_denylist: true,
+            access_token_ttl: 900,
+            session_ttl: 10800,
+            remember_me_ttl: 2592000,
+            login_lockout_threshold: 5,
+            login_lockout_base: 60,
+            login_lockout_max: 3600,
+            oidc_issuer_url: None,
+            oidc_client_id: None,
+            oidc_redirect_url: None,
+            public_url: None,
+            mail_transport: None,
+            mail_from: None,
+            mail_path: "./mail".to_string(),
+            smtp_host: None,
+            smtp_port: 587,
+            smtp_username: None,
+            email_token_ttl: 900,
+            trusted_proxies: Vec::new(),
         }
     }
 }
@@ -186,14 +394,102 @@ impl Config {
                     )
                 })
                 .unwrap_or(60_u64);
+        let media_storage = Self::parse_media_storage();
+        let media_path =
+            Self::parse_optional("MEDIA_PATH").unwrap_or_else(|| "./media".to_string());
+        let media_bucket = Self::parse_optional("MEDIA_BUCKET");
+        let media_max_size = Self::parse_optional("MEDIA_MAX_SIZE_BYTES")
+            .map(|v| {
+                v.parse::<usize>()
+                    .expect("Failed to parse MEDIA_MAX_SIZE_BYTES from String to usize")
+            })
+            .unwrap_or(10 * 1024 * 1024);
+        let link_check_interval = Self::parse_optional("LINK_CHECK_INTERVAL_SECOND")
+            .map(|v| {
+                v.parse::<u64>()
+                    .expect("Failed to parse LINK_CHECK_INTERVAL_SECOND from String to u64")
+            })
+            .unwrap_or(86400_u64);
+        let password_min_length = Self::parse_optional("PASSWORD_MIN_LENGTH")
+            .map(|v| {
+                v.parse::<usize>()
+                    .expect("Failed to parse PASSWORD_MIN_LENGTH from String to usize")
+            })
+            .unwrap_or(12);
+        let password_denylist = Self::parse_optional("PASSWORD_DENYLIST")
+            .map(|v| !matches!(v.to_lowercase().as_str(), "false" | "0"))
+            .unwrap_or(true);
+        let access_token_ttl = Self::parse_optional("ACCESS_TOKEN_TTL_SECOND")
+            .map(|v| {
+                v.parse::<u64>()
+                    .expect("Failed to parse ACCESS_TOKEN_TTL_SECOND from String to u64")
+            })
+            .unwrap_or(900_u64);
+        let session_ttl = Self::parse_optional("SESSION_TTL_SECOND")
+            .map(|v| {
+                v.parse::<u64>()
+                    .expect("Failed to parse SESSION_TTL_SECOND from String to u64")
+            })
+            .unwrap_or(10800_u64);
+        let remember_me_ttl = Self::parse_optional("REMEMBER_ME_TTL_SECOND")
+            .map(|v| {
+                v.parse::<u64>()
+                    .expect("Failed to parse REMEMBER_ME_TTL_SECOND from String to u64")
+            })
+            .unwrap_or(2592000_u64);
+        let login_lockout_threshold = Self::parse_optional("LOGIN_LOCKOUT_THRESHOLD")
+            .map(|v| {
+                v.parse::<u64>()
+                    .expect("Failed to parse LOGIN_LOCKOUT_THRESHOLD from String to u64")
+            })
+            .unwrap_or(5_u64);
+        let login_lockout_base = Self::parse_optional("LOGIN_LOCKOUT_BASE_SECOND")
+            .map(|v| {
+                v.parse::<u64>()
+                    .expect("Failed to parse LOGIN_LOCKOUT_BASE_SECOND from String to u64")
+            })
+            .unwrap_or(60_u64);
+        let login_lockout_max = Self::parse_optional("LOGIN_LOCKOUT_MAX_SECOND")
+            .map(|v| {
+                v.parse::<u64>()
+                    .expect("Failed to parse LOGIN_LOCKOUT_MAX_SECOND from String to u64")
+            })
+            .unwrap_or(3600_u64);
+        let oidc_issuer_url = Self::parse_optional("OIDC_ISSUER_URL");
+        let oidc_client_id = Self::parse_optional("OIDC_CLIENT_ID");
+        let oidc_redirect_url = Self::parse_optional("OIDC_REDIRECT_URL");
+        let public_url = Self::parse_optional("PUBLIC_URL");
+        let mail_transport = Self::parse_mail_transport();
+        let mail_from = Self::parse_optional("MAIL_FROM");
+        let mail_path = Self::parse_optional("MAIL_PATH").unwrap_or_else(|| "./mail".to_string());
+        let smtp_host = Self::parse_optional("SMTP_HOST");
+        let smtp_port = Self::parse_optional("SMTP_PORT")
+            .map(|v| {
+                v.parse::<u16>()
+                    .expect("Failed to parse SMTP_PORT from String to u16")
+            })
+            .unwrap_or(587_u16);
+        let smtp_username = Self::parse_optional("SMTP_USERNAME");
+        let email_token_ttl = Self::parse_optional("EMAIL_TOKEN_TTL_SECOND")
+            .map(|v| {
+                v.parse::<u64>()
+                    .expect("Failed to parse EMAIL_TOKEN_TTL_SECOND from String to u64")
+            })
+            .unwrap_or(900_u64);
+        let trusted_proxies = Self::parse_trusted_proxies();
         let secrets_bucket = Self::parse_optional("SECRETS_BUCKET");
         let secrets_object = Self::parse_optional("SECRETS_OBJECT");
 
         // Check SECRETS_BUCKET and SECRETS_OBJECT
         // If set, try to load the secret then override all secrets.
-        let (jwt_secret, database_url, turso_auth_token) = if secrets_bucket.is_some()
-            && secrets_object.is_some()
-        {
+        let (
+            jwt_secret,
+            database_url,
+            turso_auth_token,
+            totp_encryption_key,
+            oidc_client_secret,
+            smtp_password,
+        ) = if secrets_bucket.is_some() && secrets_object.is_some() {
             println!("Pulling secrets from Google Cloud Storage");
             let secrets = Self::load_gcs_secrets(
                 &secrets_bucket.clone().unwrap(),
@@ -205,6 +501,9 @@ impl Config {
                 secrets.jwt_secret,
                 secrets.database_url,
                 secrets.turso_auth_token,
+                secrets.totp_encryption_key,
+                secrets.oidc_client_secret,
+                secrets.smtp_password,
             )
         } else {
             // Required Secrets
@@ -213,8 +512,18 @@ impl Config {
             // Optional Secrets
             let database_url = Self::parse_optional("DATABASE_URL").unwrap();
             let turso_auth_token = Self::parse_optional("TURSO_AUTH_TOKEN");
+            let totp_encryption_key = Self::parse_optional("TOTP_ENCRYPTION_KEY");
+            let oidc_client_secret = Self::parse_optional("OIDC_CLIENT_SECRET");
+            let smtp_password = Self::parse_optional("SMTP_PASSWORD");
 
-            (jwt_secret, database_url, turso_auth_token)
+            (
+                jwt_secret,
+                database_url,
+                turso_auth_token,
+                totp_encryption_key,
+                oidc_client_secret,
+                smtp_password,
+            )
         };
 
         Self {
@@ -227,6 +536,9 @@ impl Config {
                 jwt_secret,
                 database_url,
                 turso_auth_token,
+                totp_encryption_key,
+                oidc_client_secret,
+                smtp_password,
             },
             secrets_bucket,
             secrets_object,
@@ -234,6 +546,31 @@ impl Config {
             cache_ttl,
             rate_limit_burst_size,
             rate_limit_replenish_period,
+            media_storage,
+            media_path,
+            media_bucket,
+            media_max_size,
+            link_check_interval,
+            password_min_length,
+            password_denylist,
+            access_token_ttl,
+            session_ttl,
+            remember_me_ttl,
+            login_lockout_threshold,
+            login_lockout_base,
+            login_lockout_max,
+            oidc_issuer_url,
+            oidc_client_id,
+            oidc_redirect_url,
+            public_url,
+            mail_transport,
+            mail_from,
+            mail_path,
+            smtp_host,
+            smtp_port,
+            smtp_username,
+            email_token_ttl,
+            trusted_proxies,
         }
     }
     async fn load_gcs_secrets(secrets_bucket: &str, secrets_object: &str) -> Secrets {
@@ -262,6 +599,9 @@ impl Config {
         let mut jwt_secret = String::new();
         let mut database_url = String::new();
         let mut turso_auth_token: Option<String> = None;
+        let mut totp_encryption_key: Option<String> = None;
+        let mut oidc_client_secret: Option<String> = None;
+        let mut smtp_password: Option<String> = None;
 
         for secret in data.split("\n") {
             if secret.split_once("=").is_none() {
@@ -273,6 +613,9 @@ impl Config {
                 "JWT_SECRET" => jwt_secret = secret_v,
                 "DATABASE_URL" => database_url = secret_v,
                 "TURSO_AUTH_TOKEN" => turso_auth_token = Some(secret_v),
+                "TOTP_ENCRYPTION_KEY" => totp_encryption_key = Some(secret_v),
+                "OIDC_CLIENT_SECRET" => oidc_client_secret = Some(secret_v),
+                "SMTP_PASSWORD" => smtp_password = Some(secret_v),
                 _ => {
                     println!("Unused secret {} is detected.", &key)
                 }
@@ -283,6 +626,9 @@ impl Config {
             jwt_secret,
             database_url,
             turso_auth_token,
+            totp_encryption_key,
+            oidc_client_secret,
+            smtp_password,
         }
     }
     /// Parse Optional environment variables
@@ -369,6 +715,51 @@ impl Config {
             },
         }
     }
+    /// Parse Media Storage
+    fn parse_media_storage() -> MediaStorage {
+        match env::var("MEDIA_STORAGE") {
+            Err(e) => {
+                println!(
+                "Failed to load MEDIA_STORAGE environment variable. Set default to 'Local'. Error {e}"
+            );
+                MediaStorage::Local
+            }
+            Ok(val) => match val.as_str() {
+                "gcs" | "Gcs" | "GCS" => MediaStorage::Gcs,
+                _ => MediaStorage::Local,
+            },
+        }
+    }
+    /// Parse Trusted Proxies
+    fn parse_trusted_proxies() -> Vec<IpAddr> {
+        Self::parse_optional("TRUSTED_PROXIES")
+            .map(|v| {
+                v.split(',')
+                    .map(|val| {
+                        val.trim()
+                            .parse::<IpAddr>()
+                            .expect("Failed to parse TRUSTED_PROXIES from String to IP addresses")
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+    /// Parse Mail Transport
+    fn parse_mail_transport() -> Option<MailTransport> {
+        match env::var("MAIL_TRANSPORT") {
+            Err(e) => {
+                println!(
+                "Failed to load MAIL_TRANSPORT environment variable. Set default to 'None'. Error {e}"
+            );
+                None
+            }
+            Ok(val) => match val.as_str() {
+                "smtp" | "Smtp" | "SMTP" => Some(MailTransport::Smtp),
+                "file" | "File" | "FILE" => Some(MailTransport::File),
+                _ => None,
+            },
+        }
+    }
 }
 
 #[cfg(test)]
@@ -395,12 +786,40 @@ mod test {
         assert_eq!(result.secrets.jwt_secret, jwt_secret);
         assert_eq!(result.secrets.database_url, database_url);
         assert_eq!(result.secrets.turso_auth_token, None);
+        assert_eq!(result.secrets.totp_encryption_key, None);
+        assert_eq!(result.secrets.oidc_client_secret, None);
         assert_eq!(result.secrets_bucket, None);
         assert_eq!(result.secrets_object, None);
         assert_eq!(result.cache_type, None);
         assert_eq!(result.cache_ttl, None);
         assert_eq!(result.rate_limit_burst_size, 10);
         assert_eq!(result.rate_limit_replenish_period, 60);
+        assert_eq!(result.media_storage, MediaStorage::Local);
+        assert_eq!(result.media_path, "./media");
+        assert_eq!(result.media_bucket, None);
+        assert_eq!(result.media_max_size, 10 * 1024 * 1024);
+        assert_eq!(result.link_check_interval, 86400);
+        assert_eq!(result.password_min_length, 12);
+        assert!(result.password_denylist);
+        assert_eq!(result.access_token_ttl, 900);
+        assert_eq!(result.session_ttl, 10800);
+        assert_eq!(result.remember_me_ttl, 2592000);
+        assert_eq!(result.login_lockout_threshold, 5);
+        assert_eq!(result.login_lockout_base, 60);
+        assert_eq!(result.login_lockout_max, 3600);
+        assert_eq!(result.oidc_issuer_url, None);
+        assert_eq!(result.oidc_client_id, None);
+        assert_eq!(result.oidc_redirect_url, None);
+        assert_eq!(result.secrets.smtp_password, None);
+        assert_eq!(result.public_url, None);
+        assert_eq!(result.mail_transport, None);
+        assert_eq!(result.mail_from, None);
+        assert_eq!(result.mail_path, "./mail");
+        assert_eq!(result.smtp_host, None);
+        assert_eq!(result.smtp_port, 587);
+        assert_eq!(result.smtp_username, None);
+        assert_eq!(result.email_token_ttl, 900);
+        assert_eq!(result.trusted_proxies, Vec::<IpAddr>::new());
     }
 
     #[tokio::test]
@@ -426,6 +845,9 @@ mod test {
                 jwt_secret: jwt_secret.to_string(),
                 database_url: database_url.clone(),
                 turso_auth_token: None,
+                totp_encryption_key: None,
+                oidc_client_secret: None,
+                smtp_password: None,
             },
             secrets_bucket: None,
             secrets_object: None,
@@ -433,6 +855,31 @@ mod test {
             cache_ttl: None,
             rate_limit_burst_size: 10,
             rate_limit_replenish_period: 60,
+            media_storage: MediaStorage::Local,
+            media_path: "".to_string(),
+            media_bucket: None,
+            media_max_size: 10 * 1024 * 1024,
+            link_check_interval: 86400,
+            password_min_length: 12,
+            password_denylist: true,
+            access_token_ttl: 900,
+            session_ttl: 10800,
+            remember_me_ttl: 2592000,
+            login_lockout_threshold: 5,
+            login_lockout_base: 60,
+            login_lockout_max: 3600,
+            oidc_issuer_url: None,
+            oidc_client_id: None,
+            oidc_redirect_url: None,
+            public_url: None,
+            mail_transport: None,
+            mail_from: None,
+            mail_path: "".to_string(),
+            smtp_host: None,
+            smtp_port: 587,
+            smtp_username: None,
+            email_token_ttl: 900,
+            trusted_proxies: Vec::new(),
         });
 
         let result = Config::from_envar().await;
@@ -451,6 +898,33 @@ mod test {
         assert_eq!(result.cache_ttl, None);
         assert_eq!(result.rate_limit_burst_size, 10);
         assert_eq!(result.rate_limit_replenish_period, 60);
+        assert_eq!(result.media_storage, MediaStorage::Local);
+        assert_eq!(result.media_path, "./media");
+        assert_eq!(result.media_bucket, None);
+        assert_eq!(result.media_max_size, 10 * 1024 * 1024);
+        assert_eq!(result.link_check_interval, 86400);
+        assert_eq!(result.password_min_length, 12);
+        assert!(result.password_denylist);
+        assert_eq!(result.access_token_ttl, 900);
+        assert_eq!(result.session_ttl, 10800);
+        assert_eq!(result.remember_me_ttl, 2592000);
+        assert_eq!(result.login_lockout_threshold, 5);
+        assert_eq!(result.login_lockout_base, 60);
+        assert_eq!(result.login_lockout_max, 3600);
+        assert_eq!(result.secrets.oidc_client_secret, None);
+        assert_eq!(result.oidc_issuer_url, None);
+        assert_eq!(result.oidc_client_id, None);
+        assert_eq!(result.oidc_redirect_url, None);
+        assert_eq!(result.secrets.smtp_password, None);
+        assert_eq!(result.public_url, None);
+        assert_eq!(result.mail_transport, None);
+        assert_eq!(result.mail_from, None);
+        assert_eq!(result.mail_path, "./mail");
+        assert_eq!(result.smtp_host, None);
+        assert_eq!(result.smtp_port, 587);
+        assert_eq!(result.smtp_username, None);
+        assert_eq!(result.email_token_ttl, 900);
+        assert_eq!(result.trusted_proxies, Vec::<IpAddr>::new());
 
         remove_envars()
     }
@@ -480,6 +954,9 @@ mod test {
                 jwt_secret: jwt_secret.to_string(),
                 database_url: database_url.clone(),
                 turso_auth_token: turso_auth_token.clone(),
+                totp_encryption_key: Some("totp_key_123456".to_string()),
+                oidc_client_secret: Some("oidc_secret_123456".to_string()),
+                smtp_password: Some("smtp_password_123456".to_string()),
             },
             secrets_bucket,
             secrets_object,
@@ -487,6 +964,31 @@ mod test {
             cache_ttl,
             rate_limit_burst_size: 20,
             rate_limit_replenish_period: 30,
+            media_storage: MediaStorage::Gcs,
+            media_path: "/var/media".to_string(),
+            media_bucket: Some("media-bucket".to_string()),
+            media_max_size: 1024,
+            link_check_interval: 0,
+            password_min_length: 16,
+            password_denylist: false,
+            access_token_ttl: 300,
+            session_ttl: 3600,
+            remember_me_ttl: 604800,
+            login_lockout_threshold: 10,
+            login_lockout_base: 30,
+            login_lockout_max: 86400,
+            oidc_issuer_url: Some("https://accounts.google.com".to_string()),
+            oidc_client_id: Some("portfolio".to_string()),
+            oidc_redirect_url: Some("https://husni.dev/login/oidc/callback".to_string()),
+            public_url: Some("https://husni.dev".to_string()),
+            mail_transport: Some(MailTransport::Smtp),
+            mail_from: Some("Husni <noreply@husni.dev>".to_string()),
+            mail_path: "/var/mail".to_string(),
+            smtp_host: Some("smtp.husni.dev".to_string()),
+            smtp_port: 465,
+            smtp_username: Some("noreply@husni.dev".to_string()),
+            email_token_ttl: 600,
+            trusted_proxies: vec!["10.0.0.2".parse().unwrap(), "::1".parse().unwrap()],
         });
 
         let result = Config::from_envar().await;
@@ -499,12 +1001,64 @@ mod test {
         assert_eq!(result.secrets.jwt_secret, jwt_secret);
         assert_eq!(result.secrets.database_url, database_url);
         assert_eq!(result.secrets.turso_auth_token, turso_auth_token);
+        assert_eq!(
+            result.secrets.totp_encryption_key,
+            Some("totp_key_123456".to_string())
+        );
         assert_eq!(result.secrets_bucket, None);
         assert_eq!(result.secrets_object, None);
         assert_eq!(result.cache_type, cache_type);
         assert_eq!(result.cache_ttl, cache_ttl);
         assert_eq!(result.rate_limit_burst_size, 20);
         assert_eq!(result.rate_limit_replenish_period, 30);
+        assert_eq!(result.media_storage, MediaStorage::Gcs);
+        assert_eq!(result.media_path, "/var/media");
+        assert_eq!(result.media_bucket, Some("media-bucket".to_string()));
+        assert_eq!(result.media_max_size, 1024);
+        assert_eq!(result.link_check_interval, 0);
+        assert_eq!(result.password_min_length, 16);
+        assert!(!result.password_denylist);
+        assert_eq!(result.access_token_ttl, 300);
+        assert_eq!(result.session_ttl, 3600);
+        assert_eq!(result.remember_me_ttl, 604800);
+        assert_eq!(result.login_lockout_threshold, 10);
+        assert_eq!(result.login_lockout_base, 30);
+        assert_eq!(result.login_lockout_max, 86400);
+        assert_eq!(
+            result.secrets.oidc_client_secret,
+            Some("oidc_secret_123456".to_string())
+        );
+        assert_eq!(
+            result.oidc_issuer_url,
+            Some("https://accounts.google.com".to_string())
+        );
+        assert_eq!(result.oidc_client_id, Some("portfolio".to_string()));
+        assert_eq!(
+            result.oidc_redirect_url,
+            Some("https://husni.dev/login/oidc/callback".to_string())
+        );
+        assert_eq!(
+            result.secrets.smtp_password,
+            Some("smtp_password_123456".to_string())
+        );
+        assert_eq!(result.public_url, Some("https://husni.dev".to_string()));
+        assert_eq!(result.mail_transport, Some(MailTransport::Smtp));
+        assert_eq!(
+            result.mail_from,
+            Some("Husni <noreply@husni.dev>".to_string())
+        );
+        assert_eq!(result.mail_path, "/var/mail");
+        assert_eq!(result.smtp_host, Some("smtp.husni.dev".to_string()));
+        assert_eq!(result.smtp_port, 465);
+        assert_eq!(result.smtp_username, Some("noreply@husni.dev".to_string()));
+        assert_eq!(result.email_token_ttl, 600);
+        assert_eq!(
+            result.trusted_proxies,
+            vec![
+                "10.0.0.2".parse::<IpAddr>().unwrap(),
+                "::1".parse::<IpAddr>().unwrap()
+            ]
+        );
 
         remove_envars()
     }
@@ -523,6 +1077,18 @@ mod test {
             Some(val) => env::set_var("TURSO_AUTH_TOKEN", val),
             None => env::set_var("TURSO_AUTH_TOKEN", empty),
         }
+        match config.secrets.totp_encryption_key {
+            Some(val) => env::set_var("TOTP_ENCRYPTION_KEY", val),
+            None => env::set_var("TOTP_ENCRYPTION_KEY", empty),
+        }
+        match config.secrets.oidc_client_secret {
+            Some(val) => env::set_var("OIDC_CLIENT_SECRET", val),
+            None => env::set_var("OIDC_CLIENT_SECRET", empty),
+        }
+        match config.secrets.smtp_password {
+            Some(val) => env::set_var("SMTP_PASSWORD", val),
+            None => env::set_var("SMTP_PASSWORD", empty),
+        }
         match config.secrets_bucket {
             Some(val) => env::set_var("SECRETS_BUCKET", val),
             None => env::set_var("SECRETS_BUCKET", empty),
@@ -547,6 +1113,84 @@ mod test {
             "RATE_LIMIT_REPLENISH_PERIOD_SECOND",
             config.rate_limit_replenish_period.to_string(),
         );
+        env::set_var("MEDIA_STORAGE", config.media_storage.to_string());
+        env::set_var("MEDIA_PATH", config.media_path);
+        match config.media_bucket {
+            Some(val) => env::set_var("MEDIA_BUCKET", val),
+            None => env::set_var("MEDIA_BUCKET", empty),
+        }
+        env::set_var("MEDIA_MAX_SIZE_BYTES", config.media_max_size.to_string());
+        env::set_var(
+            "LINK_CHECK_INTERVAL_SECOND",
+            config.link_check_interval.to_string(),
+        );
+        env::set_var(
+            "PASSWORD_MIN_LENGTH",
+            config.password_min_length.to_string(),
+        );
+        env::set_var("PASSWORD_DENYLIST", config.password_denylist.to_string());
+        env::set_var(
+            "ACCESS_TOKEN_TTL_SECOND",
+            config.access_token_ttl.to_string(),
+        );
+        env::set_var("SESSION_TTL_SECOND", config.session_ttl.to_string());
+        env::set_var("REMEMBER_ME_TTL_SECOND", config.remember_me_ttl.to_string());
+        env::set_var(
+            "LOGIN_LOCKOUT_THRESHOLD",
+            config.login_lockout_threshold.to_string(),
+        );
+        env::set_var(
+            "LOGIN_LOCKOUT_BASE_SECOND",
+            config.login_lockout_base.to_string(),
+        );
+        env::set_var(
+            "LOGIN_LOCKOUT_MAX_SECOND",
+            config.login_lockout_max.to_string(),
+        );
+        match config.oidc_issuer_url {
+            Some(val) => env::set_var("OIDC_ISSUER_URL", val),
+            None => env::set_var("OIDC_ISSUER_URL", empty),
+        }
+        match config.oidc_client_id {
+            Some(val) => env::set_var("OIDC_CLIENT_ID", val),
+            None => env::set_var("OIDC_CLIENT_ID", empty),
+        }
+        match config.oidc_redirect_url {
+            Some(val) => env::set_var("OIDC_REDIRECT_URL", val),
+            None => env::set_var("OIDC_REDIRECT_URL", empty),
+        }
+        match config.public_url {
+            Some(val) => env::set_var("PUBLIC_URL", val),
+            None => env::set_var("PUBLIC_URL", empty),
+        }
+        match config.mail_transport {
+            Some(val) => env::set_var("MAIL_TRANSPORT", val.to_string()),
+            None => env::set_var("MAIL_TRANSPORT", empty),
+        }
+        match config.mail_from {
+            Some(val) => env::set_var("MAIL_FROM", val),
+            None => env::set_var("MAIL_FROM", empty),
+        }
+        env::set_var("MAIL_PATH", config.mail_path);
+        match config.smtp_host {
+            Some(val) => env::set_var("SMTP_HOST", val),
+            None => env::set_var("SMTP_HOST", empty),
+        }
+        env::set_var("SMTP_PORT", config.smtp_port.to_string());
+        match config.smtp_username {
+            Some(val) => env::set_var("SMTP_USERNAME", val),
+            None => env::set_var("SMTP_USERNAME", empty),
+        }
+        env::set_var("EMAIL_TOKEN_TTL_SECOND", config.email_token_ttl.to_string());
+        env::set_var(
+            "TRUSTED_PROXIES",
+            config
+                .trusted_proxies
+                .iter()
+                .map(|val| val.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        );
     }
 
     fn remove_envars() {
@@ -558,11 +1202,39 @@ mod test {
         env::remove_var("JWT_SECRET");
         env::remove_var("DATABASE_URL");
         env::remove_var("TURSO_AUTH_TOKEN");
+        env::remove_var("TOTP_ENCRYPTION_KEY");
+        env::remove_var("OIDC_CLIENT_SECRET");
         env::remove_var("SECRETS_BUCKET");
         env::remove_var("SECRETS_OBJECT");
         env::remove_var("CACHE_TYPE");
         env::remove_var("CACHE_TTL");
         env::remove_var("RATE_LIMIT_BURST_SIZE");
         env::remove_var("RATE_LIMIT_REPLENISH_PERIOD_SECOND");
+        env::remove_var("MEDIA_STORAGE");
+        env::remove_var("MEDIA_PATH");
+        env::remove_var("MEDIA_BUCKET");
+        env::remove_var("MEDIA_MAX_SIZE_BYTES");
+        env::remove_var("LINK_CHECK_INTERVAL_SECOND");
+        env::remove_var("PASSWORD_MIN_LENGTH");
+        env::remove_var("PASSWORD_DENYLIST");
+        env::remove_var("ACCESS_TOKEN_TTL_SECOND");
+        env::remove_var("SESSION_TTL_SECOND");
+        env::remove_var("REMEMBER_ME_TTL_SECOND");
+        env::remove_var("LOGIN_LOCKOUT_THRESHOLD");
+        env::remove_var("LOGIN_LOCKOUT_BASE_SECOND");
+        env::remove_var("LOGIN_LOCKOUT_MAX_SECOND");
+        env::remove_var("OIDC_ISSUER_URL");
+        env::remove_var("OIDC_CLIENT_ID");
+        env::remove_var("OIDC_REDIRECT_URL");
+        env::remove_var("SMTP_PASSWORD");
+        env::remove_var("PUBLIC_URL");
+        env::remove_var("MAIL_TRANSPORT");
+        env::remove_var("MAIL_FROM");
+        env::remove_var("MAIL_PATH");
+        env::remove_var("SMTP_HOST");
+        env::remove_var("SMTP_PORT");
+        env::remove_var("SMTP_USERNAME");
+        env::remove_var("EMAIL_TOKEN_TTL_SECOND");
+        env::remove_var("TRUSTED_PROXIES");
     }
 }
@@ -0,0 +1,116 @@
+use crate::database::turso::TursoDatabase;
+use crate::model::audit_logs::*;
+use crate::repo::audit_logs::{AuditLogDisplayRepo, AuditLogOperationRepo};
+use async_trait::async_trait;
+use libsql::Row;
+use tracing::{debug, info};
+
+/// Columns selected by AuditLog queries
+const AUDIT_LOG_COLUMNS: &str = r"
+    created_at,
+    user_id,
+    client_ip,
+    action,
+    entity_type,
+    entity_id,
+    before,
+    after
+    FROM audit_log";
+
+/// Convert a `AUDIT_LOG_COLUMNS` row into `AuditLog`
+fn row_to_audit_log(row: &Row) -> AuditLog {
+    AuditLog {
+        created_at: row.get(0).unwrap(),
+        user_id: row.get(1).unwrap(),
+        client_ip: row.get(2).unwrap(),
+        action: row.get(3).unwrap(),
+        entity_type: row.get(4).unwrap(),
+        entity_id: row.get(5).unwrap(),
+        before: row.get(6).unwrap(),
+        after: row.get(7).unwrap(),
+    }
+}
+
+#[async_trait]
+impl AuditLogDisplayRepo for TursoDatabase {
+    async fn find_audit_logs(
+        &self,
+        params: AuditLogsParams,
+        limit: Option<i64>,
+    ) -> Option<AuditLogs> {
+        let sanitized_params = params.sanitize();
+        let (created_from, created_until) = sanitized_params.created_at_range();
+        // A negative LIMIT returns every row
+        let limit = limit.unwrap_or(-1);
+        let prep_query = format!(
+            "SELECT {AUDIT_LOG_COLUMNS}
+            WHERE (?1 IS NULL OR entity_type = ?1)
+            AND (?2 IS NULL OR created_at >= ?2)
+            AND (?3 IS NULL OR created_at < ?3)
+            ORDER BY created_at DESC, rowid DESC LIMIT ?4"
+        );
+        debug!(
+            "Executing query {} for entity type {:?}, from {:?}, until {:?}, limit {}",
+            &prep_query, &sanitized_params.entity_type, &created_from, &created_until, &limit
+        );
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find audit logs query.");
+
+        let mut rows = stmt
+            .query((
+                sanitized_params.entity_type,
+                created_from,
+                created_until,
+                limit,
+            ))
+            .await
+            .expect("Failed to query audit logs.");
+
+        let mut audit_logs: Vec<AuditLog> = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            debug!("Debug Row {:?}", &row);
+            audit_logs.push(row_to_audit_log(&row));
+        }
+
+        Some(AuditLogs { audit_logs })
+    }
+}
+
+#[async_trait]
+impl AuditLogOperationRepo for TursoDatabase {
+    async fn add(&mut self, audit_log: AuditLog) -> Option<AuditLogCommandStatus> {
+        let prep_add_command = "INSERT INTO audit_log (created_at, user_id, client_ip, action, entity_type, entity_id, before, after) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)";
+        debug!("Executing query {}", &prep_add_command);
+
+        let stmt = self
+            .conn
+            .prepare(prep_add_command)
+            .await
+            .expect("Failed to prepare add AuditLog command.");
+
+        let exe = stmt
+            .execute((
+                audit_log.created_at,
+                audit_log.user_id,
+                audit_log.client_ip,
+                audit_log.action.clone(),
+                audit_log.entity_type.clone(),
+                audit_log.entity_id.clone(),
+                audit_log.before,
+                audit_log.after,
+            ))
+            .await
+            .expect("Failed to add AuditLog.");
+        debug!("Add Execution returned: {}", exe);
+        info!(
+            "AuditLog {} {} {} added.",
+            &audit_log.action, &audit_log.entity_type, &audit_log.entity_id
+        );
+
+        Some(AuditLogCommandStatus::Stored)
+    }
+}
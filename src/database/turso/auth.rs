@@ -1,18 +1,100 @@
-use crate::database::turso::{trim_update_fields, TursoDatabase};
+use crate::database::turso::TursoDatabase;
 use crate::model::auth::*;
+use crate::model::totp::{UserTotp, UserTotpCommandStatus};
 use crate::repo::auth::AuthRepo;
 use async_trait::async_trait;
-use tracing::{debug, info};
+use libsql::Row;
+use tracing::{debug, info, warn};
+
+const USER_COLUMNS: &str = r"
+    id,
+    email,
+    hashed_password,
+    role,
+    COALESCE(user_totp.enabled, 0)
+    FROM users LEFT JOIN user_totp ON user_totp.user_id = users.id";
+
+/// Convert a `USER_COLUMNS` row into `User`
+/// Unknown roles fall back to the least privileged Viewer.
+fn row_to_user(row: &Row) -> User {
+    let role: String = row.get(3).unwrap();
+    let role = UserRole::parse(&role).unwrap_or_else(|| {
+        warn!("Unknown User role {}. Fallback to viewer.", &role);
+        UserRole::Viewer
+    });
+
+    let totp_enabled: i64 = row.get(4).unwrap();
+
+    User {
+        id: row.get(0).unwrap(),
+        email: row.get(1).unwrap(),
+        hashed_password: row.get(2).unwrap(),
+        role,
+        totp_enabled: totp_enabled != 0,
+    }
+}
+
+const SESSION_COLUMNS: &str =
+    "id, user_id, token, expire, user_agent, client_ip, created_at, last_seen_at, remember";
+
+/// Convert a `SESSION_COLUMNS` row into `Session`
+fn row_to_session(row: &Row) -> Session {
+    let remember: i64 = row.get(8).unwrap();
+    Session {
+        id: row.get(0).unwrap(),
+        user_id: row.get(1).unwrap(),
+        token: row.get(2).unwrap(),
+        expire: row.get(3).unwrap(),
+        user_agent: row.get(4).unwrap(),
+        client_ip: row.get(5).unwrap(),
+        created_at: row.get(6).unwrap(),
+        last_seen_at: row.get(7).unwrap(),
+        remember: remember != 0,
+    }
+}
+
+const LOGIN_ACTIVITY_COLUMNS: &str = "email, succeeded, reason, client_ip, user_agent, created_at";
+
+/// Convert a `LOGIN_ACTIVITY_COLUMNS` row into `LoginActivity`
+fn row_to_login_activity(row: &Row) -> LoginActivity {
+    let succeeded: i64 = row.get(1).unwrap();
+    LoginActivity {
+        email: row.get(0).unwrap(),
+        succeeded: succeeded != 0,
+        reason: row.get(2).unwrap(),
+        client_ip: row.get(3).unwrap(),
+        user_agent: row.get(4).unwrap(),
+        created_at: row.get(5).unwrap(),
+    }
+}
+
+const EMAIL_TOKEN_COLUMNS: &str = "id, user_id, purpose, expire";
+
+/// Convert an `EMAIL_TOKEN_COLUMNS` row into `EmailToken`
+/// Unknown purposes fall back to the password reset, which cannot log in.
+fn row_to_email_token(row: &Row) -> EmailToken {
+    let purpose: String = row.get(2).unwrap();
+    let purpose = EmailTokenPurpose::parse(&purpose).unwrap_or_else(|| {
+        warn!("Unknown EmailToken purpose {}.", &purpose);
+        EmailTokenPurpose::PasswordReset
+    });
+    EmailToken {
+        id: row.get(0).unwrap(),
+        user_id: row.get(1).unwrap(),
+        purpose,
+        expire: row.get(3).unwrap(),
+    }
+}
 
 #[async_trait]
 impl AuthRepo for TursoDatabase {
     async fn find_user_by_id(&self, id: String) -> Option<User> {
-        let prep_query = "SELECT id, email, hashed_password FROM users WHERE id=?1 LIMIT 1";
+        let prep_query = format!("SELECT {USER_COLUMNS} WHERE id=?1 LIMIT 1");
         debug!("Executing query {} for id {}", &prep_query, &id);
 
         let stmt = self
             .conn
-            .prepare(prep_query)
+            .prepare(&prep_query)
             .await
             .expect("Failed to prepare find user query");
 
@@ -29,21 +111,17 @@ impl AuthRepo for TursoDatabase {
             return None;
         };
 
-        let user = User {
-            id: row.get(0).unwrap(),
-            email: row.get(1).unwrap(),
-            hashed_password: row.get(2).unwrap(),
-        };
+        let user = row_to_user(&row);
         debug!("User id {:?} found", &user.id);
         Some(user)
     }
     async fn find_user_by_email(&self, email: String) -> Option<User> {
-        let prep_query = "SELECT id, email, hashed_password FROM users WHERE email=?1 LIMIT 1";
+        let prep_query = format!("SELECT {USER_COLUMNS} WHERE email=?1 LIMIT 1");
         debug!("Executing query {} for email {}", &prep_query, &email);
 
         let stmt = self
             .conn
-            .prepare(prep_query)
+            .prepare(&prep_query)
             .await
             .expect("Failed to prepare find user query");
 
@@ -60,21 +138,38 @@ impl AuthRepo for TursoDatabase {
             return None;
         };
 
-        let user = User {
-            id: row.get(0).unwrap(),
-            email: row.get(1).unwrap(),
-            hashed_password: row.get(2).unwrap(),
-        };
+        let user = row_to_user(&row);
         debug!("User id {:?} found", &user.id);
         Some(user)
     }
+    async fn find_users(&self) -> Option<Users> {
+        let prep_query = format!("SELECT {USER_COLUMNS} ORDER BY email");
+        debug!("Executing query {}", &prep_query);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find users query");
+
+        let mut rows = stmt.query(()).await.expect("Failed to query users");
+
+        let mut users = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            users.push(row_to_user(&row));
+        }
+
+        Some(Users { users })
+    }
     async fn add_user(
         &self,
         id: String,
         email: String,
         hpass: String,
+        role: UserRole,
     ) -> Option<UserCommandStatus> {
-        let prep_add_command = "INSERT INTO users (id, email, hashed_password) VALUES (?1, ?2, ?3)";
+        let prep_add_command =
+            "INSERT INTO users (id, email, hashed_password, role) VALUES (?1, ?2, ?3, ?4)";
         debug!("Executing query {} for id {}", &prep_add_command, &id);
 
         let stmt = self
@@ -84,7 +179,7 @@ impl AuthRepo for TursoDatabase {
             .expect("Failed to prepare add user commmand");
 
         let exe = stmt
-            .execute((id.clone(), email.clone(), hpass.clone()))
+            .execute((id.clone(), email.clone(), hpass.clone(), role.as_str()))
             .await
             .expect("Failed to add user.");
         debug!("Add Execution returned: {}", exe);
@@ -96,38 +191,26 @@ impl AuthRepo for TursoDatabase {
         id: String,
         email: Option<String>,
         hpass: Option<String>,
+        role: Option<UserRole>,
     ) -> Option<UserCommandStatus> {
-        let mut affected_col = "".to_string();
-        match &email {
-            Some(val) => {
-                affected_col = format!("{} email = '{}' ,", &affected_col, val);
-                debug!("Affected Column: '{}'", &affected_col)
-            }
-            None => {
-                debug!("Skipped update email field")
-            }
-        }
-        match &hpass {
-            Some(val) => {
-                affected_col = format!("{} hashed_password = '{}' ,", &affected_col, val);
-                debug!("Affected Column: '{}'", &affected_col)
-            }
-            None => {
-                debug!("Skipped update hashed_password field")
-            }
-        }
-
-        affected_col = trim_update_fields(&affected_col);
-        let prep_update_query = format!("UPDATE users SET{}WHERE id = ?1", &affected_col);
+        // Omitted fields are kept as they are
+        let prep_update_query = r"UPDATE users SET
+            email = COALESCE(?2, email),
+            hashed_password = COALESCE(?3, hashed_password),
+            role = COALESCE(?4, role)
+            WHERE id = ?1";
         debug!("Executing query {} for id {}", &prep_update_query, &id);
 
         let stmt = self
             .conn
-            .prepare(&prep_update_query)
+            .prepare(prep_update_query)
             .await
             .expect("Failed to prepare update user command");
 
-        let exe = stmt.execute([id]).await.expect("Failed to update a User");
+        let exe = stmt
+            .execute((id, email, hpass, role.map(|val| val.as_str())))
+            .await
+            .expect("Failed to update a User");
         info!("Update Execution returned: {}", exe);
 
         Some(UserCommandStatus::Updated)
@@ -142,18 +225,121 @@ impl AuthRepo for TursoDatabase {
             .await
             .expect("Failed to prepare delete user command.");
 
-        let exe = stmt.execute([id]).await.expect("Failed to delete a User.");
+        let exe = stmt
+            .execute([id.clone()])
+            .await
+            .expect("Failed to delete a User.");
 
         debug!("Delete Execution returned: {}", exe);
+        self.delete_user_totp(id.clone()).await?;
+        self.delete_user_sessions(id).await?;
         Some(UserCommandStatus::Deleted)
     }
+    async fn find_user_totp(&self, user_id: String) -> Option<UserTotp> {
+        let prep_query = r"SELECT
+            user_id,
+            encrypted_secret,
+            enabled,
+            last_step,
+            recovery_codes
+            FROM user_totp WHERE user_id=?1 LIMIT 1";
+        debug!("Executing query {} for user id {}", &prep_query, &user_id);
+
+        let stmt = self
+            .conn
+            .prepare(prep_query)
+            .await
+            .expect("Failed to prepare find user totp query");
+
+        let res = stmt
+            .query([user_id.clone()])
+            .await
+            .expect("Failed to query user totp")
+            .next()
+            .await
+            .expect("Failed to access query result");
+
+        let Some(row) = res else {
+            debug!("No UserTotp of User {} is available.", &user_id);
+            return None;
+        };
+
+        let enabled: i64 = row.get(2).unwrap();
+        let recovery_codes: String = row.get(4).unwrap();
+        Some(UserTotp {
+            user_id: row.get(0).unwrap(),
+            encrypted_secret: row.get(1).unwrap(),
+            enabled: enabled != 0,
+            last_step: row.get(3).unwrap(),
+            recovery_codes: recovery_codes
+                .split(',')
+                .filter(|val| !val.is_empty())
+                .map(|val| val.to_string())
+                .collect(),
+        })
+    }
+    async fn upsert_user_totp(&self, user_totp: UserTotp) -> Option<UserTotpCommandStatus> {
+        let prep_upsert_command = r"INSERT INTO user_totp
+            (user_id, encrypted_secret, enabled, last_step, recovery_codes)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(user_id) DO UPDATE SET
+            encrypted_secret = excluded.encrypted_secret,
+            enabled = excluded.enabled,
+            last_step = excluded.last_step,
+            recovery_codes = excluded.recovery_codes";
+        debug!(
+            "Executing query {} for user id {}",
+            &prep_upsert_command, &user_totp.user_id
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_upsert_command)
+            .await
+            .expect("Failed to prepare upsert user totp command");
+
+        let exe = stmt
+            .execute((
+                user_totp.user_id,
+                user_totp.encrypted_secret,
+                user_totp.enabled as i64,
+                user_totp.last_step,
+                user_totp.recovery_codes.join(","),
+            ))
+            .await
+            .expect("Failed to upsert user totp");
+        debug!("Upsert Execution returned: {}", exe);
+
+        Some(UserTotpCommandStatus::Stored)
+    }
+    async fn delete_user_totp(&self, user_id: String) -> Option<UserTotpCommandStatus> {
+        let prep_delete_command = "DELETE FROM user_totp WHERE user_id = ?1";
+        debug!(
+            "Executing query {} for user id {}",
+            &prep_delete_command, &user_id
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete user totp command");
+
+        let exe = stmt
+            .execute([user_id])
+            .await
+            .expect("Failed to delete a UserTotp");
+
+        debug!("Delete Execution returned: {}", exe);
+        Some(UserTotpCommandStatus::Deleted)
+    }
     async fn find_session(&self, id: String) -> Option<Session> {
-        let prep_query = "SELECT id, user_id, token, expire FROM sessions WHERE id=?1 LIMIT 1";
+        let prep_query = format!("SELECT {SESSION_COLUMNS} FROM sessions WHERE id=?1 LIMIT 1");
         debug!("Executing query {} for id {}", &prep_query, &id);
 
         let stmt = self
             .conn
-            .prepare(prep_query)
+            .prepare(&prep_query)
             .await
             .expect("Failed to prepare find session query");
 
@@ -171,23 +357,39 @@ impl AuthRepo for TursoDatabase {
         };
 
         debug!("Debug Row {:?}", &row);
-        Some(Session {
-            id: row.get(0).unwrap(),
-            user_id: row.get(1).unwrap(),
-            token: row.get(2).unwrap(),
-            expire: row.get(3).unwrap(),
-        })
+        Some(row_to_session(&row))
     }
-    async fn add_session(
-        &self,
-        id: String,
-        user_id: String,
-        token: String,
-        expire: String,
-    ) -> Option<SessionCommandStatus> {
-        let prep_add_command =
-            "INSERT INTO sessions (id, user_id, token, expire) VALUES (?1, ?2, ?3, ?4)";
-        debug!("Executing query {} for id {}", &prep_add_command, &id);
+    async fn find_user_sessions(&self, user_id: String) -> Option<Vec<Session>> {
+        let prep_query = format!(
+            "SELECT {SESSION_COLUMNS} FROM sessions WHERE user_id=?1 ORDER BY last_seen_at DESC"
+        );
+        debug!("Executing query {} for user id {}", &prep_query, &user_id);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find user sessions query");
+
+        let mut rows = stmt
+            .query([user_id])
+            .await
+            .expect("Failed to query user sessions");
+
+        let mut sessions = Vec::new();
+        while let Ok(Some(row)) = rows.next().await {
+            sessions.push(row_to_session(&row));
+        }
+        Some(sessions)
+    }
+    async fn add_session(&self, session: Session) -> Option<SessionCommandStatus> {
+        let prep_add_command = r"INSERT INTO sessions
+            (id, user_id, token, expire, user_agent, client_ip, created_at, last_seen_at, remember)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)";
+        debug!(
+            "Executing query {} for id {}",
+            &prep_add_command, &session.id
+        );
 
         let stmt = self
             .conn
@@ -196,13 +398,69 @@ impl AuthRepo for TursoDatabase {
             .expect("Failed to prepare add session commmand");
 
         let exe = stmt
-            .execute((id.clone(), user_id.clone(), token.clone(), expire.clone()))
+            .execute((
+                session.id,
+                session.user_id,
+                session.token,
+                session.expire,
+                session.user_agent,
+                session.client_ip,
+                session.created_at,
+                session.last_seen_at,
+                session.remember as i64,
+            ))
             .await
             .expect("Failed to add session");
         debug!("Add Execution returned: {}", exe);
 
         Some(SessionCommandStatus::Stored)
     }
+    async fn update_session_last_seen(
+        &self,
+        id: String,
+        last_seen_at: String,
+    ) -> Option<SessionCommandStatus> {
+        let prep_update_command = "UPDATE sessions SET last_seen_at = ?1 WHERE id = ?2";
+        debug!("Executing query {} for id {}", &prep_update_command, &id);
+
+        let stmt = self
+            .conn
+            .prepare(prep_update_command)
+            .await
+            .expect("Failed to prepare update session command");
+
+        let exe = stmt
+            .execute((last_seen_at, id))
+            .await
+            .expect("Failed to update a Session");
+
+        debug!("Update Execution returned: {}", exe);
+        Some(SessionCommandStatus::Updated)
+    }
+    async fn extend_session(
+        &self,
+        id: String,
+        expire: String,
+        last_seen_at: String,
+    ) -> Option<SessionCommandStatus> {
+        let prep_update_command =
+            "UPDATE sessions SET expire = ?1, last_seen_at = ?2 WHERE id = ?3";
+        debug!("Executing query {} for id {}", &prep_update_command, &id);
+
+        let stmt = self
+            .conn
+            .prepare(prep_update_command)
+            .await
+            .expect("Failed to prepare extend session command");
+
+        let exe = stmt
+            .execute((expire, last_seen_at, id))
+            .await
+            .expect("Failed to extend a Session");
+
+        debug!("Update Execution returned: {}", exe);
+        Some(SessionCommandStatus::Updated)
+    }
     async fn delete_session(&self, id: String) -> Option<SessionCommandStatus> {
         let prep_delete_command = "DELETE FROM sessions WHERE id = ?1";
         debug!("Executing query {} for id {}", &prep_delete_command, &id);
@@ -221,4 +479,352 @@ impl AuthRepo for TursoDatabase {
         debug!("Delete Execution returned: {}", exe);
         Some(SessionCommandStatus::Deleted)
     }
+    async fn delete_user_sessions(&self, user_id: String) -> Option<SessionCommandStatus> {
+        let prep_delete_command = "DELETE FROM sessions WHERE user_id = ?1";
+        debug!(
+            "Executing query {} for user id {}",
+            &prep_delete_command, &user_id
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete user sessions command");
+
+        let exe = stmt
+            .execute([user_id])
+            .await
+            .expect("Failed to delete User Sessions");
+
+        debug!("Delete Execution returned: {}", exe);
+        Some(SessionCommandStatus::Deleted)
+    }
+    async fn delete_expired_sessions(&self, now: String) -> Option<SessionCommandStatus> {
+        // Both dates are RFC 3339 in UTC so they compare as strings
+        let prep_delete_command = "DELETE FROM sessions WHERE expire <= ?1";
+        debug!("Executing query {} for {}", &prep_delete_command, &now);
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete expired sessions command");
+
+        let exe = stmt
+            .execute([now])
+            .await
+            .expect("Failed to delete expired Sessions");
+
+        info!("Deleted {} expired Sessions.", exe);
+        Some(SessionCommandStatus::Deleted)
+    }
+    async fn add_login_activity(
+        &self,
+        login_activity: LoginActivity,
+    ) -> Option<LoginActivityCommandStatus> {
+        let prep_add_command = format!(
+            "INSERT INTO login_activities ({LOGIN_ACTIVITY_COLUMNS}) VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+        );
+        debug!(
+            "Executing query {} for email {}",
+            &prep_add_command, &login_activity.email
+        );
+
+        let stmt = self
+            .conn
+            .prepare(&prep_add_command)
+            .await
+            .expect("Failed to prepare add login activity command");
+
+        let exe = stmt
+            .execute((
+                login_activity.email,
+                login_activity.succeeded as i64,
+                login_activity.reason,
+                login_activity.client_ip,
+                login_activity.user_agent,
+                login_activity.created_at,
+            ))
+            .await
+            .expect("Failed to add login activity");
+        debug!("Add Execution returned: {}", exe);
+
+        Some(LoginActivityCommandStatus::Stored)
+    }
+    async fn find_login_activities(&self, limit: i64) -> Option<Vec<LoginActivity>> {
+        let prep_query = format!("SELECT {LOGIN_ACTIVITY_COLUMNS} FROM login_activities ORDER BY created_at DESC, rowid DESC LIMIT ?1");
+        debug!("Executing query {} with limit {}", &prep_query, &limit);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find login activities query");
+
+        let mut rows = stmt
+            .query([limit])
+            .await
+            .expect("Failed to query login activities");
+
+        let mut login_activities = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            login_activities.push(row_to_login_activity(&row));
+        }
+
+        Some(login_activities)
+    }
+    async fn find_email_login_activities(
+        &self,
+        email: String,
+        limit: i64,
+    ) -> Option<Vec<LoginActivity>> {
+        let prep_query = format!("SELECT {LOGIN_ACTIVITY_COLUMNS} FROM login_activities WHERE email = ?1 ORDER BY created_at DESC, rowid DESC LIMIT ?2");
+        debug!(
+            "Executing query {} for email {} with limit {}",
+            &prep_query, &email, &limit
+        );
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find email login activities query");
+
+        let mut rows = stmt
+            .query((email, limit))
+            .await
+            .expect("Failed to query email login activities");
+
+        let mut login_activities = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            login_activities.push(row_to_login_activity(&row));
+        }
+
+        Some(login_activities)
+    }
+    async fn find_login_lockout(&self, email: String) -> Option<LoginLockout> {
+        let prep_query =
+            "SELECT email, failed_count, locked_until FROM login_lockouts WHERE email=?1 LIMIT 1";
+        debug!("Executing query {} for email {}", &prep_query, &email);
+
+        let stmt = self
+            .conn
+            .prepare(prep_query)
+            .await
+            .expect("Failed to prepare find login lockout query");
+
+        let res = stmt
+            .query([email.clone()])
+            .await
+            .expect("Failed to query login lockout")
+            .next()
+            .await
+            .expect("Failed to access query result");
+
+        let Some(row) = res else {
+            debug!("No LoginLockout of email {} is available.", &email);
+            return None;
+        };
+
+        let failed_count: i64 = row.get(1).unwrap();
+        Some(LoginLockout {
+            email: row.get(0).unwrap(),
+            failed_count: failed_count as u64,
+            locked_until: row.get(2).unwrap(),
+        })
+    }
+    async fn add_failed_login(&self, email: String) -> Option<u64> {
+        // Incremented in a single statement so parallel attempts are all counted
+        let prep_upsert_command = r"INSERT INTO login_lockouts
+            (email, failed_count, locked_until)
+            VALUES (?1, 1, '')
+            ON CONFLICT(email) DO UPDATE SET
+            failed_count = failed_count + 1
+            RETURNING failed_count";
+        debug!(
+            "Executing query {} for email {}",
+            &prep_upsert_command, &email
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_upsert_command)
+            .await
+            .expect("Failed to prepare add failed login command");
+
+        let row = stmt
+            .query([email])
+            .await
+            .expect("Failed to add failed login")
+            .next()
+            .await
+            .expect("Failed to access query result")?;
+
+        let failed_count: i64 = row.get(0).unwrap();
+        Some(failed_count as u64)
+    }
+    async fn update_login_lockout_until(
+        &self,
+        email: String,
+        locked_until: String,
+    ) -> Option<LoginLockoutCommandStatus> {
+        let prep_update_command = "UPDATE login_lockouts SET locked_until = ?1 WHERE email = ?2";
+        debug!(
+            "Executing query {} for email {}",
+            &prep_update_command, &email
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_update_command)
+            .await
+            .expect("Failed to prepare update login lockout command");
+
+        let exe = stmt
+            .execute((locked_until, email))
+            .await
+            .expect("Failed to update login lockout");
+        debug!("Update Execution returned: {}", exe);
+
+        Some(LoginLockoutCommandStatus::Stored)
+    }
+    async fn delete_login_lockout(&self, email: String) -> Option<LoginLockoutCommandStatus> {
+        let prep_delete_command = "DELETE FROM login_lockouts WHERE email = ?1";
+        debug!(
+            "Executing query {} for email {}",
+            &prep_delete_command, &email
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete login lockout command");
+
+        let exe = stmt
+            .execute([email])
+            .await
+            .expect("Failed to delete a login lockout");
+
+        debug!("Delete Execution returned: {}", exe);
+        Some(LoginLockoutCommandStatus::Deleted)
+    }
+    async fn find_email_token(&self, id: String) -> Option<EmailToken> {
+        let prep_query =
+            format!("SELECT {EMAIL_TOKEN_COLUMNS} FROM email_tokens WHERE id=?1 LIMIT 1");
+        debug!("Executing query {} for id {}", &prep_query, &id);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find email token query");
+
+        let res = stmt
+            .query([id.clone()])
+            .await
+            .expect("Failed to query email token")
+            .next()
+            .await
+            .expect("Failed to access query result");
+
+        let Some(row) = res else {
+            debug!("No EmailToken with id {} is available.", &id);
+            return None;
+        };
+        Some(row_to_email_token(&row))
+    }
+    async fn add_email_token(&self, email_token: EmailToken) -> Option<EmailTokenCommandStatus> {
+        let prep_add_command =
+            format!("INSERT INTO email_tokens ({EMAIL_TOKEN_COLUMNS}) VALUES (?1, ?2, ?3, ?4)");
+        debug!(
+            "Executing query {} for user id {}",
+            &prep_add_command, &email_token.user_id
+        );
+
+        let stmt = self
+            .conn
+            .prepare(&prep_add_command)
+            .await
+            .expect("Failed to prepare add email token command");
+
+        let exe = stmt
+            .execute((
+                email_token.id,
+                email_token.user_id,
+                email_token.purpose.as_str(),
+                email_token.expire,
+            ))
+            .await
+            .expect("Failed to add email token");
+        debug!("Add Execution returned: {}", exe);
+
+        Some(EmailTokenCommandStatus::Stored)
+    }
+    async fn consume_email_token(&self, id: String) -> Option<EmailToken> {
+        // Deleted in a single statement so a link cannot be used twice in parallel
+        let prep_delete_command =
+            format!("DELETE FROM email_tokens WHERE id = ?1 RETURNING {EMAIL_TOKEN_COLUMNS}");
+        debug!("Executing query {} for id {}", &prep_delete_command, &id);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_delete_command)
+            .await
+            .expect("Failed to prepare consume email token command");
+
+        let res = stmt
+            .query([id.clone()])
+            .await
+            .expect("Failed to consume email token")
+            .next()
+            .await
+            .expect("Failed to access query result");
+
+        let Some(row) = res else {
+            debug!("No EmailToken with id {} is available.", &id);
+            return None;
+        };
+        Some(row_to_email_token(&row))
+    }
+    async fn delete_user_email_tokens(&self, user_id: String) -> Option<EmailTokenCommandStatus> {
+        let prep_delete_command = "DELETE FROM email_tokens WHERE user_id = ?1";
+        debug!(
+            "Executing query {} for user id {}",
+            &prep_delete_command, &user_id
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete user email tokens command");
+
+        let exe = stmt
+            .execute([user_id])
+            .await
+            .expect("Failed to delete email tokens of a User");
+
+        debug!("Delete Execution returned: {}", exe);
+        Some(EmailTokenCommandStatus::Deleted)
+    }
+    async fn delete_expired_email_tokens(&self, now: String) -> Option<EmailTokenCommandStatus> {
+        let prep_delete_command = "DELETE FROM email_tokens WHERE expire <= ?1";
+        debug!("Executing query {} for now {}", &prep_delete_command, &now);
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete expired email tokens command");
+
+        let exe = stmt
+            .execute([now])
+            .await
+            .expect("Failed to delete expired email tokens");
+
+        debug!("Delete Execution returned: {}", exe);
+        Some(EmailTokenCommandStatus::Deleted)
+    }
 }
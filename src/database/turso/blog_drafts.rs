@@ -0,0 +1,114 @@
+use crate::database::turso::TursoDatabase;
+use crate::model::blog_drafts::*;
+use crate::repo::blog_drafts::{BlogDraftDisplayRepo, BlogDraftOperationRepo};
+use async_trait::async_trait;
+use libsql::Row;
+use tracing::{debug, error, info};
+
+/// Columns selected by BlogDraft queries
+const BLOG_DRAFT_COLUMNS: &str = r"
+    blog_id,
+    user_id,
+    name,
+    body,
+    tags,
+    updated_at
+    FROM blog_drafts";
+
+/// Convert a `BLOG_DRAFT_COLUMNS` row into `BlogDraft`
+/// Tags are stored as JSON.
+/// Return `None` if they cannot be parsed.
+fn row_to_blog_draft(row: &Row) -> Option<BlogDraft> {
+    let tags: String = row.get(4).unwrap();
+    let Ok(tags) = serde_json::from_str(&tags) else {
+        error!("Failed to parse BlogDraft tags.");
+        return None;
+    };
+
+    Some(BlogDraft {
+        blog_id: row.get(0).unwrap(),
+        user_id: row.get(1).unwrap(),
+        name: row.get(2).unwrap(),
+        body: row.get(3).unwrap(),
+        tags,
+        updated_at: row.get(5).unwrap(),
+    })
+}
+
+#[async_trait]
+impl BlogDraftDisplayRepo for TursoDatabase {
+    async fn find(&self, blog_id: i64, user_id: String) -> Option<BlogDraft> {
+        let prep_query =
+            format!("SELECT {BLOG_DRAFT_COLUMNS} WHERE blog_id = ?1 AND user_id = ?2 LIMIT 1");
+        debug!("Executing query {}", &prep_query);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find query.");
+
+        let res = stmt
+            .query((blog_id, user_id))
+            .await
+            .expect("Failed to query blog draft.")
+            .next()
+            .await
+            .expect("Failed to access query blog draft.");
+
+        let Some(row) = res else {
+            debug!("No BlogDraft with Blog Id {} is available.", &blog_id);
+            return None;
+        };
+
+        debug!("Debug Row {:?}", &row);
+        row_to_blog_draft(&row)
+    }
+}
+
+#[async_trait]
+impl BlogDraftOperationRepo for TursoDatabase {
+    async fn save(&mut self, blog_draft: BlogDraft) -> Option<BlogDraftCommandStatus> {
+        let prep_save_command = "INSERT OR REPLACE INTO blog_drafts (blog_id, user_id, name, body, tags, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)";
+        debug!("Executing query {}", &prep_save_command);
+
+        let stmt = self
+            .conn
+            .prepare(prep_save_command)
+            .await
+            .expect("Failed to prepare save BlogDraft command.");
+
+        let exe = stmt
+            .execute((
+                blog_draft.blog_id,
+                blog_draft.user_id,
+                blog_draft.name,
+                blog_draft.body,
+                serde_json::to_string(&blog_draft.tags).unwrap(),
+                blog_draft.updated_at,
+            ))
+            .await
+            .expect("Failed to save a BlogDraft.");
+        info!("Save Execution returned: {}", exe);
+
+        Some(BlogDraftCommandStatus::Saved)
+    }
+    async fn delete(&mut self, blog_id: i64, user_id: String) -> Option<BlogDraftCommandStatus> {
+        let prep_delete_command = "DELETE FROM blog_drafts WHERE blog_id = ?1 AND user_id = ?2";
+        debug!("Executing query {}", &prep_delete_command);
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete BlogDraft command.");
+
+        let exe = stmt
+            .execute((blog_id, user_id))
+            .await
+            .expect("Failed to delete a BlogDraft.");
+        debug!("Delete Execution returned: {}", exe);
+
+        Some(BlogDraftCommandStatus::Deleted)
+    }
+}
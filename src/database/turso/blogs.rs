@@ -1,4 +1,4 @@
-use crate::database::turso::TursoDatabase;
+use crate::database::turso::{updated_at_now, TursoDatabase};
 use crate::model::blogs::*;
 use crate::repo::blogs::{BlogDisplayRepo, BlogOperationRepo};
 use async_trait::async_trait;
@@ -83,23 +83,15 @@ impl BlogDisplayRepo for TursoDatabase {
         let end_seq = end;
         let limit = end_seq - start_seq;
 
-        let tag_names: Vec<String> = tags
-            .split(",")
-            .map(|tag| format!(" tags.name='{tag}' "))
-            .collect();
-        let tag_names_joined = tag_names.join("OR");
-        let tags_query = if tags.is_empty() {
-            String::new()
-        } else {
-            format!("WHERE {tag_names_joined}")
-        };
-        let prep_query = format!(
-            r"
+        // `?3` is a comma separated tag names. Match blogs with any of them.
+        let tags = Some(tags).filter(|tags| !tags.is_empty());
+        let prep_query = r"
             WITH blogs_with_tags AS (
                 SELECT blog_ref AS blog_id
                 FROM blog_tag_mapping
                 JOIN tags ON tag_ref=tags.id
-                {tags_query}
+                WHERE ?3 IS NULL
+                OR instr(',' || ?3 || ',', ',' || tags.name || ',') > 0
                 GROUP BY blog_ref
             )
             SELECT 
@@ -117,21 +109,20 @@ impl BlogDisplayRepo for TursoDatabase {
             ORDER BY blog_ref DESC
             LIMIT ?1
             OFFSET ?2;
-        "
-        );
+        ";
         debug!(
-            "Executing query {} for start {}, end {}, limit {}",
-            &prep_query, &start_seq, &end_seq, &limit
+            "Executing query {} for start {}, end {}, limit {}, tags {:?}",
+            &prep_query, &start_seq, &end_seq, &limit, &tags
         );
 
         let stmt = self
             .conn
-            .prepare(&prep_query)
+            .prepare(prep_query)
             .await
             .expect("Failed to prepare find blogs query.");
 
         let mut rows = stmt
-            .query([limit, start_seq])
+            .query((limit, start_seq, tags))
             .await
             .expect("Failed to query blogs.");
 
@@ -237,7 +228,7 @@ impl BlogOperationRepo for TursoDatabase {
         let blog_body = blog_body_raw.replace("'", "''");
 
         let prep_add_query =
-            "INSERT INTO blogs (id, name, filename, source, body) VALUES (?1, ?2, ?3, ?4, ?5)";
+            "INSERT INTO blogs (id, name, filename, source, body, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)";
         debug!("Executing query {} for id {}", &prep_add_query, &blog_id);
 
         let stmt = self
@@ -253,6 +244,7 @@ impl BlogOperationRepo for TursoDatabase {
                 blog_filename.clone(),
                 blog_source.clone(),
                 blog_body.clone(),
+                updated_at_now(),
             ))
             .await
             .expect("Failed to add a blog.");
@@ -323,6 +315,7 @@ impl BlogOperationRepo for TursoDatabase {
                 debug!("Skipped update body field")
             }
         }
+        affected_col = format!("{} updated_at = '{}' ,", &affected_col, updated_at_now());
 
         // Trimming the last ','
         affected_col = affected_col.as_str()[0..affected_col.len() - 1].to_string();
@@ -0,0 +1,90 @@
+use crate::database::turso::TursoDatabase;
+use crate::model::dashboard::*;
+use crate::repo::dashboard::DashboardDisplayRepo;
+use async_trait::async_trait;
+use tracing::debug;
+
+#[async_trait]
+impl DashboardDisplayRepo for TursoDatabase {
+    async fn find_content_stats(&self) -> Option<ContentStats> {
+        let prep_query = r"SELECT
+            (SELECT COUNT(*) FROM blogs),
+            (SELECT COUNT(*) FROM talks),
+            (SELECT COUNT(*) FROM tags),
+            (SELECT COUNT(*) FROM projects),
+            (SELECT COUNT(*) FROM blog_drafts)";
+        debug!("Executing query {}", &prep_query);
+
+        let row = self
+            .conn
+            .query(prep_query, ())
+            .await
+            .expect("Failed to query content stats.")
+            .next()
+            .await
+            .expect("Failed to access content stats.")
+            .expect("Failed to access content stats row.");
+
+        debug!("Debug Row {:?}", &row);
+        Some(ContentStats {
+            blogs: row.get(0).unwrap(),
+            talks: row.get(1).unwrap(),
+            tags: row.get(2).unwrap(),
+            projects: row.get(3).unwrap(),
+            drafts: row.get(4).unwrap(),
+        })
+    }
+    async fn find_tag_usages(&self) -> Option<TagUsages> {
+        let prep_query = r"SELECT
+            name,
+            (SELECT COUNT(*) FROM blog_tag_mapping WHERE tag_ref = tags.id),
+            (SELECT COUNT(*) FROM talk_tag_mapping WHERE tag_ref = tags.id)
+            FROM tags ORDER BY id";
+        debug!("Executing query {}", &prep_query);
+
+        let mut rows = self
+            .conn
+            .query(prep_query, ())
+            .await
+            .expect("Failed to query tag usages.");
+
+        let mut tag_usages: Vec<TagUsage> = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            debug!("Debug Row {:?}", &row);
+            tag_usages.push(TagUsage {
+                name: row.get(0).unwrap(),
+                blogs: row.get(1).unwrap(),
+                talks: row.get(2).unwrap(),
+            });
+        }
+
+        Some(TagUsages { tag_usages })
+    }
+    async fn find_recent_updates(&self, limit: i64) -> Option<Vec<RecentUpdate>> {
+        // Rows created before updated_at was tracked are left out
+        let prep_query = r"SELECT 'blog', id, name, updated_at FROM blogs WHERE updated_at IS NOT NULL
+            UNION ALL
+            SELECT 'talk', id, name, updated_at FROM talks WHERE updated_at IS NOT NULL
+            ORDER BY 4 DESC LIMIT ?1";
+        debug!("Executing query {}", &prep_query);
+
+        let mut rows = self
+            .conn
+            .query(prep_query, [limit])
+            .await
+            .expect("Failed to query recent updates.");
+
+        let mut recent_updates: Vec<RecentUpdate> = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            debug!("Debug Row {:?}", &row);
+            recent_updates.push(RecentUpdate {
+                entity_type: row.get(0).unwrap(),
+                id: row.get(1).unwrap(),
+                name: row.get(2).unwrap(),
+                updated_at: row.get(3).unwrap(),
+            });
+        }
+
+        Some(recent_updates)
+    }
+}
@@ -0,0 +1,106 @@
+use crate::database::turso::TursoDatabase;
+use crate::model::link_checks::*;
+use crate::repo::link_checks::{LinkCheckDisplayRepo, LinkCheckOperationRepo};
+use async_trait::async_trait;
+use libsql::Row;
+use tracing::{debug, info};
+
+/// Columns selected by LinkCheck queries
+const LINK_CHECK_COLUMNS: &str = r"
+    source_type,
+    source_id,
+    source_name,
+    url,
+    is_broken,
+    detail,
+    checked_at
+    FROM link_checks";
+
+/// Convert a `LINK_CHECK_COLUMNS` row into `LinkCheck`
+fn row_to_link_check(row: &Row) -> LinkCheck {
+    let is_broken: i64 = row.get(4).unwrap();
+    LinkCheck {
+        source_type: row.get(0).unwrap(),
+        source_id: row.get(1).unwrap(),
+        source_name: row.get(2).unwrap(),
+        url: row.get(3).unwrap(),
+        is_broken: is_broken != 0,
+        detail: row.get(5).unwrap(),
+        checked_at: row.get(6).unwrap(),
+    }
+}
+
+#[async_trait]
+impl LinkCheckDisplayRepo for TursoDatabase {
+    async fn find_link_checks(&self) -> Option<LinkChecks> {
+        // Rows are inserted in check order
+        let prep_query = format!("SELECT {LINK_CHECK_COLUMNS} ORDER BY rowid");
+        debug!("Executing query {}", &prep_query);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find link checks query.");
+
+        let mut rows = stmt.query(()).await.expect("Failed to query link checks.");
+
+        let mut link_checks: Vec<LinkCheck> = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            debug!("Debug Row {:?}", &row);
+            link_checks.push(row_to_link_check(&row));
+        }
+
+        Some(LinkChecks { link_checks })
+    }
+}
+
+#[async_trait]
+impl LinkCheckOperationRepo for TursoDatabase {
+    async fn replace(&mut self, link_checks: LinkChecks) -> Option<LinkCheckCommandStatus> {
+        let prep_delete_command = "DELETE FROM link_checks";
+        let prep_add_command = "INSERT OR REPLACE INTO link_checks (source_type, source_id, source_name, url, is_broken, detail, checked_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)";
+        debug!(
+            "Executing query {} then {} for {} link checks",
+            &prep_delete_command,
+            &prep_add_command,
+            link_checks.link_checks.len()
+        );
+
+        let tx = self
+            .conn
+            .transaction()
+            .await
+            .expect("Failed to begin replace LinkChecks transaction.");
+
+        let exe = tx
+            .execute(prep_delete_command, ())
+            .await
+            .expect("Failed to delete LinkChecks.");
+        debug!("Delete Execution returned: {}", exe);
+
+        for link_check in link_checks.link_checks {
+            tx.execute(
+                prep_add_command,
+                (
+                    link_check.source_type,
+                    link_check.source_id,
+                    link_check.source_name,
+                    link_check.url,
+                    link_check.is_broken as i64,
+                    link_check.detail,
+                    link_check.checked_at,
+                ),
+            )
+            .await
+            .expect("Failed to add a LinkCheck.");
+        }
+
+        tx.commit()
+            .await
+            .expect("Failed to commit replace LinkChecks transaction.");
+        info!("LinkChecks replaced.");
+
+        Some(LinkCheckCommandStatus::Replaced)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::database::turso::TursoDatabase;
+use crate::model::media::*;
+use crate::repo::media::{MediaDisplayRepo, MediaOperationRepo};
+use async_trait::async_trait;
+use libsql::Row;
+use tracing::{debug, info};
+
+/// Columns selected by Media queries
+const MEDIA_COLUMNS: &str = r"
+    key,
+    filename,
+    content_type,
+    size,
+    created_at
+    FROM media";
+
+/// Convert a `MEDIA_COLUMNS` row into `MediaFile`
+fn row_to_media_file(row: &Row) -> MediaFile {
+    MediaFile {
+        key: row.get(0).unwrap(),
+        filename: row.get(1).unwrap(),
+        content_type: row.get(2).unwrap(),
+        size: row.get(3).unwrap(),
+        created_at: row.get(4).unwrap(),
+    }
+}
+
+#[async_trait]
+impl MediaDisplayRepo for TursoDatabase {
+    async fn find(&self, key: String) -> Option<MediaFile> {
+        let prep_query = format!("SELECT {MEDIA_COLUMNS} WHERE key = ?1 LIMIT 1");
+        debug!("Executing query {} for key {}", &prep_query, &key);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find query.");
+
+        let res = stmt
+            .query([key.clone()])
+            .await
+            .expect("Failed to query media file.")
+            .next()
+            .await
+            .expect("Failed to access query media file.");
+
+        let Some(row) = res else {
+            debug!("No Media File with key {} is available.", &key);
+            return None;
+        };
+
+        debug!("Debug Row {:?}", &row);
+        Some(row_to_media_file(&row))
+    }
+    async fn find_media_files(&self, params: MediaFilesParams) -> Option<MediaFiles> {
+        let start_seq = params.start.unwrap();
+        let end_seq = params.end.unwrap();
+        let limit = (end_seq - start_seq).max(0);
+        let prep_query =
+            format!("SELECT {MEDIA_COLUMNS} ORDER BY created_at DESC, key DESC LIMIT ?1 OFFSET ?2");
+        debug!(
+            "Executing query {} with limit {} and start sequence {}",
+            &prep_query, limit, start_seq
+        );
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find media files query.");
+
+        let mut rows = stmt
+            .query([limit, start_seq])
+            .await
+            .expect("Failed to query media files.");
+
+        let mut media_files: Vec<MediaFile> = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            debug!("Debug Row {:?}", &row);
+            media_files.push(row_to_media_file(&row));
+        }
+
+        Some(MediaFiles { media_files })
+    }
+}
+
+#[async_trait]
+impl MediaOperationRepo for TursoDatabase {
+    async fn add(&mut self, media_file: MediaFile) -> Option<MediaCommandStatus> {
+        let prep_add_command = "INSERT INTO media (key, filename, content_type, size, created_at) VALUES (?1, ?2, ?3, ?4, ?5)";
+        debug!(
+            "Executing query {} for key {}",
+            &prep_add_command, &media_file.key
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_add_command)
+            .await
+            .expect("Failed to prepare add Media File command.");
+
+        let exe = stmt
+            .execute((
+                media_file.key,
+                media_file.filename,
+                media_file.content_type,
+                media_file.size,
+                media_file.created_at,
+            ))
+            .await
+            .expect("Failed to add a Media File.");
+        info!("Add Execution returned: {}", exe);
+
+        Some(MediaCommandStatus::Stored)
+    }
+    async fn delete(&mut self, key: String) -> Option<MediaCommandStatus> {
+        let prep_delete_command = "DELETE FROM media WHERE key = ?1";
+        debug!("Executing query {} for key {}", &prep_delete_command, &key);
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete Media File command.");
+
+        let exe = stmt
+            .execute([key])
+            .await
+            .expect("Failed to delete a Media File.");
+        debug!("Delete Execution returned: {}", exe);
+
+        Some(MediaCommandStatus::Deleted)
+    }
+}
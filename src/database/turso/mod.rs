@@ -1,9 +1,20 @@
+pub mod audit_logs;
 pub mod auth;
+pub mod blog_drafts;
 pub mod blog_tag_mappings;
 pub mod blogs;
+pub mod dashboard;
+pub mod link_checks;
+pub mod media;
+pub mod organizations;
+pub mod profiles;
+pub mod projects;
+pub mod resume;
 pub mod tags;
+pub mod talk_tag_mappings;
 pub mod talks;
 
+use chrono::{SecondsFormat, Utc};
 use std::collections::HashMap;
 
 use libsql::{Builder, Connection};
@@ -68,6 +79,86 @@ impl TursoDatabase {
                     org_link TEXT
                     );",
             ),
+            (
+                "2026-10-19 Organizations Migration",
+                r"CREATE TABLE IF NOT EXISTS organizations (
+                    id INTEGER PRIMARY KEY NOT NULL,
+                    name TEXT NOT NULL,
+                    url TEXT,
+                    logo TEXT,
+                    description TEXT
+                    );",
+            ),
+            (
+                "2026-10-19 Projects Migration",
+                r"CREATE TABLE IF NOT EXISTS projects (
+                    id INTEGER PRIMARY KEY NOT NULL,
+                    name TEXT NOT NULL,
+                    summary TEXT NOT NULL,
+                    body TEXT NOT NULL,
+                    repo_url TEXT,
+                    status TEXT NOT NULL,
+                    tags TEXT NOT NULL,
+                    position INTEGER NOT NULL DEFAULT 0
+                    );",
+            ),
+            (
+                "2026-10-19 Profiles Migration",
+                r"CREATE TABLE IF NOT EXISTS profiles (
+                    version TEXT PRIMARY KEY NOT NULL,
+                    headline TEXT NOT NULL,
+                    bio TEXT NOT NULL,
+                    experiences TEXT NOT NULL,
+                    skills TEXT NOT NULL,
+                    links TEXT NOT NULL
+                    );",
+            ),
+            (
+                "2026-10-19 Resume Migration",
+                r"CREATE TABLE IF NOT EXISTS resume (
+                    id INTEGER PRIMARY KEY NOT NULL,
+                    basics TEXT NOT NULL,
+                    work TEXT NOT NULL,
+                    education TEXT NOT NULL,
+                    certificates TEXT NOT NULL,
+                    skills TEXT NOT NULL
+                    );",
+            ),
+            (
+                "2026-10-19 Media Migration",
+                r"CREATE TABLE IF NOT EXISTS media (
+                    key TEXT PRIMARY KEY NOT NULL,
+                    filename TEXT NOT NULL,
+                    content_type TEXT NOT NULL,
+                    size INTEGER NOT NULL,
+                    created_at TEXT NOT NULL
+                    );",
+            ),
+            (
+                "2026-10-19 Blog Drafts Migration",
+                r"CREATE TABLE IF NOT EXISTS blog_drafts (
+                    blog_id INTEGER NOT NULL,
+                    user_id TEXT NOT NULL,
+                    name TEXT NOT NULL,
+                    body TEXT NOT NULL,
+                    tags TEXT NOT NULL,
+                    updated_at TEXT NOT NULL,
+                    PRIMARY KEY (blog_id, user_id)
+                    );",
+            ),
+            (
+                "2026-10-19 Link Checks Migration",
+                r"CREATE TABLE IF NOT EXISTS link_checks (
+                    source_type TEXT NOT NULL,
+                    source_id INTEGER NOT NULL,
+                    source_name TEXT NOT NULL,
+                    url TEXT NOT NULL,
+                    is_broken INTEGER NOT NULL,
+                    detail TEXT NOT NULL,
+                    checked_at TEXT NOT NULL,
+                    PRIMARY KEY (source_type, source_id, url)
+                    );",
+            ),
             (
                 "2025-04-28 Tags Migration",
                 r"CREATE TABLE IF NOT EXISTS tags (
@@ -82,6 +173,13 @@ impl TursoDatabase {
                     tag_ref INTEGER NOT NULL
                     );",
             ),
+            (
+                "2026-10-19 Talk Tag Mapping Migration",
+                r"CREATE TABLE IF NOT EXISTS talk_tag_mapping(
+                    talk_ref INTEGER NOT NULL,
+                    tag_ref INTEGER NOT NULL
+                    );",
+            ),
             (
                 "2025-09-23 User Migration",
                 r"CREATE TABLE IF NOT EXISTS users (
@@ -90,6 +188,54 @@ impl TursoDatabase {
                     hashed_password TEXT NOT NULL
                 )",
             ),
+            (
+                "2026-10-19 Login Activities Migration",
+                r"CREATE TABLE IF NOT EXISTS login_activities (
+                    email TEXT NOT NULL,
+                    succeeded INTEGER NOT NULL,
+                    created_at TEXT NOT NULL
+                )",
+            ),
+            (
+                "2026-10-19 Login Lockout Migration",
+                r"CREATE TABLE IF NOT EXISTS login_lockouts (
+                    email TEXT PRIMARY KEY NOT NULL,
+                    failed_count INTEGER NOT NULL,
+                    locked_until TEXT NOT NULL
+                )",
+            ),
+            (
+                "2026-10-19 Email Token Migration",
+                r"CREATE TABLE IF NOT EXISTS email_tokens (
+                    id TEXT PRIMARY KEY NOT NULL,
+                    user_id TEXT NOT NULL,
+                    purpose TEXT NOT NULL,
+                    expire TEXT NOT NULL
+                )",
+            ),
+            (
+                "2026-10-19 Audit Log Migration",
+                r"CREATE TABLE IF NOT EXISTS audit_log (
+                    created_at TEXT NOT NULL,
+                    user_id TEXT NOT NULL,
+                    client_ip TEXT NOT NULL,
+                    action TEXT NOT NULL,
+                    entity_type TEXT NOT NULL,
+                    entity_id TEXT NOT NULL,
+                    before TEXT NOT NULL,
+                    after TEXT NOT NULL
+                )",
+            ),
+            (
+                "2026-10-19 User TOTP Migration",
+                r"CREATE TABLE IF NOT EXISTS user_totp (
+                    user_id TEXT PRIMARY KEY NOT NULL,
+                    encrypted_secret TEXT NOT NULL,
+                    enabled INTEGER NOT NULL,
+                    last_step INTEGER,
+                    recovery_codes TEXT NOT NULL
+                )",
+            ),
             (
                 "2025-09-23 Session Migration",
                 r"CREATE TABLE IF NOT EXISTS sessions (
@@ -108,13 +254,68 @@ impl TursoDatabase {
                 .await
                 .expect("Failed to migrate tables.");
         }
+
+        // Data migrations
+        info!("Starting Talk Dates Migration...");
+        talks::migrate_talk_dates(&conn).await;
+        info!("Starting Talk Organizations Migration...");
+        talks::migrate_talk_organizations(&conn).await;
+        info!("Starting Profile Seed Migration...");
+        profiles::migrate_profile_seed(&conn).await;
+        info!("Starting Updated At Migration...");
+        add_missing_column(&conn, "blogs", "updated_at", "TEXT").await;
+        add_missing_column(&conn, "talks", "updated_at", "TEXT").await;
+        // Users added before roles were all-powerful
+        info!("Starting User Role Migration...");
+        add_missing_column(&conn, "users", "role", "TEXT NOT NULL DEFAULT 'owner'").await;
+        info!("Starting Session Details Migration...");
+        for column in ["user_agent", "client_ip", "created_at", "last_seen_at"] {
+            add_missing_column(&conn, "sessions", column, "TEXT NOT NULL DEFAULT ''").await;
+        }
+        add_missing_column(&conn, "sessions", "remember", "INTEGER NOT NULL DEFAULT 0").await;
+        info!("Starting Login Activity Details Migration...");
+        for column in ["reason", "client_ip", "user_agent"] {
+            add_missing_column(
+                &conn,
+                "login_activities",
+                column,
+                "TEXT NOT NULL DEFAULT ''",
+            )
+            .await;
+        }
         info!("Database Setup is finished");
 
         Self { conn }
     }
 }
 
-/// Trimming the last ',' for UPDATE command
-fn trim_update_fields(affected_columns: &str) -> String {
-    affected_columns[0..affected_columns.len() - 1].to_string()
+/// Add a column into an existing table if it doesn't have it yet
+async fn add_missing_column(conn: &Connection, table: &str, column: &str, definition: &str) {
+    let prep_column_query =
+        format!("SELECT COUNT(*) FROM pragma_table_info('{table}') WHERE name = '{column}'");
+    debug!("Executing query {}", &prep_column_query);
+    let column_count: i64 = conn
+        .query(&prep_column_query, ())
+        .await
+        .expect("Failed to query table columns.")
+        .next()
+        .await
+        .expect("Failed to access table columns.")
+        .expect("Failed to access table columns row.")
+        .get(0)
+        .unwrap();
+    if column_count == 0 {
+        info!("Adding {} column into {} table", column, table);
+        conn.execute(
+            &format!("ALTER TABLE {table} ADD COLUMN {column} {definition}"),
+            (),
+        )
+        .await
+        .expect("Failed to add table column.");
+    }
+}
+
+/// Current time to be stored in `updated_at` columns
+fn updated_at_now() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
 }
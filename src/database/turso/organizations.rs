@@ -0,0 +1,201 @@
+use crate::database::turso::TursoDatabase;
+use crate::model::organizations::*;
+use crate::repo::organizations::{OrganizationDisplayRepo, OrganizationOperationRepo};
+use async_trait::async_trait;
+use libsql::Row;
+use tracing::{debug, info};
+
+/// Convert an `organizations` table row into `Organization`
+/// Empty url, logo, and description columns are converted to `None`.
+fn row_to_organization(row: &Row) -> Organization {
+    let non_empty = |val: Option<String>| val.filter(|val| !val.is_empty());
+
+    Organization {
+        id: row.get(0).unwrap(),
+        name: row.get(1).unwrap(),
+        url: non_empty(row.get(2).unwrap()),
+        logo: non_empty(row.get(3).unwrap()),
+        description: non_empty(row.get(4).unwrap()),
+    }
+}
+
+#[async_trait]
+impl OrganizationDisplayRepo for TursoDatabase {
+    async fn find(&self, id: i64) -> Option<Organization> {
+        let prep_query = r"
+            SELECT
+                id,
+                name,
+                url,
+                logo,
+                description
+            FROM organizations
+            WHERE id = ?1
+            LIMIT 1
+        ";
+        debug!("Executing query {} for id {}", &prep_query, &id);
+
+        let stmt = self
+            .conn
+            .prepare(prep_query)
+            .await
+            .expect("Failed to prepare find query.");
+
+        let res = stmt
+            .query([id])
+            .await
+            .expect("Failed to query organization.")
+            .next()
+            .await
+            .expect("Failed to access query organization.");
+
+        let Some(row) = res else {
+            debug!("No Organization with Id {} is available.", &id);
+            return None;
+        };
+
+        debug!("Debug Row {:?}", &row);
+        Some(row_to_organization(&row))
+    }
+    async fn find_organizations(&self, params: OrganizationsParams) -> Option<Organizations> {
+        let sanitized_params = params.sanitize();
+        let start_seq = sanitized_params.start.unwrap();
+        let end_seq = sanitized_params.end.unwrap();
+        let limit = end_seq - start_seq;
+        let prep_query = r"
+            SELECT
+                id,
+                name,
+                url,
+                logo,
+                description
+            FROM organizations
+            ORDER BY name COLLATE NOCASE
+            LIMIT ?1
+            OFFSET ?2
+        ";
+        debug!(
+            "Executing query {} with limit {} and start sequence {}",
+            &prep_query, limit, start_seq
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_query)
+            .await
+            .expect("Failed to prepare find organizations query.");
+
+        let mut rows = stmt
+            .query([limit, start_seq])
+            .await
+            .expect("Failed to query organizations.");
+
+        let mut organizations: Vec<Organization> = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            debug!("Debug Row {:?}", &row);
+            organizations.push(row_to_organization(&row));
+        }
+
+        Some(Organizations { organizations })
+    }
+}
+
+#[async_trait]
+impl OrganizationOperationRepo for TursoDatabase {
+    async fn get_new_id(&self) -> Option<i64> {
+        let prep_query = "SELECT COALESCE(MAX(id), 0) + 1 FROM organizations";
+        debug!("Executing query {}", &prep_query);
+
+        let row = self
+            .conn
+            .query(prep_query, ())
+            .await
+            .expect("Failed to query Organizations new id.")
+            .next()
+            .await
+            .expect("Failed to access Organizations new id.")
+            .expect("Failed to access Organizations new id row.");
+
+        debug!("Get New Id {:?}", &row);
+        Some(row.get(0).unwrap())
+    }
+    async fn add(
+        &mut self,
+        id: i64,
+        name: String,
+        url: Option<String>,
+        logo: Option<String>,
+        description: Option<String>,
+    ) -> Option<OrganizationCommandStatus> {
+        let prep_add_command = "INSERT INTO organizations (id, name, url, logo, description) VALUES (?1, ?2, ?3, ?4, ?5)";
+        debug!("Executing query {} for id {}", &prep_add_command, &id);
+
+        let stmt = self
+            .conn
+            .prepare(prep_add_command)
+            .await
+            .expect("Failed to prepare add Organization command.");
+
+        let exe = stmt
+            .execute((id, name, url, logo, description))
+            .await
+            .expect("Failed to add an Organization.");
+        info!("Add Execution returned: {}", exe);
+
+        Some(OrganizationCommandStatus::Stored)
+    }
+    async fn update(
+        &mut self,
+        id: i64,
+        name: String,
+        url: Option<String>,
+        logo: Option<String>,
+        description: Option<String>,
+    ) -> Option<OrganizationCommandStatus> {
+        let prep_update_command = "UPDATE organizations SET name = ?2, url = ?3, logo = ?4, description = ?5 WHERE id = ?1";
+        debug!("Executing query {} for id {}", &prep_update_command, &id);
+
+        let stmt = self
+            .conn
+            .prepare(prep_update_command)
+            .await
+            .expect("Failed to prepare update Organization command.");
+
+        let exe = stmt
+            .execute((id, name, url, logo, description))
+            .await
+            .expect("Failed to update an Organization.");
+        info!("Update Execution returned: {}", exe);
+
+        Some(OrganizationCommandStatus::Updated)
+    }
+    /// Delete an Organization
+    /// Talks of the deleted Organization are detached from it.
+    async fn delete(&mut self, id: i64) -> Option<OrganizationCommandStatus> {
+        let prep_detach_command = "UPDATE talks SET org_id = NULL WHERE org_id = ?1";
+        debug!("Executing query {} for id {}", &prep_detach_command, &id);
+        let exe = self
+            .conn
+            .execute(prep_detach_command, [id])
+            .await
+            .expect("Failed to detach Talks from an Organization.");
+        debug!("Detach Execution returned: {}", exe);
+
+        let prep_delete_command = "DELETE FROM organizations WHERE id = ?1";
+        debug!("Executing query {} for id {}", &prep_delete_command, &id);
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete Organization command.");
+
+        let exe = stmt
+            .execute([id])
+            .await
+            .expect("Failed to delete an Organization.");
+        debug!("Delete Execution returned: {}", exe);
+
+        Some(OrganizationCommandStatus::Deleted)
+    }
+}
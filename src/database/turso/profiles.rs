@@ -0,0 +1,257 @@
+use crate::database::turso::TursoDatabase;
+use crate::model::profiles::*;
+use crate::repo::profiles::{ProfileDisplayRepo, ProfileOperationRepo};
+use async_trait::async_trait;
+use libsql::{Connection, Row};
+use tracing::{debug, error, info};
+
+/// Columns selected by Profile queries
+const PROFILE_COLUMNS: &str = r"
+    version,
+    headline,
+    bio,
+    experiences,
+    skills,
+    links
+    FROM profiles";
+
+/// Version of the seeded Profile. Older than any Profile saved from the admin.
+const SEED_PROFILE_VERSION: &str = "1970-01-01T00:00:00.000Z";
+
+/// Profile page content before it was editable from the admin.
+/// Written in the admin profile editor format. See `ProfileForm`.
+const SEED_PROFILE_HEADLINE: &str = "Husni Naufal Zuhdi - Site Reliability Engineer";
+const SEED_PROFILE_BIO: &str = r"Hi! I'm a Site Reliability Engineer by job and Software Engineer by passion. I'm comfortable writing code with python, golang, and rust.
+
+I love exploring several topics such as Cloud Computing, DevOps, Platform Engineering, Nuclear Science, and Computational Physics.
+
+*Running, Hard Sci-fi and Fantasy, Books, Climbing, Staring*";
+const SEED_PROFILE_EXPERIENCES: &str = r"2022 - Now | Site Reliability Engineer | Accelbyte | https://accelbyte.io/about-us
+- Manage and operate Accelbyte game-platform cloud infrastructure
+- Handle incident management related to client and internal cloud infrastructure
+- Develop Backend Service for internal alerting subscription
+
+2022 - 2022 | System Engineer | Tokopedia | https://www.tokopedia.com/about/
+- Handle Tokopedia Multi-Cloud Environment
+- Onboard several existing Jenkins Pipelines into our new GitHub Action Workflows
+- Rolling out our existing Consul Service Discovery into the Enterprise version
+- Develop Cloud Platform Diary for our Internal Cloud-related Data Analytics Platform
+
+2024 - Now | Organizer | DevOps Jogja | https://devops-jogja.github.io
+- Coordinate with several co-working spaces to hold the first Community Meetup
+- Organized community meetups and collaborate with other communities in Indonesia
+- Sometimes I present DevOps/SRE/Cloud materials
+- Having fun with community members
+
+2023 - Now | Instructor and Advisor | Bangkit Academy | https://grow.google/intl/id_id/bangkit
+- Teaching on 20+ Cloud ILT sessions in Bangkit 2023
+- Colaborate with Mentors to setup ILT sessions
+- Advise 3 capstone teams to deliver their final projects
+
+2022 - 2022 | Facilitator | Bangkit Academy | https://grow.google/intl/id_id/bangkit
+- Colaborate with Instructors to setup ILT sessions
+- Conduct weekly meeting to monitor students progress and play games (to refresh their mind)
+- During the final project period, conduct 1-on-1 session to give advices to students";
+const SEED_PROFILE_SKILLS: &str = r"Cloud Computing | Mostly manage and operating GCP and AWS in day to day basis
+Kubernetes | Manage hundreds of Kubernetes clusters and create my own cluster
+Programming (Rust, Go, Python) | Build, Deploy, Maintain, and Operate internal infra software for my organizations
+Terraform | Develop and maintain internal terraform modules
+Linux | Use Linux and in daily basis. Experienced delete whole database cluster";
+const SEED_PROFILE_LINKS: &str = r"AWS Certified Developer – Associate | https://www.credly.com/badges/a76fc573-ac7d-479a-a391-9b597b667850/public_url
+GCP Certified Associate Cloud Engineer | https://www.credential.net/c2cbc5cd-b8ca-41fc-8417-f618631078e1
+K8S Certified Kubernetes Administrator | https://www.credly.com/badges/a7ef8fab-b735-4c73-9fe2-39e14e2a458d/public_url";
+
+/// Return the seeded Profile
+pub fn seed_profile() -> Profile {
+    ProfileForm {
+        headline: SEED_PROFILE_HEADLINE.to_string(),
+        bio: SEED_PROFILE_BIO.to_string(),
+        experiences: SEED_PROFILE_EXPERIENCES.to_string(),
+        skills: SEED_PROFILE_SKILLS.to_string(),
+        links: SEED_PROFILE_LINKS.to_string(),
+    }
+    .validate(SEED_PROFILE_VERSION.to_string())
+    .expect("Seed profile is invalid.")
+}
+
+/// Seed the `profiles` table with the previously static Profile page
+/// Only run when there is no Profile version yet.
+pub async fn migrate_profile_seed(conn: &Connection) {
+    let prep_query = "SELECT COUNT(*) FROM profiles";
+    debug!("Executing query {}", &prep_query);
+    let count: i64 = conn
+        .query(prep_query, ())
+        .await
+        .expect("Failed to query profiles count.")
+        .next()
+        .await
+        .expect("Failed to access profiles count.")
+        .expect("Failed to access profiles count row.")
+        .get(0)
+        .unwrap();
+    if count > 0 {
+        debug!("Profiles are available. Skip seeding.");
+        return;
+    }
+
+    let profile = seed_profile();
+    conn.execute(
+        "INSERT OR IGNORE INTO profiles (version, headline, bio, experiences, skills, links) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        (
+            profile.version.clone(),
+            profile.headline,
+            profile.bio,
+            serde_json::to_string(&profile.experiences).unwrap(),
+            serde_json::to_string(&profile.skills).unwrap(),
+            serde_json::to_string(&profile.links).unwrap(),
+        ),
+    )
+    .await
+    .expect("Failed to seed a profile.");
+    info!("Seeded Profile version {}", &profile.version);
+}
+
+/// Convert a `PROFILE_COLUMNS` row into `Profile`
+/// Experiences, skills, and links are stored as JSON.
+/// Return `None` if any of them cannot be parsed.
+fn row_to_profile(row: &Row) -> Option<Profile> {
+    let version: String = row.get(0).unwrap();
+    let experiences: String = row.get(3).unwrap();
+    let skills: String = row.get(4).unwrap();
+    let links: String = row.get(5).unwrap();
+
+    let parsed = (
+        serde_json::from_str(&experiences),
+        serde_json::from_str(&skills),
+        serde_json::from_str(&links),
+    );
+    let (Ok(experiences), Ok(skills), Ok(links)) = parsed else {
+        error!("Failed to parse Profile version {}.", &version);
+        return None;
+    };
+
+    Some(Profile {
+        version,
+        headline: row.get(1).unwrap(),
+        bio: row.get(2).unwrap(),
+        experiences,
+        skills,
+        links,
+    })
+}
+
+#[async_trait]
+impl ProfileDisplayRepo for TursoDatabase {
+    async fn find(&self, version: String) -> Option<Profile> {
+        let prep_query = format!("SELECT {PROFILE_COLUMNS} WHERE version = ?1 LIMIT 1");
+        debug!("Executing query {} for version {}", &prep_query, &version);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find query.");
+
+        let res = stmt
+            .query([version.clone()])
+            .await
+            .expect("Failed to query profile.")
+            .next()
+            .await
+            .expect("Failed to access query profile.");
+
+        let Some(row) = res else {
+            debug!("No Profile with version {} is available.", &version);
+            return None;
+        };
+
+        debug!("Debug Row {:?}", &row);
+        row_to_profile(&row)
+    }
+    async fn find_latest(&self) -> Option<Profile> {
+        let prep_query = format!("SELECT {PROFILE_COLUMNS} ORDER BY version DESC LIMIT 1");
+        debug!("Executing query {}", &prep_query);
+
+        let res = self
+            .conn
+            .query(&prep_query, ())
+            .await
+            .expect("Failed to query latest profile.")
+            .next()
+            .await
+            .expect("Failed to access query latest profile.");
+
+        let Some(row) = res else {
+            debug!("No Profile is available.");
+            return None;
+        };
+
+        debug!("Debug Row {:?}", &row);
+        row_to_profile(&row)
+    }
+    async fn find_profiles(&self, params: ProfilesParams) -> Option<Profiles> {
+        let sanitized_params = params.sanitize();
+        let start_seq = sanitized_params.start.unwrap();
+        let end_seq = sanitized_params.end.unwrap();
+        let limit = end_seq - start_seq;
+        let prep_query =
+            format!("SELECT {PROFILE_COLUMNS} ORDER BY version DESC LIMIT ?1 OFFSET ?2");
+        debug!(
+            "Executing query {} with limit {} and start sequence {}",
+            &prep_query, limit, start_seq
+        );
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find profiles query.");
+
+        let mut rows = stmt
+            .query([limit, start_seq])
+            .await
+            .expect("Failed to query profiles.");
+
+        let mut profiles: Vec<Profile> = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            debug!("Debug Row {:?}", &row);
+            if let Some(profile) = row_to_profile(&row) {
+                profiles.push(profile);
+            }
+        }
+
+        Some(Profiles { profiles })
+    }
+}
+
+#[async_trait]
+impl ProfileOperationRepo for TursoDatabase {
+    async fn add(&mut self, profile: Profile) -> Option<ProfileCommandStatus> {
+        let prep_add_command = "INSERT INTO profiles (version, headline, bio, experiences, skills, links) VALUES (?1, ?2, ?3, ?4, ?5, ?6)";
+        debug!(
+            "Executing query {} for version {}",
+            &prep_add_command, &profile.version
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_add_command)
+            .await
+            .expect("Failed to prepare add Profile command.");
+
+        let exe = stmt
+            .execute((
+                profile.version,
+                profile.headline,
+                profile.bio,
+                serde_json::to_string(&profile.experiences).unwrap(),
+                serde_json::to_string(&profile.skills).unwrap(),
+                serde_json::to_string(&profile.links).unwrap(),
+            ))
+            .await
+            .expect("Failed to add a Profile.");
+        info!("Add Execution returned: {}", exe);
+
+        Some(ProfileCommandStatus::Stored)
+    }
+}
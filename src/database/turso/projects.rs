@@ -0,0 +1,232 @@
+use crate::database::turso::TursoDatabase;
+use crate::model::projects::*;
+use crate::repo::projects::{ProjectDisplayRepo, ProjectOperationRepo};
+use async_trait::async_trait;
+use libsql::Row;
+use tracing::{debug, error, info};
+
+/// Columns selected by Project queries
+const PROJECT_COLUMNS: &str = r"
+    id,
+    name,
+    summary,
+    body,
+    repo_url,
+    status,
+    tags,
+    position
+    FROM projects";
+
+/// Convert a `PROJECT_COLUMNS` row into `Project`
+/// Tech tags are stored as a comma separated string.
+/// Return `None` if the stored status cannot be parsed.
+fn row_to_project(row: &Row) -> Option<Project> {
+    let id: i64 = row.get(0).unwrap();
+    let raw_status: String = row.get(5).unwrap();
+    let status = match raw_status.parse::<ProjectStatus>() {
+        Ok(val) => val,
+        Err(err) => {
+            error!("Failed to parse status of Project Id {}. {}", &id, err);
+            return None;
+        }
+    };
+    let repo_url: Option<String> = row.get(4).unwrap();
+
+    Some(Project {
+        id,
+        name: row.get(1).unwrap(),
+        summary: row.get(2).unwrap(),
+        body: row.get(3).unwrap(),
+        repo_url: repo_url.filter(|val| !val.is_empty()),
+        status,
+        tags: row
+            .get::<String>(6)
+            .unwrap_or_default()
+            .split(',')
+            .filter(|tag| !tag.is_empty())
+            .map(|tag| tag.to_string())
+            .collect(),
+        position: row.get(7).unwrap(),
+    })
+}
+
+#[async_trait]
+impl ProjectDisplayRepo for TursoDatabase {
+    async fn find(&self, id: i64) -> Option<Project> {
+        let prep_query = format!("SELECT {PROJECT_COLUMNS} WHERE id = ?1 LIMIT 1");
+        debug!("Executing query {} for id {}", &prep_query, &id);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find query.");
+
+        let res = stmt
+            .query([id])
+            .await
+            .expect("Failed to query project.")
+            .next()
+            .await
+            .expect("Failed to access query project.");
+
+        let Some(row) = res else {
+            debug!("No Project with Id {} is available.", &id);
+            return None;
+        };
+
+        debug!("Debug Row {:?}", &row);
+        row_to_project(&row)
+    }
+    async fn find_projects(&self, params: ProjectsParams) -> Option<Projects> {
+        let sanitized_params = params.sanitize();
+        let start_seq = sanitized_params.start.unwrap();
+        let end_seq = sanitized_params.end.unwrap();
+        let limit = end_seq - start_seq;
+        let status = sanitized_params.status;
+        let tags = sanitized_params.tags;
+        // `?4` is a comma separated tag names. Split it and match projects
+        // with any of them against the stored comma separated tags.
+        let prep_query = format!(
+            "SELECT {PROJECT_COLUMNS}
+            WHERE (?3 IS NULL OR status = ?3)
+            AND (?4 IS NULL OR EXISTS (
+                WITH RECURSIVE split(tag, rest) AS (
+                    SELECT '', ?4 || ','
+                    UNION ALL
+                    SELECT substr(rest, 1, instr(rest, ',') - 1), substr(rest, instr(rest, ',') + 1)
+                    FROM split WHERE rest != ''
+                )
+                SELECT 1 FROM split
+                WHERE tag != ''
+                AND instr(',' || tags || ',', ',' || tag || ',') > 0
+            ))
+            ORDER BY position, id LIMIT ?1 OFFSET ?2"
+        );
+        debug!(
+            "Executing query {} for start {}, end {}, limit {}, status {:?}, tags {:?}",
+            &prep_query, &start_seq, &end_seq, &limit, &status, &tags
+        );
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find Projects query.");
+
+        let mut rows = stmt
+            .query((limit, start_seq, status, tags))
+            .await
+            .expect("Failed to query projects.");
+
+        let mut projects: Vec<Project> = Vec::new();
+        while let Some(row) = rows.next().await.unwrap() {
+            debug!("Find Projects {:?}", &row);
+
+            if let Some(project) = row_to_project(&row) {
+                projects.push(project);
+            }
+        }
+
+        Some(Projects { projects })
+    }
+}
+
+#[async_trait]
+impl ProjectOperationRepo for TursoDatabase {
+    async fn get_new_id(&self) -> Option<i64> {
+        let prep_query = "SELECT COALESCE(MAX(id), 0) + 1 FROM projects";
+        debug!("Executing query {}", &prep_query);
+
+        let row = self
+            .conn
+            .query(prep_query, ())
+            .await
+            .expect("Failed to query Projects new id.")
+            .next()
+            .await
+            .expect("Failed to access Projects new id.")
+            .expect("Failed to access Projects new id row.");
+
+        debug!("Get New Id {:?}", &row);
+        Some(row.get(0).unwrap())
+    }
+    async fn add(&mut self, project: Project) -> Option<ProjectCommandStatus> {
+        let prep_add_command = "INSERT INTO projects (id, name, summary, body, repo_url, status, tags, position) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)";
+        debug!(
+            "Executing query {} for id {}",
+            &prep_add_command, &project.id
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_add_command)
+            .await
+            .expect("Failed to prepare add Project command.");
+
+        let exe = stmt
+            .execute((
+                project.id,
+                project.name,
+                project.summary,
+                project.body,
+                project.repo_url,
+                project.status.to_string(),
+                project.tags.join(","),
+                project.position,
+            ))
+            .await
+            .expect("Failed to add a Project.");
+        info!("Add Execution returned: {}", exe);
+
+        Some(ProjectCommandStatus::Stored)
+    }
+    async fn update(&mut self, project: Project) -> Option<ProjectCommandStatus> {
+        let prep_update_command = "UPDATE projects SET name = ?2, summary = ?3, body = ?4, repo_url = ?5, status = ?6, tags = ?7, position = ?8 WHERE id = ?1";
+        debug!(
+            "Executing query {} for id {}",
+            &prep_update_command, &project.id
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_update_command)
+            .await
+            .expect("Failed to prepare update Project command.");
+
+        let exe = stmt
+            .execute((
+                project.id,
+                project.name,
+                project.summary,
+                project.body,
+                project.repo_url,
+                project.status.to_string(),
+                project.tags.join(","),
+                project.position,
+            ))
+            .await
+            .expect("Failed to update a Project.");
+        info!("Update Execution returned: {}", exe);
+
+        Some(ProjectCommandStatus::Updated)
+    }
+    async fn delete(&mut self, id: i64) -> Option<ProjectCommandStatus> {
+        let prep_delete_command = "DELETE FROM projects WHERE id = ?1";
+        debug!("Executing query {} for id {}", &prep_delete_command, &id);
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete Project command.");
+
+        let exe = stmt
+            .execute([id])
+            .await
+            .expect("Failed to delete a Project.");
+        debug!("Delete Execution returned: {}", exe);
+
+        Some(ProjectCommandStatus::Deleted)
+    }
+}
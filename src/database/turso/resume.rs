@@ -0,0 +1,108 @@
+use crate::database::turso::TursoDatabase;
+use crate::model::resume::*;
+use crate::repo::resume::{ResumeDisplayRepo, ResumeOperationRepo};
+use async_trait::async_trait;
+use libsql::Row;
+use tracing::{debug, error, info};
+
+/// The `resume` table only has a single Resume row
+const RESUME_ID: i64 = 1;
+
+/// Columns selected by Resume queries
+const RESUME_COLUMNS: &str = r"
+    basics,
+    work,
+    education,
+    certificates,
+    skills
+    FROM resume";
+
+/// Convert a `RESUME_COLUMNS` row into `Resume`
+/// All sections are stored as JSON.
+/// Return `None` if any of them cannot be parsed.
+fn row_to_resume(row: &Row) -> Option<Resume> {
+    let basics: String = row.get(0).unwrap();
+    let work: String = row.get(1).unwrap();
+    let education: String = row.get(2).unwrap();
+    let certificates: String = row.get(3).unwrap();
+    let skills: String = row.get(4).unwrap();
+
+    let parsed = (
+        serde_json::from_str(&basics),
+        serde_json::from_str(&work),
+        serde_json::from_str(&education),
+        serde_json::from_str(&certificates),
+        serde_json::from_str(&skills),
+    );
+    let (Ok(basics), Ok(work), Ok(education), Ok(certificates), Ok(skills)) = parsed else {
+        error!("Failed to parse Resume.");
+        return None;
+    };
+
+    Some(Resume {
+        basics,
+        work,
+        education,
+        certificates,
+        skills,
+    })
+}
+
+#[async_trait]
+impl ResumeDisplayRepo for TursoDatabase {
+    async fn find(&self) -> Option<Resume> {
+        let prep_query = format!("SELECT {RESUME_COLUMNS} WHERE id = ?1 LIMIT 1");
+        debug!("Executing query {}", &prep_query);
+
+        let stmt = self
+            .conn
+            .prepare(&prep_query)
+            .await
+            .expect("Failed to prepare find query.");
+
+        let res = stmt
+            .query([RESUME_ID])
+            .await
+            .expect("Failed to query resume.")
+            .next()
+            .await
+            .expect("Failed to access query resume.");
+
+        let Some(row) = res else {
+            debug!("No Resume is available.");
+            return None;
+        };
+
+        debug!("Debug Row {:?}", &row);
+        row_to_resume(&row)
+    }
+}
+
+#[async_trait]
+impl ResumeOperationRepo for TursoDatabase {
+    async fn save(&mut self, resume: Resume) -> Option<ResumeCommandStatus> {
+        let prep_save_command = "INSERT OR REPLACE INTO resume (id, basics, work, education, certificates, skills) VALUES (?1, ?2, ?3, ?4, ?5, ?6)";
+        debug!("Executing query {}", &prep_save_command);
+
+        let stmt = self
+            .conn
+            .prepare(prep_save_command)
+            .await
+            .expect("Failed to prepare save Resume command.");
+
+        let exe = stmt
+            .execute((
+                RESUME_ID,
+                serde_json::to_string(&resume.basics).unwrap(),
+                serde_json::to_string(&resume.work).unwrap(),
+                serde_json::to_string(&resume.education).unwrap(),
+                serde_json::to_string(&resume.certificates).unwrap(),
+                serde_json::to_string(&resume.skills).unwrap(),
+            ))
+            .await
+            .expect("Failed to save a Resume.");
+        info!("Save Execution returned: {}", exe);
+
+        Some(ResumeCommandStatus::Stored)
+    }
+}
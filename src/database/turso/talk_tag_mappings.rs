@@ -0,0 +1,147 @@
+use crate::database::turso::TursoDatabase;
+use crate::model::talk_tag_mappings::*;
+use crate::repo::talk_tag_mappings::{TalkTagMappingDisplayRepo, TalkTagMappingOperationRepo};
+use async_trait::async_trait;
+use tracing::debug;
+
+#[async_trait]
+impl TalkTagMappingDisplayRepo for TursoDatabase {
+    async fn find_by_talk_id(&self, talk_id: i64) -> Option<TalkTagMappings> {
+        let prep_query = r"
+            SELECT
+                talk_ref,
+                tag_ref
+            FROM talk_tag_mapping
+            WHERE talk_ref = ?1
+        ";
+        debug!("Executing query {} for id {}", &prep_query, &talk_id);
+
+        let stmt = self
+            .conn
+            .prepare(prep_query)
+            .await
+            .expect("Failed to prepare find talk tag mappings by talk_id query.");
+
+        let mut rows = stmt
+            .query([talk_id])
+            .await
+            .expect("Failed to query talk tag mappings by talk_id.");
+
+        let mut maps: Vec<TalkTagMapping> = Vec::new();
+
+        while let Some(row) = rows.next().await.unwrap() {
+            debug!("Debug Row {:?}", &row);
+            maps.push(TalkTagMapping {
+                talk_id: row.get(0).unwrap(),
+                tag_id: row.get(1).unwrap(),
+            });
+        }
+
+        Some(TalkTagMappings { maps })
+    }
+    async fn find_by_tag_id(&self, tag_id: i64) -> Option<TalkTagMappings> {
+        let prep_query = r"
+            SELECT
+                talk_ref,
+                tag_ref
+            FROM talk_tag_mapping
+            WHERE tag_ref = ?1
+        ";
+        debug!("Executing query {} for id {}", &prep_query, &tag_id);
+
+        let stmt = self
+            .conn
+            .prepare(prep_query)
+            .await
+            .expect("Failed to prepare find talk tag mappings by talk_id query.");
+
+        let mut rows = stmt
+            .query([tag_id])
+            .await
+            .expect("Failed to query talk tag mappings by talk_id.");
+
+        let mut maps: Vec<TalkTagMapping> = Vec::new();
+
+        while let Some(row) = rows.next().await.unwrap() {
+            debug!("Debug Row {:?}", &row);
+            maps.push(TalkTagMapping {
+                talk_id: row.get(0).unwrap(),
+                tag_id: row.get(1).unwrap(),
+            });
+        }
+
+        Some(TalkTagMappings { maps })
+    }
+}
+
+#[async_trait]
+impl TalkTagMappingOperationRepo for TursoDatabase {
+    async fn add(&mut self, talk_id: i64, tag_id: i64) -> Option<TalkTagMappingCommandStatus> {
+        let prep_add_command = "INSERT INTO talk_tag_mapping (talk_ref, tag_ref) VALUES (?1, ?2)";
+        debug!(
+            "Executing query {} for talk_id {} and tag_id {}",
+            &prep_add_command, &talk_id, &tag_id
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_add_command)
+            .await
+            .expect("Failed to prepare add commmand.");
+
+        let exe = stmt
+            .execute((talk_id, tag_id))
+            .await
+            .expect("Failed to add talk tag mapping.");
+        debug!("Add Execution returned: {}", exe);
+
+        Some(TalkTagMappingCommandStatus::Stored)
+    }
+    async fn delete_by_talk_id(&mut self, talk_id: i64) -> Option<TalkTagMappingCommandStatus> {
+        let prep_delete_command = "DELETE FROM talk_tag_mapping WHERE talk_ref = ?1";
+        debug!(
+            "Executing query {} for id {}",
+            &prep_delete_command, &talk_id
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete command.");
+
+        let exe = stmt
+            .execute([talk_id])
+            .await
+            .expect("Failed to delete a Talk Tag Mapping.");
+
+        debug!("Delete Execution returned: {}", exe);
+        Some(TalkTagMappingCommandStatus::Deleted)
+    }
+    async fn delete_by_talk_id_and_tag_id(
+        &mut self,
+        talk_id: i64,
+        tag_id: i64,
+    ) -> Option<TalkTagMappingCommandStatus> {
+        let prep_delete_command =
+            "DELETE FROM talk_tag_mapping WHERE talk_ref = ?1 AND tag_ref = ?2";
+        debug!(
+            "Executing query {} for talk id {} and tag id {}",
+            &prep_delete_command, &talk_id, &tag_id
+        );
+
+        let stmt = self
+            .conn
+            .prepare(prep_delete_command)
+            .await
+            .expect("Failed to prepare delete command.");
+
+        let exe = stmt
+            .execute([talk_id, tag_id])
+            .await
+            .expect("Failed to delete a Talk Tag Mapping.");
+
+        debug!("Delete Execution returned: {}", exe);
+        Some(TalkTagMappingCommandStatus::Deleted)
+    }
+}
@@ -1,18 +1,234 @@
-use crate::database::turso::TursoDatabase;
+use crate::database::turso::{updated_at_now, TursoDatabase};
 use crate::model::talks::*;
 use crate::repo::talks::{TalkDisplayRepo, TalkOperationRepo};
 use async_trait::async_trait;
-use tracing::{debug, info};
+use chrono::NaiveDate;
+use libsql::{Connection, Row};
+use tracing::{debug, error, info, warn};
+
+/// Convert a `TALK_COLUMNS` row into `Talk`
+/// Empty media and organisation columns are converted to `None`.
+/// A stored date that cannot be parsed is kept in `invalid_date`, so the Talk
+/// can still be fixed from the admin.
+fn row_to_talk(row: &Row) -> Option<Talk> {
+    let id: i64 = row.get(0).unwrap();
+    let raw_date: String = row.get(2).unwrap();
+    let (date, invalid_date) = match parse_talk_date(&raw_date) {
+        Some(date) => (date, None),
+        None => {
+            error!("Failed to parse date '{}' of Talk Id {}", &raw_date, &id);
+            (NaiveDate::default(), Some(raw_date))
+        }
+    };
+
+    let non_empty = |val: Option<String>| val.filter(|val| !val.is_empty());
+
+    Some(Talk {
+        id,
+        name: row.get(1).unwrap(),
+        date,
+        invalid_date,
+        media_link: non_empty(row.get(3).unwrap()),
+        org_id: row.get(6).unwrap(),
+        org_name: non_empty(row.get(4).unwrap()),
+        org_link: non_empty(row.get(5).unwrap()),
+        tags: row
+            .get::<String>(7)
+            .unwrap_or_default()
+            .split(',')
+            .filter(|tag| !tag.is_empty())
+            .map(|tag| tag.to_string())
+            .collect(),
+    })
+}
+
+/// Columns selected by Talk queries. Organisation name and link
+/// are resolved from the `organizations` table and tag names from
+/// the `talk_tag_mapping` table.
+const TALK_COLUMNS: &str = r"
+    t.id,
+    t.name,
+    t.date,
+    t.media_link,
+    o.name,
+    o.url,
+    t.org_id,
+    (
+        SELECT group_concat(tags.name, ',')
+        FROM talk_tag_mapping
+        JOIN tags ON tag_ref = tags.id
+        WHERE talk_ref = t.id
+    )
+    FROM talks t
+    LEFT JOIN organizations o ON o.id = t.org_id";
+
+/// Migrate stored Talk dates into `TALK_DATE_FORMAT`
+/// Parse every free-form `talks.date` and rewrite it into the canonical format.
+/// Dates that cannot be parsed are left untouched and reported in the logs.
+pub async fn migrate_talk_dates(conn: &Connection) {
+    let prep_query = "SELECT id, date FROM talks";
+    debug!("Executing query {}", &prep_query);
+
+    let mut rows = conn
+        .query(prep_query, ())
+        .await
+        .expect("Failed to query talk dates.");
+
+    let mut stored_dates: Vec<(i64, String)> = Vec::new();
+    while let Some(row) = rows.next().await.unwrap() {
+        stored_dates.push((row.get(0).unwrap(), row.get(1).unwrap()));
+    }
+
+    let mut migrated = 0;
+    let mut unparsable = 0;
+    for (id, raw_date) in stored_dates {
+        let Some(date) = parse_talk_date(&raw_date) else {
+            warn!(
+                "Talk Id {} has unparsable or ambiguous date '{}'. Please fix it in the admin talks.",
+                &id, &raw_date
+            );
+            unparsable += 1;
+            continue;
+        };
+
+        let formatted_date = date.format(TALK_DATE_FORMAT).to_string();
+        if formatted_date == raw_date {
+            continue;
+        }
+
+        let prep_update_command = "UPDATE talks SET date = ?1 WHERE id = ?2";
+        debug!("Executing query {} for id {}", &prep_update_command, &id);
+        conn.execute(prep_update_command, (formatted_date.clone(), id))
+            .await
+            .expect("Failed to migrate a talk date.");
+        info!(
+            "Migrated Talk Id {} date from '{}' to '{}'",
+            &id, &raw_date, &formatted_date
+        );
+        migrated += 1;
+    }
+
+    info!("Talk dates migration finished. {migrated} migrated, {unparsable} unparsable.");
+}
+
+/// Migrate free-form Talk organisations into the `organizations` table
+/// Add `talks.org_id` column if missing, then create one Organization per
+/// distinct (case-insensitive) legacy `org_name` and link the Talks to it.
+/// Legacy `org_name` and `org_link` columns are cleared once migrated.
+pub async fn migrate_talk_organizations(conn: &Connection) {
+    let prep_column_query = "SELECT COUNT(*) FROM pragma_table_info('talks') WHERE name = 'org_id'";
+    debug!("Executing query {}", &prep_column_query);
+    let column_count: i64 = conn
+        .query(prep_column_query, ())
+        .await
+        .expect("Failed to query talks columns.")
+        .next()
+        .await
+        .expect("Failed to access talks columns.")
+        .expect("Failed to access talks columns row.")
+        .get(0)
+        .unwrap();
+    if column_count == 0 {
+        info!("Adding org_id column into talks table");
+        conn.execute(
+            "ALTER TABLE talks ADD COLUMN org_id INTEGER REFERENCES organizations(id)",
+            (),
+        )
+        .await
+        .expect("Failed to add talks org_id column.");
+    }
+
+    let prep_query = "SELECT id, trim(org_name), org_link FROM talks
+        WHERE org_id IS NULL AND trim(coalesce(org_name, '')) != ''
+        ORDER BY id";
+    debug!("Executing query {}", &prep_query);
+    let mut rows = conn
+        .query(prep_query, ())
+        .await
+        .expect("Failed to query talk organizations.");
+
+    let mut legacy_orgs: Vec<(i64, String, Option<String>)> = Vec::new();
+    while let Some(row) = rows.next().await.unwrap() {
+        let org_link: Option<String> = row.get(2).unwrap();
+        legacy_orgs.push((
+            row.get(0).unwrap(),
+            row.get(1).unwrap(),
+            org_link.filter(|val| !val.trim().is_empty()),
+        ));
+    }
+
+    let mut created = 0;
+    for (talk_id, org_name, org_link) in &legacy_orgs {
+        let existing_org: Option<i64> = conn
+            .query(
+                "SELECT id FROM organizations WHERE lower(name) = lower(?1) ORDER BY id LIMIT 1",
+                [org_name.clone()],
+            )
+            .await
+            .expect("Failed to query organization by name.")
+            .next()
+            .await
+            .expect("Failed to access organization by name.")
+            .map(|row| row.get(0).unwrap());
+
+        let org_id = match existing_org {
+            Some(org_id) => {
+                // Fill the organisation link if it's not set yet
+                conn.execute(
+                    "UPDATE organizations SET url = ?2 WHERE id = ?1 AND coalesce(url, '') = ''",
+                    (org_id, org_link.clone()),
+                )
+                .await
+                .expect("Failed to update organization link.");
+                org_id
+            }
+            None => {
+                let org_id: i64 = conn
+                    .query("SELECT COALESCE(MAX(id), 0) + 1 FROM organizations", ())
+                    .await
+                    .expect("Failed to query Organizations new id.")
+                    .next()
+                    .await
+                    .expect("Failed to access Organizations new id.")
+                    .expect("Failed to access Organizations new id row.")
+                    .get(0)
+                    .unwrap();
+                conn.execute(
+                    "INSERT INTO organizations (id, name, url) VALUES (?1, ?2, ?3)",
+                    (org_id, org_name.clone(), org_link.clone()),
+                )
+                .await
+                .expect("Failed to add an organization.");
+                info!("Created Organization Id {} for '{}'", &org_id, &org_name);
+                created += 1;
+                org_id
+            }
+        };
+
+        conn.execute(
+            "UPDATE talks SET org_id = ?1, org_name = NULL, org_link = NULL WHERE id = ?2",
+            (org_id, *talk_id),
+        )
+        .await
+        .expect("Failed to link a talk to an organization.");
+        debug!("Linked Talk Id {} to Organization Id {}", &talk_id, &org_id);
+    }
+
+    info!(
+        "Talk organizations migration finished. {} talks linked, {created} organizations created.",
+        legacy_orgs.len()
+    );
+}
 
 #[async_trait]
 impl TalkDisplayRepo for TursoDatabase {
     async fn find(&self, id: i64) -> Option<Talk> {
-        let prep_query = "SELECT * FROM talks WHERE id = ?1 ORDER BY id";
+        let prep_query = format!("SELECT {TALK_COLUMNS} WHERE t.id = ?1");
         debug!("Executing query {} for id {}", &prep_query, &id);
 
         let stmt = self
             .conn
-            .prepare(prep_query)
+            .prepare(&prep_query)
             .await
             .expect("Failed to prepare find query.");
 
@@ -27,47 +243,43 @@ impl TalkDisplayRepo for TursoDatabase {
 
         debug!("Find {:?}", &row);
 
-        let mut media_link: Option<String> = row.get(3).unwrap();
-        if media_link.clone().unwrap().is_empty() {
-            media_link = None;
-        }
-        let mut org_name: Option<String> = row.get(4).unwrap();
-        if org_name.clone().unwrap().is_empty() {
-            org_name = None;
-        }
-        let mut org_link: Option<String> = row.get(5).unwrap();
-        if org_link.clone().unwrap().is_empty() {
-            org_link = None;
-        }
-
-        Some(Talk {
-            id: row.get(0).unwrap(),
-            name: row.get(1).unwrap(),
-            date: row.get(2).unwrap(),
-            media_link,
-            org_name,
-            org_link,
-        })
+        row_to_talk(&row)
     }
     async fn find_talks(&self, params: TalksParams) -> Option<Talks> {
         let sanitized_params = params.sanitize();
         let start_seq = sanitized_params.start.unwrap();
         let end_seq = sanitized_params.end.unwrap();
         let limit = end_seq - start_seq;
-        let prep_query = "SELECT * FROM talks ORDER BY id DESC LIMIT ?1 OFFSET ?2";
+        let year = sanitized_params.year.map(|val| format!("{val:04}"));
+        let org = sanitized_params.org;
+        let tags = sanitized_params.tags;
+        // `?5` is a comma separated tag names. Match talks with any of them.
+        let prep_query = format!(
+            "SELECT {TALK_COLUMNS}
+            WHERE (?3 IS NULL OR substr(t.date, 1, 4) = ?3)
+            AND (?4 IS NULL OR o.name = ?4)
+            AND (?5 IS NULL OR EXISTS (
+                SELECT 1
+                FROM talk_tag_mapping
+                JOIN tags ON tag_ref = tags.id
+                WHERE talk_ref = t.id
+                AND instr(',' || ?5 || ',', ',' || tags.name || ',') > 0
+            ))
+            ORDER BY t.id DESC LIMIT ?1 OFFSET ?2"
+        );
         debug!(
-            "Executing query {} for start {}, end {}, limit {}",
-            &prep_query, &start_seq, &end_seq, &limit
+            "Executing query {} for start {}, end {}, limit {}, year {:?}, org {:?}, tags {:?}",
+            &prep_query, &start_seq, &end_seq, &limit, &year, &org, &tags
         );
 
         let stmt = self
             .conn
-            .prepare(prep_query)
+            .prepare(&prep_query)
             .await
             .expect("Failed to prepare find Talks query.");
 
         let mut rows = stmt
-            .query([limit, start_seq])
+            .query((limit, start_seq, year, org, tags))
             .await
             .expect("Failed to query talks.");
 
@@ -76,27 +288,9 @@ impl TalkDisplayRepo for TursoDatabase {
         while let Some(row) = rows.next().await.unwrap() {
             debug!("Find Talks {:?}", &row);
 
-            let mut media_link: Option<String> = row.get(3).unwrap();
-            if media_link.clone().unwrap().is_empty() {
-                media_link = None;
-            }
-            let mut org_name: Option<String> = row.get(4).unwrap();
-            if org_name.clone().unwrap().is_empty() {
-                org_name = None;
+            if let Some(talk) = row_to_talk(&row) {
+                talks.push(talk);
             }
-            let mut org_link: Option<String> = row.get(5).unwrap();
-            if org_link.clone().unwrap().is_empty() {
-                org_link = None;
-            }
-
-            talks.push(Talk {
-                id: row.get(0).unwrap(),
-                name: row.get(1).unwrap(),
-                date: row.get(2).unwrap(),
-                media_link,
-                org_name,
-                org_link,
-            });
         }
 
         Some(Talks { talks })
@@ -130,13 +324,12 @@ impl TalkOperationRepo for TursoDatabase {
         &mut self,
         id: i64,
         name: String,
-        date: String,
+        date: NaiveDate,
         media_link: Option<String>,
-        org_name: Option<String>,
-        org_link: Option<String>,
+        org_id: Option<i64>,
     ) -> Option<TalkCommandStatus> {
         let talk_name = &name;
-        let talk_date = &date;
+        let talk_date = date.format(TALK_DATE_FORMAT).to_string();
         let talk_media_link = media_link.map_or_else(
             || "".to_string(),
             |val| {
@@ -144,23 +337,9 @@ impl TalkOperationRepo for TursoDatabase {
                 val
             },
         );
-        let talk_org_name = org_name.map_or_else(
-            || "".to_string(),
-            |val| {
-                debug!("Organization Name is present for talk id {}", &id);
-                val
-            },
-        );
-        let talk_org_link = org_link.map_or_else(
-            || "".to_string(),
-            |val| {
-                debug!("Organization Link is present for talk id {}", &id);
-                val
-            },
-        );
 
         let prep_add_command =
-            "INSERT INTO talks (id, name, date, media_link, org_name, org_link) VALUES (?1, ?2, ?3, ?4, ?5, ?6)";
+            "INSERT INTO talks (id, name, date, media_link, org_id, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)";
         debug!("Executing query {} for id {}", &prep_add_command, &id);
 
         let stmt = self
@@ -177,8 +356,8 @@ impl TalkOperationRepo for TursoDatabase {
                 talk_name.clone(),
                 talk_date.clone(),
                 talk_media_link.clone(),
-                talk_org_name.clone(),
-                talk_org_link.clone(),
+                org_id,
+                updated_at_now(),
             ))
             .await
             .expect("Failed to add a Talk.");
@@ -205,10 +384,9 @@ impl TalkOperationRepo for TursoDatabase {
         &mut self,
         id: i64,
         name: Option<String>,
-        date: Option<String>,
+        date: Option<NaiveDate>,
         media_link: Option<String>,
-        org_name: Option<String>,
-        org_link: Option<String>,
+        org_id: Option<i64>,
     ) -> Option<TalkCommandStatus> {
         let mut affected_col = "".to_string();
         match &name {
@@ -222,7 +400,11 @@ impl TalkOperationRepo for TursoDatabase {
         }
         match &date {
             Some(val) => {
-                affected_col = format!("{} date = '{}' ,", &affected_col, val);
+                affected_col = format!(
+                    "{} date = '{}' ,",
+                    &affected_col,
+                    val.format(TALK_DATE_FORMAT)
+                );
                 debug!("Affected Column: '{}'", &affected_col)
             }
             None => {
@@ -238,24 +420,13 @@ impl TalkOperationRepo for TursoDatabase {
                 debug!("Skipped update media_link field")
             }
         }
-        match &org_name {
-            Some(val) => {
-                affected_col = format!("{} org_name = '{}' ,", &affected_col, val);
-                debug!("Affected Column: '{}'", &affected_col)
-            }
-            None => {
-                debug!("Skipped update org_name field")
-            }
-        }
-        match &org_link {
-            Some(val) => {
-                affected_col = format!("{} org_link = '{}' ,", &affected_col, val);
-                debug!("Affected Column: '{}'", &affected_col)
-            }
-            None => {
-                debug!("Skipped update org_link field")
-            }
+        // Organisation is always updated. `None` detach the Talk from its organisation
+        match &org_id {
+            Some(val) => affected_col = format!("{} org_id = {} ,", &affected_col, val),
+            None => affected_col = format!("{} org_id = NULL ,", &affected_col),
         }
+        affected_col = format!("{} updated_at = '{}' ,", &affected_col, updated_at_now());
+        debug!("Affected Column: '{}'", &affected_col);
         // Trimming the last ','
         affected_col = affected_col.as_str()[0..affected_col.len() - 1].to_string();
 
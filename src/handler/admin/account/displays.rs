@@ -0,0 +1,111 @@
+use crate::handler::admin::account::{
+    render_admin_account_password, render_admin_account_sessions, render_admin_account_totp,
+};
+use crate::handler::status::get_500_internal_server_error;
+use crate::model::auth::{AuthUser, ACCOUNT_LOGIN_ACTIVITIES_LIMIT};
+use crate::model::axum::AppState;
+use crate::model::templates_admin::{AdminAccountTemplate, AdminGetAccountLoginsTemplate};
+use crate::repo::auth::AuthRepo;
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::State;
+use axum::response::Html;
+use tracing::{error, info};
+
+/// get_base_admin_account
+/// Serve GET (base) admin account HTML file of the logged in User
+/// Under endpoint /admin/account
+#[debug_handler]
+pub async fn get_base_admin_account(auth_user: AuthUser) -> Html<String> {
+    let account = AdminAccountTemplate {
+        email: auth_user.user.email,
+        role: auth_user.user.role.to_string(),
+    };
+
+    match account.render() {
+        Ok(res) => {
+            info!("AdminAccount askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/account/account.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_account_password
+/// Serve GET change password HTML file in a form format
+/// Under endpoint /admin/account/password
+#[debug_handler]
+pub async fn get_admin_account_password(State(app_state): State<AppState>) -> Html<String> {
+    render_admin_account_password(
+        app_state.config.password_min_length,
+        String::new(),
+        Vec::new(),
+    )
+}
+
+/// get_admin_account_totp
+/// Serve GET two-factor authentication status HTML file of the logged in User
+/// Under endpoint /admin/account/totp
+#[debug_handler]
+pub async fn get_admin_account_totp(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let user_totp = auth_uc.find_user_totp(auth_user.user.id).await;
+    render_admin_account_totp(user_totp.as_ref(), String::new(), Vec::new())
+}
+
+/// get_admin_account_logins
+/// Serve GET login history HTML file of the logged in User
+/// Under endpoint /admin/account/logins
+#[debug_handler]
+pub async fn get_admin_account_logins(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    // Login attempts are recorded by the submitted email
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(login_activities) = auth_uc
+        .find_email_login_activities(auth_user.user.email, ACCOUNT_LOGIN_ACTIVITIES_LIMIT)
+        .await
+    else {
+        error!("Failed to find login activities.");
+        return get_500_internal_server_error();
+    };
+
+    match (AdminGetAccountLoginsTemplate { login_activities }).render() {
+        Ok(res) => {
+            info!("AdminGetAccountLogins askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/account/get_account_logins.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_account_sessions
+/// Serve GET active sessions HTML file of the logged in User
+/// Under endpoint /admin/account/sessions
+#[debug_handler]
+pub async fn get_admin_account_sessions(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    render_admin_account_sessions(
+        &app_state,
+        auth_user.user.id,
+        auth_user.session.id,
+        String::new(),
+        Vec::new(),
+    )
+    .await
+}
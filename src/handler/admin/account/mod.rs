@@ -0,0 +1,223 @@
+pub mod displays;
+pub mod operations;
+
+use crate::handler::auth::totp::render_totp_qr_svg;
+use crate::handler::status::get_500_internal_server_error;
+use crate::model::auth::Session;
+use crate::model::axum::AppState;
+use crate::model::templates_admin::{
+    AdminGetAccountPasswordTemplate, AdminGetAccountSessionsTemplate,
+    AdminGetAccountTotpRecoveryCodesTemplate, AdminGetAccountTotpSetupTemplate,
+    AdminGetAccountTotpTemplate,
+};
+use crate::model::totp::{base32_encode, totp_uri, UserTotp};
+use crate::repo::auth::AuthRepo;
+use askama::Template;
+use axum::response::Html;
+use chrono::Utc;
+use tracing::{debug, error, info, warn};
+use urlencoding::decode;
+
+/// Take request body String from POST change password to get the current,
+/// new and confirmed passwords
+fn process_password_body(body: &str) -> (String, String, String) {
+    // Initialize fields
+    let mut current_password = String::new();
+    let mut new_password = String::new();
+    let mut confirm_password = String::new();
+
+    for req_field in body.split("&") {
+        let Some((key, value)) = req_field.split_once("=") else {
+            continue;
+        };
+        let value_decoded = decode(value).unwrap_or_default().to_string();
+        match key {
+            "current_password" => current_password = value_decoded,
+            "new_password" => new_password = value_decoded,
+            "confirm_password" => confirm_password = value_decoded,
+            _ => {
+                warn!("Unrecognized key: {:?}", key);
+            }
+        }
+    }
+    debug!("Processed change password body");
+
+    (current_password, new_password, confirm_password)
+}
+
+// Render the change password form with an optional notice and validation errors
+fn render_admin_account_password(
+    password_min_length: usize,
+    notice: String,
+    errors: Vec<String>,
+) -> Html<String> {
+    let password_form = AdminGetAccountPasswordTemplate {
+        password_min_length,
+        notice,
+        errors,
+    };
+    match password_form.render() {
+        Ok(res) => {
+            info!("AdminGetAccountPassword askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/account/get_account_password.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// Take request body String from POST two-factor authentication forms to get the code
+fn process_totp_code_body(body: &str) -> String {
+    body.split("&")
+        .filter_map(|req_field| req_field.split_once("="))
+        .find(|(key, _)| *key == "totp_code")
+        .and_then(|(_, value)| decode(value).ok())
+        .map(|value| value.trim().to_string())
+        .unwrap_or_default()
+}
+
+// Render the two-factor authentication status of the logged in User
+fn render_admin_account_totp(
+    user_totp: Option<&UserTotp>,
+    notice: String,
+    errors: Vec<String>,
+) -> Html<String> {
+    let account_totp = AdminGetAccountTotpTemplate {
+        enabled: user_totp.is_some_and(|user_totp| user_totp.enabled),
+        recovery_codes_left: user_totp.map_or(0, |user_totp| user_totp.recovery_codes.len()),
+        notice,
+        errors,
+    };
+    match account_totp.render() {
+        Ok(res) => {
+            info!("AdminGetAccountTotp askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/account/get_account_totp.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+// Render the QR code and the confirmation form of a pending TOTP enrollment
+fn render_admin_account_totp_setup(
+    totp_secret: &[u8],
+    email: &str,
+    errors: Vec<String>,
+) -> Html<String> {
+    let Some(qr_svg) = render_totp_qr_svg(&totp_uri(totp_secret, email)) else {
+        return get_500_internal_server_error();
+    };
+    let account_totp_setup = AdminGetAccountTotpSetupTemplate {
+        qr_svg,
+        secret: base32_encode(totp_secret),
+        errors,
+    };
+    match account_totp_setup.render() {
+        Ok(res) => {
+            info!("AdminGetAccountTotpSetup askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/account/get_account_totp_setup.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+// Render recovery codes which are only shown once
+fn render_admin_account_totp_recovery_codes(recovery_codes: Vec<String>) -> Html<String> {
+    let account_recovery_codes = AdminGetAccountTotpRecoveryCodesTemplate { recovery_codes };
+    match account_recovery_codes.render() {
+        Ok(res) => {
+            info!("AdminGetAccountTotpRecoveryCodes askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/account/get_account_totp_recovery_codes.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+// Render the active Sessions of the logged in User
+// `current_session_id` is the Session of the request, which cannot be revoked here.
+async fn render_admin_account_sessions(
+    app_state: &AppState,
+    user_id: String,
+    current_session_id: String,
+    notice: String,
+    errors: Vec<String>,
+) -> Html<String> {
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let now = Utc::now();
+    let sessions: Vec<Session> = auth_uc
+        .find_user_sessions(user_id)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|session| !session.is_expired(now))
+        .collect();
+    let account_sessions = AdminGetAccountSessionsTemplate {
+        sessions,
+        current_session_id,
+        notice,
+        errors,
+    };
+    match account_sessions.render() {
+        Ok(res) => {
+            info!("AdminGetAccountSessions askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/account/get_account_sessions.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_process_password_body() {
+        let (current, new, confirm) = process_password_body(
+            "current_password=old%26pw&new_password=n%C3%A9w-pw&confirm_password=other",
+        );
+        assert_eq!(current, "old&pw");
+        assert_eq!(new, "néw-pw");
+        assert_eq!(confirm, "other");
+
+        let (current, new, confirm) = process_password_body("");
+        assert!(current.is_empty() && new.is_empty() && confirm.is_empty());
+    }
+
+    #[test]
+    fn test_process_totp_code_body() {
+        assert_eq!(process_totp_code_body("totp_code=%20123456%20"), "123456");
+        assert_eq!(
+            process_totp_code_body("totp_code=abcde-12345"),
+            "abcde-12345"
+        );
+        assert_eq!(process_totp_code_body("other=1"), "");
+    }
+}
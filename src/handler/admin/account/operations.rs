@@ -0,0 +1,373 @@
+use crate::handler::admin::account::{
+    process_password_body, process_totp_code_body, render_admin_account_password,
+    render_admin_account_sessions, render_admin_account_totp,
+    render_admin_account_totp_recovery_codes, render_admin_account_totp_setup,
+};
+use crate::handler::admin::audit::{record_audit_log, AuditContext};
+use crate::handler::auth::csrf::csrf_clear_cookie_header;
+use crate::handler::auth::displays::get_logout;
+use crate::handler::auth::session::append_session_clear_cookies;
+use crate::handler::auth::totp::{
+    decrypt_totp_secret, encrypt_totp_secret, generate_recovery_codes, generate_totp_secret,
+    verify_totp_or_recovery_code,
+};
+use crate::handler::auth::{hash_password, is_password_match, sanitize_password};
+use crate::handler::status::get_500_internal_server_error;
+use crate::handler::HX_REDIRECT;
+use crate::model::audit_logs::{AUDIT_ACTION_UPDATE, AUDIT_ENTITY_USER};
+use crate::model::auth::{AuthUser, PasswordPolicy, SessionCommandStatus, User, UserCommandStatus};
+use crate::model::axum::AppState;
+use crate::model::totp::{verify_totp_code, UserTotp, UserTotpCommandStatus};
+use crate::repo::auth::AuthRepo;
+use axum::debug_handler;
+use axum::extract::{ConnectInfo, Path, State};
+use axum::http::header::SET_COOKIE;
+use axum::http::HeaderMap;
+use axum::response::{Html, IntoResponse};
+use chrono::Utc;
+use std::net::SocketAddr;
+use tracing::{error, info};
+
+/// post_admin_account_password
+/// Serve POST change password HTML file
+/// Change the password of the logged in User. The current password is required
+/// and the new password has to follow the PasswordPolicy.
+#[debug_handler]
+pub async fn post_admin_account_password(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    auth_user: AuthUser,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    let min_length = app_state.config.password_min_length;
+    let user = auth_user.user;
+
+    let (current_password, new_password, confirm_password) = process_password_body(&body);
+    let current_password = sanitize_password(&current_password);
+    let new_password = sanitize_password(&new_password);
+    let confirm_password = sanitize_password(&confirm_password);
+
+    let errors = if !is_password_match(&current_password, &user.hashed_password) {
+        vec!["Current password is wrong.".to_string()]
+    } else if new_password != confirm_password {
+        vec!["New passwords do not match.".to_string()]
+    } else if new_password == current_password {
+        vec!["New password must be different from the current password.".to_string()]
+    } else {
+        PasswordPolicy::from(&app_state.config).check(&new_password)
+    };
+    if !errors.is_empty() {
+        info!(
+            "Rejected password change of User {}. {}",
+            &user.id,
+            errors.join(" ")
+        );
+        return render_admin_account_password(min_length, String::new(), errors);
+    }
+
+    let Some(hashed_password) = hash_password(&new_password) else {
+        return get_500_internal_server_error();
+    };
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let update_result = auth_uc
+        .update_user(user.id.clone(), None, Some(hashed_password), None)
+        .await;
+    if update_result != Some(UserCommandStatus::Updated) {
+        error!("Failed to change the password of User {}.", &user.id);
+        return get_500_internal_server_error();
+    }
+    record_account_audit_log(&app_state, &headers, addr, &user, "password changed").await;
+
+    render_admin_account_password(min_length, "Password changed.".to_string(), Vec::new())
+}
+
+/// post_admin_account_totp_setup
+/// Serve POST two-factor authentication setup HTML file
+/// Store a new TOTP secret of the logged in User, pending until it is confirmed
+/// with a code, and show it as a QR code.
+#[debug_handler]
+pub async fn post_admin_account_totp_setup(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    let user = auth_user.user;
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let saved_totp = auth_uc.find_user_totp(user.id.clone()).await;
+    if saved_totp
+        .as_ref()
+        .is_some_and(|user_totp| user_totp.enabled)
+    {
+        info!("User {} already enabled TOTP.", &user.id);
+        return render_admin_account_totp(saved_totp.as_ref(), String::new(), Vec::new());
+    }
+
+    let totp_secret = generate_totp_secret();
+    let Some(encrypted_secret) = encrypt_totp_secret(&app_state.config.secrets, &totp_secret)
+    else {
+        return get_500_internal_server_error();
+    };
+    let user_totp = UserTotp {
+        user_id: user.id.clone(),
+        encrypted_secret,
+        enabled: false,
+        last_step: None,
+        recovery_codes: Vec::new(),
+    };
+    if auth_uc.upsert_user_totp(user_totp).await != Some(UserTotpCommandStatus::Stored) {
+        error!("Failed to store pending TOTP of User {}.", &user.id);
+        return get_500_internal_server_error();
+    }
+
+    render_admin_account_totp_setup(&totp_secret, &user.email, Vec::new())
+}
+
+/// post_admin_account_totp_enable
+/// Serve POST two-factor authentication confirmation HTML file
+/// Enable the pending TOTP of the logged in User once a valid code is submitted
+/// and show the recovery codes.
+#[debug_handler]
+pub async fn post_admin_account_totp_enable(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    auth_user: AuthUser,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    let secrets = &app_state.config.secrets;
+    let user = auth_user.user;
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let pending_totp = auth_uc
+        .find_user_totp(user.id.clone())
+        .await
+        .filter(|user_totp| !user_totp.enabled);
+    let pending = pending_totp.and_then(|user_totp| {
+        let totp_secret = decrypt_totp_secret(secrets, &user_totp.encrypted_secret)?;
+        Some((user_totp, totp_secret))
+    });
+    let Some((mut user_totp, totp_secret)) = pending else {
+        info!("User {} has no pending TOTP to enable.", &user.id);
+        let error = "The setup expired. Please set up two-factor authentication again.";
+        return render_admin_account_totp(None, String::new(), vec![error.to_string()]);
+    };
+
+    let code = process_totp_code_body(&body);
+    let now = Utc::now().timestamp();
+    let Some(step) = verify_totp_code(&totp_secret, &code, now, None) else {
+        info!("Rejected TOTP confirmation of User {}.", &user.id);
+        let error = "The code is incorrect. Check the time of your device and try again.";
+        return render_admin_account_totp_setup(&totp_secret, &user.email, vec![error.to_string()]);
+    };
+
+    let (recovery_codes, hashed_recovery_codes) = generate_recovery_codes();
+    user_totp.enabled = true;
+    user_totp.last_step = Some(step);
+    user_totp.recovery_codes = hashed_recovery_codes;
+    if auth_uc.upsert_user_totp(user_totp).await != Some(UserTotpCommandStatus::Stored) {
+        error!("Failed to enable TOTP of User {}.", &user.id);
+        return get_500_internal_server_error();
+    }
+    record_account_audit_log(&app_state, &headers, addr, &user, "totp enabled").await;
+
+    render_admin_account_totp_recovery_codes(recovery_codes)
+}
+
+/// post_admin_account_totp_recovery_codes
+/// Serve POST regenerate recovery codes HTML file
+/// Replace the recovery codes of the logged in User. A TOTP or recovery code is required.
+#[debug_handler]
+pub async fn post_admin_account_totp_recovery_codes(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    auth_user: AuthUser,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    let (user, mut user_totp) = match verify_account_totp(&app_state, auth_user.user, &body).await {
+        Ok(val) => val,
+        Err(html) => return html,
+    };
+    let (recovery_codes, hashed_recovery_codes) = generate_recovery_codes();
+    user_totp.recovery_codes = hashed_recovery_codes;
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    if auth_uc.upsert_user_totp(user_totp).await != Some(UserTotpCommandStatus::Stored) {
+        error!("Failed to regenerate recovery codes of User {}.", &user.id);
+        return get_500_internal_server_error();
+    }
+    record_account_audit_log(
+        &app_state,
+        &headers,
+        addr,
+        &user,
+        "recovery codes regenerated",
+    )
+    .await;
+
+    render_admin_account_totp_recovery_codes(recovery_codes)
+}
+
+/// post_admin_account_totp_disable
+/// Serve POST turn off two-factor authentication HTML file
+/// Remove the TOTP of the logged in User. A TOTP or recovery code is required.
+#[debug_handler]
+pub async fn post_admin_account_totp_disable(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    auth_user: AuthUser,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    let (user, _) = match verify_account_totp(&app_state, auth_user.user, &body).await {
+        Ok(val) => val,
+        Err(html) => return html,
+    };
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    if auth_uc.delete_user_totp(user.id.clone()).await != Some(UserTotpCommandStatus::Deleted) {
+        error!("Failed to disable TOTP of User {}.", &user.id);
+        return get_500_internal_server_error();
+    }
+    record_account_audit_log(&app_state, &headers, addr, &user, "totp disabled").await;
+
+    let notice = "Two-factor authentication is turned off.".to_string();
+    render_admin_account_totp(None, notice, Vec::new())
+}
+
+/// Verify the submitted code against the enabled TOTP of the logged in User
+/// Return the User and the UserTotp with the code consumed, or the status to re-render.
+async fn verify_account_totp(
+    app_state: &AppState,
+    user: User,
+    body: &str,
+) -> Result<(User, UserTotp), Html<String>> {
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(mut user_totp) = auth_uc
+        .find_user_totp(user.id.clone())
+        .await
+        .filter(|user_totp| user_totp.enabled)
+    else {
+        return Err(render_admin_account_totp(None, String::new(), Vec::new()));
+    };
+
+    let code = process_totp_code_body(body);
+    let now = Utc::now().timestamp();
+    if !verify_totp_or_recovery_code(&app_state.config.secrets, &mut user_totp, &code, now) {
+        info!("Rejected TOTP code of User {}.", &user.id);
+        let error = "The code is incorrect. Please try again.".to_string();
+        return Err(render_admin_account_totp(
+            Some(&user_totp),
+            String::new(),
+            vec![error],
+        ));
+    }
+    Ok((user, user_totp))
+}
+
+/// delete_admin_account_session
+/// Serve DELETE revoke session HTML file
+/// Revoke another Session of the logged in User. Its token is rejected from
+/// the next request on.
+#[debug_handler]
+pub async fn delete_admin_account_session(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Path(session_id): Path<String>,
+    auth_user: AuthUser,
+    headers: HeaderMap,
+) -> Html<String> {
+    let AuthUser {
+        user,
+        session: current_session,
+    } = auth_user;
+    let user_id = user.id.clone();
+    if session_id == current_session.id {
+        let error = "Use Logout to end the session of this device.";
+        return render_admin_account_sessions(
+            &app_state,
+            user_id,
+            current_session.id,
+            String::new(),
+            vec![error.to_string()],
+        )
+        .await;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    // Only Sessions of the logged in User can be revoked here
+    let owned_session = auth_uc
+        .find_session(session_id.clone())
+        .await
+        .filter(|session| session.user_id == user_id);
+    if owned_session.is_none() {
+        info!("Session {} of User {} not found.", &session_id, &user_id);
+        let error = "The session already ended.";
+        return render_admin_account_sessions(
+            &app_state,
+            user_id,
+            current_session.id,
+            String::new(),
+            vec![error.to_string()],
+        )
+        .await;
+    }
+    if auth_uc.delete_session(session_id.clone()).await != Some(SessionCommandStatus::Deleted) {
+        error!("Failed to revoke Session {}.", &session_id);
+        return get_500_internal_server_error();
+    }
+    record_account_audit_log(&app_state, &headers, addr, &user, "session revoked").await;
+
+    render_admin_account_sessions(
+        &app_state,
+        user_id,
+        current_session.id,
+        "Session revoked.".to_string(),
+        Vec::new(),
+    )
+    .await
+}
+
+/// delete_admin_account_sessions
+/// Serve DELETE log out everywhere endpoint.
+/// Revoke every Session of the logged in User, including this one, and remove
+/// JWT from client cookie
+#[debug_handler]
+pub async fn delete_admin_account_sessions(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    auth_user: AuthUser,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let mut resp_headers = HeaderMap::new();
+    let user = auth_user.user;
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    if auth_uc.delete_user_sessions(user.id.clone()).await != Some(SessionCommandStatus::Deleted) {
+        error!("Failed to revoke Sessions of User {}.", &user.id);
+        return (resp_headers, get_500_internal_server_error());
+    }
+    record_account_audit_log(&app_state, &headers, addr, &user, "logged out everywhere").await;
+
+    append_session_clear_cookies(&mut resp_headers);
+    resp_headers.append(SET_COOKIE, csrf_clear_cookie_header().parse().unwrap());
+    resp_headers.insert(HX_REDIRECT, "/".parse().unwrap());
+
+    get_logout(resp_headers).await
+}
+
+/// Record a change of the logged in User's own account in the audit log
+async fn record_account_audit_log(
+    app_state: &AppState,
+    headers: &HeaderMap,
+    addr: SocketAddr,
+    user: &User,
+    change: &str,
+) {
+    record_audit_log(
+        app_state,
+        &AuditContext::from_request(app_state, headers, addr),
+        AUDIT_ACTION_UPDATE,
+        AUDIT_ENTITY_USER,
+        user.email.clone(),
+        String::new(),
+        change.to_string(),
+    )
+    .await;
+}
@@ -0,0 +1,107 @@
+use crate::handler::status::{get_401_unauthorized, get_500_internal_server_error};
+use crate::model::audit_logs::{AuditLogsParams, AUDIT_ENTITY_TYPES, AUDIT_LOGS_LIST_LIMIT};
+use crate::model::auth::{AuthUser, Permission};
+use crate::model::axum::AppState;
+use crate::model::templates_admin::AdminAuditLogsTemplate;
+use crate::repo::audit_logs::AuditLogDisplayRepo;
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::{Query, State};
+use axum::http::header;
+use axum::response::{Html, IntoResponse, Response};
+use tracing::{error, info};
+
+/// get_base_admin_audit
+/// Serve GET (base) admin audit log HTML file
+/// Under endpoint /admin/audit
+#[debug_handler]
+pub async fn get_base_admin_audit(auth_user: AuthUser) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ViewAudit) {
+        return get_401_unauthorized().await;
+    }
+
+    let entity_types = AUDIT_ENTITY_TYPES.map(|val| val.to_string()).to_vec();
+    match (AdminAuditLogsTemplate { entity_types }).render() {
+        Ok(res) => {
+            info!("AdminAuditLogs askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/audit/audit.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_audit_list
+/// Serve the latest AuditLogs filtered by entity type and date
+/// Under endpoint /admin/audit/list
+#[debug_handler]
+pub async fn get_admin_audit_list(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    Query(params): Query<AuditLogsParams>,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ViewAudit) {
+        return get_401_unauthorized().await;
+    }
+
+    let audit_log_db_uc = app_state.audit_log_db_usecase.lock().await.clone().unwrap();
+    let sanitized_params = params.sanitize();
+    let Some(audit_logs) = audit_log_db_uc
+        .find_audit_logs(sanitized_params.clone(), Some(AUDIT_LOGS_LIST_LIMIT))
+        .await
+    else {
+        error!("Failed to find audit logs.");
+        return get_500_internal_server_error();
+    };
+
+    match audit_logs
+        .to_admin_list_template(&sanitized_params)
+        .render()
+    {
+        Ok(res) => {
+            info!("AdminListAuditLogs askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/audit/list_audit.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_audit_export
+/// Serve every AuditLog filtered by entity type and date as a CSV file
+/// Under endpoint /admin/audit/export
+#[debug_handler]
+pub async fn get_admin_audit_export(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    Query(params): Query<AuditLogsParams>,
+) -> Response {
+    if !auth_user.is_permitted(Permission::ViewAudit) {
+        return get_401_unauthorized().await.into_response();
+    }
+
+    let audit_log_db_uc = app_state.audit_log_db_usecase.lock().await.clone().unwrap();
+    let Some(audit_logs) = audit_log_db_uc
+        .find_audit_logs(params.sanitize(), None)
+        .await
+    else {
+        error!("Failed to find audit logs.");
+        return get_500_internal_server_error().into_response();
+    };
+
+    (
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"audit_log.csv\"",
+            ),
+        ],
+        audit_logs.to_csv(),
+    )
+        .into_response()
+}
@@ -0,0 +1,170 @@
+pub mod displays;
+
+use crate::handler::auth::get_auth_user_id;
+use crate::model::audit_logs::{
+    AuditLog, AUDIT_ACTION_CREATE, AUDIT_ACTION_DELETE, AUDIT_ACTION_UPDATE,
+};
+use crate::model::axum::AppState;
+use crate::repo::audit_logs::AuditLogOperationRepo;
+use axum::http::{HeaderMap, HeaderName};
+use chrono::{SecondsFormat, Utc};
+use std::net::{IpAddr, SocketAddr};
+use tracing::{debug, error};
+
+/// Header appended to by the reverse proxies in front of the application
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+
+/// AuditContext
+/// Actor of a state-changing request with fields:
+/// - user_id: User Identifier from the JWT. Empty if unknown
+/// - client_ip: Client IP found by `client_ip`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditContext {
+    pub user_id: String,
+    pub client_ip: String,
+}
+
+impl AuditContext {
+    /// Build AuditContext of the logged in User
+    pub fn from_request(app_state: &AppState, headers: &HeaderMap, addr: SocketAddr) -> Self {
+        Self {
+            user_id: get_auth_user_id(headers, &app_state.config.secrets.jwt_secret)
+                .unwrap_or_default(),
+            client_ip: client_ip(headers, addr, &app_state.config.trusted_proxies),
+        }
+    }
+}
+
+/// Find the client IP of a request
+/// `X-Forwarded-For` is set by the client so it is only read if the peer is
+/// one of `trusted_proxies`. Every proxy appends the address it received the
+/// request from, so the right-most address which isn't a trusted proxy is
+/// the client. Fallback to the peer address.
+pub fn client_ip(headers: &HeaderMap, addr: SocketAddr, trusted_proxies: &[IpAddr]) -> String {
+    let peer_ip = addr.ip();
+    if !trusted_proxies.contains(&peer_ip) {
+        return peer_ip.to_string();
+    }
+
+    let forwarded_ips: Vec<&str> = headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .filter_map(|val| val.to_str().ok())
+        .flat_map(|val| val.split(','))
+        .map(|val| val.trim())
+        .collect();
+    for forwarded_ip in forwarded_ips.into_iter().rev() {
+        match forwarded_ip.parse::<IpAddr>() {
+            Ok(ip) if trusted_proxies.contains(&ip) => continue,
+            Ok(ip) => return ip.to_string(),
+            // Addresses left of an invalid one can't be trusted
+            Err(_) => break,
+        }
+    }
+    peer_ip.to_string()
+}
+
+/// Append an AuditLog of a state-changing request
+/// Failures are logged and don't fail the request.
+pub async fn record_audit_log(
+    app_state: &AppState,
+    context: &AuditContext,
+    action: &str,
+    entity_type: &str,
+    entity_id: String,
+    before: String,
+    after: String,
+) {
+    let Some(mut audit_log_db_uc) = app_state.audit_log_db_usecase.lock().await.clone() else {
+        error!("Failed to lock AuditLog Usecase Mutex.");
+        return;
+    };
+
+    let audit_log = AuditLog {
+        created_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+        user_id: context.user_id.clone(),
+        client_ip: context.client_ip.clone(),
+        action: action.to_string(),
+        entity_type: entity_type.to_string(),
+        entity_id,
+        before,
+        after,
+    };
+    if audit_log_db_uc.add(audit_log).await.is_none() {
+        error!("Failed to add {action} {entity_type} AuditLog.");
+    }
+}
+
+/// Append an AuditLog of changed Tag Mappings of a Blog or Talk
+/// Nothing is recorded when the Tags are unchanged.
+pub async fn record_tag_mapping_audit_log(
+    app_state: &AppState,
+    context: &AuditContext,
+    entity_type: &str,
+    id: i64,
+    before: &[String],
+    after: &[String],
+) {
+    let mut sorted_before = before.to_vec();
+    let mut sorted_after = after.to_vec();
+    sorted_before.sort();
+    sorted_after.sort();
+    if sorted_before == sorted_after {
+        debug!("Tags of {entity_type} {id} are unchanged.");
+        return;
+    }
+
+    let action = match (before.is_empty(), after.is_empty()) {
+        (true, _) => AUDIT_ACTION_CREATE,
+        (_, true) => AUDIT_ACTION_DELETE,
+        _ => AUDIT_ACTION_UPDATE,
+    };
+    record_audit_log(
+        app_state,
+        context,
+        action,
+        entity_type,
+        id.to_string(),
+        format!("tags={}", before.join(",")),
+        format!("tags={}", after.join(",")),
+    )
+    .await;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_client_ip() {
+        let addr: SocketAddr = "10.0.0.1:5000".parse().unwrap();
+        let trusted_proxies: Vec<IpAddr> =
+            vec!["10.0.0.1".parse().unwrap(), "10.0.0.2".parse().unwrap()];
+        let mut headers = HeaderMap::new();
+        assert_eq!(client_ip(&headers, addr, &trusted_proxies), "10.0.0.1");
+
+        // The client spoofed the first address, proxies appended the rest
+        headers.insert(
+            X_FORWARDED_FOR,
+            "1.2.3.4, 203.0.113.7, 10.0.0.2".parse().unwrap(),
+        );
+        assert_eq!(client_ip(&headers, addr, &trusted_proxies), "203.0.113.7");
+
+        headers.insert(X_FORWARDED_FOR, "10.0.0.2".parse().unwrap());
+        assert_eq!(client_ip(&headers, addr, &trusted_proxies), "10.0.0.1");
+
+        headers.insert(X_FORWARDED_FOR, "1.2.3.4, unknown".parse().unwrap());
+        assert_eq!(client_ip(&headers, addr, &trusted_proxies), "10.0.0.1");
+    }
+
+    #[test]
+    fn test_client_ip_spoofed_by_direct_client() {
+        let addr: SocketAddr = "198.51.100.9:5000".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(X_FORWARDED_FOR, "203.0.113.7".parse().unwrap());
+        assert_eq!(client_ip(&headers, addr, &[]), "198.51.100.9");
+
+        let trusted_proxies: Vec<IpAddr> = vec!["10.0.0.2".parse().unwrap()];
+        assert_eq!(client_ip(&headers, addr, &trusted_proxies), "198.51.100.9");
+    }
+}
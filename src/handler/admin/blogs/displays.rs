@@ -1,7 +1,6 @@
-use crate::handler::auth::is_auth_verified;
-use crate::handler::status::{
-    get_401_unauthorized, get_404_not_found, get_500_internal_server_error,
-};
+use crate::handler::admin::blogs::find_blog_draft;
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::auth::AuthUser;
 use crate::model::axum::AppState;
 use crate::model::blogs::BlogsParams;
 use crate::model::tags::TagsListParams;
@@ -14,7 +13,6 @@ use crate::utils::convert_tags_string_to_vec;
 use askama::Template;
 use axum::debug_handler;
 use axum::extract::{Path, Query, State};
-use axum::http::HeaderMap;
 use axum::response::Html;
 use tracing::{debug, error, info, warn};
 
@@ -23,14 +21,7 @@ use tracing::{debug, error, info, warn};
 /// Under endpoint /admin/blogs
 /// It's the base of Admin Blogs feature
 #[debug_handler]
-pub async fn get_base_admin_blogs(
-    State(app_state): State<AppState>,
-    headers: HeaderMap,
-) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
+pub async fn get_base_admin_blogs() -> Html<String> {
     let blogs_res = AdminBlogsTemplate {}.render();
     match blogs_res {
         Ok(res) => {
@@ -51,13 +42,8 @@ pub async fn get_base_admin_blogs(
 #[debug_handler]
 pub async fn get_admin_blogs_list(
     State(app_state): State<AppState>,
-    headers: HeaderMap,
     params: Query<BlogsParams>,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     // Locking Mutex
     let blog_cache_uc_opt = app_state.blog_cache_usecase.lock().await;
     let cache_is_enabled = blog_cache_uc_opt.is_some();
@@ -152,12 +138,7 @@ pub async fn get_admin_blogs_list(
 pub async fn get_admin_blog(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
-    headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     // Locking Mutex
     let blog_cache_uc_opt = app_state.blog_cache_usecase.lock().await;
     let cache_is_enabled = blog_cache_uc_opt.is_some();
@@ -240,14 +221,7 @@ pub async fn get_admin_blog(
 /// get_add_admin_blog
 /// Serve GET add blog HTML file in a form format.
 #[debug_handler]
-pub async fn get_add_admin_blog(
-    State(app_state): State<AppState>,
-    headers: HeaderMap,
-) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
+pub async fn get_add_admin_blog(State(app_state): State<AppState>) -> Html<String> {
     // Locking Mutex
     // Calculate new Blog Id
     let db_result = app_state
@@ -294,7 +268,12 @@ pub async fn get_add_admin_blog(
 
         let add_blog = AdminGetAddBlogTemplate {
             id: blog_id,
+            name: String::new(),
+            body: "Your new blog...".to_string(),
+            blog_tags: Vec::new(),
             avail_tags,
+            errors: Vec::new(),
+            warnings: Vec::new(),
         }
         .render();
         if add_blog.is_err() {
@@ -333,7 +312,12 @@ pub async fn get_add_admin_blog(
 
     let add_blog = AdminGetAddBlogTemplate {
         id: blog_id,
+        name: String::new(),
+        body: "Your new blog...".to_string(),
+        blog_tags: Vec::new(),
         avail_tags,
+        errors: Vec::new(),
+        warnings: Vec::new(),
     }
     .render();
     if add_blog.is_err() {
@@ -353,12 +337,8 @@ pub async fn get_add_admin_blog(
 pub async fn get_edit_admin_blog(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
-    headers: HeaderMap,
+    auth_user: AuthUser,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     let blog_uc = app_state.blog_db_usecase.lock().await.clone();
     let blog_cache_uc_opt = app_state.blog_cache_usecase.lock().await.clone();
     let is_blog_cache_enabled = blog_cache_uc_opt.is_some();
@@ -369,6 +349,11 @@ pub async fn get_edit_admin_blog(
         return get_404_not_found().await;
     };
 
+    // Offer to restore an unsaved draft
+    let draft_updated_at = find_blog_draft(&app_state, auth_user.user.id, id)
+        .await
+        .map(|blog_draft| blog_draft.updated_at);
+
     // Get Blog Data from Cache
     let blog_cache_result = if is_blog_cache_enabled {
         blog_cache_uc_opt
@@ -424,6 +409,10 @@ pub async fn get_edit_admin_blog(
             body: blog_cache_result.clone().unwrap().body.unwrap(),
             blog_tags: blog_cache_result.clone().unwrap().tags.unwrap(),
             avail_tags: unselected_tags,
+            draft_updated_at,
+            notice: String::new(),
+            errors: Vec::new(),
+            warnings: Vec::new(),
         }
         .render();
         if edit_blog.is_err() {
@@ -485,6 +474,10 @@ pub async fn get_edit_admin_blog(
         body: blog_data.body.unwrap(),
         blog_tags: blog_data.tags.unwrap(),
         avail_tags: unselected_tags,
+        draft_updated_at,
+        notice: String::new(),
+        errors: Vec::new(),
+        warnings: Vec::new(),
     }
     .render();
     debug!("AdminGetEditBlogTemplate : {:?}", &edit_blog);
@@ -500,18 +493,81 @@ pub async fn get_edit_admin_blog(
     Html(edit_blog.unwrap())
 }
 
+/// get_admin_blog_draft
+/// Serve GET edit blog HTML file filled with the unsaved draft
+/// of the logged in User to restore it
+#[debug_handler]
+pub async fn get_admin_blog_draft(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    // Sanitize `path`
+    let Ok(id) = path.parse::<i64>() else {
+        warn!("Failed to parse path {} to i64", &path);
+        return get_404_not_found().await;
+    };
+
+    let Some(blog_draft) = find_blog_draft(&app_state, auth_user.user.id, id).await else {
+        info!("Failed to find BlogDraft with Blog Id {}.", &id);
+        return get_404_not_found().await;
+    };
+
+    let Some(tag_db_uc) = app_state.tag_db_usecase.lock().await.clone() else {
+        error!("Failed to lock Tag Usecase Mutex.");
+        return get_500_internal_server_error();
+    };
+    let Some(complete_tags_data) = tag_db_uc
+        .tag_display_repo
+        .find_tags(TagsListParams {
+            start: Some(0),
+            end: Some(1000),
+        })
+        .await
+    else {
+        error!("Failed to get all Tags");
+        return get_500_internal_server_error();
+    };
+    let unselected_tags: Vec<String> = complete_tags_data
+        .tags
+        .iter()
+        // Get tags not available in the draft Blog Tags
+        .filter(|t| !blog_draft.tags.contains(&t.name))
+        // Grab only tag name
+        .map(|t| t.name.clone())
+        .collect();
+
+    let edit_blog = AdminGetEditBlogTemplate {
+        id,
+        name: blog_draft.name,
+        body: blog_draft.body,
+        blog_tags: blog_draft.tags,
+        avail_tags: unselected_tags,
+        draft_updated_at: None,
+        notice: String::new(),
+        errors: Vec::new(),
+        warnings: Vec::new(),
+    }
+    .render();
+    match edit_blog {
+        Ok(res) => {
+            info!("AdminGetEditBlog askama template rendered from BlogDraft.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/blogs/get_edit_blog.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
 /// get_delete_admin_blog
 /// Serve GET delete blog HTML file to delete a blog
 #[debug_handler]
 pub async fn get_delete_admin_blog(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
-    headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     // Locking Mutex
     let blog_db_uc = app_state.blog_db_usecase.lock().await.clone();
     let blogs_cache_uc_opt = app_state.blog_cache_usecase.lock().await.clone();
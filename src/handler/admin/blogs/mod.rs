@@ -2,18 +2,98 @@ pub mod displays;
 pub mod operations;
 pub mod tags;
 
-use crate::model::blogs::Blog;
+use crate::handler::admin::find_tag_names;
+use crate::handler::status::get_500_internal_server_error;
+use crate::model::axum::AppState;
+use crate::model::blog_drafts::BlogDraft;
+use crate::model::blogs::{Blog, BlogLint};
+use crate::model::templates_admin::{AdminGetAddBlogTemplate, AdminGetEditBlogTemplate};
+use crate::repo::blog_drafts::BlogDraftDisplayRepo;
 use crate::utils::remove_whitespace;
-use tracing::{debug, warn};
+use askama::Template;
+use axum::response::Html;
+use tracing::{debug, error, warn};
 use urlencoding::decode;
 
+// Find the BlogDraft of a Blog autosaved by the logged in User
+async fn find_blog_draft(app_state: &AppState, user_id: String, blog_id: i64) -> Option<BlogDraft> {
+    let blog_draft_db_uc = app_state.blog_draft_db_usecase.lock().await.clone()?;
+    blog_draft_db_uc.find(blog_id, user_id).await
+}
+
+// Lint a Blog against the existing Blogs and Tags before publishing it
+async fn lint_blog(app_state: &AppState, blog: &Blog) -> BlogLint {
+    let blog_db_uc = app_state.blog_db_usecase.lock().await.clone();
+    let mut blog_ids = Vec::new();
+    for blog_id in blog.linked_blog_ids() {
+        if blog_db_uc.blog_display_repo.find(blog_id).await.is_some() {
+            blog_ids.push(blog_id);
+        }
+    }
+    blog.lint(&blog_ids, &find_tag_names(app_state).await)
+}
+
+// Re-render admin blog add/edit form with the submitted Blog and its lint results
+async fn render_admin_blog_form(
+    app_state: &AppState,
+    blog: Blog,
+    is_new: bool,
+    notice: String,
+    lint: BlogLint,
+) -> Html<String> {
+    let blog_tags: Vec<String> = blog
+        .tags
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    let avail_tags: Vec<String> = find_tag_names(app_state)
+        .await
+        .into_iter()
+        .filter(|tag| !blog_tags.contains(tag))
+        .collect();
+
+    let form = if is_new {
+        AdminGetAddBlogTemplate {
+            id: blog.id,
+            name: blog.name.unwrap_or_default(),
+            body: blog.body.unwrap_or_default(),
+            blog_tags,
+            avail_tags,
+            errors: lint.errors,
+            warnings: lint.warnings,
+        }
+        .render()
+    } else {
+        AdminGetEditBlogTemplate {
+            id: blog.id,
+            name: blog.name.unwrap_or_default(),
+            body: blog.body.unwrap_or_default(),
+            blog_tags,
+            avail_tags,
+            draft_updated_at: None,
+            notice,
+            errors: lint.errors,
+            warnings: lint.warnings,
+        }
+        .render()
+    };
+    match form {
+        Ok(res) => Html(res),
+        Err(err) => {
+            error!("Failed to render admin blog form. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
 // Take request body String from PUT and POST operations to create a new blog
 fn process_blog_body(body: String) -> Blog {
     // Initialize fields
     let mut blog_id = 0_i64;
     let mut blog_name = String::new();
     let mut blog_body = String::new();
-    let mut blog_tags = vec![String::new()];
+    let mut blog_tags = Vec::new();
 
     let req_fields: Vec<&str> = body.split("&").collect();
     for req_field in req_fields {
@@ -30,7 +110,9 @@ fn process_blog_body(body: String) -> Blog {
             "blog_body" => blog_body = value_decoded.to_string(),
             "blog_tag" => {
                 let clean_tag = remove_whitespace(&value_decoded);
-                blog_tags.push(clean_tag);
+                if !clean_tag.is_empty() {
+                    blog_tags.push(clean_tag);
+                }
             }
             _ => {
                 warn!("Unrecognized key/value: {:?}/{:?}", key, value_decoded);
@@ -47,3 +129,23 @@ fn process_blog_body(body: String) -> Blog {
         filename: None,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_process_blog_body() {
+        let blog = process_blog_body(
+            "blog_id=3&blog_name=Hello&blog_body=%23%20World&blog_tag=rust&blog_tag=".to_string(),
+        );
+        assert_eq!(blog.id, 3);
+        assert_eq!(blog.name, Some("Hello".to_string()));
+        assert_eq!(blog.body, Some("# World".to_string()));
+        assert_eq!(blog.tags, Some(vec!["rust".to_string()]));
+
+        // Without tags there is no empty tag to render in the preview
+        let blog = process_blog_body("blog_id=3&blog_name=Hello&blog_body=World".to_string());
+        assert_eq!(blog.tags, Some(Vec::new()));
+    }
+}
@@ -1,31 +1,62 @@
-use crate::handler::admin::blogs::displays::get_admin_blogs_list;
-use crate::handler::admin::blogs::process_blog_body;
-use crate::handler::auth::csrf::verify_csrf_token;
-use crate::handler::auth::is_auth_verified;
+use crate::handler::admin::audit::{record_audit_log, record_tag_mapping_audit_log, AuditContext};
+use crate::handler::admin::blogs::displays::{get_admin_blogs_list, get_edit_admin_blog};
+use crate::handler::admin::blogs::{lint_blog, process_blog_body, render_admin_blog_form};
 use crate::handler::status::{
     get_401_unauthorized, get_404_not_found, get_500_internal_server_error,
 };
+use crate::model::audit_logs::{
+    AUDIT_ACTION_CREATE, AUDIT_ACTION_DELETE, AUDIT_ACTION_UPDATE, AUDIT_ENTITY_BLOG,
+    AUDIT_ENTITY_BLOG_TAG_MAPPING,
+};
+use crate::model::auth::{AuthUser, Permission};
 use crate::model::axum::AppState;
+use crate::model::blog_drafts::{BlogDraft, BlogDraftCommandStatus};
 use crate::model::blog_tag_mappings::{BlogTagMapping, BlogTagMappingCommandStatus};
-use crate::model::blogs::{BlogCommandStatus, BlogsParams};
+use crate::model::blogs::{Blog, BlogCommandStatus, BlogsParams};
 use crate::model::tags::{Tag, TagsListParams};
+use crate::model::templates_admin::AdminBlogDraftStatusTemplate;
+use crate::repo::blog_drafts::BlogDraftOperationRepo;
+use askama::Template;
 use axum::debug_handler;
-use axum::extract::{Path, Query, State};
+use axum::extract::{ConnectInfo, Path, Query, State};
 use axum::http::HeaderMap;
 use axum::response::Html;
+use std::net::SocketAddr;
 use tracing::{debug, error, info, warn};
 
+/// post_preview_admin_blog
+/// Serve POST preview blog endpoint
+/// Render the unsaved blog form like `get_blog` and return it as an HTML fragment
+#[debug_handler]
+pub async fn post_preview_admin_blog(auth_user: AuthUser, body: String) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    let blog = process_blog_body(body);
+    match blog.as_preview_template().render() {
+        Ok(res) => {
+            debug!("Blog preview askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render blog.html preview. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
 /// post_add_admin_blog
 /// Serve POST add blog endpoint
 #[debug_handler]
 pub async fn post_add_admin_blog(
     State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     body: String,
 ) -> Html<String> {
-    if !is_auth_verified(headers.clone(), &app_state.config.secrets.jwt_secret)
-        || !verify_csrf_token(&headers)
-    {
+    if !auth_user.is_permitted(Permission::Edit) {
         return get_401_unauthorized().await;
     }
 
@@ -35,6 +66,11 @@ pub async fn post_add_admin_blog(
     let is_blogs_cache_enabled = blogs_cache_uc_opt.is_some();
 
     let blog = process_blog_body(body);
+    let lint = lint_blog(&app_state, &blog).await;
+    if !lint.errors.is_empty() {
+        info!("New blog with Id {} has lint errors.", &blog.id);
+        return render_admin_blog_form(&app_state, blog, true, String::new(), lint).await;
+    }
     let add_result = blogs_db_uc.blog_operation_repo.add(blog.clone()).await;
 
     if add_result.is_none() {
@@ -46,6 +82,17 @@ pub async fn post_add_admin_blog(
         error!("Failed to add blog with Id {}", &blog.id);
         return get_500_internal_server_error();
     }
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_CREATE,
+        AUDIT_ENTITY_BLOG,
+        blog.id.to_string(),
+        String::new(),
+        blog.audit_summary(),
+    )
+    .await;
 
     // Insert cache
     if is_blogs_cache_enabled {
@@ -110,6 +157,7 @@ pub async fn post_add_admin_blog(
         .cloned()
         .collect();
     debug!("Selected Tags: {:?}", selected_tags);
+    let selected_tag_names: Vec<String> = selected_tags.iter().map(|t| t.name.clone()).collect();
 
     // Add blog_tag_mappings
     let btms_uc = app_state.blog_tag_mapping_db_usecase.lock().await.clone();
@@ -159,13 +207,29 @@ pub async fn post_add_admin_blog(
         }
     }
 
+    record_tag_mapping_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ENTITY_BLOG_TAG_MAPPING,
+        blog.id,
+        &[],
+        &selected_tag_names,
+    )
+    .await;
+
+    // Show the warnings in the saved blog form
+    if !lint.warnings.is_empty() {
+        let notice = format!("Blog {} is saved with warnings.", &blog.id);
+        return render_admin_blog_form(&app_state, blog, false, notice, lint).await;
+    }
+
     let query_params = BlogsParams {
         start: None,
         end: None,
         tags: None,
     };
 
-    get_admin_blogs_list(State(app_state), headers, Query(query_params)).await
+    get_admin_blogs_list(State(app_state), Query(query_params)).await
 }
 
 /// put_edit_admin_blog
@@ -174,12 +238,12 @@ pub async fn post_add_admin_blog(
 pub async fn put_edit_admin_blog(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     body: String,
 ) -> Html<String> {
-    if !is_auth_verified(headers.clone(), &app_state.config.secrets.jwt_secret)
-        || !verify_csrf_token(&headers)
-    {
+    if !auth_user.is_permitted(Permission::Edit) {
         return get_401_unauthorized().await;
     }
 
@@ -194,6 +258,12 @@ pub async fn put_edit_admin_blog(
     };
 
     let blog = process_blog_body(body);
+    let lint = lint_blog(&app_state, &blog).await;
+    if !lint.errors.is_empty() {
+        info!("Edited blog with Id {} has lint errors.", &blog.id);
+        return render_admin_blog_form(&app_state, blog, false, String::new(), lint).await;
+    }
+    let saved_blog = blogs_db_uc.blog_display_repo.find(id).await;
     let edit_result = blogs_db_uc.blog_operation_repo.update(blog.clone()).await;
 
     if edit_result.is_none() {
@@ -205,6 +275,26 @@ pub async fn put_edit_admin_blog(
         error!("Failed to edit blog with Id {}", &blog.id);
         return get_500_internal_server_error();
     }
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_UPDATE,
+        AUDIT_ENTITY_BLOG,
+        id.to_string(),
+        saved_blog
+            .as_ref()
+            .map(|val| val.audit_summary())
+            .unwrap_or_default(),
+        blog.audit_summary(),
+    )
+    .await;
+
+    // Discard the autosaved draft once it's saved
+    if let Some(mut blog_draft_db_uc) = app_state.blog_draft_db_usecase.lock().await.clone() {
+        debug!("Discarding draft of blog {}", &blog.id);
+        let _ = blog_draft_db_uc.delete(blog.id, auth_user.user.id).await;
+    }
 
     // Re-insert cache
     if is_blogs_cache_enabled {
@@ -272,6 +362,12 @@ pub async fn put_edit_admin_blog(
         .filter(|t| blog.tags.clone().unwrap().contains(&t.name))
         .map(|t| t.id)
         .collect();
+    let selected_tag_names: Vec<String> = tags
+        .tags
+        .iter()
+        .filter(|t| selected_tag_ids.contains(&t.id))
+        .map(|t| t.name.clone())
+        .collect();
     debug!("Selected Tag IDs {:?}", &selected_tag_ids);
 
     // Get blog tag mapping by blog id and tag id
@@ -384,6 +480,21 @@ pub async fn put_edit_admin_blog(
                 .await;
         }
     }
+    record_tag_mapping_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ENTITY_BLOG_TAG_MAPPING,
+        id,
+        &saved_blog.and_then(|val| val.tags).unwrap_or_default(),
+        &selected_tag_names,
+    )
+    .await;
+
+    // Show the warnings in the saved blog form
+    if !lint.warnings.is_empty() {
+        let notice = format!("Blog {} is saved with warnings.", &blog.id);
+        return render_admin_blog_form(&app_state, blog, false, notice, lint).await;
+    }
 
     let query_params = BlogsParams {
         start: None,
@@ -391,7 +502,7 @@ pub async fn put_edit_admin_blog(
         tags: None,
     };
 
-    get_admin_blogs_list(State(app_state), headers, Query(query_params)).await
+    get_admin_blogs_list(State(app_state), Query(query_params)).await
 }
 
 /// delete_delete_admin_blog
@@ -400,11 +511,11 @@ pub async fn put_edit_admin_blog(
 pub async fn delete_delete_admin_blog(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers.clone(), &app_state.config.secrets.jwt_secret)
-        || !verify_csrf_token(&headers)
-    {
+    if !auth_user.is_permitted(Permission::Delete) {
         return get_401_unauthorized().await;
     }
 
@@ -418,12 +529,27 @@ pub async fn delete_delete_admin_blog(
         return get_404_not_found().await;
     };
 
+    let saved_blog = blogs_db_uc.blog_display_repo.find(id).await;
     let delete_result = blogs_db_uc.blog_operation_repo.delete(id).await;
 
     if delete_result.is_none() || delete_result.unwrap() != BlogCommandStatus::Deleted {
         error!("Failed to delete blog with Id {}", id);
         return get_500_internal_server_error();
     }
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_DELETE,
+        AUDIT_ENTITY_BLOG,
+        id.to_string(),
+        saved_blog
+            .as_ref()
+            .map(|val| val.audit_summary())
+            .unwrap_or_default(),
+        String::new(),
+    )
+    .await;
 
     // Invalidate cache
     if is_blogs_cache_enabled {
@@ -479,6 +605,15 @@ pub async fn delete_delete_admin_blog(
             error!("Failed to delete blog tag mappings for blog id {}", &id,);
         }
     }
+    record_tag_mapping_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ENTITY_BLOG_TAG_MAPPING,
+        id,
+        &saved_blog.and_then(|val| val.tags).unwrap_or_default(),
+        &[],
+    )
+    .await;
 
     let query_params = BlogsParams {
         start: None,
@@ -486,5 +621,113 @@ pub async fn delete_delete_admin_blog(
         tags: None,
     };
 
-    get_admin_blogs_list(State(app_state), headers, Query(query_params)).await
+    get_admin_blogs_list(State(app_state), Query(query_params)).await
+}
+
+/// put_admin_blog_draft
+/// Serve PUT autosave blog draft endpoint
+/// Save the in-progress edit form of the logged in User.
+/// A draft without changes from the saved blog is discarded.
+#[debug_handler]
+pub async fn put_admin_blog_draft(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    body: String,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    // Sanitize `path`
+    let Ok(id) = path.parse::<i64>() else {
+        warn!("Failed to parse path {} to i64", &path);
+        return get_404_not_found().await;
+    };
+
+    let user_id = auth_user.user.id;
+    let Some(mut blog_draft_db_uc) = app_state.blog_draft_db_usecase.lock().await.clone() else {
+        error!("Failed to lock BlogDraft Usecase Mutex.");
+        return get_500_internal_server_error();
+    };
+    let blogs_db_uc = app_state.blog_db_usecase.lock().await.clone();
+    let Some(saved_blog) = blogs_db_uc.blog_display_repo.find(id).await else {
+        info!("Failed to find blog with Id {}.", &id);
+        return get_404_not_found().await;
+    };
+
+    let blog = Blog {
+        id,
+        ..process_blog_body(body)
+    };
+    let blog_draft = BlogDraft::from_blog(&blog, user_id.clone(), chrono::Utc::now());
+    let updated_at = if blog_draft.is_unchanged_from(&saved_blog) {
+        debug!("Blog {} is unchanged. Discarding its draft.", &id);
+        match blog_draft_db_uc.delete(id, user_id).await {
+            Some(BlogDraftCommandStatus::Deleted) => None,
+            _ => {
+                error!("Failed to delete draft of blog with Id {}", &id);
+                return get_500_internal_server_error();
+            }
+        }
+    } else {
+        let updated_at = blog_draft.updated_at.clone();
+        match blog_draft_db_uc.save(blog_draft).await {
+            Some(BlogDraftCommandStatus::Saved) => Some(updated_at),
+            _ => {
+                error!("Failed to save draft of blog with Id {}", &id);
+                return get_500_internal_server_error();
+            }
+        }
+    };
+
+    match (AdminBlogDraftStatusTemplate { updated_at }).render() {
+        Ok(res) => {
+            debug!("AdminBlogDraftStatus askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/blogs/blog_draft_status.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// delete_admin_blog_draft
+/// Serve DELETE discard blog draft endpoint
+/// Delete the unsaved draft of the logged in User and render the saved blog
+#[debug_handler]
+pub async fn delete_admin_blog_draft(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    // Sanitize `path`
+    let Ok(id) = path.parse::<i64>() else {
+        warn!("Failed to parse path {} to i64", &path);
+        return get_404_not_found().await;
+    };
+
+    let user_id = auth_user.user.id.clone();
+    let Some(mut blog_draft_db_uc) = app_state.blog_draft_db_usecase.lock().await.clone() else {
+        error!("Failed to lock BlogDraft Usecase Mutex.");
+        return get_500_internal_server_error();
+    };
+
+    match blog_draft_db_uc.delete(id, user_id).await {
+        Some(BlogDraftCommandStatus::Deleted) => {}
+        _ => {
+            error!("Failed to delete draft of blog with Id {}", &id);
+            return get_500_internal_server_error();
+        }
+    }
+
+    get_edit_admin_blog(Path(path), State(app_state), auth_user).await
 }
@@ -1,7 +1,4 @@
-use crate::handler::auth::is_auth_verified;
-use crate::handler::status::{
-    get_401_unauthorized, get_404_not_found, get_500_internal_server_error,
-};
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
 use crate::model::axum::AppState;
 use crate::model::tags::{TagsListParams, TagsSearchParams};
 use crate::model::templates_admin::{
@@ -11,7 +8,6 @@ use crate::model::templates_admin::{
 use askama::Template;
 use axum::debug_handler;
 use axum::extract::{Path, Query, State};
-use axum::http::HeaderMap;
 use axum::response::Html;
 use tracing::{debug, error, info, warn};
 
@@ -19,14 +15,7 @@ use tracing::{debug, error, info, warn};
 /// Serve GET (base) admin tags HTML file
 /// Under endpoint /admin/blogs/tags
 #[debug_handler]
-pub async fn get_base_admin_tags(
-    State(app_state): State<AppState>,
-    headers: HeaderMap,
-) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
+pub async fn get_base_admin_tags() -> Html<String> {
     let tags_res = AdminBlogTagsTemplate {}.render();
     match tags_res {
         Ok(res) => {
@@ -49,13 +38,8 @@ pub async fn get_base_admin_tags(
 #[debug_handler]
 pub async fn get_admin_tags_list(
     State(app_state): State<AppState>,
-    headers: HeaderMap,
     params: Query<TagsListParams>,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     // Locking Mutex
     let tags_db_uc = app_state.tag_db_usecase.lock().await.clone().unwrap();
     let tags_cache_uc_opt = app_state.tag_cache_usecase.lock().await.clone();
@@ -141,13 +125,8 @@ pub async fn get_admin_tags_list(
 #[debug_handler]
 pub async fn get_admin_tags_search(
     State(app_state): State<AppState>,
-    headers: HeaderMap,
     params: Query<TagsSearchParams>,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     // Locking Mutex
     let tags_db_uc = app_state.tag_db_usecase.lock().await.clone().unwrap();
     let tags_cache_uc_opt = app_state.tag_cache_usecase.lock().await.clone();
@@ -231,12 +210,7 @@ pub async fn get_admin_tags_search(
 pub async fn get_admin_tag(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
-    headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     let tags_db_uc = app_state.tag_db_usecase.lock().await.clone().unwrap();
     let tags_cache_uc_opt = app_state.tag_cache_usecase.lock().await.clone();
     let is_cache_enabled = tags_cache_uc_opt.is_some();
@@ -316,14 +290,7 @@ pub async fn get_admin_tag(
 /// get_add_admin_tag
 /// /// Serve GET add tag HTML file in a form format.
 #[debug_handler]
-pub async fn get_add_admin_tag(
-    State(app_state): State<AppState>,
-    headers: HeaderMap,
-) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
+pub async fn get_add_admin_tag(State(app_state): State<AppState>) -> Html<String> {
     // Locking Mutex
     let tag_uc = app_state.tag_db_usecase.lock().await.clone().unwrap();
     // Calculate new Blog Id
@@ -356,12 +323,7 @@ pub async fn get_add_admin_tag(
 pub async fn get_edit_admin_tag(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
-    headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     let tag_uc = app_state.tag_db_usecase.lock().await.clone().unwrap();
     let tags_cache_uc_opt = app_state.tag_cache_usecase.lock().await.clone();
     let is_cache_enabled = tags_cache_uc_opt.is_some();
@@ -451,12 +413,7 @@ pub async fn get_edit_admin_tag(
 pub async fn get_delete_admin_tag(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
-    headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     // Locking Mutex
     let tags_db_uc = app_state.tag_db_usecase.lock().await.clone().unwrap();
     let tags_cache_uc_opt = app_state.tag_cache_usecase.lock().await.clone();
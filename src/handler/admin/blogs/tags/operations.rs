@@ -1,17 +1,21 @@
+use crate::handler::admin::audit::{record_audit_log, AuditContext};
 use crate::handler::admin::blogs::tags::displays::{get_admin_tag, get_admin_tags_list};
 use crate::handler::admin::blogs::tags::process_tag_body;
-use crate::handler::auth::csrf::verify_csrf_token;
 use crate::handler::status::{
     get_401_unauthorized, get_404_not_found, get_500_internal_server_error,
 };
+use crate::model::audit_logs::{
+    AUDIT_ACTION_CREATE, AUDIT_ACTION_DELETE, AUDIT_ACTION_UPDATE, AUDIT_ENTITY_TAG,
+};
 use crate::model::axum::AppState;
 use crate::model::tags::{TagCommandStatus, TagsListParams};
 use axum::debug_handler;
-use axum::extract::{Path, Query, State};
+use axum::extract::{ConnectInfo, Path, Query, State};
 use axum::response::Html;
+use std::net::SocketAddr;
 use tracing::{debug, error, info, warn};
 
-use crate::handler::auth::is_auth_verified;
+use crate::model::auth::{AuthUser, Permission};
 use axum::http::HeaderMap;
 
 /// post_add_admin_tag
@@ -19,12 +23,12 @@ use axum::http::HeaderMap;
 #[debug_handler]
 pub async fn post_add_admin_tag(
     State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     body: String,
 ) -> Html<String> {
-    if !is_auth_verified(headers.clone(), &app_state.config.secrets.jwt_secret)
-        || !verify_csrf_token(&headers)
-    {
+    if !auth_user.is_permitted(Permission::Edit) {
         return get_401_unauthorized().await;
     }
 
@@ -49,6 +53,16 @@ pub async fn post_add_admin_tag(
         error!("Failed to add Tag with Id {}", &tag.id);
         return get_500_internal_server_error();
     }
+    record_audit_log(
+        &app_state,
+        &AuditContext::from_request(&app_state, &headers, addr),
+        AUDIT_ACTION_CREATE,
+        AUDIT_ENTITY_TAG,
+        tag.id.to_string(),
+        String::new(),
+        tag.audit_summary(),
+    )
+    .await;
 
     // Insert cache
     if is_cache_enabled {
@@ -66,7 +80,7 @@ pub async fn post_add_admin_tag(
         end: None,
     };
 
-    get_admin_tags_list(State(app_state), headers, Query(params)).await
+    get_admin_tags_list(State(app_state), Query(params)).await
 }
 
 /// put_edit_admin_tag
@@ -75,12 +89,12 @@ pub async fn post_add_admin_tag(
 pub async fn put_edit_admin_tag(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     body: String,
 ) -> Html<String> {
-    if !is_auth_verified(headers.clone(), &app_state.config.secrets.jwt_secret)
-        || !verify_csrf_token(&headers)
-    {
+    if !auth_user.is_permitted(Permission::Edit) {
         return get_401_unauthorized().await;
     }
 
@@ -102,6 +116,12 @@ pub async fn put_edit_admin_tag(
 
     let tag = process_tag_body(body);
 
+    let saved_tag = tag_uc
+        .clone()
+        .unwrap()
+        .tag_display_repo
+        .find(id.clone().unwrap())
+        .await;
     let edit_result = tag_uc
         .unwrap()
         .tag_operation_repo
@@ -117,6 +137,16 @@ pub async fn put_edit_admin_tag(
         error!("Failed to edit Tag with Id {}", &tag.id);
         return get_500_internal_server_error();
     }
+    record_audit_log(
+        &app_state,
+        &AuditContext::from_request(&app_state, &headers, addr),
+        AUDIT_ACTION_UPDATE,
+        AUDIT_ENTITY_TAG,
+        path.clone(),
+        saved_tag.map(|val| val.audit_summary()).unwrap_or_default(),
+        tag.audit_summary(),
+    )
+    .await;
 
     // Re-insert cache
     if is_cache_enabled {
@@ -136,7 +166,7 @@ pub async fn put_edit_admin_tag(
             .await;
     }
 
-    get_admin_tag(Path(path), State(app_state), headers).await
+    get_admin_tag(Path(path), State(app_state)).await
 }
 
 /// delete_delete_admin_tag
@@ -145,11 +175,11 @@ pub async fn put_edit_admin_tag(
 pub async fn delete_delete_admin_tag(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers.clone(), &app_state.config.secrets.jwt_secret)
-        || !verify_csrf_token(&headers)
-    {
+    if !auth_user.is_permitted(Permission::Delete) {
         return get_401_unauthorized().await;
     }
 
@@ -169,6 +199,12 @@ pub async fn delete_delete_admin_tag(
         }
     };
 
+    let saved_tag = tag_uc
+        .clone()
+        .unwrap()
+        .tag_display_repo
+        .find(id.clone().unwrap())
+        .await;
     let delete_result = tag_uc
         .unwrap()
         .tag_operation_repo
@@ -179,6 +215,16 @@ pub async fn delete_delete_admin_tag(
         error!("Failed to edit Tag with Id {}", &id.clone().unwrap());
         return get_500_internal_server_error();
     }
+    record_audit_log(
+        &app_state,
+        &AuditContext::from_request(&app_state, &headers, addr),
+        AUDIT_ACTION_DELETE,
+        AUDIT_ENTITY_TAG,
+        path.clone(),
+        saved_tag.map(|val| val.audit_summary()).unwrap_or_default(),
+        String::new(),
+    )
+    .await;
 
     // Invalidate cache
     if is_cache_enabled {
@@ -196,5 +242,5 @@ pub async fn delete_delete_admin_tag(
         end: None,
     };
 
-    get_admin_tags_list(State(app_state), headers, Query(params)).await
+    get_admin_tags_list(State(app_state), Query(params)).await
 }
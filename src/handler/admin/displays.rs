@@ -1,11 +1,17 @@
-use crate::handler::auth::is_auth_verified;
-use crate::handler::status::{get_401_unauthorized, get_500_internal_server_error};
+use crate::handler::status::get_500_internal_server_error;
 use crate::model::axum::AppState;
-use crate::model::templates_admin::AdminTemplate;
+use crate::model::dashboard::{
+    DASHBOARD_LOGIN_ACTIVITIES_LIMIT, DASHBOARD_RECENT_UPDATES_LIMIT, DASHBOARD_TAGS_LIMIT,
+};
+use crate::model::templates_admin::{
+    AdminDashboardCacheTemplate, AdminDashboardLoginsTemplate, AdminDashboardRecentTemplate,
+    AdminTemplate,
+};
+use crate::repo::auth::AuthRepo;
+use crate::repo::dashboard::{DashboardCacheRepo, DashboardDisplayRepo};
 use askama::Template;
 use axum::debug_handler;
 use axum::extract::State;
-use axum::http::HeaderMap;
 use axum::response::Html;
 use tracing::{error, info};
 
@@ -13,11 +19,7 @@ use tracing::{error, info};
 /// Serve GET (base) admin HTML file
 /// Under endpoint /admin
 #[debug_handler]
-pub async fn get_base_admin(State(app_state): State<AppState>, headers: HeaderMap) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
+pub async fn get_base_admin() -> Html<String> {
     let admin_res = AdminTemplate {}.render();
     match admin_res {
         Ok(res) => {
@@ -30,3 +32,105 @@ pub async fn get_base_admin(State(app_state): State<AppState>, headers: HeaderMa
         }
     }
 }
+
+/// get_admin_dashboard_content
+/// Serve number of blogs, talks, tags, projects and pending drafts
+/// Under endpoint /admin/dashboard/content
+#[debug_handler]
+pub async fn get_admin_dashboard_content(State(app_state): State<AppState>) -> Html<String> {
+    let dashboard_db_uc = app_state.dashboard_db_usecase.lock().await.clone().unwrap();
+    let Some(content_stats) = dashboard_db_uc.find_content_stats().await else {
+        error!("Failed to find content stats.");
+        return get_500_internal_server_error();
+    };
+
+    render_dashboard_partial(content_stats.to_admin_template(), "content")
+}
+
+/// get_admin_dashboard_tags
+/// Serve the most used and unused tags
+/// Under endpoint /admin/dashboard/tags
+#[debug_handler]
+pub async fn get_admin_dashboard_tags(State(app_state): State<AppState>) -> Html<String> {
+    let dashboard_db_uc = app_state.dashboard_db_usecase.lock().await.clone().unwrap();
+    let Some(tag_usages) = dashboard_db_uc.find_tag_usages().await else {
+        error!("Failed to find tag usages.");
+        return get_500_internal_server_error();
+    };
+
+    render_dashboard_partial(tag_usages.to_admin_template(DASHBOARD_TAGS_LIMIT), "tags")
+}
+
+/// get_admin_dashboard_recent
+/// Serve recently added or edited blogs and talks
+/// Under endpoint /admin/dashboard/recent
+#[debug_handler]
+pub async fn get_admin_dashboard_recent(State(app_state): State<AppState>) -> Html<String> {
+    let dashboard_db_uc = app_state.dashboard_db_usecase.lock().await.clone().unwrap();
+    let Some(recent_updates) = dashboard_db_uc
+        .find_recent_updates(DASHBOARD_RECENT_UPDATES_LIMIT)
+        .await
+    else {
+        error!("Failed to find recent updates.");
+        return get_500_internal_server_error();
+    };
+
+    render_dashboard_partial(AdminDashboardRecentTemplate { recent_updates }, "recent")
+}
+
+/// get_admin_dashboard_cache
+/// Serve InMemoryCache hit ratio and entry counts
+/// Under endpoint /admin/dashboard/cache
+#[debug_handler]
+pub async fn get_admin_dashboard_cache(State(app_state): State<AppState>) -> Html<String> {
+    let dashboard_cache_uc_opt = app_state.dashboard_cache_usecase.lock().await.clone();
+    let template = match dashboard_cache_uc_opt {
+        Some(dashboard_cache_uc) => {
+            let Some(cache_stats) = dashboard_cache_uc.find_cache_stats().await else {
+                error!("Failed to find cache stats.");
+                return get_500_internal_server_error();
+            };
+            cache_stats.to_admin_template()
+        }
+        None => AdminDashboardCacheTemplate {
+            is_enabled: false,
+            hits: 0,
+            misses: 0,
+            hit_ratio: String::new(),
+            entries: Vec::new(),
+        },
+    };
+
+    render_dashboard_partial(template, "cache")
+}
+
+/// get_admin_dashboard_logins
+/// Serve the latest login attempts
+/// Under endpoint /admin/dashboard/logins
+#[debug_handler]
+pub async fn get_admin_dashboard_logins(State(app_state): State<AppState>) -> Html<String> {
+    let auth_db_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(login_activities) = auth_db_uc
+        .find_login_activities(DASHBOARD_LOGIN_ACTIVITIES_LIMIT)
+        .await
+    else {
+        error!("Failed to find login activities.");
+        return get_500_internal_server_error();
+    };
+
+    render_dashboard_partial(AdminDashboardLoginsTemplate { login_activities }, "logins")
+}
+
+// Render an admin dashboard partial under templates/admin/dashboard
+fn render_dashboard_partial(template: impl Template, name: &str) -> Html<String> {
+    match template.render() {
+        Ok(res) => {
+            info!("AdminDashboard {name} askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/dashboard/{name}.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
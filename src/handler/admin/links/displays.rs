@@ -0,0 +1,36 @@
+use crate::handler::admin::links::render_admin_links_list;
+use crate::handler::status::get_500_internal_server_error;
+use crate::model::axum::AppState;
+use crate::model::link_checks::describe_link_check_interval;
+use crate::model::templates_admin::AdminLinkChecksTemplate;
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::State;
+use axum::response::Html;
+use tracing::{error, info};
+
+/// get_base_admin_links
+/// Serve GET (base) admin broken links report HTML file
+/// Under endpoint /admin/links
+#[debug_handler]
+pub async fn get_base_admin_links(State(app_state): State<AppState>) -> Html<String> {
+    let interval = describe_link_check_interval(app_state.config.link_check_interval);
+    match (AdminLinkChecksTemplate { interval }).render() {
+        Ok(res) => {
+            info!("AdminLinkChecks askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/links/links.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_links_list
+/// Serve broken links per blog and talk from the latest link check
+/// Under endpoint /admin/links/list
+#[debug_handler]
+pub async fn get_admin_links_list(State(app_state): State<AppState>) -> Html<String> {
+    render_admin_links_list(&app_state, String::new()).await
+}
@@ -0,0 +1,35 @@
+pub mod displays;
+pub mod operations;
+
+use crate::handler::status::get_500_internal_server_error;
+use crate::model::axum::AppState;
+use crate::repo::link_checks::LinkCheckDisplayRepo;
+use askama::Template;
+use axum::response::Html;
+use tracing::{error, info};
+
+// Render the broken links report of the latest link check with an optional notice
+async fn render_admin_links_list(app_state: &AppState, notice: String) -> Html<String> {
+    let link_check_db_uc = app_state
+        .link_check_db_usecase
+        .lock()
+        .await
+        .clone()
+        .unwrap();
+
+    let Some(link_checks) = link_check_db_uc.find_link_checks().await else {
+        error!("Failed to find link checks.");
+        return get_500_internal_server_error();
+    };
+
+    match link_checks.to_admin_list_template(notice).render() {
+        Ok(res) => {
+            info!("AdminListLinkChecks askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/links/list_links.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
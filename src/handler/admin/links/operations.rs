@@ -0,0 +1,35 @@
+use crate::handler::admin::links::render_admin_links_list;
+use crate::handler::status::get_401_unauthorized;
+use crate::jobs::link_checker::{is_link_check_running, run_link_check, ExternalLinkChecker};
+use crate::model::auth::{AuthUser, Permission};
+use crate::model::axum::AppState;
+use axum::debug_handler;
+use axum::extract::State;
+use axum::response::Html;
+use tracing::info;
+
+/// post_check_admin_links
+/// Start a link check in the background then serve the current report
+/// Under endpoint /admin/links/check
+#[debug_handler]
+pub async fn post_check_admin_links(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+    if is_link_check_running() {
+        let notice = "A link check is already running.".to_string();
+        return render_admin_links_list(&app_state, notice).await;
+    }
+
+    info!("Link check is requested from admin.");
+    let check_app_state = app_state.clone();
+    tokio::spawn(async move {
+        run_link_check(&check_app_state, &ExternalLinkChecker::default()).await;
+    });
+
+    let notice = "Link check started. Reload this page in a moment to see the results.".to_string();
+    render_admin_links_list(&app_state, notice).await
+}
@@ -0,0 +1,72 @@
+use crate::handler::admin::media::render_admin_media_list;
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::axum::AppState;
+use crate::model::media::{format_size, MediaFilesParams};
+use crate::model::templates_admin::{AdminGetDeleteMediaTemplate, AdminMediaTemplate};
+use crate::repo::media::MediaDisplayRepo;
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::{Path, Query, State};
+use axum::response::Html;
+use tracing::{error, info};
+
+/// get_base_admin_media
+/// Serve GET (base) admin media library HTML file
+/// Under endpoint /admin/media
+#[debug_handler]
+pub async fn get_base_admin_media(State(app_state): State<AppState>) -> Html<String> {
+    let max_size = format_size(app_state.config.media_max_size as i64);
+    match (AdminMediaTemplate { max_size }).render() {
+        Ok(res) => {
+            info!("AdminMedia askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/media/media.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_media_list
+/// Serve to list media files for Admin Media from the newest upload
+/// Under endpoint /admin/media/list
+/// Accepted parameters:
+/// - start: initial media files pagination
+/// - end: end of media files pagination
+#[debug_handler]
+pub async fn get_admin_media_list(
+    State(app_state): State<AppState>,
+    params: Query<MediaFilesParams>,
+) -> Html<String> {
+    render_admin_media_list(&app_state, params.0, String::new(), Vec::new()).await
+}
+
+/// get_delete_admin_media
+/// Serve GET delete media HTML file to delete a media file
+#[debug_handler]
+pub async fn get_delete_admin_media(
+    Path(key): Path<String>,
+    State(app_state): State<AppState>,
+) -> Html<String> {
+    let media_db_uc = app_state.media_db_usecase.lock().await.clone().unwrap();
+
+    if media_db_uc.find(key.clone()).await.is_none() {
+        info!("Failed to find Media File with key {}.", &key);
+        return get_404_not_found().await;
+    }
+
+    match (AdminGetDeleteMediaTemplate { key }).render() {
+        Ok(res) => {
+            info!("AdminGetDeleteMedia askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/media/get_delete_media.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
@@ -0,0 +1,41 @@
+pub mod displays;
+pub mod operations;
+
+use crate::handler::status::get_500_internal_server_error;
+use crate::model::axum::AppState;
+use crate::model::media::MediaFilesParams;
+use crate::repo::media::MediaDisplayRepo;
+use askama::Template;
+use axum::response::Html;
+use tracing::{error, info};
+
+// Render the admin media list with an optional notice and validation errors
+async fn render_admin_media_list(
+    app_state: &AppState,
+    params: MediaFilesParams,
+    notice: String,
+    errors: Vec<String>,
+) -> Html<String> {
+    let media_db_uc = app_state.media_db_usecase.lock().await.clone().unwrap();
+    let sanitized_params = params.sanitize();
+
+    let Some(media_files) = media_db_uc.find_media_files(sanitized_params.clone()).await else {
+        error!(
+            "Failed to find media files started at {} and ended at {}.",
+            sanitized_params.start.unwrap(),
+            sanitized_params.end.unwrap()
+        );
+        return get_500_internal_server_error();
+    };
+
+    match media_files.to_admin_list_template(notice, errors).render() {
+        Ok(res) => {
+            info!("AdminListMedia askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/media/list_media.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use crate::handler::admin::audit::{record_audit_log, AuditContext};
+use crate::handler::admin::media::render_admin_media_list;
+use crate::handler::status::{
+    get_401_unauthorized, get_404_not_found, get_500_internal_server_error,
+};
+use crate::model::audit_logs::{AUDIT_ACTION_CREATE, AUDIT_ACTION_DELETE, AUDIT_ENTITY_MEDIA};
+use crate::model::auth::{AuthUser, Permission};
+use crate::model::axum::AppState;
+use crate::model::media::{
+    format_size, process_media_image, MediaCommandStatus, MediaFilesParams, MediaImageKey,
+    MediaUpload,
+};
+use crate::repo::media::{MediaDisplayRepo, MediaOperationRepo, MediaStorageRepo};
+use axum::debug_handler;
+use axum::extract::{ConnectInfo, Multipart, Path, State};
+use axum::http::HeaderMap;
+use axum::response::Html;
+use bytes::Bytes;
+use std::net::SocketAddr;
+use tracing::{debug, error, info, warn};
+
+/// Default pagination of the media list rendered after an operation
+const MEDIA_FILES_PARAMS: MediaFilesParams = MediaFilesParams {
+    start: None,
+    end: None,
+};
+
+/// Read the `media_file` field of an upload form
+/// Stop reading once the file is larger than `max_size`
+/// so a huge upload isn't fully buffered in memory.
+async fn read_media_upload(
+    multipart: &mut Multipart,
+    max_size: usize,
+) -> Result<MediaUpload, Vec<String>> {
+    let mut upload = MediaUpload::default();
+    loop {
+        let mut field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(err) => {
+                warn!("Failed to read upload form. {}", err);
+                return Err(vec!["Failed to read the uploaded file.".to_string()]);
+            }
+        };
+        if field.name() != Some("media_file") {
+            warn!("Unrecognized upload field: {:?}", field.name());
+            continue;
+        }
+        upload.filename = field.file_name().unwrap_or_default().to_string();
+        loop {
+            match field.chunk().await {
+                Ok(Some(chunk)) => {
+                    upload.data.extend_from_slice(&chunk);
+                    if upload.data.len() > max_size {
+                        return Err(vec![format!(
+                            "File '{}' is larger than {}.",
+                            &upload.filename,
+                            format_size(max_size as i64)
+                        )]);
+                    }
+                }
+                Ok(None) => break,
+                Err(err) => {
+                    warn!("Failed to read uploaded file. {}", err);
+                    return Err(vec!["Failed to read the uploaded file.".to_string()]);
+                }
+            }
+        }
+    }
+    Ok(upload)
+}
+
+/// post_upload_admin_media
+/// Serve POST upload media endpoint
+/// Store the file into the media storage and its metadata into the database.
+/// PNG and JPEG images are stripped of their metadata and stored along with
+/// resized and WebP variants.
+#[debug_handler]
+pub async fn post_upload_admin_media(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    mut multipart: Multipart,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    let Some(mut media_storage_uc) = app_state.media_storage_usecase.lock().await.clone() else {
+        warn!("Media storage is not configured.");
+        return render_admin_media_list(
+            &app_state,
+            MEDIA_FILES_PARAMS,
+            String::new(),
+            vec!["Media storage is not configured.".to_string()],
+        )
+        .await;
+    };
+    let mut media_db_uc = app_state.media_db_usecase.lock().await.clone().unwrap();
+
+    let max_size = app_state.config.media_max_size;
+    let (media_file, data) =
+        match read_media_upload(&mut multipart, max_size)
+            .await
+            .and_then(|upload| {
+                upload
+                    .validate(max_size, chrono::Utc::now())
+                    .map(|media_file| (media_file, upload.data))
+            }) {
+            Ok(val) => val,
+            Err(errors) => {
+                info!("Invalid media upload.");
+                return render_admin_media_list(
+                    &app_state,
+                    MEDIA_FILES_PARAMS,
+                    String::new(),
+                    errors,
+                )
+                .await;
+            }
+        };
+
+    // Decoding and resizing images is CPU bound
+    let processed =
+        tokio::task::spawn_blocking(move || process_media_image(&media_file, data)).await;
+    let (media_file, data, variants) = match processed {
+        Ok(Ok(val)) => val,
+        Ok(Err(err)) => {
+            info!("Invalid media image.");
+            return render_admin_media_list(
+                &app_state,
+                MEDIA_FILES_PARAMS,
+                String::new(),
+                vec![err],
+            )
+            .await;
+        }
+        Err(err) => {
+            error!("Failed to process media image. {}", err);
+            return get_500_internal_server_error();
+        }
+    };
+    debug!(
+        "Uploading {} as {} ({}) with {} variants",
+        &media_file.filename,
+        &media_file.key,
+        &media_file.content_type,
+        variants.len()
+    );
+
+    for variant in variants {
+        match media_storage_uc
+            .upload(
+                variant.key.clone(),
+                variant.content_type,
+                Bytes::from(variant.data),
+            )
+            .await
+        {
+            Some(MediaCommandStatus::Uploaded) => {}
+            _ => {
+                error!("Failed to upload Media File variant {}", &variant.key);
+                return get_500_internal_server_error();
+            }
+        }
+    }
+    match media_storage_uc
+        .upload(
+            media_file.key.clone(),
+            media_file.content_type.clone(),
+            Bytes::from(data),
+        )
+        .await
+    {
+        Some(MediaCommandStatus::Uploaded) => {}
+        _ => {
+            error!("Failed to upload Media File {}", &media_file.key);
+            return get_500_internal_server_error();
+        }
+    }
+    match media_db_uc.add(media_file.clone()).await {
+        Some(MediaCommandStatus::Stored) => {}
+        _ => {
+            error!("Failed to add Media File {}", &media_file.key);
+            return get_500_internal_server_error();
+        }
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_CREATE,
+        AUDIT_ENTITY_MEDIA,
+        media_file.key.clone(),
+        String::new(),
+        media_file.audit_summary(),
+    )
+    .await;
+
+    render_admin_media_list(
+        &app_state,
+        MEDIA_FILES_PARAMS,
+        format!("Uploaded {}.", &media_file.filename),
+        Vec::new(),
+    )
+    .await
+}
+
+/// delete_delete_admin_media
+/// Serve DELETE delete media endpoint
+/// Remove the file and its image variants from the media storage and its
+/// metadata from the database
+#[debug_handler]
+pub async fn delete_delete_admin_media(
+    Path(key): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Delete) {
+        return get_401_unauthorized().await;
+    }
+
+    let Some(mut media_storage_uc) = app_state.media_storage_usecase.lock().await.clone() else {
+        warn!("Media storage is not configured.");
+        return get_500_internal_server_error();
+    };
+    let mut media_db_uc = app_state.media_db_usecase.lock().await.clone().unwrap();
+
+    let Some(media_file) = media_db_uc.find(key.clone()).await else {
+        info!("Failed to find Media File with key {}.", &key);
+        return get_404_not_found().await;
+    };
+
+    let mut keys = MediaImageKey::parse(&key)
+        .map(|image_key| image_key.variant_keys())
+        .unwrap_or_default();
+    keys.push(key.clone());
+    for storage_key in keys {
+        match media_storage_uc.remove(storage_key.clone()).await {
+            Some(MediaCommandStatus::Removed) => {}
+            _ => {
+                error!("Failed to remove Media File {}", &storage_key);
+                return get_500_internal_server_error();
+            }
+        }
+    }
+    match media_db_uc.delete(key.clone()).await {
+        Some(MediaCommandStatus::Deleted) => {}
+        _ => {
+            error!("Failed to delete Media File {}", &key);
+            return get_500_internal_server_error();
+        }
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_DELETE,
+        AUDIT_ENTITY_MEDIA,
+        key.clone(),
+        media_file.audit_summary(),
+        String::new(),
+    )
+    .await;
+
+    render_admin_media_list(
+        &app_state,
+        MEDIA_FILES_PARAMS,
+        format!("Deleted {}.", &media_file.filename),
+        Vec::new(),
+    )
+    .await
+}
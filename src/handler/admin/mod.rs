@@ -1,3 +1,49 @@
+pub mod account;
+pub mod audit;
 pub mod blogs;
 pub mod displays;
+pub mod links;
+pub mod media;
+pub mod profile;
+pub mod projects;
+pub mod resume;
 pub mod talks;
+pub mod users;
+
+use crate::model::axum::AppState;
+use crate::model::tags::{Tags, TagsListParams};
+use tracing::{debug, warn};
+
+// Find all Tags from cache or database
+async fn find_all_tags(app_state: &AppState) -> Option<Tags> {
+    let params = TagsListParams {
+        start: Some(0),
+        end: Some(1000),
+    };
+
+    let tags_cache_uc_opt = app_state.tag_cache_usecase.lock().await.clone();
+    if let Some(tags_cache_uc) = tags_cache_uc_opt {
+        let tags_cache_result = tags_cache_uc
+            .tag_display_repo
+            .find_tags(params.clone())
+            .await;
+        if tags_cache_result.is_some() {
+            return tags_cache_result;
+        }
+        debug!("Tags Cache is empty. Getting data from database");
+    }
+
+    let tag_db_uc = app_state.tag_db_usecase.lock().await.clone()?;
+    tag_db_uc.tag_display_repo.find_tags(params).await
+}
+
+// Find Tag names to be picked in the admin add/edit forms
+async fn find_tag_names(app_state: &AppState) -> Vec<String> {
+    match find_all_tags(app_state).await {
+        Some(tags) => tags.tags.iter().map(|tag| tag.name.clone()).collect(),
+        None => {
+            warn!("Failed to find Tags.");
+            Vec::new()
+        }
+    }
+}
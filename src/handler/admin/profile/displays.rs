@@ -0,0 +1,112 @@
+use crate::handler::admin::profile::render_admin_profile_form;
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::axum::AppState;
+use crate::model::profiles::{ProfileForm, ProfilesParams};
+use crate::model::templates_admin::AdminProfileTemplate;
+use crate::repo::profiles::ProfileDisplayRepo;
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::{Path, Query, State};
+use axum::response::Html;
+use tracing::{error, info};
+
+/// get_base_admin_profile
+/// Serve GET (base) admin profile HTML file
+/// Under endpoint /admin/profile
+#[debug_handler]
+pub async fn get_base_admin_profile() -> Html<String> {
+    match (AdminProfileTemplate {}).render() {
+        Ok(res) => {
+            info!("AdminProfile askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/profile/profile.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_edit_admin_profile
+/// Serve GET profile editor HTML file filled with the latest Profile version
+/// Under endpoint /admin/profile/edit
+#[debug_handler]
+pub async fn get_edit_admin_profile(State(app_state): State<AppState>) -> Html<String> {
+    let profile_db_uc = app_state.profile_db_usecase.lock().await.clone().unwrap();
+
+    let form = match profile_db_uc.find_latest().await {
+        Some(profile) => profile.to_form(),
+        None => {
+            info!("No Profile version yet. Serve an empty profile editor.");
+            ProfileForm::default()
+        }
+    };
+    render_admin_profile_form(&form, String::new(), Vec::new())
+}
+
+/// get_admin_profile_history
+/// Serve to list Profile versions from the newest
+/// Under endpoint /admin/profile/history
+/// Accepted parameters:
+/// - start: initial versions pagination
+/// - end: end of versions pagination
+#[debug_handler]
+pub async fn get_admin_profile_history(
+    State(app_state): State<AppState>,
+    params: Query<ProfilesParams>,
+) -> Html<String> {
+    let profile_db_uc = app_state.profile_db_usecase.lock().await.clone().unwrap();
+    let sanitized_params = params.sanitize();
+
+    let Some(profiles) = profile_db_uc.find_profiles(sanitized_params.clone()).await else {
+        error!(
+            "Failed to find profiles started at {} and ended at {}.",
+            sanitized_params.start.unwrap(),
+            sanitized_params.end.unwrap()
+        );
+        return get_500_internal_server_error();
+    };
+    let latest_version = profile_db_uc
+        .find_latest()
+        .await
+        .map(|profile| profile.version)
+        .unwrap_or_default();
+
+    match profiles.to_admin_list_template(&latest_version).render() {
+        Ok(res) => {
+            info!("AdminListProfiles askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/profile/list_profiles.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_profile
+/// Serve GET preview of a Profile version
+/// Under endpoint /admin/profile/{version}
+#[debug_handler]
+pub async fn get_admin_profile(
+    Path(version): Path<String>,
+    State(app_state): State<AppState>,
+) -> Html<String> {
+    let profile_db_uc = app_state.profile_db_usecase.lock().await.clone().unwrap();
+
+    let Some(profile) = profile_db_uc.find(version.clone()).await else {
+        info!("Failed to find Profile with version {}.", &version);
+        return get_404_not_found().await;
+    };
+
+    match profile.to_admin_template().render() {
+        Ok(res) => {
+            info!("AdminGetProfile askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/profile/get_profile.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
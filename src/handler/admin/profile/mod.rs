@@ -0,0 +1,63 @@
+pub mod displays;
+pub mod operations;
+
+use crate::handler::status::get_500_internal_server_error;
+use crate::model::profiles::ProfileForm;
+use crate::model::templates_admin::AdminGetEditProfileTemplate;
+use askama::Template;
+use axum::response::Html;
+use tracing::{debug, error, info, warn};
+use urlencoding::decode;
+
+// Take request body String from POST operation to create a new ProfileForm
+fn process_profile_body(body: String) -> ProfileForm {
+    let mut form = ProfileForm::default();
+
+    let req_fields: Vec<&str> = body.split("&").collect();
+    for req_field in req_fields {
+        let Some((key, value)) = req_field.split_once("=") else {
+            continue;
+        };
+        let value_decoded = decode(value).unwrap().into_owned();
+        debug!("Request field key/value {:?}/{:?}", key, value_decoded);
+        match key {
+            "profile_headline" => form.headline = value_decoded,
+            "profile_bio" => form.bio = value_decoded,
+            "profile_experiences" => form.experiences = value_decoded,
+            "profile_skills" => form.skills = value_decoded,
+            "profile_links" => form.links = value_decoded,
+            _ => {
+                warn!("Unrecognized key/value: {:?}/{:?}", key, value_decoded);
+            }
+        }
+    }
+
+    form
+}
+
+// Render the admin profile editor with an optional notice and validation errors
+fn render_admin_profile_form(
+    form: &ProfileForm,
+    notice: String,
+    errors: Vec<String>,
+) -> Html<String> {
+    let edit_profile = AdminGetEditProfileTemplate {
+        profile: form.to_admin_template(),
+        notice,
+        errors,
+    }
+    .render();
+    match edit_profile {
+        Ok(res) => {
+            info!("AdminGetEditProfile askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/profile/get_edit_profile.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::handler::admin::audit::{record_audit_log, AuditContext};
+use crate::handler::admin::profile::{process_profile_body, render_admin_profile_form};
+use crate::handler::status::{
+    get_401_unauthorized, get_404_not_found, get_500_internal_server_error,
+};
+use crate::model::audit_logs::{AUDIT_ACTION_UPDATE, AUDIT_ENTITY_PROFILE};
+use crate::model::auth::{AuthUser, Permission};
+use crate::model::axum::AppState;
+use crate::model::profiles::{new_profile_version, Profile, ProfileCommandStatus};
+use crate::repo::profiles::{ProfileCacheOperationRepo, ProfileDisplayRepo, ProfileOperationRepo};
+use axum::debug_handler;
+use axum::extract::{ConnectInfo, Path, State};
+use axum::http::HeaderMap;
+use axum::response::Html;
+use std::net::SocketAddr;
+use tracing::{debug, error, info};
+
+/// Store a new Profile version and cache it as the latest Profile
+/// The change from the previous latest Profile is recorded in the audit log.
+/// Return `None` if the Profile failed to be stored
+async fn store_profile_version(
+    app_state: &AppState,
+    audit_context: &AuditContext,
+    profile: Profile,
+) -> Option<()> {
+    let mut profile_db_uc = app_state.profile_db_usecase.lock().await.clone().unwrap();
+    let old_profile = profile_db_uc.find_latest().await;
+
+    match profile_db_uc.add(profile.clone()).await {
+        Some(ProfileCommandStatus::Stored) => {}
+        _ => {
+            error!("Failed to add Profile with version {}", &profile.version);
+            return None;
+        }
+    }
+
+    record_audit_log(
+        app_state,
+        audit_context,
+        AUDIT_ACTION_UPDATE,
+        AUDIT_ENTITY_PROFILE,
+        profile.version.clone(),
+        old_profile
+            .map(|val| val.audit_summary())
+            .unwrap_or_default(),
+        profile.audit_summary(),
+    )
+    .await;
+
+    // Insert cache
+    if let Some(mut profile_cache_uc) = app_state.profile_cache_usecase.lock().await.clone() {
+        debug!("Caching profile {}", &profile.version);
+        let _ = profile_cache_uc.insert(profile).await;
+    }
+    Some(())
+}
+
+/// post_edit_admin_profile
+/// Serve POST profile editor endpoint
+/// Every save creates a new Profile version
+#[debug_handler]
+pub async fn post_edit_admin_profile(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    let profile_form = process_profile_body(body);
+    let version = new_profile_version(chrono::Utc::now());
+    let profile = match profile_form.validate(version) {
+        Ok(profile) => profile,
+        Err(errors) => {
+            info!("Invalid new Profile version.");
+            return render_admin_profile_form(&profile_form, String::new(), errors);
+        }
+    };
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    if store_profile_version(&app_state, &audit_context, profile.clone())
+        .await
+        .is_none()
+    {
+        return get_500_internal_server_error();
+    }
+
+    render_admin_profile_form(
+        &profile.to_form(),
+        format!("Saved profile version {}.", &profile.version),
+        Vec::new(),
+    )
+}
+
+/// post_rollback_admin_profile
+/// Serve POST rollback profile endpoint
+/// Copy a Profile version into a new Profile version so the history is kept
+#[debug_handler]
+pub async fn post_rollback_admin_profile(
+    Path(version): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    let profile_db_uc = app_state.profile_db_usecase.lock().await.clone().unwrap();
+
+    let Some(old_profile) = profile_db_uc.find(version.clone()).await else {
+        info!("Failed to find Profile with version {}.", &version);
+        return get_404_not_found().await;
+    };
+    let profile = Profile {
+        version: new_profile_version(chrono::Utc::now()),
+        ..old_profile
+    };
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    if store_profile_version(&app_state, &audit_context, profile.clone())
+        .await
+        .is_none()
+    {
+        return get_500_internal_server_error();
+    }
+
+    render_admin_profile_form(
+        &profile.to_form(),
+        format!(
+            "Rolled back to version {} as version {}.",
+            &version, &profile.version
+        ),
+        Vec::new(),
+    )
+}
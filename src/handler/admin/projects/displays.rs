@@ -0,0 +1,241 @@
+use crate::handler::admin::projects::project_statuses;
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::axum::AppState;
+use crate::model::projects::{ProjectForm, ProjectStatus, ProjectsParams};
+use crate::model::templates_admin::{
+    AdminGetAddProjectTemplate, AdminGetDeleteProjectTemplate, AdminGetEditProjectTemplate,
+    AdminGetProjectTemplate, AdminProjectsTemplate,
+};
+use crate::repo::projects::{ProjectDisplayRepo, ProjectOperationRepo};
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::{Path, Query, State};
+use axum::response::Html;
+use tracing::{debug, error, info, warn};
+
+/// get_base_admin_projects
+/// Serve GET (base) admin projects HTML file
+/// Under endpoint /admin/projects
+#[debug_handler]
+pub async fn get_base_admin_projects() -> Html<String> {
+    match (AdminProjectsTemplate {}).render() {
+        Ok(res) => {
+            info!("AdminProjects askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/projects/projects.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_projects_list
+/// Serve to list projects for Admin Projects
+/// Under endpoint /admin/projects/list
+/// Accepted parameters:
+/// - start: initial projects pagination
+/// - end: end of projects pagination
+#[debug_handler]
+pub async fn get_admin_projects_list(
+    State(app_state): State<AppState>,
+    params: Query<ProjectsParams>,
+) -> Html<String> {
+    let project_db_uc = app_state.project_db_usecase.lock().await.clone().unwrap();
+    let sanitized_params = params.sanitize();
+
+    let Some(projects) = project_db_uc.find_projects(sanitized_params.clone()).await else {
+        error!(
+            "Failed to find projects started at {} and ended at {}.",
+            sanitized_params.start.unwrap(),
+            sanitized_params.end.unwrap()
+        );
+        return get_500_internal_server_error();
+    };
+
+    match projects.to_admin_list_template().render() {
+        Ok(res) => {
+            info!("AdminListProjects askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/projects/list_projects.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_project
+/// Serve GET project HTML file and return point for several cancelation endpoints
+/// Returned single project
+#[debug_handler]
+pub async fn get_admin_project(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+) -> Html<String> {
+    let project_db_uc = app_state.project_db_usecase.lock().await.clone().unwrap();
+
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    let Some(project) = project_db_uc.find(id).await else {
+        info!("Failed to find Project with Id {}.", &id);
+        return get_404_not_found().await;
+    };
+
+    let project_res = AdminGetProjectTemplate {
+        project: project.to_admin_template(),
+    }
+    .render();
+    match project_res {
+        Ok(res) => {
+            info!("AdminGetProject askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/projects/get_project.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_add_admin_project
+/// Serve GET add project HTML file in a form format.
+#[debug_handler]
+pub async fn get_add_admin_project(State(app_state): State<AppState>) -> Html<String> {
+    let project_db_uc = app_state.project_db_usecase.lock().await.clone().unwrap();
+
+    let Some(id) = project_db_uc.get_new_id().await else {
+        error!("Failed to get new Project ID.");
+        return get_500_internal_server_error();
+    };
+    debug!(
+        "Construct AdminGetAddProjectTemplate for Project Id {}",
+        &id
+    );
+
+    let add_project = AdminGetAddProjectTemplate {
+        project: ProjectForm {
+            id,
+            status: ProjectStatus::default().to_string(),
+            ..Default::default()
+        }
+        .to_admin_template(),
+        statuses: project_statuses(),
+        errors: Vec::new(),
+    }
+    .render();
+    match add_project {
+        Ok(res) => {
+            info!("AdminGetAddProject askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/projects/get_add_project.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_edit_admin_project
+/// Serve GET edit project HTML file to edit an project
+#[debug_handler]
+pub async fn get_edit_admin_project(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+) -> Html<String> {
+    let project_db_uc = app_state.project_db_usecase.lock().await.clone().unwrap();
+
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    let Some(project) = project_db_uc.find(id).await else {
+        info!("Failed to find Project with Id {}.", &id);
+        return get_404_not_found().await;
+    };
+
+    let edit_project = AdminGetEditProjectTemplate {
+        project: project.to_admin_template(),
+        statuses: project_statuses(),
+        errors: Vec::new(),
+    }
+    .render();
+    match edit_project {
+        Ok(res) => {
+            info!("AdminGetEditProject askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/projects/get_edit_project.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_delete_admin_project
+/// Serve GET delete project HTML file to delete an project
+#[debug_handler]
+pub async fn get_delete_admin_project(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+) -> Html<String> {
+    let project_db_uc = app_state.project_db_usecase.lock().await.clone().unwrap();
+
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    if project_db_uc.find(id).await.is_none() {
+        info!("Failed to find Project with Id {}.", &id);
+        return get_404_not_found().await;
+    }
+
+    match (AdminGetDeleteProjectTemplate { id }).render() {
+        Ok(res) => {
+            info!("AdminGetDeleteProject askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/projects/get_delete_project.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
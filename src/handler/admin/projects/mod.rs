@@ -0,0 +1,47 @@
+pub mod displays;
+pub mod operations;
+
+use crate::model::projects::{ProjectForm, ProjectStatus};
+use tracing::{debug, warn};
+use urlencoding::decode;
+
+// Take request body String from PUT and POST operations to create a new ProjectForm
+fn process_project_body(body: String) -> Option<ProjectForm> {
+    let mut form = ProjectForm::default();
+
+    let req_fields: Vec<&str> = body.split("&").collect();
+    for req_field in req_fields {
+        let (key, value) = req_field.split_once("=").unwrap();
+        let value_decoded = decode(value).unwrap().into_owned();
+        debug!("Request field key/value {:?}/{:?}", key, value_decoded);
+        match key {
+            "project_id" => match value_decoded.parse::<i64>() {
+                Ok(val) => form.id = val,
+                Err(err) => {
+                    warn!("Failed to parse project_id with error, {err}");
+                    return None;
+                }
+            },
+            "project_name" => form.name = value_decoded,
+            "project_summary" => form.summary = value_decoded,
+            "project_body" => form.body = value_decoded,
+            "project_repo_url" => form.repo_url = value_decoded,
+            "project_status" => form.status = value_decoded,
+            "project_tags" => form.tags = value_decoded,
+            "project_position" => form.position = value_decoded,
+            _ => {
+                warn!("Unrecognized key/value: {:?}/{:?}", key, value_decoded);
+            }
+        }
+    }
+
+    Some(form)
+}
+
+// All Project statuses for the admin status select input
+fn project_statuses() -> Vec<String> {
+    ProjectStatus::ALL
+        .iter()
+        .map(|status| status.to_string())
+        .collect()
+}
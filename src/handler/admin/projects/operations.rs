@@ -0,0 +1,255 @@
+use crate::handler::admin::audit::{record_audit_log, AuditContext};
+use crate::handler::admin::projects::displays::{get_admin_project, get_admin_projects_list};
+use crate::handler::admin::projects::{process_project_body, project_statuses};
+use crate::handler::status::get_401_unauthorized;
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::audit_logs::{
+    AUDIT_ACTION_CREATE, AUDIT_ACTION_DELETE, AUDIT_ACTION_UPDATE, AUDIT_ENTITY_PROJECT,
+};
+use crate::model::auth::{AuthUser, Permission};
+use crate::model::axum::AppState;
+use crate::model::projects::{ProjectCommandStatus, ProjectsParams};
+use crate::model::templates_admin::{AdminGetAddProjectTemplate, AdminGetEditProjectTemplate};
+use crate::repo::projects::{ProjectCacheOperationRepo, ProjectDisplayRepo, ProjectOperationRepo};
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::{ConnectInfo, Path, Query, State};
+use axum::http::HeaderMap;
+use axum::response::Html;
+use std::net::SocketAddr;
+use tracing::{debug, error, info, warn};
+
+/// Re-render admin project add/edit form with validation errors
+fn render_admin_project_form(form: impl Template) -> Html<String> {
+    match form.render() {
+        Ok(res) => Html(res),
+        Err(err) => {
+            error!("Failed to render admin project form. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// post_add_admin_project
+/// Serve POST add project endpoint
+#[debug_handler]
+pub async fn post_add_admin_project(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    let mut project_db_uc = app_state.project_db_usecase.lock().await.clone().unwrap();
+
+    let Some(project_form) = process_project_body(body) else {
+        warn!("Failed to process a new Project body.");
+        return get_500_internal_server_error();
+    };
+    let project = match project_form.validate() {
+        Ok(project) => project,
+        Err(errors) => {
+            info!("Invalid new Project with Id {}.", &project_form.id);
+            return render_admin_project_form(AdminGetAddProjectTemplate {
+                project: project_form.to_admin_template(),
+                statuses: project_statuses(),
+                errors,
+            });
+        }
+    };
+
+    match project_db_uc.add(project.clone()).await {
+        Some(ProjectCommandStatus::Stored) => {}
+        Some(_) => {
+            error!("Failed to add Project with Id {}", &project.id);
+            return get_500_internal_server_error();
+        }
+        None => {
+            info!("Failed to add Project with Id {}.", &project.id);
+            return get_404_not_found().await;
+        }
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_CREATE,
+        AUDIT_ENTITY_PROJECT,
+        project.id.to_string(),
+        String::new(),
+        project.audit_summary(),
+    )
+    .await;
+
+    // Insert cache
+    if let Some(mut project_cache_uc) = app_state.project_cache_usecase.lock().await.clone() {
+        debug!("Caching project {}", &project.id);
+        let _ = project_cache_uc.insert(project).await;
+    }
+
+    let params = ProjectsParams {
+        start: None,
+        end: None,
+        status: None,
+        tags: None,
+    };
+    get_admin_projects_list(State(app_state), Query(params)).await
+}
+
+/// put_edit_admin_project
+/// Serve PUT edit project HTML file
+#[debug_handler]
+pub async fn put_edit_admin_project(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    let mut project_db_uc = app_state.project_db_usecase.lock().await.clone().unwrap();
+
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    let Some(old_project) = project_db_uc.find(id).await else {
+        info!("Failed to find Project with Id {}.", &id);
+        return get_404_not_found().await;
+    };
+
+    let Some(project_form) = process_project_body(body) else {
+        warn!("Failed to process Project body with Id {}.", &path);
+        return get_500_internal_server_error();
+    };
+    let mut project = match project_form.validate() {
+        Ok(project) => project,
+        Err(errors) => {
+            info!("Invalid Project edit with Id {}.", &path);
+            return render_admin_project_form(AdminGetEditProjectTemplate {
+                project: project_form.to_admin_template(),
+                statuses: project_statuses(),
+                errors,
+            });
+        }
+    };
+    // Always update the Project from the path
+    project.id = id;
+
+    match project_db_uc.update(project.clone()).await {
+        Some(ProjectCommandStatus::Updated) => {}
+        Some(_) => {
+            error!("Failed to edit Project with Id {}", &path);
+            return get_500_internal_server_error();
+        }
+        None => {
+            info!("Failed to edit Project with Id {}.", &path);
+            return get_404_not_found().await;
+        }
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_UPDATE,
+        AUDIT_ENTITY_PROJECT,
+        id.to_string(),
+        old_project.audit_summary(),
+        project.audit_summary(),
+    )
+    .await;
+
+    // Refresh cache
+    if let Some(mut project_cache_uc) = app_state.project_cache_usecase.lock().await.clone() {
+        debug!("Caching project {}", &id);
+        let _ = project_cache_uc.insert(project).await;
+    }
+
+    get_admin_project(Path(path), State(app_state)).await
+}
+
+/// delete_delete_admin_project
+/// Serve DELETE delete project HTML file
+#[debug_handler]
+pub async fn delete_delete_admin_project(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Delete) {
+        return get_401_unauthorized().await;
+    }
+
+    let mut project_db_uc = app_state.project_db_usecase.lock().await.clone().unwrap();
+
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    let Some(old_project) = project_db_uc.find(id).await else {
+        info!("Failed to find Project with Id {}.", &id);
+        return get_404_not_found().await;
+    };
+
+    match project_db_uc.delete(id).await {
+        Some(ProjectCommandStatus::Deleted) => {}
+        Some(_) | None => {
+            error!("Failed to delete Project with Id {}", &path);
+            return get_500_internal_server_error();
+        }
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_DELETE,
+        AUDIT_ENTITY_PROJECT,
+        id.to_string(),
+        old_project.audit_summary(),
+        String::new(),
+    )
+    .await;
+
+    // Invalidate cache
+    if let Some(mut project_cache_uc) = app_state.project_cache_usecase.lock().await.clone() {
+        debug!("Invalidating project {} cache", &id);
+        let _ = project_cache_uc.invalidate(id).await;
+    }
+
+    let params = ProjectsParams {
+        start: None,
+        end: None,
+        status: None,
+        tags: None,
+    };
+    get_admin_projects_list(State(app_state), Query(params)).await
+}
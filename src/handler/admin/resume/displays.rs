@@ -0,0 +1,45 @@
+use crate::handler::admin::resume::render_admin_resume_form;
+use crate::handler::status::get_500_internal_server_error;
+use crate::model::axum::AppState;
+use crate::model::resume::ResumeForm;
+use crate::model::templates_admin::AdminResumeTemplate;
+use crate::repo::resume::ResumeDisplayRepo;
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::State;
+use axum::response::Html;
+use tracing::{error, info};
+
+/// get_base_admin_resume
+/// Serve GET (base) admin resume HTML file
+/// Under endpoint /admin/resume
+#[debug_handler]
+pub async fn get_base_admin_resume() -> Html<String> {
+    match (AdminResumeTemplate {}).render() {
+        Ok(res) => {
+            info!("AdminResume askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/resume/resume.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_edit_admin_resume
+/// Serve GET resume editor HTML file filled with the current Resume
+/// Under endpoint /admin/resume/edit
+#[debug_handler]
+pub async fn get_edit_admin_resume(State(app_state): State<AppState>) -> Html<String> {
+    let resume_db_uc = app_state.resume_db_usecase.lock().await.clone().unwrap();
+
+    let form = match resume_db_uc.find().await {
+        Some(resume) => resume.to_form(),
+        None => {
+            info!("No Resume yet. Serve an empty resume editor.");
+            ResumeForm::default()
+        }
+    };
+    render_admin_resume_form(&form, String::new(), Vec::new())
+}
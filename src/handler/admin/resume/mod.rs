@@ -0,0 +1,67 @@
+pub mod displays;
+pub mod operations;
+
+use crate::handler::status::get_500_internal_server_error;
+use crate::model::resume::ResumeForm;
+use crate::model::templates_admin::AdminGetEditResumeTemplate;
+use askama::Template;
+use axum::response::Html;
+use tracing::{debug, error, info, warn};
+use urlencoding::decode;
+
+// Take request body String from POST operation to create a new ResumeForm
+fn process_resume_body(body: String) -> ResumeForm {
+    let mut form = ResumeForm::default();
+
+    let req_fields: Vec<&str> = body.split("&").collect();
+    for req_field in req_fields {
+        let Some((key, value)) = req_field.split_once("=") else {
+            continue;
+        };
+        let value_decoded = decode(value).unwrap().into_owned();
+        debug!("Request field key/value {:?}/{:?}", key, value_decoded);
+        match key {
+            "resume_name" => form.name = value_decoded,
+            "resume_label" => form.label = value_decoded,
+            "resume_email" => form.email = value_decoded,
+            "resume_url" => form.url = value_decoded,
+            "resume_summary" => form.summary = value_decoded,
+            "resume_work" => form.work = value_decoded,
+            "resume_education" => form.education = value_decoded,
+            "resume_certificates" => form.certificates = value_decoded,
+            "resume_skills" => form.skills = value_decoded,
+            _ => {
+                warn!("Unrecognized key/value: {:?}/{:?}", key, value_decoded);
+            }
+        }
+    }
+
+    form
+}
+
+// Render the admin resume editor with an optional notice and validation errors
+fn render_admin_resume_form(
+    form: &ResumeForm,
+    notice: String,
+    errors: Vec<String>,
+) -> Html<String> {
+    let edit_resume = AdminGetEditResumeTemplate {
+        resume: form.to_admin_template(),
+        notice,
+        errors,
+    }
+    .render();
+    match edit_resume {
+        Ok(res) => {
+            info!("AdminGetEditResume askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/resume/get_edit_resume.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
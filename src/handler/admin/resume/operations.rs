@@ -0,0 +1,75 @@
+use crate::handler::admin::audit::{record_audit_log, AuditContext};
+use crate::handler::admin::resume::{process_resume_body, render_admin_resume_form};
+use crate::handler::status::{get_401_unauthorized, get_500_internal_server_error};
+use crate::model::audit_logs::{AUDIT_ACTION_CREATE, AUDIT_ACTION_UPDATE, AUDIT_ENTITY_RESUME};
+use crate::model::auth::{AuthUser, Permission};
+use crate::model::axum::AppState;
+use crate::model::resume::ResumeCommandStatus;
+use crate::repo::resume::{ResumeCacheOperationRepo, ResumeDisplayRepo, ResumeOperationRepo};
+use axum::debug_handler;
+use axum::extract::{ConnectInfo, State};
+use axum::http::HeaderMap;
+use axum::response::Html;
+use std::net::SocketAddr;
+use tracing::{debug, error, info};
+
+/// post_edit_admin_resume
+/// Serve POST resume editor endpoint
+/// Replace the current Resume with the submitted one
+#[debug_handler]
+pub async fn post_edit_admin_resume(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    let mut resume_db_uc = app_state.resume_db_usecase.lock().await.clone().unwrap();
+
+    let resume_form = process_resume_body(body);
+    let resume = match resume_form.validate() {
+        Ok(resume) => resume,
+        Err(errors) => {
+            info!("Invalid Resume.");
+            return render_admin_resume_form(&resume_form, String::new(), errors);
+        }
+    };
+
+    let old_resume = resume_db_uc.find().await;
+    match resume_db_uc.save(resume.clone()).await {
+        Some(ResumeCommandStatus::Stored) => {}
+        _ => {
+            error!("Failed to save Resume.");
+            return get_500_internal_server_error();
+        }
+    }
+
+    // There is a single Resume so the entity type also identifies it
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    let (action, before) = match old_resume {
+        Some(old_resume) => (AUDIT_ACTION_UPDATE, old_resume.audit_summary()),
+        None => (AUDIT_ACTION_CREATE, String::new()),
+    };
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        action,
+        AUDIT_ENTITY_RESUME,
+        AUDIT_ENTITY_RESUME.to_string(),
+        before,
+        resume.audit_summary(),
+    )
+    .await;
+
+    // Insert cache
+    if let Some(mut resume_cache_uc) = app_state.resume_cache_usecase.lock().await.clone() {
+        debug!("Caching resume");
+        let _ = resume_cache_uc.insert(resume.clone()).await;
+    }
+
+    render_admin_resume_form(&resume.to_form(), "Saved resume.".to_string(), Vec::new())
+}
@@ -1,8 +1,7 @@
-use crate::handler::auth::is_auth_verified;
-use crate::handler::status::{
-    get_401_unauthorized, get_404_not_found, get_500_internal_server_error,
-};
-use crate::model::talks::TalksParams;
+use crate::handler::admin::find_tag_names;
+use crate::handler::admin::talks::find_organization_templates;
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::talks::{TalkForm, TalksParams, TALK_DATE_FORMAT};
 use crate::model::{
     axum::AppState,
     templates_admin::{
@@ -14,7 +13,6 @@ use crate::repo::talks::TalkDisplayRepo;
 use askama::Template;
 use axum::debug_handler;
 use axum::extract::{Path, Query, State};
-use axum::http::HeaderMap;
 use axum::response::Html;
 use tracing::{debug, error, info, warn};
 
@@ -23,14 +21,7 @@ use tracing::{debug, error, info, warn};
 /// Under endpoint /admin/talks
 /// It's the base of Admin Talks feature
 #[debug_handler]
-pub async fn get_base_admin_talks(
-    State(app_state): State<AppState>,
-    headers: HeaderMap,
-) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
+pub async fn get_base_admin_talks() -> Html<String> {
     let talks_res = AdminTalksTemplate {}.render();
     match talks_res {
         Ok(res) => {
@@ -49,13 +40,8 @@ pub async fn get_base_admin_talks(
 #[debug_handler]
 pub async fn get_admin_talks_list(
     State(app_state): State<AppState>,
-    headers: HeaderMap,
     params: Query<TalksParams>,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     let talks_db_uc = app_state.talk_db_usecase.lock().await.clone().unwrap();
     let talks_cache_uc_opt = app_state.talk_cache_usecase.lock().await.clone();
     let is_cache_enabled = talks_cache_uc_opt.is_some();
@@ -140,12 +126,7 @@ pub async fn get_admin_talks_list(
 pub async fn get_admin_talk(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
-    headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     let talks_db_uc = app_state.talk_db_usecase.lock().await.clone().unwrap();
     let talks_cache_uc_opt = app_state.talk_cache_usecase.lock().await;
     let is_cache_enabled = talks_cache_uc_opt.is_some();
@@ -238,14 +219,7 @@ pub async fn get_admin_talk(
 /// get_add_admin_talk
 /// Serve GET add talk HTML file in a form format.
 #[debug_handler]
-pub async fn get_add_admin_talk(
-    State(app_state): State<AppState>,
-    headers: HeaderMap,
-) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
+pub async fn get_add_admin_talk(State(app_state): State<AppState>) -> Html<String> {
     let talks_db_uc = app_state.talk_db_usecase.lock().await.clone().unwrap();
     // Calculate new Talk Id
     let result = talks_db_uc.talk_operation_repo.get_new_id().await;
@@ -258,8 +232,15 @@ pub async fn get_add_admin_talk(
     let talk_id = result.unwrap();
     debug!("Construct AdminGetAddTalkTemplate for Talk Id {}", &talk_id);
     let add_talk = AdminGetAddTalkTemplate {
-        id: talk_id,
-        date: chrono::Local::now().format("%Y-%m-%d").to_string(),
+        talk: TalkForm {
+            id: talk_id,
+            date: chrono::Local::now().format(TALK_DATE_FORMAT).to_string(),
+            ..Default::default()
+        }
+        .to_admin_template(),
+        organizations: find_organization_templates(&app_state).await,
+        avail_tags: find_tag_names(&app_state).await,
+        errors: Vec::new(),
     }
     .render();
 
@@ -281,12 +262,7 @@ pub async fn get_add_admin_talk(
 pub async fn get_edit_admin_talk(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
-    headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     let talks_db_uc = app_state.talk_db_usecase.lock().await.clone().unwrap();
     let talks_cache_uc_opt = app_state.talk_cache_usecase.lock().await.clone();
     let is_cache_enabled = talks_cache_uc_opt.is_some();
@@ -318,6 +294,9 @@ pub async fn get_edit_admin_talk(
     if let Some(res) = cache_result {
         let edit_talk = AdminGetEditTalkTemplate {
             talk: res.sanitize_talk_media_org().to_admin_template(),
+            organizations: find_organization_templates(&app_state).await,
+            avail_tags: find_tag_names(&app_state).await,
+            errors: Vec::new(),
         }
         .render();
         if edit_talk.is_err() {
@@ -362,6 +341,9 @@ pub async fn get_edit_admin_talk(
             .unwrap()
             .sanitize_talk_media_org()
             .to_admin_template(),
+        organizations: find_organization_templates(&app_state).await,
+        avail_tags: find_tag_names(&app_state).await,
+        errors: Vec::new(),
     }
     .render();
     if edit_talk.is_err() {
@@ -381,12 +363,7 @@ pub async fn get_edit_admin_talk(
 pub async fn get_delete_admin_talk(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
-    headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
-        return get_401_unauthorized().await;
-    }
-
     let talks_db_uc = app_state.talk_db_usecase.lock().await.clone().unwrap();
     let talks_cache_uc_opt = app_state.talk_cache_usecase.lock().await;
     let is_cache_enabled = talks_cache_uc_opt.is_some();
@@ -1,19 +1,27 @@
 pub mod displays;
 pub mod operations;
+pub mod organizations;
 
-use crate::model::talks::Talk;
-use tracing::{debug, warn};
+use crate::handler::admin::find_all_tags;
+use crate::model::axum::AppState;
+use crate::model::organizations::OrganizationsParams;
+use crate::model::talk_tag_mappings::TalkTagMappingCommandStatus;
+use crate::model::talks::TalkForm;
+use crate::model::templates_admin::AdminOrganizationTemplate;
+use crate::repo::organizations::OrganizationDisplayRepo;
+use crate::utils::remove_whitespace;
+use tracing::{debug, error, info, warn};
 use urlencoding::decode;
 
-// Take request body String from PUT and POST operations to create a new Talk
-fn process_talk_body(body: String) -> Option<Talk> {
+// Take request body String from PUT and POST operations to create a new TalkForm
+fn process_talk_body(body: String) -> Option<TalkForm> {
     // Initialize fields
     let mut talk_id = 0_i64;
     let mut talk_name = String::new();
     let mut talk_media_link = String::new();
     let mut talk_date = String::new();
-    let mut talk_org_name = String::new();
-    let mut talk_org_link = String::new();
+    let mut talk_org_id = String::new();
+    let mut talk_tags: Vec<String> = Vec::new();
 
     let req_fields: Vec<&str> = body.split("&").collect();
     for req_field in req_fields {
@@ -34,20 +42,164 @@ fn process_talk_body(body: String) -> Option<Talk> {
             "talk_name" => talk_name = value_decoded.to_string(),
             "talk_media_link" => talk_media_link = value_decoded.to_string(),
             "talk_date" => talk_date = value_decoded.to_string(),
-            "talk_org_name" => talk_org_name = value_decoded.to_string(),
-            "talk_org_link" => talk_org_link = value_decoded.to_string(),
+            "talk_org_id" => talk_org_id = value_decoded.to_string(),
+            "talk_tag" => {
+                let clean_tag = remove_whitespace(&value_decoded);
+                if !clean_tag.is_empty() {
+                    talk_tags.push(clean_tag);
+                }
+            }
             _ => {
                 warn!("Unrecognized key/value: {:?}/{:?}", key, value_decoded);
             }
         }
     }
 
-    Some(Talk {
+    Some(TalkForm {
         id: talk_id,
         name: talk_name,
         date: talk_date,
-        media_link: Some(talk_media_link),
-        org_name: Some(talk_org_name),
-        org_link: Some(talk_org_link),
+        media_link: talk_media_link,
+        org_id: talk_org_id,
+        tags: talk_tags,
     })
 }
+
+// Find Organizations to be picked in the add/edit talk forms
+async fn find_organization_templates(app_state: &AppState) -> Vec<AdminOrganizationTemplate> {
+    let organization_db_uc_opt = app_state.organization_db_usecase.lock().await.clone();
+    let Some(organization_db_uc) = organization_db_uc_opt else {
+        warn!("Organization DB Usecase is not available.");
+        return Vec::new();
+    };
+
+    let params = OrganizationsParams {
+        start: None,
+        end: None,
+    };
+    match organization_db_uc.find_organizations(params).await {
+        Some(organizations) => organizations.to_admin_templates(),
+        None => {
+            warn!("Failed to find Organizations.");
+            Vec::new()
+        }
+    }
+}
+
+// Sync Talk Tag Mappings of a Talk with the selected tag names.
+// Unknown tag names are ignored. Return None if any mapping operation failed
+async fn sync_talk_tag_mappings(
+    app_state: &AppState,
+    talk_id: i64,
+    tag_names: &[String],
+) -> Option<()> {
+    let Some(tags) = find_all_tags(app_state).await else {
+        error!("Failed to get all tags");
+        return None;
+    };
+    let selected_tag_ids: Vec<i64> = tags
+        .tags
+        .iter()
+        .filter(|tag| tag_names.contains(&tag.name))
+        .map(|tag| tag.id)
+        .collect();
+    debug!("Selected Tag IDs {:?}", &selected_tag_ids);
+
+    let Some(mut ttms_db_uc) = app_state.talk_tag_mapping_db_usecase.lock().await.clone() else {
+        error!("Failed to lock talk tag mapping usecase mutex");
+        return None;
+    };
+    let mut ttms_cache_uc_opt = app_state
+        .talk_tag_mapping_cache_usecase
+        .lock()
+        .await
+        .clone();
+
+    let Some(ttms) = ttms_db_uc.display.find_by_talk_id(talk_id).await else {
+        error!("Failed to get Talk Tag Mapping for Talk ID {}", &talk_id);
+        return None;
+    };
+
+    // Delete mappings not present in the selected tags
+    for ttm in ttms
+        .maps
+        .iter()
+        .filter(|map| !selected_tag_ids.contains(&map.tag_id))
+    {
+        info!(
+            "Deleting Talk Tag Mapping for Talk ID {} and Tag ID {}",
+            &talk_id, &ttm.tag_id
+        );
+        let delete_result = ttms_db_uc
+            .operation
+            .delete_by_talk_id_and_tag_id(talk_id, ttm.tag_id)
+            .await;
+        if delete_result != Some(TalkTagMappingCommandStatus::Deleted) {
+            error!(
+                "Failed to delete Talk Tag Mapping with Talk Id {} and Tag Id {}.",
+                &talk_id, &ttm.tag_id
+            );
+            return None;
+        }
+        if let Some(ttms_cache_uc) = ttms_cache_uc_opt.as_mut() {
+            let _ = ttms_cache_uc
+                .operation
+                .invalidate(talk_id, ttm.tag_id)
+                .await;
+        }
+    }
+
+    // Add selected tags not present in the mappings
+    for tag_id in selected_tag_ids
+        .iter()
+        .filter(|tag_id| !ttms.maps.iter().any(|map| map.tag_id == **tag_id))
+    {
+        info!(
+            "Adding Talk Tag Mapping for Talk ID {} and Tag ID {}",
+            &talk_id, &tag_id
+        );
+        let add_result = ttms_db_uc.operation.add(talk_id, *tag_id).await;
+        if add_result != Some(TalkTagMappingCommandStatus::Stored) {
+            error!(
+                "Failed to add Talk Tag Mapping for Talk ID {} and Tag ID {}",
+                &talk_id, &tag_id
+            );
+            return None;
+        }
+        if let Some(ttms_cache_uc) = ttms_cache_uc_opt.as_mut() {
+            let _ = ttms_cache_uc.operation.insert(talk_id, *tag_id).await;
+        }
+    }
+
+    Some(())
+}
+
+// Delete all Talk Tag Mappings of a Talk.
+// Return None if the mapping deletion failed
+async fn delete_talk_tag_mappings(app_state: &AppState, talk_id: i64) -> Option<()> {
+    let Some(mut ttms_db_uc) = app_state.talk_tag_mapping_db_usecase.lock().await.clone() else {
+        error!("Failed to lock talk tag mapping usecase mutex");
+        return None;
+    };
+
+    let delete_result = ttms_db_uc.operation.delete_by_talk_id(talk_id).await;
+    if delete_result != Some(TalkTagMappingCommandStatus::Deleted) {
+        error!(
+            "Failed to delete talk tag mappings for talk id {}",
+            &talk_id
+        );
+        return None;
+    }
+
+    let ttms_cache_uc_opt = app_state
+        .talk_tag_mapping_cache_usecase
+        .lock()
+        .await
+        .clone();
+    if let Some(mut ttms_cache_uc) = ttms_cache_uc_opt {
+        debug!("Invalidating Talk Tag Mapping cache talk_id {}", &talk_id);
+        let _ = ttms_cache_uc.operation.invalidate_by_talk_id(talk_id).await;
+    }
+
+    Some(())
+}
@@ -1,28 +1,50 @@
+use crate::handler::admin::audit::{record_audit_log, record_tag_mapping_audit_log, AuditContext};
+use crate::handler::admin::find_tag_names;
 use crate::handler::admin::talks::displays::{get_admin_talk, get_admin_talks_list};
-use crate::handler::admin::talks::process_talk_body;
-use crate::handler::auth::csrf::verify_csrf_token;
-use crate::handler::auth::is_auth_verified;
+use crate::handler::admin::talks::{
+    delete_talk_tag_mappings, find_organization_templates, process_talk_body,
+    sync_talk_tag_mappings,
+};
 use crate::handler::status::get_401_unauthorized;
 use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::audit_logs::{
+    AUDIT_ACTION_CREATE, AUDIT_ACTION_DELETE, AUDIT_ACTION_UPDATE, AUDIT_ENTITY_TALK,
+    AUDIT_ENTITY_TALK_TAG_MAPPING,
+};
+use crate::model::auth::{AuthUser, Permission};
 use crate::model::axum::AppState;
 use crate::model::talks::{TalkCommandStatus, TalksParams};
+use crate::model::templates_admin::{AdminGetAddTalkTemplate, AdminGetEditTalkTemplate};
+use askama::Template;
 use axum::debug_handler;
-use axum::extract::{Path, Query, State};
+use axum::extract::{ConnectInfo, Path, Query, State};
 use axum::http::HeaderMap;
 use axum::response::Html;
+use std::net::SocketAddr;
 use tracing::{debug, error, info, warn};
 
+/// Re-render admin talk add/edit form with validation errors
+fn render_admin_talk_form(form: impl Template) -> Html<String> {
+    match form.render() {
+        Ok(res) => Html(res),
+        Err(err) => {
+            error!("Failed to render admin talk form. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
 /// post_add_admin_talk
 /// Serve POST add talk endpoint
 #[debug_handler]
 pub async fn post_add_admin_talk(
     State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     body: String,
 ) -> Html<String> {
-    if !is_auth_verified(headers.clone(), &app_state.config.secrets.jwt_secret)
-        || !verify_csrf_token(&headers)
-    {
+    if !auth_user.is_permitted(Permission::Edit) {
         return get_401_unauthorized().await;
     }
 
@@ -30,22 +52,31 @@ pub async fn post_add_admin_talk(
     let talks_cache_uc_opt = app_state.talk_cache_usecase.lock().await.clone();
     let is_cache_enabled = talks_cache_uc_opt.is_some();
 
-    let talk_res = process_talk_body(body);
-    if talk_res.is_none() {
+    let Some(talk_form) = process_talk_body(body) else {
         warn!("Failed to process a new Talk body.");
         return get_500_internal_server_error();
-    }
-    let talk = talk_res.unwrap().sanitize_talk_media_org();
+    };
+    let talk = match talk_form.validate() {
+        Ok(talk) => talk,
+        Err(errors) => {
+            info!("Invalid new Talk with Id {}.", &talk_form.id);
+            return render_admin_talk_form(AdminGetAddTalkTemplate {
+                talk: talk_form.to_admin_template(),
+                organizations: find_organization_templates(&app_state).await,
+                avail_tags: find_tag_names(&app_state).await,
+                errors,
+            });
+        }
+    };
 
     let add_result = talks_db_uc
         .talk_operation_repo
         .add(
             talk.id,
             talk.name.clone(),
-            talk.date.clone(),
+            talk.date,
             talk.media_link.clone(),
-            talk.org_name.clone(),
-            talk.org_link.clone(),
+            talk.org_id,
         )
         .await;
 
@@ -59,21 +90,56 @@ pub async fn post_add_admin_talk(
         return get_500_internal_server_error();
     }
 
+    if sync_talk_tag_mappings(&app_state, talk.id, &talk.tags)
+        .await
+        .is_none()
+    {
+        error!("Failed to add Talk Tag Mappings for Talk Id {}", &talk.id);
+        return get_500_internal_server_error();
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_CREATE,
+        AUDIT_ENTITY_TALK,
+        talk.id.to_string(),
+        String::new(),
+        talk.audit_summary(),
+    )
+    .await;
+    record_tag_mapping_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ENTITY_TALK_TAG_MAPPING,
+        talk.id,
+        &[],
+        &talk.tags,
+    )
+    .await;
+
     if is_cache_enabled {
-        debug!("Caching talk {}", talk.id);
-        let _ = talks_cache_uc_opt
-            .clone()
-            .unwrap()
-            .talk_operation_repo
-            .insert(talk.clone())
-            .await;
+        // Re-read the stored Talk to resolve its organisation before caching
+        if let Some(stored_talk) = talks_db_uc.talk_display_repo.find(talk.id).await {
+            debug!("Caching talk {}", talk.id);
+            let _ = talks_cache_uc_opt
+                .clone()
+                .unwrap()
+                .talk_operation_repo
+                .insert(stored_talk.sanitize_talk_media_org())
+                .await;
+        }
     }
 
     let params = TalksParams {
         start: None,
         end: None,
+        year: None,
+        org: None,
+        tags: None,
     };
-    get_admin_talks_list(State(app_state), headers, Query(params)).await
+    get_admin_talks_list(State(app_state), Query(params)).await
 }
 
 /// put_edit_admin_talk
@@ -82,12 +148,12 @@ pub async fn post_add_admin_talk(
 pub async fn put_edit_admin_talk(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     body: String,
 ) -> Html<String> {
-    if !is_auth_verified(headers.clone(), &app_state.config.secrets.jwt_secret)
-        || !verify_csrf_token(&headers)
-    {
+    if !auth_user.is_permitted(Permission::Edit) {
         return get_401_unauthorized().await;
     }
 
@@ -107,22 +173,32 @@ pub async fn put_edit_admin_talk(
         }
     };
 
-    let talk_res = process_talk_body(body);
-    if talk_res.is_none() {
+    let Some(talk_form) = process_talk_body(body) else {
         warn!("Failed to process Talk body with Id {}.", &path);
         return get_500_internal_server_error();
-    }
-    let talk = talk_res.unwrap().sanitize_talk_media_org();
+    };
+    let talk = match talk_form.validate() {
+        Ok(talk) => talk,
+        Err(errors) => {
+            info!("Invalid Talk edit with Id {}.", &path);
+            return render_admin_talk_form(AdminGetEditTalkTemplate {
+                talk: talk_form.to_admin_template(),
+                organizations: find_organization_templates(&app_state).await,
+                avail_tags: find_tag_names(&app_state).await,
+                errors,
+            });
+        }
+    };
 
+    let saved_talk = talks_db_uc.talk_display_repo.find(talk.id).await;
     let edit_result = talks_db_uc
         .talk_operation_repo
         .update(
             talk.id,
             Some(talk.name.clone()),
-            Some(talk.date.clone()),
+            Some(talk.date),
             talk.media_link.clone(),
-            talk.org_name.clone(),
-            talk.org_link.clone(),
+            talk.org_id,
         )
         .await;
 
@@ -136,6 +212,38 @@ pub async fn put_edit_admin_talk(
         return get_500_internal_server_error();
     }
 
+    if sync_talk_tag_mappings(&app_state, talk.id, &talk.tags)
+        .await
+        .is_none()
+    {
+        error!("Failed to edit Talk Tag Mappings for Talk Id {}", &path);
+        return get_500_internal_server_error();
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_UPDATE,
+        AUDIT_ENTITY_TALK,
+        talk.id.to_string(),
+        saved_talk
+            .as_ref()
+            .map(|val| val.audit_summary())
+            .unwrap_or_default(),
+        talk.audit_summary(),
+    )
+    .await;
+    record_tag_mapping_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ENTITY_TALK_TAG_MAPPING,
+        talk.id,
+        &saved_talk.map(|val| val.tags).unwrap_or_default(),
+        &talk.tags,
+    )
+    .await;
+
     if is_cache_enabled {
         debug!("Invalidating talk {} cache", talk.id);
         let _ = talks_cache_uc_opt
@@ -144,16 +252,10 @@ pub async fn put_edit_admin_talk(
             .talk_operation_repo
             .invalidate(talk.id)
             .await;
-        debug!("Re-caching talk {}", talk.id);
-        let _ = talks_cache_uc_opt
-            .clone()
-            .unwrap()
-            .talk_operation_repo
-            .insert(talk.clone())
-            .await;
+        // get_admin_talk re-cache the stored Talk with its resolved organisation
     }
 
-    get_admin_talk(Path(path), State(app_state), headers).await
+    get_admin_talk(Path(path), State(app_state)).await
 }
 
 /// delete_delete_admin_talk
@@ -162,11 +264,11 @@ pub async fn put_edit_admin_talk(
 pub async fn delete_delete_admin_talk(
     Path(path): Path<String>,
     State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> Html<String> {
-    if !is_auth_verified(headers.clone(), &app_state.config.secrets.jwt_secret)
-        || !verify_csrf_token(&headers)
-    {
+    if !auth_user.is_permitted(Permission::Delete) {
         return get_401_unauthorized().await;
     }
 
@@ -186,6 +288,10 @@ pub async fn delete_delete_admin_talk(
         }
     };
 
+    let saved_talk = talks_db_uc
+        .talk_display_repo
+        .find(id.clone().unwrap())
+        .await;
     let delete_result = talks_db_uc
         .talk_operation_repo
         .delete(id.clone().unwrap())
@@ -201,6 +307,38 @@ pub async fn delete_delete_admin_talk(
         return get_500_internal_server_error();
     }
 
+    if delete_talk_tag_mappings(&app_state, id.clone().unwrap())
+        .await
+        .is_none()
+    {
+        error!("Failed to delete Talk Tag Mappings for Talk Id {}", &path);
+        return get_500_internal_server_error();
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_DELETE,
+        AUDIT_ENTITY_TALK,
+        path.clone(),
+        saved_talk
+            .as_ref()
+            .map(|val| val.audit_summary())
+            .unwrap_or_default(),
+        String::new(),
+    )
+    .await;
+    record_tag_mapping_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ENTITY_TALK_TAG_MAPPING,
+        id.clone().unwrap(),
+        &saved_talk.map(|val| val.tags).unwrap_or_default(),
+        &[],
+    )
+    .await;
+
     if is_cache_enabled {
         debug!("Invalidating talk {} cache", id.clone().unwrap());
         let _ = talks_cache_uc_opt
@@ -214,6 +352,9 @@ pub async fn delete_delete_admin_talk(
     let params = TalksParams {
         start: None,
         end: None,
+        year: None,
+        org: None,
+        tags: None,
     };
-    get_admin_talks_list(State(app_state), headers, Query(params)).await
+    get_admin_talks_list(State(app_state), Query(params)).await
 }
@@ -0,0 +1,271 @@
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::axum::AppState;
+use crate::model::organizations::{OrganizationForm, OrganizationsParams};
+use crate::model::templates_admin::{
+    AdminGetAddOrganizationTemplate, AdminGetDeleteOrganizationTemplate,
+    AdminGetEditOrganizationTemplate, AdminGetOrganizationTemplate, AdminOrganizationsTemplate,
+};
+use crate::repo::organizations::{OrganizationDisplayRepo, OrganizationOperationRepo};
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::{Path, Query, State};
+use axum::response::Html;
+use tracing::{debug, error, info, warn};
+
+/// get_base_admin_organizations
+/// Serve GET (base) admin organizations HTML file
+/// Under endpoint /admin/talks/organizations
+#[debug_handler]
+pub async fn get_base_admin_organizations() -> Html<String> {
+    match (AdminOrganizationsTemplate {}).render() {
+        Ok(res) => {
+            info!("AdminOrganizations askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/talks/organizations/organizations.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_organizations_list
+/// Serve to list organizations for Admin Talks
+/// Under endpoint /admin/talks/organizations/list
+/// Accepted parameters:
+/// - start: initial organizations pagination
+/// - end: end of organizations pagination
+#[debug_handler]
+pub async fn get_admin_organizations_list(
+    State(app_state): State<AppState>,
+    params: Query<OrganizationsParams>,
+) -> Html<String> {
+    let organization_db_uc = app_state
+        .organization_db_usecase
+        .lock()
+        .await
+        .clone()
+        .unwrap();
+    let sanitized_params = params.sanitize();
+
+    let Some(organizations) = organization_db_uc
+        .find_organizations(sanitized_params.clone())
+        .await
+    else {
+        error!(
+            "Failed to find organizations started at {} and ended at {}.",
+            sanitized_params.start.unwrap(),
+            sanitized_params.end.unwrap()
+        );
+        return get_500_internal_server_error();
+    };
+
+    match organizations.to_admin_list_template().render() {
+        Ok(res) => {
+            info!("AdminListOrganizations askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/talks/organizations/list_organizations.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_organization
+/// Serve GET organization HTML file and return point for several cancelation endpoints
+/// Returned single organization
+#[debug_handler]
+pub async fn get_admin_organization(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+) -> Html<String> {
+    let organization_db_uc = app_state
+        .organization_db_usecase
+        .lock()
+        .await
+        .clone()
+        .unwrap();
+
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    let Some(organization) = organization_db_uc.find(id).await else {
+        info!("Failed to find Organization with Id {}.", &id);
+        return get_404_not_found().await;
+    };
+
+    let organization_res = AdminGetOrganizationTemplate {
+        organization: organization.to_admin_template(),
+    }
+    .render();
+    match organization_res {
+        Ok(res) => {
+            info!("AdminGetOrganization askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/talks/organizations/get_organization.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_add_admin_organization
+/// Serve GET add organization HTML file in a form format.
+#[debug_handler]
+pub async fn get_add_admin_organization(State(app_state): State<AppState>) -> Html<String> {
+    let organization_db_uc = app_state
+        .organization_db_usecase
+        .lock()
+        .await
+        .clone()
+        .unwrap();
+
+    let Some(id) = organization_db_uc.get_new_id().await else {
+        error!("Failed to get new Organization ID.");
+        return get_500_internal_server_error();
+    };
+    debug!(
+        "Construct AdminGetAddOrganizationTemplate for Organization Id {}",
+        &id
+    );
+
+    let add_organization = AdminGetAddOrganizationTemplate {
+        organization: OrganizationForm {
+            id,
+            ..Default::default()
+        }
+        .to_admin_template(),
+        errors: Vec::new(),
+    }
+    .render();
+    match add_organization {
+        Ok(res) => {
+            info!("AdminGetAddOrganization askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/talks/organizations/get_add_organization.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_edit_admin_organization
+/// Serve GET edit organization HTML file to edit an organization
+#[debug_handler]
+pub async fn get_edit_admin_organization(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+) -> Html<String> {
+    let organization_db_uc = app_state
+        .organization_db_usecase
+        .lock()
+        .await
+        .clone()
+        .unwrap();
+
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    let Some(organization) = organization_db_uc.find(id).await else {
+        info!("Failed to find Organization with Id {}.", &id);
+        return get_404_not_found().await;
+    };
+
+    let edit_organization = AdminGetEditOrganizationTemplate {
+        organization: organization.to_admin_template(),
+        errors: Vec::new(),
+    }
+    .render();
+    match edit_organization {
+        Ok(res) => {
+            info!("AdminGetEditOrganization askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/talks/organizations/get_edit_organization.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_delete_admin_organization
+/// Serve GET delete organization HTML file to delete an organization
+#[debug_handler]
+pub async fn get_delete_admin_organization(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+) -> Html<String> {
+    let organization_db_uc = app_state
+        .organization_db_usecase
+        .lock()
+        .await
+        .clone()
+        .unwrap();
+
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    if organization_db_uc.find(id).await.is_none() {
+        info!("Failed to find Organization with Id {}.", &id);
+        return get_404_not_found().await;
+    }
+
+    match (AdminGetDeleteOrganizationTemplate { id }).render() {
+        Ok(res) => {
+            info!("AdminGetDeleteOrganization askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/talks/organizations/get_delete_organization.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
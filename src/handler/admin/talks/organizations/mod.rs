@@ -0,0 +1,119 @@
+pub mod displays;
+pub mod operations;
+
+use crate::handler::admin::audit::{record_audit_log, AuditContext};
+use crate::model::audit_logs::{AUDIT_ACTION_UPDATE, AUDIT_ENTITY_TALK};
+use crate::model::axum::AppState;
+use crate::model::organizations::{Organization, OrganizationForm};
+use crate::model::talks::{Talk, TalksParams};
+use crate::repo::talks::TalkDisplayRepo;
+use tracing::{debug, warn};
+use urlencoding::decode;
+
+// Take request body String from PUT and POST operations to create a new OrganizationForm
+fn process_organization_body(body: String) -> Option<OrganizationForm> {
+    let mut form = OrganizationForm::default();
+
+    let req_fields: Vec<&str> = body.split("&").collect();
+    for req_field in req_fields {
+        let (key, value) = req_field.split_once("=").unwrap();
+        let value_decoded = decode(value).unwrap();
+        debug!("Request field key/value {:?}/{:?}", key, value_decoded);
+        match key {
+            "organization_id" => match value_decoded.parse::<i64>() {
+                Ok(val) => form.id = val,
+                Err(err) => {
+                    warn!("Failed to parse organization_id with error, {err}");
+                    return None;
+                }
+            },
+            "organization_name" => form.name = value_decoded.to_string(),
+            "organization_url" => form.url = value_decoded.to_string(),
+            "organization_logo" => form.logo = value_decoded.to_string(),
+            "organization_description" => form.description = value_decoded.to_string(),
+            _ => {
+                warn!("Unrecognized key/value: {:?}/{:?}", key, value_decoded);
+            }
+        }
+    }
+
+    Some(form)
+}
+
+// Find Talks hosted by an Organization
+async fn find_organization_talks(app_state: &AppState, org_name: &str) -> Vec<Talk> {
+    let talks_db_uc = app_state.talk_db_usecase.lock().await.clone().unwrap();
+
+    let params = TalksParams {
+        org: Some(org_name.to_string()),
+        ..TalksParams::all()
+    };
+    match talks_db_uc.find_talks(params).await {
+        Some(talks) => talks.talks,
+        None => {
+            warn!("Failed to find Talks for Organization {}.", org_name);
+            Vec::new()
+        }
+    }
+}
+
+// Invalidate cached Talks hosted by an Organization so their resolved
+// organization name and link are re-read from the database
+async fn invalidate_organization_talks(app_state: &AppState, talks: &[Talk]) {
+    let Some(mut talks_cache_uc) = app_state.talk_cache_usecase.lock().await.clone() else {
+        return;
+    };
+    for talk in talks {
+        debug!("Invalidating talk {} cache", talk.id);
+        let _ = talks_cache_uc.talk_operation_repo.invalidate(talk.id).await;
+    }
+}
+
+// Summarize the Organization of a Talk for the audit log
+fn talk_organization_summary(id: Option<i64>, name: Option<&str>, link: Option<&str>) -> String {
+    format!(
+        "org_id={}; org_name={}; org_link={}",
+        id.map(|val| val.to_string()).unwrap_or_default(),
+        name.unwrap_or_default(),
+        link.unwrap_or_default()
+    )
+}
+
+// Append an AuditLog for each Talk whose Organization is changed by an
+// Organization edit. `organization` is None when the Organization is deleted.
+async fn record_organization_talks_audit_log(
+    app_state: &AppState,
+    context: &AuditContext,
+    talks: &[Talk],
+    organization: Option<&Organization>,
+) {
+    let after = match organization {
+        Some(organization) => talk_organization_summary(
+            Some(organization.id),
+            Some(&organization.name),
+            organization.url.as_deref(),
+        ),
+        None => talk_organization_summary(None, None, None),
+    };
+    for talk in talks {
+        let before = talk_organization_summary(
+            talk.org_id,
+            talk.org_name.as_deref(),
+            talk.org_link.as_deref(),
+        );
+        if before == after {
+            debug!("Organization of talk {} is unchanged.", talk.id);
+            continue;
+        }
+        record_audit_log(
+            app_state,
+            context,
+            AUDIT_ACTION_UPDATE,
+            AUDIT_ENTITY_TALK,
+            talk.id.to_string(),
+            before,
+            after.clone(),
+        )
+        .await;
+    }
+}
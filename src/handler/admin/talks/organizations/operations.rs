@@ -0,0 +1,289 @@
+use crate::handler::admin::audit::{record_audit_log, AuditContext};
+use crate::handler::admin::talks::organizations::displays::{
+    get_admin_organization, get_admin_organizations_list,
+};
+use crate::handler::admin::talks::organizations::{
+    find_organization_talks, invalidate_organization_talks, process_organization_body,
+    record_organization_talks_audit_log,
+};
+use crate::handler::status::get_401_unauthorized;
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::audit_logs::{
+    AUDIT_ACTION_CREATE, AUDIT_ACTION_DELETE, AUDIT_ACTION_UPDATE, AUDIT_ENTITY_ORGANIZATION,
+};
+use crate::model::auth::{AuthUser, Permission};
+use crate::model::axum::AppState;
+use crate::model::organizations::{OrganizationCommandStatus, OrganizationsParams};
+use crate::model::templates_admin::{
+    AdminGetAddOrganizationTemplate, AdminGetEditOrganizationTemplate,
+};
+use crate::repo::organizations::{OrganizationDisplayRepo, OrganizationOperationRepo};
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::{ConnectInfo, Path, Query, State};
+use axum::http::HeaderMap;
+use axum::response::Html;
+use std::net::SocketAddr;
+use tracing::{debug, error, info, warn};
+
+/// Re-render admin organization add/edit form with validation errors
+fn render_admin_organization_form(form: impl Template) -> Html<String> {
+    match form.render() {
+        Ok(res) => Html(res),
+        Err(err) => {
+            error!("Failed to render admin organization form. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// post_add_admin_organization
+/// Serve POST add organization endpoint
+#[debug_handler]
+pub async fn post_add_admin_organization(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    let mut organization_db_uc = app_state
+        .organization_db_usecase
+        .lock()
+        .await
+        .clone()
+        .unwrap();
+
+    let Some(organization_form) = process_organization_body(body) else {
+        warn!("Failed to process a new Organization body.");
+        return get_500_internal_server_error();
+    };
+    let organization = match organization_form.validate() {
+        Ok(organization) => organization,
+        Err(errors) => {
+            info!(
+                "Invalid new Organization with Id {}.",
+                &organization_form.id
+            );
+            return render_admin_organization_form(AdminGetAddOrganizationTemplate {
+                organization: organization_form.to_admin_template(),
+                errors,
+            });
+        }
+    };
+
+    let add_result = organization_db_uc
+        .add(
+            organization.id,
+            organization.name.clone(),
+            organization.url.clone(),
+            organization.logo.clone(),
+            organization.description.clone(),
+        )
+        .await;
+
+    match add_result {
+        Some(OrganizationCommandStatus::Stored) => {}
+        Some(_) => {
+            error!("Failed to add Organization with Id {}", &organization.id);
+            return get_500_internal_server_error();
+        }
+        None => {
+            info!("Failed to add Organization with Id {}.", &organization.id);
+            return get_404_not_found().await;
+        }
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_CREATE,
+        AUDIT_ENTITY_ORGANIZATION,
+        organization.id.to_string(),
+        String::new(),
+        organization.audit_summary(),
+    )
+    .await;
+
+    let params = OrganizationsParams {
+        start: None,
+        end: None,
+    };
+    get_admin_organizations_list(State(app_state), Query(params)).await
+}
+
+/// put_edit_admin_organization
+/// Serve PUT edit organization HTML file
+#[debug_handler]
+pub async fn put_edit_admin_organization(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Edit) {
+        return get_401_unauthorized().await;
+    }
+
+    let mut organization_db_uc = app_state
+        .organization_db_usecase
+        .lock()
+        .await
+        .clone()
+        .unwrap();
+
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    let Some(old_organization) = organization_db_uc.find(id).await else {
+        info!("Failed to find Organization with Id {}.", &id);
+        return get_404_not_found().await;
+    };
+
+    let Some(organization_form) = process_organization_body(body) else {
+        warn!("Failed to process Organization body with Id {}.", &path);
+        return get_500_internal_server_error();
+    };
+    let mut organization = match organization_form.validate() {
+        Ok(organization) => organization,
+        Err(errors) => {
+            info!("Invalid Organization edit with Id {}.", &path);
+            return render_admin_organization_form(AdminGetEditOrganizationTemplate {
+                organization: organization_form.to_admin_template(),
+                errors,
+            });
+        }
+    };
+    // Always update the Organization from the path
+    organization.id = id;
+
+    // Find the Talks before the edit to audit their previous Organization
+    let talks = find_organization_talks(&app_state, &old_organization.name).await;
+
+    let edit_result = organization_db_uc
+        .update(
+            id,
+            organization.name.clone(),
+            organization.url.clone(),
+            organization.logo.clone(),
+            organization.description.clone(),
+        )
+        .await;
+
+    match edit_result {
+        Some(OrganizationCommandStatus::Updated) => {}
+        Some(_) => {
+            error!("Failed to edit Organization with Id {}", &path);
+            return get_500_internal_server_error();
+        }
+        None => {
+            info!("Failed to edit Organization with Id {}.", &path);
+            return get_404_not_found().await;
+        }
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_UPDATE,
+        AUDIT_ENTITY_ORGANIZATION,
+        id.to_string(),
+        old_organization.audit_summary(),
+        organization.audit_summary(),
+    )
+    .await;
+    record_organization_talks_audit_log(&app_state, &audit_context, &talks, Some(&organization))
+        .await;
+
+    invalidate_organization_talks(&app_state, &talks).await;
+
+    get_admin_organization(Path(path), State(app_state)).await
+}
+
+/// delete_delete_admin_organization
+/// Serve DELETE delete organization HTML file
+/// Talks hosted by the Organization are kept without an Organization
+#[debug_handler]
+pub async fn delete_delete_admin_organization(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::Delete) {
+        return get_401_unauthorized().await;
+    }
+
+    let mut organization_db_uc = app_state
+        .organization_db_usecase
+        .lock()
+        .await
+        .clone()
+        .unwrap();
+
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    let Some(organization) = organization_db_uc.find(id).await else {
+        info!("Failed to find Organization with Id {}.", &id);
+        return get_404_not_found().await;
+    };
+
+    // Find the Talks before deleting since they lose their Organization afterwards
+    let talks = find_organization_talks(&app_state, &organization.name).await;
+    invalidate_organization_talks(&app_state, &talks).await;
+
+    match organization_db_uc.delete(id).await {
+        Some(OrganizationCommandStatus::Deleted) => {}
+        Some(_) | None => {
+            error!("Failed to delete Organization with Id {}", &path);
+            return get_500_internal_server_error();
+        }
+    }
+
+    let audit_context = AuditContext::from_request(&app_state, &headers, addr);
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_DELETE,
+        AUDIT_ENTITY_ORGANIZATION,
+        id.to_string(),
+        organization.audit_summary(),
+        String::new(),
+    )
+    .await;
+    record_organization_talks_audit_log(&app_state, &audit_context, &talks, None).await;
+
+    let params = OrganizationsParams {
+        start: None,
+        end: None,
+    };
+    get_admin_organizations_list(State(app_state), Query(params)).await
+}
@@ -0,0 +1,254 @@
+use crate::handler::admin::users::user_role_names;
+use crate::handler::status::{
+    get_401_unauthorized, get_404_not_found, get_500_internal_server_error,
+};
+use crate::model::auth::{AuthUser, Permission, UserRole};
+use crate::model::axum::AppState;
+use crate::model::templates_admin::{
+    AdminGetAddUserTemplate, AdminGetDeleteUserTemplate, AdminGetEditUserTemplate,
+    AdminGetResetUserTotpTemplate, AdminUsersTemplate,
+};
+use crate::repo::auth::AuthRepo;
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::{Path, State};
+use axum::response::Html;
+use tracing::{error, info};
+
+/// get_base_admin_users
+/// Serve GET (base) admin users HTML file
+/// Under endpoint /admin/users
+#[debug_handler]
+pub async fn get_base_admin_users(auth_user: AuthUser) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    match (AdminUsersTemplate {}).render() {
+        Ok(res) => {
+            info!("AdminUsers askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/users/users.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_users_list
+/// Serve to list every User
+/// Under endpoint /admin/users/list
+#[debug_handler]
+pub async fn get_admin_users_list(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(users) = auth_uc.find_users().await else {
+        error!("Failed to find Users.");
+        return get_500_internal_server_error();
+    };
+
+    match users.to_admin_list_template().render() {
+        Ok(res) => {
+            info!("AdminListUsers askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/users/list_users.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_admin_user
+/// Serve GET user HTML file and return point for several cancelation endpoints
+#[debug_handler]
+pub async fn get_admin_user(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(user) = auth_uc.find_user_by_id(path.clone()).await else {
+        info!("Failed to find User with Id {}.", &path);
+        return get_404_not_found().await;
+    };
+
+    match user.to_admin_template().render() {
+        Ok(res) => {
+            info!("AdminGetUser askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/users/get_user.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_add_admin_user
+/// Serve GET add user HTML file in a form format.
+#[debug_handler]
+pub async fn get_add_admin_user(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    let add_user = AdminGetAddUserTemplate {
+        email: String::new(),
+        role: UserRole::default().to_string(),
+        roles: user_role_names(),
+        password_min_length: app_state.config.password_min_length,
+        errors: Vec::new(),
+    };
+    render_add_admin_user(add_user)
+}
+
+/// Render the add user form, optionally with validation errors
+pub(super) fn render_add_admin_user(add_user: AdminGetAddUserTemplate) -> Html<String> {
+    match add_user.render() {
+        Ok(res) => {
+            info!("AdminGetAddUser askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/users/get_add_user.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_edit_admin_user
+/// Serve GET edit user HTML file to edit the email and role of a user
+#[debug_handler]
+pub async fn get_edit_admin_user(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(user) = auth_uc.find_user_by_id(path.clone()).await else {
+        info!("Failed to find User with Id {}.", &path);
+        return get_404_not_found().await;
+    };
+
+    let edit_user = AdminGetEditUserTemplate {
+        id: user.id,
+        email: user.email,
+        role: user.role.to_string(),
+        roles: user_role_names(),
+        errors: Vec::new(),
+    };
+    render_edit_admin_user(edit_user)
+}
+
+/// Render the edit user form, optionally with validation errors
+pub(super) fn render_edit_admin_user(edit_user: AdminGetEditUserTemplate) -> Html<String> {
+    match edit_user.render() {
+        Ok(res) => {
+            info!("AdminGetEditUser askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/users/get_edit_user.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_reset_admin_user_totp
+/// Serve GET reset two-factor authentication HTML file of a user
+/// Used when a User lost their authenticator app and recovery codes.
+#[debug_handler]
+pub async fn get_reset_admin_user_totp(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(user) = auth_uc.find_user_by_id(path.clone()).await else {
+        info!("Failed to find User with Id {}.", &path);
+        return get_404_not_found().await;
+    };
+
+    let reset_user_totp = AdminGetResetUserTotpTemplate {
+        id: user.id,
+        email: user.email,
+    };
+    match reset_user_totp.render() {
+        Ok(res) => {
+            info!("AdminGetResetUserTotp askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!(
+                "Failed to render admin/users/get_reset_user_totp.html. {}",
+                err
+            );
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_delete_admin_user
+/// Serve GET delete user HTML file to remove a user
+/// The logged in User cannot remove themselves.
+#[debug_handler]
+pub async fn get_delete_admin_user(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(user) = auth_uc.find_user_by_id(path.clone()).await else {
+        info!("Failed to find User with Id {}.", &path);
+        return get_404_not_found().await;
+    };
+
+    let error = if auth_user.user.id == user.id {
+        "You cannot remove yourself.".to_string()
+    } else {
+        String::new()
+    };
+
+    let delete_user = AdminGetDeleteUserTemplate {
+        id: user.id,
+        email: user.email,
+        error,
+    };
+    match delete_user.render() {
+        Ok(res) => {
+            info!("AdminGetDeleteUser askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render admin/users/get_delete_user.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
@@ -0,0 +1,56 @@
+pub mod displays;
+pub mod operations;
+
+use crate::model::auth::USER_ROLES;
+use tracing::{debug, warn};
+use urlencoding::decode;
+
+/// Every UserRole name to be picked in the admin users forms
+fn user_role_names() -> Vec<String> {
+    USER_ROLES.map(|role| role.to_string()).to_vec()
+}
+
+/// Take request body String from POST add and PUT edit user to get email,
+/// password and role. The password is empty when editing a User.
+fn process_user_body(body: &str) -> (String, String, String) {
+    // Initialize fields
+    let mut email = String::new();
+    let mut password = String::new();
+    let mut role = String::new();
+
+    for req_field in body.split("&") {
+        let Some((key, value)) = req_field.split_once("=") else {
+            continue;
+        };
+        let value_decoded = decode(value).unwrap_or_default();
+        match key {
+            "user_email" => email = value_decoded.trim().to_string(),
+            "user_password" => password = value_decoded.to_string(),
+            "user_role" => role = value_decoded.to_string(),
+            _ => {
+                warn!("Unrecognized key/value: {:?}/{:?}", key, value_decoded);
+            }
+        }
+    }
+    debug!("Processed User body for email {}", &email);
+
+    (email, password, role)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_process_user_body() {
+        let (email, password, role) = process_user_body(
+            "user_email=ed%40example.com&user_password=s3cr%26t&user_role=editor",
+        );
+        assert_eq!(email, "ed@example.com");
+        assert_eq!(password, "s3cr&t");
+        assert_eq!(role, "editor");
+
+        let (email, password, role) = process_user_body("");
+        assert!(email.is_empty() && password.is_empty() && role.is_empty());
+    }
+}
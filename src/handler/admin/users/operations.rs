@@ -0,0 +1,262 @@
+use crate::handler::admin::audit::{record_audit_log, AuditContext};
+use crate::handler::admin::users::displays::{
+    get_admin_user, get_admin_users_list, render_add_admin_user, render_edit_admin_user,
+};
+use crate::handler::admin::users::{process_user_body, user_role_names};
+use crate::handler::auth::{generate_user_id, hash_password, sanitize_email, sanitize_password};
+use crate::handler::status::{
+    get_401_unauthorized, get_404_not_found, get_500_internal_server_error,
+};
+use crate::model::audit_logs::{
+    AUDIT_ACTION_CREATE, AUDIT_ACTION_DELETE, AUDIT_ACTION_UPDATE, AUDIT_ENTITY_USER,
+};
+use crate::model::auth::{AuthUser, PasswordPolicy, Permission, User, UserCommandStatus, UserRole};
+use crate::model::axum::AppState;
+use crate::model::templates_admin::{AdminGetAddUserTemplate, AdminGetEditUserTemplate};
+use crate::model::totp::UserTotpCommandStatus;
+use crate::repo::auth::AuthRepo;
+use axum::debug_handler;
+use axum::extract::{ConnectInfo, Path, State};
+use axum::http::HeaderMap;
+use axum::response::Html;
+use std::net::SocketAddr;
+use tracing::{error, info, warn};
+
+/// post_add_admin_user
+/// Serve POST add user HTML file
+/// Invite a User with an initial password. Invalid submissions re-render the form.
+#[debug_handler]
+pub async fn post_add_admin_user(
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let (email, password, role) = process_user_body(&body);
+    let password = sanitize_password(&password);
+
+    let password_errors = PasswordPolicy::from(&app_state.config).check(&password);
+    let validation = match (sanitize_email(&email), UserRole::parse(&role)) {
+        (None, _) => Err(vec!["Email is not valid.".to_string()]),
+        (_, None) => Err(vec!["Role is not valid.".to_string()]),
+        _ if !password_errors.is_empty() => Err(password_errors),
+        (Some(email), Some(role)) => match auth_uc.find_user_by_email(email.clone()).await {
+            Some(_) => Err(vec!["Email is already registered.".to_string()]),
+            None => Ok((email, role)),
+        },
+    };
+    let (email, role) = match validation {
+        Ok(val) => val,
+        Err(errors) => {
+            info!("Rejected new User {}. {}", &email, errors.join(" "));
+            return render_add_admin_user(AdminGetAddUserTemplate {
+                email,
+                role,
+                roles: user_role_names(),
+                password_min_length: app_state.config.password_min_length,
+                errors,
+            });
+        }
+    };
+
+    let Some(hashed_password) = hash_password(&password) else {
+        return get_500_internal_server_error();
+    };
+    let user = User {
+        id: generate_user_id(),
+        email,
+        hashed_password,
+        role,
+        totp_enabled: false,
+    };
+    let add_result = auth_uc
+        .add_user(
+            user.id.clone(),
+            user.email.clone(),
+            user.hashed_password.clone(),
+            user.role,
+        )
+        .await;
+    if add_result != Some(UserCommandStatus::Stored) {
+        error!("Failed to add User {}.", &user.email);
+        return get_500_internal_server_error();
+    }
+    record_audit_log(
+        &app_state,
+        &AuditContext::from_request(&app_state, &headers, addr),
+        AUDIT_ACTION_CREATE,
+        AUDIT_ENTITY_USER,
+        user.email.clone(),
+        String::new(),
+        user.audit_summary(),
+    )
+    .await;
+
+    get_admin_users_list(State(app_state), auth_user).await
+}
+
+/// put_edit_admin_user
+/// Serve PUT edit user HTML file
+/// The logged in User cannot change their own role, so there is always an Owner left.
+/// Role changes apply immediately since the role is loaded on every request.
+#[debug_handler]
+pub async fn put_edit_admin_user(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(saved_user) = auth_uc.find_user_by_id(path.clone()).await else {
+        info!("Failed to find User with Id {}.", &path);
+        return get_404_not_found().await;
+    };
+
+    let (email, _, role) = process_user_body(&body);
+    let validation = match (sanitize_email(&email), UserRole::parse(&role)) {
+        (None, _) => Err("Email is not valid."),
+        (_, None) => Err("Role is not valid."),
+        (Some(_), Some(role)) if auth_user.user.id == saved_user.id && role != saved_user.role => {
+            Err("You cannot change your own role.")
+        }
+        (Some(email), Some(role)) => match auth_uc.find_user_by_email(email.clone()).await {
+            Some(user) if user.id != saved_user.id => Err("Email is already registered."),
+            _ => Ok((email, role)),
+        },
+    };
+    let (email, role) = match validation {
+        Ok(val) => val,
+        Err(err) => {
+            info!("Rejected edit of User {}. {}", &saved_user.id, err);
+            return render_edit_admin_user(AdminGetEditUserTemplate {
+                id: saved_user.id,
+                email,
+                role,
+                roles: user_role_names(),
+                errors: vec![err.to_string()],
+            });
+        }
+    };
+
+    let edit_result = auth_uc
+        .update_user(path.clone(), Some(email.clone()), None, Some(role))
+        .await;
+    if edit_result != Some(UserCommandStatus::Updated) {
+        error!("Failed to edit User with Id {}.", &path);
+        return get_500_internal_server_error();
+    }
+    let user = User {
+        email,
+        role,
+        ..saved_user.clone()
+    };
+    record_audit_log(
+        &app_state,
+        &AuditContext::from_request(&app_state, &headers, addr),
+        AUDIT_ACTION_UPDATE,
+        AUDIT_ENTITY_USER,
+        saved_user.email.clone(),
+        saved_user.audit_summary(),
+        user.audit_summary(),
+    )
+    .await;
+
+    get_admin_user(Path(path), State(app_state), auth_user).await
+}
+
+/// delete_reset_admin_user_totp
+/// Serve DELETE reset two-factor authentication HTML file
+/// Turn off the TOTP of a User, who can log in with their password only and set it up again.
+#[debug_handler]
+pub async fn delete_reset_admin_user_totp(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(saved_user) = auth_uc.find_user_by_id(path.clone()).await else {
+        info!("Failed to find User with Id {}.", &path);
+        return get_404_not_found().await;
+    };
+
+    let reset_result = auth_uc.delete_user_totp(path.clone()).await;
+    if reset_result != Some(UserTotpCommandStatus::Deleted) {
+        error!("Failed to reset TOTP of User with Id {}.", &path);
+        return get_500_internal_server_error();
+    }
+    record_audit_log(
+        &app_state,
+        &AuditContext::from_request(&app_state, &headers, addr),
+        AUDIT_ACTION_UPDATE,
+        AUDIT_ENTITY_USER,
+        saved_user.email.clone(),
+        String::new(),
+        "totp reset".to_string(),
+    )
+    .await;
+
+    get_admin_user(Path(path), State(app_state), auth_user).await
+}
+
+/// delete_delete_admin_user
+/// Serve DELETE delete user HTML file
+/// The logged in User cannot remove themselves.
+#[debug_handler]
+pub async fn delete_delete_admin_user(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+    auth_user: AuthUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Html<String> {
+    if !auth_user.is_permitted(Permission::ManageUsers) {
+        return get_401_unauthorized().await;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(saved_user) = auth_uc.find_user_by_id(path.clone()).await else {
+        info!("Failed to find User with Id {}.", &path);
+        return get_404_not_found().await;
+    };
+
+    if auth_user.user.id == saved_user.id {
+        warn!("User {} tried to remove themselves.", &saved_user.id);
+        return get_admin_users_list(State(app_state), auth_user).await;
+    }
+
+    let delete_result = auth_uc.delete_user(path.clone()).await;
+    if delete_result != Some(UserCommandStatus::Deleted) {
+        error!("Failed to delete User with Id {}.", &path);
+        return get_500_internal_server_error();
+    }
+    record_audit_log(
+        &app_state,
+        &AuditContext::from_request(&app_state, &headers, addr),
+        AUDIT_ACTION_DELETE,
+        AUDIT_ENTITY_USER,
+        saved_user.email.clone(),
+        saved_user.audit_summary(),
+        String::new(),
+    )
+    .await;
+
+    get_admin_users_list(State(app_state), auth_user).await
+}
@@ -1,10 +1,19 @@
+use crate::handler::auth::email_link::{
+    find_email_link_user, is_email_link_enabled, EMAIL_LINK_INVALID,
+};
+use crate::model::auth::{EmailTokenParams, EmailTokenPurpose};
 use crate::model::axum::AppState;
+use crate::model::oidc::OidcConfig;
 use crate::{
     handler::{auth::is_auth_verified, status::get_404_not_found, HX_REDIRECT},
-    model::templates::{LoginRetryTemplate, LoginSuccessTemplate, LoginTemplate, LogoutTemplate},
+    model::templates::{
+        LoginEmailSentTemplate, LoginEmailTemplate, LoginOidcTemplate, LoginResetResultTemplate,
+        LoginResetTemplate, LoginRetryTemplate, LoginSuccessTemplate, LoginTemplate,
+        LoginTotpTemplate, LogoutTemplate,
+    },
 };
 use askama::Template;
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum::{http::HeaderMap, response::Html};
 use tracing::{error, info};
 
@@ -17,11 +26,15 @@ pub async fn get_login(
     let mut resp_headers = HeaderMap::new();
 
     // Redirect User to Admin Blog when token is verified
-    if is_auth_verified(headers, &app_state.config.secrets.jwt_secret) {
+    if is_auth_verified(headers, &app_state).await {
         resp_headers.insert(HX_REDIRECT, "/admin".parse().unwrap());
     }
 
-    let login = LoginTemplate.render();
+    let login = LoginTemplate {
+        oidc_enabled: OidcConfig::from_config(&app_state.config).is_some(),
+        email_link_enabled: is_email_link_enabled(&app_state).await,
+    }
+    .render();
     match login {
         Ok(res) => {
             info!("Get Login askama template rendered.");
@@ -38,7 +51,21 @@ pub async fn get_login(
 /// Serve Login Retry HTML template
 pub async fn get_login_retry(header_map: Option<HeaderMap>) -> (HeaderMap, Html<String>) {
     let header_map_final = header_map.map_or_else(HeaderMap::new, |hm| hm);
-    let login_retry = LoginRetryTemplate.render();
+    render_login_retry(header_map_final, String::new()).await
+}
+
+/// get_login_locked
+/// Serve Login Retry HTML template telling the email is locked out
+pub async fn get_login_locked(error: String) -> (HeaderMap, Html<String>) {
+    render_login_retry(HeaderMap::new(), error).await
+}
+
+// Render the Login Retry HTML template with the reason of the rejection
+async fn render_login_retry(
+    header_map_final: HeaderMap,
+    error: String,
+) -> (HeaderMap, Html<String>) {
+    let login_retry = LoginRetryTemplate { error }.render();
     match login_retry {
         Ok(res) => {
             info!("Get Login Retry askama template rendered.");
@@ -51,6 +78,159 @@ pub async fn get_login_retry(header_map: Option<HeaderMap>) -> (HeaderMap, Html<
     }
 }
 
+/// get_login_totp
+/// Serve Login TOTP HTML template asking the second factor
+/// `error` is shown when the previous code was rejected.
+pub async fn get_login_totp(header_map: HeaderMap, error: &str) -> (HeaderMap, Html<String>) {
+    let login_totp = LoginTotpTemplate {
+        error: error.to_string(),
+    }
+    .render();
+    match login_totp {
+        Ok(res) => {
+            info!("Get Login TOTP askama template rendered.");
+            (header_map, Html(res))
+        }
+        Err(err) => {
+            error!("Failed to render auth/login_totp.html. {}", err);
+            (header_map, get_404_not_found().await)
+        }
+    }
+}
+
+/// get_login_oidc_result
+/// Serve Login OIDC HTML page the identity provider redirects back to
+/// Ask the TOTP code if `totp` is set. Otherwise, show why the login failed.
+pub async fn get_login_oidc_result(
+    header_map: HeaderMap,
+    error: &str,
+    totp: bool,
+) -> (HeaderMap, Html<String>) {
+    let login_oidc = LoginOidcTemplate {
+        error: error.to_string(),
+        totp,
+    }
+    .render();
+    match login_oidc {
+        Ok(res) => {
+            info!("Get Login OIDC askama template rendered.");
+            (header_map, Html(res))
+        }
+        Err(err) => {
+            error!("Failed to render auth/login_oidc.html. {}", err);
+            (header_map, get_404_not_found().await)
+        }
+    }
+}
+
+/// get_login_email_sent
+/// Serve Login Email Sent HTML template
+/// Tell a link was sent without telling whether the email has an account, or
+/// show `error` if the request was rejected.
+pub async fn get_login_email_sent(error: &str) -> Html<String> {
+    let login_email_sent = LoginEmailSentTemplate {
+        error: error.to_string(),
+    }
+    .render();
+    match login_email_sent {
+        Ok(res) => {
+            info!("Get Login Email Sent askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render auth/login_email_sent.html. {}", err);
+            get_404_not_found().await
+        }
+    }
+}
+
+/// get_login_email
+/// Serve Login Email HTML page opened from a login link sent by email
+/// The link is only used up once the login is confirmed, since mail scanners
+/// prefetch links.
+pub async fn get_login_email(
+    State(app_state): State<AppState>,
+    Query(params): Query<EmailTokenParams>,
+) -> Html<String> {
+    if !is_email_link_enabled(&app_state).await {
+        return get_404_not_found().await;
+    }
+    let purpose = EmailTokenPurpose::Login;
+    let error = match find_email_link_user(&app_state, &params.token, purpose).await {
+        Some(_) => String::new(),
+        None => EMAIL_LINK_INVALID.to_string(),
+    };
+    let login_email = LoginEmailTemplate {
+        token: params.token,
+        error,
+    }
+    .render();
+    match login_email {
+        Ok(res) => {
+            info!("Get Login Email askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render auth/login_email.html. {}", err);
+            get_404_not_found().await
+        }
+    }
+}
+
+/// get_login_reset
+/// Serve Login Reset HTML page opened from a password reset link sent by email
+/// Ask the new password. The link is only used up once it is submitted.
+pub async fn get_login_reset(
+    State(app_state): State<AppState>,
+    Query(params): Query<EmailTokenParams>,
+) -> Html<String> {
+    if !is_email_link_enabled(&app_state).await {
+        return get_404_not_found().await;
+    }
+    let purpose = EmailTokenPurpose::PasswordReset;
+    let error = match find_email_link_user(&app_state, &params.token, purpose).await {
+        Some(_) => String::new(),
+        None => EMAIL_LINK_INVALID.to_string(),
+    };
+    let login_reset = LoginResetTemplate {
+        token: params.token,
+        error,
+        min_length: app_state.config.password_min_length,
+    }
+    .render();
+    match login_reset {
+        Ok(res) => {
+            info!("Get Login Reset askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render auth/login_reset.html. {}", err);
+            get_404_not_found().await
+        }
+    }
+}
+
+/// get_login_reset_result
+/// Serve Login Reset Result HTML template
+/// Show why the new password was rejected, or that the password was reset if
+/// `errors` is empty.
+pub async fn get_login_reset_result(
+    header_map: HeaderMap,
+    errors: Vec<String>,
+) -> (HeaderMap, Html<String>) {
+    let login_reset_result = LoginResetResultTemplate { errors }.render();
+    match login_reset_result {
+        Ok(res) => {
+            info!("Get Login Reset Result askama template rendered.");
+            (header_map, Html(res))
+        }
+        Err(err) => {
+            error!("Failed to render auth/login_reset_result.html. {}", err);
+            (header_map, get_404_not_found().await)
+        }
+    }
+}
+
 /// get_login_success
 /// Serve Login Success HTML template
 pub async fn get_login_sucess(header_map: Option<HeaderMap>) -> (HeaderMap, Html<String>) {
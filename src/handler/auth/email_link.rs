@@ -0,0 +1,349 @@
+use crate::config::Secrets;
+use crate::handler::auth::session::generate_random_hex;
+use crate::model::auth::{
+    EmailToken, EmailTokenClaims, EmailTokenCommandStatus, EmailTokenPurpose, User,
+};
+use crate::model::axum::AppState;
+use crate::model::mail::{Mail, MailCommandStatus};
+use crate::repo::auth::AuthRepo;
+use crate::repo::mail::MailRepo;
+use chrono::{Duration, SecondsFormat, Utc};
+use jsonwebtoken::{
+    decode as jwt_decode, encode as jwt_encode, DecodingKey, EncodingKey, Header, Validation,
+};
+use tracing::{debug, error, info, warn};
+
+/// Shown when a link sent by email cannot be used
+pub const EMAIL_LINK_INVALID: &str =
+    "This link is invalid or expired. Please request a new one from the login page.";
+
+/// Signing secret of the tokens of links sent by email
+/// Differs from the JWT secret so a link cannot be used as a login token.
+fn email_token_secret(secrets: &Secrets) -> String {
+    format!("email-token:{}", secrets.jwt_secret)
+}
+
+/// Whether login and password reset by email are enabled
+/// Links are only built from PUBLIC_URL, never from the request `Host` header.
+pub async fn is_email_link_enabled(app_state: &AppState) -> bool {
+    app_state.config.public_url.is_some() && app_state.mail_usecase.lock().await.is_some()
+}
+
+/// Path of the page a link sent by email opens
+fn email_link_path(purpose: EmailTokenPurpose) -> &'static str {
+    match purpose {
+        EmailTokenPurpose::Login => "/login/email/verify",
+        EmailTokenPurpose::PasswordReset => "/login/reset",
+    }
+}
+
+/// Sign the token of a link sent by email
+fn create_email_token(
+    secrets: &Secrets,
+    email_token: &EmailToken,
+    now: usize,
+    ttl: u64,
+) -> Option<String> {
+    let claims = EmailTokenClaims {
+        sub: email_token.user_id.clone(),
+        purpose: email_token.purpose,
+        jti: email_token.id.clone(),
+        exp: now + ttl as usize,
+        iat: now,
+    };
+    match jwt_encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(email_token_secret(secrets).as_ref()),
+    ) {
+        Ok(token) => Some(token),
+        Err(e) => {
+            error!("Failed to create email token. {:?}", e);
+            None
+        }
+    }
+}
+
+/// Return the Claims of a verified token of a link sent for `purpose`
+fn decode_email_token(
+    secrets: &Secrets,
+    token: &str,
+    purpose: EmailTokenPurpose,
+) -> Option<EmailTokenClaims> {
+    if token.is_empty() {
+        return None;
+    }
+    let claims = match jwt_decode::<EmailTokenClaims>(
+        token,
+        &DecodingKey::from_secret(email_token_secret(secrets).as_ref()),
+        &Validation::default(),
+    ) {
+        Ok(data) => data.claims,
+        Err(e) => {
+            debug!("Failed to verify email token. {:?}", e);
+            return None;
+        }
+    };
+    if claims.purpose != purpose {
+        warn!(
+            "Email token of User {} is for {}, not {}.",
+            &claims.sub,
+            claims.purpose.as_str(),
+            purpose.as_str()
+        );
+        return None;
+    }
+    Some(claims)
+}
+
+/// Whether a stored EmailToken belongs to the Claims and is still valid
+fn is_email_token_matched(email_token: &EmailToken, claims: &EmailTokenClaims) -> bool {
+    email_token.user_id == claims.sub
+        && email_token.purpose == claims.purpose
+        && !email_token.is_expired(Utc::now())
+}
+
+/// Create a single-use link for `purpose` of a User
+/// Return the link to be sent to the email of the User.
+pub async fn create_email_link(
+    app_state: &AppState,
+    user: &User,
+    purpose: EmailTokenPurpose,
+) -> Option<String> {
+    let public_url = app_state.config.public_url.as_ref()?;
+    let ttl = app_state.config.email_token_ttl;
+    let now = Utc::now();
+    let email_token = EmailToken {
+        id: generate_random_hex(16),
+        user_id: user.id.clone(),
+        purpose,
+        expire: (now + Duration::seconds(ttl as i64)).to_rfc3339_opts(SecondsFormat::Secs, true),
+    };
+    let token = create_email_token(
+        &app_state.config.secrets,
+        &email_token,
+        now.timestamp() as usize,
+        ttl,
+    )?;
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    if auth_uc.add_email_token(email_token).await != Some(EmailTokenCommandStatus::Stored) {
+        warn!("Failed to store email token of User {}.", &user.id);
+        return None;
+    }
+    Some(format!(
+        "{}{}?token={}",
+        public_url.trim_end_matches('/'),
+        email_link_path(purpose),
+        token
+    ))
+}
+
+/// Find the User of a link sent for `purpose` without using the link up
+/// Pages opened from the link call this, since mail scanners prefetch links.
+pub async fn find_email_link_user(
+    app_state: &AppState,
+    token: &str,
+    purpose: EmailTokenPurpose,
+) -> Option<User> {
+    let claims = decode_email_token(&app_state.config.secrets, token, purpose)?;
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let email_token = auth_uc.find_email_token(claims.jti.clone()).await?;
+    if !is_email_token_matched(&email_token, &claims) {
+        return None;
+    }
+    auth_uc.find_user_by_id(claims.sub).await
+}
+
+/// Use up a link sent for `purpose` and return its User
+/// Return None if the link is invalid, expired or was already used.
+pub async fn consume_email_link(
+    app_state: &AppState,
+    token: &str,
+    purpose: EmailTokenPurpose,
+) -> Option<User> {
+    let claims = decode_email_token(&app_state.config.secrets, token, purpose)?;
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(email_token) = auth_uc.consume_email_token(claims.jti.clone()).await else {
+        info!("Email token of User {} was already used.", &claims.sub);
+        return None;
+    };
+    if !is_email_token_matched(&email_token, &claims) {
+        return None;
+    }
+    auth_uc.find_user_by_id(claims.sub).await
+}
+
+/// Build the email of a link sent for `purpose`
+fn build_email_link_mail(
+    email: &str,
+    link: &str,
+    purpose: EmailTokenPurpose,
+    ttl_minutes: u64,
+) -> Mail {
+    let (subject, action) = match purpose {
+        EmailTokenPurpose::Login => ("Your login link", "log in"),
+        EmailTokenPurpose::PasswordReset => ("Reset your password", "reset your password"),
+    };
+    Mail {
+        to: email.to_string(),
+        subject: subject.to_string(),
+        body: format!(
+            "Open the link below to {action}. It can be used once and expires in \
+             {ttl_minutes} minutes.\n\n{link}\n\n\
+             If you didn't request it, you can ignore this email.\n"
+        ),
+    }
+}
+
+/// Create a link for `purpose` of a User and send it to their email
+pub async fn send_email_link(app_state: &AppState, user: &User, purpose: EmailTokenPurpose) {
+    let Some(mail_uc) = app_state.mail_usecase.lock().await.clone() else {
+        warn!(
+            "Emails are disabled. Cannot send a link to User {}.",
+            &user.id
+        );
+        return;
+    };
+    let Some(link) = create_email_link(app_state, user, purpose).await else {
+        warn!("Failed to create email link of User {}.", &user.id);
+        return;
+    };
+    let ttl_minutes = app_state.config.email_token_ttl.div_ceil(60);
+    let mail = build_email_link_mail(&user.email, &link, purpose, ttl_minutes);
+    if mail_uc.send(mail).await == Some(MailCommandStatus::Sent) {
+        info!("Sent {} link to User {}.", purpose.as_str(), &user.id);
+    } else {
+        warn!(
+            "Failed to send {} link to User {}.",
+            purpose.as_str(),
+            &user.id
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::Config;
+    use crate::model::auth::UserRole;
+    use crate::state::state_factory;
+
+    async fn app_state() -> AppState {
+        let mut config = Config::default();
+        config.secrets.database_url = ":memory:".to_string();
+        config.public_url = Some("https://example.com/".to_string());
+        state_factory(config).await
+    }
+
+    async fn add_user(app_state: &AppState) -> User {
+        let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+        auth_uc
+            .add_user(
+                "user-1".to_string(),
+                "me@example.com".to_string(),
+                String::new(),
+                UserRole::Owner,
+            )
+            .await
+            .unwrap();
+        auth_uc.find_user_by_id("user-1".to_string()).await.unwrap()
+    }
+
+    fn link_token(link: &str) -> &str {
+        link.split_once("?token=").unwrap().1
+    }
+
+    #[tokio::test]
+    async fn test_email_link_is_single_use() {
+        let app_state = app_state().await;
+        let user = add_user(&app_state).await;
+        let login = EmailTokenPurpose::Login;
+
+        let link = create_email_link(&app_state, &user, login).await.unwrap();
+        assert!(link.starts_with("https://example.com/login/email/verify?token="));
+        let token = link_token(&link);
+
+        // Opening the page doesn't use the link up
+        assert!(find_email_link_user(&app_state, token, login)
+            .await
+            .is_some());
+        let consumed = consume_email_link(&app_state, token, login).await.unwrap();
+        assert_eq!(consumed.id, user.id);
+        assert!(consume_email_link(&app_state, token, login).await.is_none());
+        assert!(find_email_link_user(&app_state, token, login)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_email_link_purpose_and_signature() {
+        let app_state = app_state().await;
+        let user = add_user(&app_state).await;
+        let reset = EmailTokenPurpose::PasswordReset;
+
+        let link = create_email_link(&app_state, &user, reset).await.unwrap();
+        assert!(link.starts_with("https://example.com/login/reset?token="));
+        let token = link_token(&link);
+        // A password reset link cannot log in
+        assert!(
+            consume_email_link(&app_state, token, EmailTokenPurpose::Login)
+                .await
+                .is_none()
+        );
+
+        let mut tampered = token.to_string();
+        tampered.push('x');
+        assert!(consume_email_link(&app_state, &tampered, reset)
+            .await
+            .is_none());
+        assert!(consume_email_link(&app_state, "", reset).await.is_none());
+        assert!(consume_email_link(&app_state, token, reset).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_email_link_revoked() {
+        let app_state = app_state().await;
+        let user = add_user(&app_state).await;
+        let login = EmailTokenPurpose::Login;
+
+        let link = create_email_link(&app_state, &user, login).await.unwrap();
+        let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+        auth_uc.delete_user_email_tokens(user.id.clone()).await;
+        assert!(consume_email_link(&app_state, link_token(&link), login)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_email_link_disabled() {
+        let mut config = Config::default();
+        config.secrets.database_url = ":memory:".to_string();
+        let app_state = state_factory(config).await;
+        assert!(!is_email_link_enabled(&app_state).await);
+        let user = User {
+            id: "user-1".to_string(),
+            email: "me@example.com".to_string(),
+            hashed_password: String::new(),
+            role: UserRole::Owner,
+            totp_enabled: false,
+        };
+        // Without PUBLIC_URL there is no link to send
+        assert!(
+            create_email_link(&app_state, &user, EmailTokenPurpose::Login)
+                .await
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_build_email_link_mail() {
+        let link = "https://example.com/login/reset?token=abc";
+        let mail =
+            build_email_link_mail("me@example.com", link, EmailTokenPurpose::PasswordReset, 15);
+        assert_eq!(mail.to, "me@example.com");
+        assert_eq!(mail.subject, "Reset your password");
+        assert!(mail.body.contains(link));
+        assert!(mail.body.contains("expires in 15 minutes"));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::handler::auth::csrf::verify_csrf_token;
+use crate::handler::auth::session::get_auth_session;
+use crate::handler::status::get_401_unauthorized;
+use crate::handler::HX_REQUEST;
+use crate::model::auth::AuthUser;
+use crate::model::axum::AppState;
+use crate::repo::auth::AuthRepo;
+use axum::extract::{FromRequestParts, Request, State};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Redirect, Response};
+use tracing::{info, warn};
+
+/// Whether a request was made by HTMX rather than a browser navigation
+fn is_htmx_request(headers: &HeaderMap) -> bool {
+    headers
+        .get(&HX_REQUEST)
+        .is_some_and(|value| value.as_bytes() == b"true")
+}
+
+/// Whether a request method changes state and has to carry the CSRF token
+fn is_csrf_required(method: &Method) -> bool {
+    !matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+/// Reject a request without an active Session
+/// Browsers are redirected to the login page. HTMX requests get a 401 to be
+/// swapped in place of the requested fragment.
+async fn reject_unauthenticated(headers: &HeaderMap) -> Response {
+    if !is_htmx_request(headers) {
+        return Redirect::to("/login").into_response();
+    }
+    (StatusCode::UNAUTHORIZED, get_401_unauthorized().await).into_response()
+}
+
+/// require_auth
+/// Middleware of the admin routes
+/// Reject requests without an active Session of an existing User, and
+/// state-changing requests without a matching CSRF token. Accepted requests
+/// carry the AuthUser for the handlers.
+pub async fn require_auth(
+    State(app_state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let headers = request.headers();
+    let Some((_, session)) = get_auth_session(headers, &app_state).await else {
+        info!("Unauthorized access to {}.", request.uri().path());
+        return reject_unauthenticated(headers).await;
+    };
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    // The User may be deleted while the Session is active
+    let Some(user) = auth_uc.find_user_by_id(session.user_id.clone()).await else {
+        warn!(
+            "User {} of Session {} is deleted.",
+            &session.user_id, &session.id
+        );
+        return reject_unauthenticated(headers).await;
+    };
+    if is_csrf_required(request.method()) && !verify_csrf_token(headers) {
+        warn!(
+            "Rejected {} {} of User {} without CSRF token.",
+            request.method(),
+            request.uri().path(),
+            &user.id
+        );
+        return (StatusCode::FORBIDDEN, get_401_unauthorized().await).into_response();
+    }
+
+    request.extensions_mut().insert(AuthUser { user, session });
+    next.run(request).await
+}
+
+impl<S> FromRequestParts<S> for AuthUser
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    /// Take the AuthUser set by [`require_auth`]
+    /// Handlers outside of the admin routes are rejected as unauthorized.
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        match parts.extensions.get::<AuthUser>() {
+            Some(auth_user) => Ok(auth_user.clone()),
+            None => {
+                warn!(
+                    "No AuthUser on {}. Is require_auth missing?",
+                    parts.uri.path()
+                );
+                Err((StatusCode::UNAUTHORIZED, get_401_unauthorized().await).into_response())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_is_htmx_request() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_htmx_request(&headers));
+        headers.insert(HX_REQUEST, HeaderValue::from_static("true"));
+        assert!(is_htmx_request(&headers));
+    }
+
+    #[test]
+    fn test_is_csrf_required() {
+        assert!(!is_csrf_required(&Method::GET));
+        assert!(!is_csrf_required(&Method::HEAD));
+        assert!(is_csrf_required(&Method::POST));
+        assert!(is_csrf_required(&Method::PUT));
+        assert!(is_csrf_required(&Method::DELETE));
+    }
+}
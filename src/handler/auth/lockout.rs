@@ -0,0 +1,113 @@
+use crate::model::auth::LoginLockoutPolicy;
+use crate::model::axum::AppState;
+use crate::repo::auth::AuthRepo;
+use chrono::Utc;
+use tracing::{info, warn};
+
+/// Seconds left of the lockout of an email. None if logins are accepted.
+pub async fn find_login_lockout_seconds(app_state: &AppState, email: &str) -> Option<i64> {
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    auth_uc
+        .find_login_lockout(email.to_string())
+        .await?
+        .remaining_seconds(Utc::now())
+}
+
+/// Count a failed login of an email, locking it out once the threshold is reached
+pub async fn register_failed_login(app_state: &AppState, email: &str) {
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let Some(failed_count) = auth_uc.add_failed_login(email.to_string()).await else {
+        warn!("Failed to count failed login of {}.", email);
+        return;
+    };
+    let policy = LoginLockoutPolicy::from(&app_state.config);
+    let Some(locked_until) = policy.locked_until(failed_count, Utc::now()) else {
+        return;
+    };
+    info!(
+        "Locking out {} until {} after {} failed logins.",
+        email, &locked_until, failed_count
+    );
+    if auth_uc
+        .update_login_lockout_until(email.to_string(), locked_until)
+        .await
+        .is_none()
+    {
+        warn!("Failed to lock out {}.", email);
+    }
+}
+
+/// Forget the failed logins of an email after a successful login
+pub async fn reset_failed_logins(app_state: &AppState, email: &str) {
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    if auth_uc
+        .delete_login_lockout(email.to_string())
+        .await
+        .is_none()
+    {
+        warn!("Failed to reset failed logins of {}.", email);
+    }
+}
+
+/// Message shown while an email is locked out
+pub fn lockout_message(seconds: i64) -> String {
+    let minutes = (seconds + 59) / 60;
+    let unit = if minutes == 1 { "minute" } else { "minutes" };
+    format!("Too many failed logins. Please try again in {minutes} {unit}.")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::Config;
+    use crate::state::state_factory;
+
+    async fn app_state() -> AppState {
+        let mut config = Config::default();
+        config.secrets.database_url = ":memory:".to_string();
+        config.login_lockout_threshold = 3;
+        config.login_lockout_base = 60;
+        state_factory(config).await
+    }
+
+    #[tokio::test]
+    async fn test_login_lockout() {
+        let app_state = app_state().await;
+        let email = "me@example.com";
+        for _ in 0..2 {
+            register_failed_login(&app_state, email).await;
+        }
+        assert_eq!(find_login_lockout_seconds(&app_state, email).await, None);
+
+        register_failed_login(&app_state, email).await;
+        let seconds = find_login_lockout_seconds(&app_state, email).await.unwrap();
+        assert!(seconds > 55 && seconds <= 60);
+        // Other emails are not affected
+        assert_eq!(
+            find_login_lockout_seconds(&app_state, "other@example.com").await,
+            None
+        );
+
+        // The next failure doubles the lockout
+        register_failed_login(&app_state, email).await;
+        let seconds = find_login_lockout_seconds(&app_state, email).await.unwrap();
+        assert!(seconds > 115 && seconds <= 120);
+
+        reset_failed_logins(&app_state, email).await;
+        assert_eq!(find_login_lockout_seconds(&app_state, email).await, None);
+        register_failed_login(&app_state, email).await;
+        assert_eq!(find_login_lockout_seconds(&app_state, email).await, None);
+    }
+
+    #[test]
+    fn test_lockout_message() {
+        assert_eq!(
+            lockout_message(30),
+            "Too many failed logins. Please try again in 1 minute."
+        );
+        assert_eq!(
+            lockout_message(61),
+            "Too many failed logins. Please try again in 2 minutes."
+        );
+    }
+}
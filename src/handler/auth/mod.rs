@@ -1,18 +1,27 @@
 pub mod csrf;
 pub mod displays;
+pub mod email_link;
+pub mod guard;
+pub mod lockout;
+pub mod oidc;
 pub mod operations;
-
-use crate::{model::auth::Claims, utils::remove_whitespace};
-use argon2::Argon2;
-use axum::http::{
-    header::{COOKIE, USER_AGENT},
-    HeaderMap,
+pub mod session;
+pub mod totp;
+
+use crate::{
+    handler::auth::session::{get_auth_session, request_user_agent},
+    model::auth::{Claims, UserRole},
+    model::axum::AppState,
+    utils::remove_whitespace,
 };
+use argon2::{Algorithm, Argon2, Params, Version};
+use axum::http::{header::COOKIE, HeaderMap};
 use jsonwebtoken::{
     decode as jwt_decode, encode as jwt_encode, DecodingKey, EncodingKey, Header, Validation,
 };
-use password_hash::{phc::PasswordHash, PasswordVerifier};
+use password_hash::{phc::PasswordHash, PasswordHasher, PasswordVerifier};
 use regex::Regex;
+use ring::rand::{SecureRandom, SystemRandom};
 use tracing::info;
 use tracing::{debug, error, warn};
 use urlencoding::decode as url_decode;
@@ -20,11 +29,13 @@ use urlencoding::decode as url_decode;
 /// Name of the JWT auth cookie as it appears in the `Cookie` header.
 const JWT_COOKIE_NAME: &str = "token=";
 
-/// Take request body String from POST login to get email and password
-fn process_login_body(body: &str) -> Option<(String, String)> {
+/// Take request body String from POST login to get email, password and
+/// whether "remember me" was checked
+fn process_login_body(body: &str) -> Option<(String, String, bool)> {
     // Initialize fields
     let mut email = String::new();
     let mut password = String::new();
+    let mut remember = false;
 
     let req_fields: Vec<&str> = body.split("&").collect();
     for req_field in req_fields {
@@ -33,12 +44,69 @@ fn process_login_body(body: &str) -> Option<(String, String)> {
         match key {
             "login_email" => email = value_decoded.to_string(),
             "login_password" => password = value_decoded.to_string(),
+            "remember_me" => remember = value_decoded == "on",
             _ => {
                 warn!("Unrecognized key/value: {:?}/{:?}", key, value_decoded);
             }
         }
     }
-    Some((email, password))
+    Some((email, password, remember))
+}
+
+/// Take request body String from POST login TOTP to get the submitted code
+fn process_totp_body(body: &str) -> String {
+    body.split("&")
+        .filter_map(|req_field| req_field.split_once("="))
+        .find(|(key, _)| *key == "totp_code")
+        .and_then(|(_, value)| url_decode(value).ok())
+        .map(|value| remove_whitespace(&value))
+        .unwrap_or_default()
+}
+
+/// Take request body String from POST login email to get the email and what
+/// the link is for
+fn process_email_link_body(body: &str) -> (String, String) {
+    let mut email = String::new();
+    let mut purpose = String::new();
+
+    for req_field in body.split("&") {
+        let Some((key, value)) = req_field.split_once("=") else {
+            continue;
+        };
+        let value_decoded = url_decode(value).unwrap_or_default().to_string();
+        match key {
+            "login_email" => email = value_decoded,
+            "purpose" => purpose = value_decoded,
+            _ => {
+                warn!("Unrecognized key: {:?}", key);
+            }
+        }
+    }
+    (email, purpose)
+}
+
+/// Take request body String of the forms of a link sent by email to get the
+/// token, and the new password with its confirmation for a password reset
+fn process_email_token_body(body: &str) -> (String, String, String) {
+    let mut token = String::new();
+    let mut new_password = String::new();
+    let mut confirm_password = String::new();
+
+    for req_field in body.split("&") {
+        let Some((key, value)) = req_field.split_once("=") else {
+            continue;
+        };
+        let value_decoded = url_decode(value).unwrap_or_default().to_string();
+        match key {
+            "token" => token = value_decoded,
+            "new_password" => new_password = value_decoded,
+            "confirm_password" => confirm_password = value_decoded,
+            _ => {
+                warn!("Unrecognized key: {:?}", key);
+            }
+        }
+    }
+    (token, new_password, confirm_password)
 }
 
 /// Extract a cookie value by name from a `Cookie` header.
@@ -56,36 +124,18 @@ pub fn extract_cookie_from_cookies(cookie_header: &str, cookie_name: &str) -> Op
         .map(|c| c[cookie_name.len()..].to_string())
 }
 
-/// Verify the `token` cookie holds a valid JWT signed with `jwt_secret`.
+/// Verify the `token` cookie holds a valid JWT of an active Session.
 ///
-/// Returns `true` only when the JWT is present, well-formed, and not expired.
-/// Missing, empty, or invalid tokens return `false` (resulting in a 401).
+/// Returns `true` only when the JWT is present, well-formed, not expired, and
+/// its Session was neither revoked nor expired. Missing, empty, or invalid
+/// tokens return `false` (resulting in a 401).
 /// The `token` cookie is read via [`extract_cookie_from_cookies`].
-pub fn is_auth_verified(header: HeaderMap, jwt_secret: &str) -> bool {
-    let mut user_agent = String::new();
-    let mut token = String::new();
-
-    for (key, value) in header.iter() {
-        match *key {
-            USER_AGENT => user_agent = value.to_str().unwrap().to_string(),
-            COOKIE => {
-                let tkn = extract_cookie_from_cookies(value.to_str().unwrap(), JWT_COOKIE_NAME);
-                match tkn {
-                    Some(v) => token = v,
-                    None => {
-                        debug!("No token in cookies");
-                        continue;
-                    }
-                };
-            }
-            _ => {
-                debug!("Unrecognized key/value: {:?}/{:?}", key, value);
-            }
-        }
-    }
-
-    info!("User Agent: {} and JWT processed", user_agent);
-    if !verify_jwt(&token, jwt_secret) {
+pub async fn is_auth_verified(header: HeaderMap, app_state: &AppState) -> bool {
+    info!(
+        "User Agent: {} and JWT processed",
+        request_user_agent(&header)
+    );
+    if get_auth_session(&header, app_state).await.is_none() {
         info!("Unauthorized access.");
         return false;
     }
@@ -95,7 +145,7 @@ pub fn is_auth_verified(header: HeaderMap, jwt_secret: &str) -> bool {
 /// sanitize_email
 /// Remove whitespace and check email pattern of an email
 /// Return sanitized email or None
-fn sanitize_email(email: &str) -> Option<String> {
+pub fn sanitize_email(email: &str) -> Option<String> {
     let non_whitespace_email = remove_whitespace(email);
     // Reference: https://regexr.com/3e48o
     // Before @, allow words (alphanumeric and numbers), ''-',  and '.'
@@ -124,13 +174,13 @@ fn sanitize_email(email: &str) -> Option<String> {
 /// sanitize_password
 /// Remove whitespace.
 /// TODO: think about it later
-fn sanitize_password(password: &str) -> String {
+pub fn sanitize_password(password: &str) -> String {
     remove_whitespace(password)
 }
 
 /// is_password_match
 /// Compare password from user with hashed_passwrod in the DB
-fn is_password_match(password: &str, hashed_passwrod: &str) -> bool {
+pub fn is_password_match(password: &str, hashed_passwrod: &str) -> bool {
     let password_hash = PasswordHash::new(hashed_passwrod).expect("Invalid password hash");
     let argon2_algo: &dyn PasswordVerifier<PasswordHash> = &Argon2::default();
 
@@ -145,13 +195,64 @@ fn is_password_match(password: &str, hashed_passwrod: &str) -> bool {
     }
 }
 
+/// is_password_rehash_needed
+/// Whether a stored hash uses a different algorithm or weaker Argon2
+/// parameters than [`hash_password`] does now
+pub fn is_password_rehash_needed(hashed_passwrod: &str) -> bool {
+    let Ok(password_hash) = PasswordHash::new(hashed_passwrod) else {
+        warn!("Stored password hash cannot be parsed.");
+        return true;
+    };
+    let Ok(params) = Params::try_from(&password_hash) else {
+        warn!("Stored password hash parameters cannot be parsed.");
+        return true;
+    };
+    let default_params = Params::default();
+
+    password_hash.algorithm != Algorithm::default().ident()
+        || password_hash.version != Some(Version::default() as u32)
+        || params.m_cost() < default_params.m_cost()
+        || params.t_cost() < default_params.t_cost()
+        || params.p_cost() < default_params.p_cost()
+}
+
+/// hash_password
+/// Hash a password with Argon2 and a random salt to be stored in the DB
+pub fn hash_password(password: &str) -> Option<String> {
+    let argon2_algo: &dyn PasswordHasher<PasswordHash> = &Argon2::default();
+    match argon2_algo.hash_password(password.as_bytes()) {
+        Ok(password_hash) => Some(password_hash.to_string()),
+        Err(e) => {
+            error!("Failed to hash password. {:?}", e);
+            None
+        }
+    }
+}
+
+/// generate_user_id
+/// Generate a random User Id
+pub fn generate_user_id() -> String {
+    let rng = SystemRandom::new();
+    let mut bytes = [0u8; 16];
+    rng.fill(&mut bytes).expect("Failed to generate User Id");
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 /// create_jwt
-/// Create JWT Claim and token
-fn create_jwt(secret: &str) -> Option<String> {
+/// Create JWT Claim and token for a User and its Session, valid for `ttl` seconds
+fn create_jwt(
+    secret: &str,
+    user_id: &str,
+    role: UserRole,
+    session_id: &str,
+    ttl: u64,
+) -> Option<String> {
     let now = chrono::Utc::now().timestamp() as usize;
-    let three_hour_in_s = 10800_usize;
     let my_claims = Claims {
-        exp: now + three_hour_in_s,
+        sub: user_id.to_string(),
+        role,
+        sid: session_id.to_string(),
+        exp: now + ttl as usize,
         iat: now,
     };
     match jwt_encode(
@@ -170,9 +271,15 @@ fn create_jwt(secret: &str) -> Option<String> {
 /// verify_jwt
 /// Return bool of verified JWT
 pub fn verify_jwt(token: &str, secret: &str) -> bool {
+    decode_jwt(token, secret).is_some()
+}
+
+/// decode_jwt
+/// Return the Claims of a verified JWT
+fn decode_jwt(token: &str, secret: &str) -> Option<Claims> {
     if token.is_empty() {
         debug!("JWT is empty. Skip JWT verification.");
-        return false;
+        return None;
     }
 
     let token = jwt_decode::<Claims>(
@@ -181,22 +288,70 @@ pub fn verify_jwt(token: &str, secret: &str) -> bool {
         &Validation::default(),
     );
     match token {
-        Ok(_) => true,
+        Ok(data) => Some(data.claims),
         Err(e) => {
             warn!("Failed to verify JWT Token. {:?}", e);
-            false
+            None
         }
     }
 }
 
+/// get_auth_token
+/// Return the raw JWT of the `token` cookie
+fn get_auth_token(header: &HeaderMap) -> Option<String> {
+    header
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find_map(|cookies| extract_cookie_from_cookies(cookies, JWT_COOKIE_NAME))
+}
+
+/// get_auth_claims
+/// Return the Claims of the verified `token` cookie
+fn get_auth_claims(header: &HeaderMap, jwt_secret: &str) -> Option<Claims> {
+    decode_jwt(&get_auth_token(header)?, jwt_secret)
+}
+
+/// get_auth_user_id
+/// Return the User Id of the verified `token` cookie
+/// Tokens issued before the User Id was added return an empty Id.
+pub fn get_auth_user_id(header: &HeaderMap, jwt_secret: &str) -> Option<String> {
+    get_auth_claims(header, jwt_secret).map(|claims| claims.sub)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::config::Config;
     use crate::handler::auth::csrf::CSRF_COOKIE_NAME;
+    use crate::handler::auth::session::create_session;
+    use crate::model::auth::User;
+    use crate::state::state_factory;
     use axum::http::HeaderValue;
 
     const SECRET: &str = "test-secret";
 
+    async fn app_state() -> AppState {
+        let mut config = Config::default();
+        config.secrets.database_url = ":memory:".to_string();
+        state_factory(config).await
+    }
+
+    /// Create a Session of `user-1` and return its JWT
+    async fn session_token(app_state: &AppState, role: UserRole) -> String {
+        let user = User {
+            id: "user-1".to_string(),
+            email: "me@example.com".to_string(),
+            hashed_password: String::new(),
+            role,
+            totp_enabled: false,
+        };
+        create_session(app_state, &user, false, String::new(), String::new())
+            .await
+            .unwrap()
+            .access_token
+    }
+
     fn insert_cookie(headers: &mut HeaderMap, cookie: &str) {
         if !cookie.is_empty() {
             headers.insert(COOKIE, HeaderValue::from_str(cookie).unwrap());
@@ -257,15 +412,48 @@ mod test {
         assert_eq!(csrf, Some("def".to_string()));
     }
 
+    #[test]
+    fn test_process_email_link_bodies() {
+        let (email, purpose) =
+            process_email_link_body("login_email=me%40example.com&purpose=password_reset");
+        assert_eq!(email, "me@example.com");
+        assert_eq!(purpose, "password_reset");
+
+        let (token, new_password, confirm_password) = process_email_token_body(
+            "token=a.b-c&new_password=n%C3%A9w%26pw&confirm_password=other",
+        );
+        assert_eq!(token, "a.b-c");
+        assert_eq!(new_password, "néw&pw");
+        assert_eq!(confirm_password, "other");
+
+        let (token, _, _) = process_email_token_body("");
+        assert!(token.is_empty());
+    }
+
+    #[test]
+    fn test_process_login_body() {
+        let (email, password, remember) =
+            process_login_body("login_email=me%40example.com&login_password=pw&remember_me=on")
+                .unwrap();
+        assert_eq!(email, "me@example.com");
+        assert_eq!(password, "pw");
+        assert!(remember);
+
+        let (_, _, remember) =
+            process_login_body("login_email=me%40example.com&login_password=pw").unwrap();
+        assert!(!remember);
+    }
+
     #[test]
     fn test_create_jwt_structure() {
-        let token = create_jwt(SECRET).expect("should create a JWT");
+        let token = create_jwt(SECRET, "user-1", UserRole::Owner, "session-1", 900)
+            .expect("should create a JWT");
         assert_eq!(token.split('.').count(), 3);
     }
 
     #[test]
     fn test_verify_jwt_valid_token() {
-        let token = create_jwt(SECRET).unwrap();
+        let token = create_jwt(SECRET, "user-1", UserRole::Owner, "session-1", 900).unwrap();
         assert!(verify_jwt(&token, SECRET));
     }
 
@@ -281,7 +469,7 @@ mod test {
 
     #[test]
     fn test_verify_jwt_wrong_secret() {
-        let token = create_jwt(SECRET).unwrap();
+        let token = create_jwt(SECRET, "user-1", UserRole::Owner, "session-1", 900).unwrap();
         assert!(!verify_jwt(&token, "different-secret"));
     }
 
@@ -289,6 +477,9 @@ mod test {
     fn test_verify_jwt_expired_token() {
         let now = chrono::Utc::now().timestamp() as usize;
         let expired_claims = Claims {
+            sub: "user-1".to_string(),
+            role: UserRole::Owner,
+            sid: "session-1".to_string(),
             exp: now.saturating_sub(3600),
             iat: now.saturating_sub(7200),
         };
@@ -303,7 +494,7 @@ mod test {
 
     #[test]
     fn test_verify_jwt_tampered_token() {
-        let token = create_jwt(SECRET).unwrap();
+        let token = create_jwt(SECRET, "user-1", UserRole::Owner, "session-1", 900).unwrap();
         let mut bytes = token.into_bytes();
         let last = bytes.last_mut().unwrap();
         *last = if *last == b'X' { b'Y' } else { b'X' };
@@ -311,47 +502,108 @@ mod test {
         assert!(!verify_jwt(&tampered, SECRET));
     }
 
-    #[test]
-    fn test_is_auth_verified_valid_token_first() {
-        let token = create_jwt(SECRET).unwrap();
+    #[tokio::test]
+    async fn test_is_auth_verified_valid_token_first() {
+        let app_state = app_state().await;
+        let token = session_token(&app_state, UserRole::Owner).await;
         let mut headers = HeaderMap::new();
         insert_cookie(&mut headers, &format!("token={token}; _csrf_token=abc123"));
-        assert!(is_auth_verified(headers, SECRET));
+        assert!(is_auth_verified(headers, &app_state).await);
     }
 
-    #[test]
-    fn test_is_auth_verified_csrf_first() {
-        let token = create_jwt(SECRET).unwrap();
+    #[tokio::test]
+    async fn test_is_auth_verified_csrf_first() {
+        let app_state = app_state().await;
+        let token = session_token(&app_state, UserRole::Owner).await;
         let mut headers = HeaderMap::new();
         insert_cookie(&mut headers, &format!("_csrf_token=abc123; token={token}"));
-        assert!(is_auth_verified(headers, SECRET));
+        assert!(is_auth_verified(headers, &app_state).await);
     }
 
-    #[test]
-    fn test_is_auth_verified_missing_token_cookie() {
+    #[tokio::test]
+    async fn test_is_auth_verified_missing_token_cookie() {
+        let app_state = app_state().await;
         let mut headers = HeaderMap::new();
         insert_cookie(&mut headers, "_csrf_token=abc123");
-        assert!(!is_auth_verified(headers, SECRET));
+        assert!(!is_auth_verified(headers, &app_state).await);
     }
 
-    #[test]
-    fn test_is_auth_verified_no_cookie_header() {
+    #[tokio::test]
+    async fn test_is_auth_verified_no_cookie_header() {
+        let app_state = app_state().await;
         let headers = HeaderMap::new();
-        assert!(!is_auth_verified(headers, SECRET));
+        assert!(!is_auth_verified(headers, &app_state).await);
     }
 
-    #[test]
-    fn test_is_auth_verified_garbage_token() {
+    #[tokio::test]
+    async fn test_is_auth_verified_garbage_token() {
+        let app_state = app_state().await;
         let mut headers = HeaderMap::new();
         insert_cookie(&mut headers, "token=garbage; _csrf_token=abc123");
-        assert!(!is_auth_verified(headers, SECRET));
+        assert!(!is_auth_verified(headers, &app_state).await);
     }
 
-    #[test]
-    fn test_is_auth_verified_wrong_secret() {
-        let token = create_jwt(SECRET).unwrap();
+    #[tokio::test]
+    async fn test_is_auth_verified_wrong_secret() {
+        let app_state = app_state().await;
+        let token = session_token(&app_state, UserRole::Owner).await;
+        let mut other_state = app_state.clone();
+        other_state.config.secrets.jwt_secret = "different-secret".to_string();
+        let mut headers = HeaderMap::new();
+        insert_cookie(&mut headers, &format!("token={token}; _csrf_token=abc123"));
+        assert!(!is_auth_verified(headers, &other_state).await);
+    }
+
+    #[tokio::test]
+    async fn test_is_auth_verified_without_session() {
+        let app_state = app_state().await;
+        let token = create_jwt(
+            &app_state.config.secrets.jwt_secret,
+            "user-1",
+            UserRole::Owner,
+            "session-1",
+            900,
+        )
+        .unwrap();
         let mut headers = HeaderMap::new();
         insert_cookie(&mut headers, &format!("token={token}; _csrf_token=abc123"));
-        assert!(!is_auth_verified(headers, "different-secret"));
+        assert!(!is_auth_verified(headers, &app_state).await);
+    }
+
+    #[test]
+    fn test_get_auth_user_id() {
+        let token = create_jwt(SECRET, "user-1", UserRole::Owner, "session-1", 900).unwrap();
+        let mut headers = HeaderMap::new();
+        insert_cookie(&mut headers, &format!("_csrf_token=abc123; token={token}"));
+        assert_eq!(
+            get_auth_user_id(&headers, SECRET),
+            Some("user-1".to_string())
+        );
+        assert_eq!(get_auth_user_id(&headers, "different-secret"), None);
+        assert_eq!(get_auth_user_id(&HeaderMap::new(), SECRET), None);
+    }
+
+    #[test]
+    fn test_hash_password() {
+        let hashed_password = hash_password("correct horse").unwrap();
+        assert_ne!(hashed_password, "correct horse");
+        assert!(is_password_match("correct horse", &hashed_password));
+        assert!(!is_password_match("wrong horse", &hashed_password));
+    }
+
+    #[test]
+    fn test_is_password_rehash_needed() {
+        let hashed_password = hash_password("correct horse").unwrap();
+        assert!(!is_password_rehash_needed(&hashed_password));
+
+        let weak_argon2 = Argon2::new(
+            Algorithm::Argon2i,
+            Version::V0x13,
+            Params::new(Params::MIN_M_COST, 1, 1, None).unwrap(),
+        );
+        let weak_password_hash: PasswordHash = weak_argon2.hash_password(b"correct horse").unwrap();
+        let weak_hashed_password = weak_password_hash.to_string();
+        assert!(is_password_match("correct horse", &weak_hashed_password));
+        assert!(is_password_rehash_needed(&weak_hashed_password));
     }
 }
@@ -0,0 +1,492 @@
+use crate::config::Secrets;
+use crate::handler::auth::extract_cookie_from_cookies;
+use crate::model::oidc::{
+    OidcConfig, OidcFlowClaims, OidcIdTokenClaims, OidcProviderMetadata, OidcTokenResponse,
+    OIDC_FLOW_TTL,
+};
+use axum::http::{header::COOKIE, HeaderMap};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{
+    decode as jwt_decode, decode_header, encode as jwt_encode, Algorithm, DecodingKey, EncodingKey,
+    Header, Validation,
+};
+use reqwest::Client;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+use tracing::{debug, error};
+
+/// Name of the OIDC flow cookie as it appears in the `Cookie` header.
+pub const OIDC_FLOW_COOKIE_NAME: &str = "oidc_flow=";
+/// Timeout of a single request to the identity provider
+const OIDC_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// OidcClient
+/// Authorization code flow with PKCE against an OpenID Connect identity provider
+#[derive(Debug, Clone)]
+pub struct OidcClient {
+    client: Client,
+    oidc_config: OidcConfig,
+}
+
+impl OidcClient {
+    pub fn new(oidc_config: OidcConfig) -> Self {
+        let client = Client::builder()
+            .timeout(OIDC_REQUEST_TIMEOUT)
+            .user_agent(concat!("husni-portfolio/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .expect("Failed to build OIDC HTTP client");
+        Self {
+            client,
+            oidc_config,
+        }
+    }
+    pub fn oidc_config(&self) -> &OidcConfig {
+        &self.oidc_config
+    }
+    /// Fetch the discovery document of the issuer
+    /// The advertised issuer must be the configured one.
+    pub async fn discover(&self) -> Result<OidcProviderMetadata, String> {
+        let metadata: OidcProviderMetadata =
+            self.get_json(&self.oidc_config.discovery_url()).await?;
+        if metadata.issuer.trim_end_matches('/')
+            != self.oidc_config.issuer_url.trim_end_matches('/')
+        {
+            return Err(format!(
+                "Discovery document belongs to issuer {}.",
+                metadata.issuer
+            ));
+        }
+        Ok(metadata)
+    }
+    /// Exchange an authorization code and its PKCE code verifier for an ID token
+    pub async fn exchange_code(
+        &self,
+        metadata: &OidcProviderMetadata,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<String, String> {
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &self.oidc_config.redirect_url),
+            ("client_id", &self.oidc_config.client_id),
+            ("code_verifier", code_verifier),
+        ];
+        if let Some(client_secret) = &self.oidc_config.client_secret {
+            form.push(("client_secret", client_secret));
+        }
+        let res = self
+            .client
+            .post(&metadata.token_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|err| format!("Failed to request token endpoint. {err}"))?;
+        let status = res.status();
+        let body = res
+            .text()
+            .await
+            .map_err(|err| format!("Failed to read token response. {err}"))?;
+        if !status.is_success() {
+            return Err(format!("Token endpoint returned {status}. {body}"));
+        }
+        serde_json::from_str::<OidcTokenResponse>(&body)
+            .map(|token| token.id_token)
+            .map_err(|err| format!("Failed to parse token response. {err}"))
+    }
+    /// Verify the signature, issuer, audience, expiry and nonce of an ID token
+    /// Signing keys are fetched from the JWKS of the identity provider.
+    pub async fn verify_id_token(
+        &self,
+        metadata: &OidcProviderMetadata,
+        id_token: &str,
+        nonce: &str,
+    ) -> Result<OidcIdTokenClaims, String> {
+        let header =
+            decode_header(id_token).map_err(|err| format!("Invalid ID token header. {err}"))?;
+        // A HMAC signature would be verified with a key the client also holds
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            return Err(format!(
+                "ID token algorithm {:?} is not allowed.",
+                header.alg
+            ));
+        }
+        let jwks: JwkSet = self.get_json(&metadata.jwks_uri).await?;
+        let jwk = match &header.kid {
+            Some(kid) => jwks.find(kid),
+            None => jwks.keys.first(),
+        }
+        .ok_or_else(|| "No JWKS key matches the ID token.".to_string())?;
+        let decoding_key =
+            DecodingKey::from_jwk(jwk).map_err(|err| format!("Invalid JWKS key. {err}"))?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_audience(&[&self.oidc_config.client_id]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+        let claims = jwt_decode::<OidcIdTokenClaims>(id_token, &decoding_key, &validation)
+            .map_err(|err| format!("Invalid ID token. {err}"))?
+            .claims;
+        if claims.nonce.as_deref() != Some(nonce) {
+            return Err("ID token nonce doesn't match.".to_string());
+        }
+        Ok(claims)
+    }
+    /// Complete the login the identity provider redirected back with `code`
+    pub async fn authenticate(
+        &self,
+        code: &str,
+        flow: &OidcFlowClaims,
+    ) -> Result<OidcIdTokenClaims, String> {
+        let metadata = self.discover().await?;
+        let id_token = self
+            .exchange_code(&metadata, code, &flow.code_verifier)
+            .await?;
+        self.verify_id_token(&metadata, &id_token, &flow.nonce)
+            .await
+    }
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, String> {
+        let res = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|err| format!("Failed to request {url}. {err}"))?;
+        if !res.status().is_success() {
+            return Err(format!("{url} returned {}.", res.status()));
+        }
+        let body = res
+            .text()
+            .await
+            .map_err(|err| format!("Failed to read {url}. {err}"))?;
+        serde_json::from_str(&body).map_err(|err| format!("Failed to parse {url}. {err}"))
+    }
+}
+
+/// Generate 32 crypto random bytes as a base64url string
+fn generate_oidc_token() -> String {
+    let rng = SystemRandom::new();
+    let mut bytes = [0u8; 32];
+    rng.fill(&mut bytes).expect("Failed to generate OIDC token");
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// Signing secret of OIDC flow tokens
+/// Differs from the JWT secret so a flow cannot be used as a login token.
+fn oidc_flow_secret(secrets: &Secrets) -> String {
+    format!("oidc-flow:{}", secrets.jwt_secret)
+}
+
+/// Generate the state, nonce and PKCE code verifier of a new login
+pub fn generate_oidc_flow() -> OidcFlowClaims {
+    let now = chrono::Utc::now().timestamp() as usize;
+    OidcFlowClaims {
+        state: generate_oidc_token(),
+        nonce: generate_oidc_token(),
+        code_verifier: generate_oidc_token(),
+        exp: now + OIDC_FLOW_TTL,
+        iat: now,
+    }
+}
+
+/// Sign an OIDC flow to be kept in the `oidc_flow` cookie
+pub fn create_oidc_flow_token(secrets: &Secrets, flow: &OidcFlowClaims) -> Option<String> {
+    match jwt_encode(
+        &Header::default(),
+        flow,
+        &EncodingKey::from_secret(oidc_flow_secret(secrets).as_ref()),
+    ) {
+        Ok(token) => Some(token),
+        Err(e) => {
+            error!("Failed to create OIDC flow. {:?}", e);
+            None
+        }
+    }
+}
+
+/// Return the verified OIDC flow of the `oidc_flow` cookie
+pub fn get_oidc_flow(header: &HeaderMap, secrets: &Secrets) -> Option<OidcFlowClaims> {
+    let token = header
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find_map(|cookies| extract_cookie_from_cookies(cookies, OIDC_FLOW_COOKIE_NAME))
+        .filter(|token| !token.is_empty())?;
+    match jwt_decode::<OidcFlowClaims>(
+        &token,
+        &DecodingKey::from_secret(oidc_flow_secret(secrets).as_ref()),
+        &Validation::default(),
+    ) {
+        Ok(data) => Some(data.claims),
+        Err(e) => {
+            debug!("Failed to verify OIDC flow. {:?}", e);
+            None
+        }
+    }
+}
+
+/// Build the Set-Cookie header value of the OIDC flow
+/// SameSite=Lax since the identity provider redirects back cross-site.
+pub fn oidc_flow_set_cookie_header(token: &str) -> String {
+    format!(
+        "{}{}; Secure; HttpOnly; SameSite=Lax; Path=/login/oidc; Max-Age={}",
+        OIDC_FLOW_COOKIE_NAME, token, OIDC_FLOW_TTL
+    )
+}
+
+/// Build the Set-Cookie header value to clear the OIDC flow
+pub fn oidc_flow_clear_cookie_header() -> String {
+    format!("{OIDC_FLOW_COOKIE_NAME}; Secure; HttpOnly; SameSite=Lax; Path=/login/oidc; Max-Age=0")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::handler::auth::verify_jwt;
+    use crate::model::oidc::{authorization_url, pkce_challenge};
+    use axum::extract::State;
+    use axum::http::{HeaderValue, StatusCode};
+    use axum::response::IntoResponse;
+    use axum::routing::{get, post};
+    use axum::{Form, Json, Router};
+    use ring::signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_FIXED_SIGNING};
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    const MOCK_CODE: &str = "code-1";
+    const MOCK_NONCE: &str = "nonce-1";
+
+    /// Local identity provider signing ES256 ID tokens
+    struct MockIdp {
+        issuer: String,
+        pkcs8: Vec<u8>,
+        public_key: Vec<u8>,
+        code_challenge: String,
+        claims: Value,
+    }
+
+    async fn discovery(State(idp): State<Arc<MockIdp>>) -> Json<Value> {
+        Json(json!({
+            "issuer": idp.issuer,
+            "authorization_endpoint": format!("{}/authorize", idp.issuer),
+            "token_endpoint": format!("{}/token", idp.issuer),
+            "jwks_uri": format!("{}/jwks", idp.issuer),
+            "response_types_supported": ["code"],
+        }))
+    }
+
+    async fn jwks(State(idp): State<Arc<MockIdp>>) -> Json<Value> {
+        // Uncompressed SEC1 point: 0x04 || x || y
+        let (x, y) = idp.public_key[1..].split_at(32);
+        Json(json!({
+            "keys": [{
+                "kty": "EC",
+                "crv": "P-256",
+                "kid": "mock-key",
+                "use": "sig",
+                "alg": "ES256",
+                "x": URL_SAFE_NO_PAD.encode(x),
+                "y": URL_SAFE_NO_PAD.encode(y),
+            }]
+        }))
+    }
+
+    async fn token(
+        State(idp): State<Arc<MockIdp>>,
+        Form(form): Form<HashMap<String, String>>,
+    ) -> impl IntoResponse {
+        let verifier = form.get("code_verifier").cloned().unwrap_or_default();
+        if form.get("grant_type").map(String::as_str) != Some("authorization_code")
+            || form.get("code").map(String::as_str) != Some(MOCK_CODE)
+            || pkce_challenge(&verifier) != idp.code_challenge
+        {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({"error": "invalid_grant"})),
+            );
+        }
+        let now = chrono::Utc::now().timestamp();
+        let mut claims = json!({
+            "iss": idp.issuer,
+            "aud": "portfolio",
+            "sub": "1234",
+            "exp": now + 300,
+            "iat": now,
+            "nonce": MOCK_NONCE,
+            "email": "me@example.com",
+            "email_verified": true,
+        });
+        for (key, value) in idp.claims.as_object().unwrap() {
+            claims[key] = value.clone();
+        }
+        let mut header = Header::new(Algorithm::ES256);
+        header.kid = Some("mock-key".to_string());
+        let id_token = jwt_encode(&header, &claims, &EncodingKey::from_ec_der(&idp.pkcs8)).unwrap();
+        (StatusCode::OK, Json(json!({"id_token": id_token})))
+    }
+
+    /// Serve a mock identity provider whose ID tokens hold `claims` on top of
+    /// valid defaults
+    async fn serve_mock_idp(code_verifier: &str, claims: Value) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let issuer = format!("http://{}", listener.local_addr().unwrap());
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &rng).unwrap();
+        let key_pair =
+            EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, pkcs8.as_ref(), &rng)
+                .unwrap();
+        let idp = Arc::new(MockIdp {
+            issuer: issuer.clone(),
+            pkcs8: pkcs8.as_ref().to_vec(),
+            public_key: key_pair.public_key().as_ref().to_vec(),
+            code_challenge: pkce_challenge(code_verifier),
+            claims,
+        });
+        let app = Router::new()
+            .route("/.well-known/openid-configuration", get(discovery))
+            .route("/jwks", get(jwks))
+            .route("/token", post(token))
+            .with_state(idp);
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        issuer
+    }
+
+    fn oidc_client(issuer_url: &str) -> OidcClient {
+        OidcClient::new(OidcConfig {
+            issuer_url: issuer_url.to_string(),
+            client_id: "portfolio".to_string(),
+            client_secret: Some("client-secret".to_string()),
+            redirect_url: "http://localhost:8080/login/oidc/callback".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_oidc_client_login() {
+        let flow = generate_oidc_flow();
+        let issuer = serve_mock_idp(&flow.code_verifier, json!({})).await;
+        let client = oidc_client(&issuer);
+
+        let metadata = client.discover().await.unwrap();
+        assert!(authorization_url(&metadata, client.oidc_config(), &flow)
+            .starts_with(&format!("{issuer}/authorize?response_type=code")));
+
+        assert!(client
+            .exchange_code(&metadata, MOCK_CODE, "wrong-verifier")
+            .await
+            .is_err());
+        let id_token = client
+            .exchange_code(&metadata, MOCK_CODE, &flow.code_verifier)
+            .await
+            .unwrap();
+
+        assert!(client
+            .verify_id_token(&metadata, &id_token, "wrong-nonce")
+            .await
+            .is_err());
+        let claims = client
+            .verify_id_token(&metadata, &id_token, MOCK_NONCE)
+            .await
+            .unwrap();
+        assert_eq!(claims.sub, "1234");
+        assert_eq!(claims.verified_email(), Some("me@example.com".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_oidc_client_authenticate() {
+        let mut flow = generate_oidc_flow();
+        let issuer = serve_mock_idp(&flow.code_verifier, json!({})).await;
+        let client = oidc_client(&issuer);
+
+        assert!(client.authenticate("wrong-code", &flow).await.is_err());
+        // The nonce of another login
+        assert!(client.authenticate(MOCK_CODE, &flow).await.is_err());
+        flow.nonce = MOCK_NONCE.to_string();
+        let claims = client.authenticate(MOCK_CODE, &flow).await.unwrap();
+        assert_eq!(claims.email, Some("me@example.com".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_oidc_client_rejects_id_token() {
+        let flow = generate_oidc_flow();
+        for claims in [
+            json!({"aud": "another-client"}),
+            json!({"iss": "http://evil.example.com"}),
+            json!({"exp": 1000}),
+        ] {
+            let issuer = serve_mock_idp(&flow.code_verifier, claims.clone()).await;
+            let client = oidc_client(&issuer);
+            let metadata = client.discover().await.unwrap();
+            let id_token = client
+                .exchange_code(&metadata, MOCK_CODE, &flow.code_verifier)
+                .await
+                .unwrap();
+            assert!(
+                client
+                    .verify_id_token(&metadata, &id_token, MOCK_NONCE)
+                    .await
+                    .is_err(),
+                "{claims} should be rejected"
+            );
+        }
+
+        // A token signed with the client secret instead of the JWKS
+        let issuer = serve_mock_idp(&flow.code_verifier, json!({})).await;
+        let client = oidc_client(&issuer);
+        let metadata = client.discover().await.unwrap();
+        let id_token = jwt_encode(
+            &Header::default(),
+            &json!({"iss": issuer, "aud": "portfolio", "sub": "1234", "exp": 9999999999_u64, "nonce": MOCK_NONCE}),
+            &EncodingKey::from_secret(b"client-secret"),
+        )
+        .unwrap();
+        assert!(client
+            .verify_id_token(&metadata, &id_token, MOCK_NONCE)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_oidc_client_discover() {
+        let issuer = serve_mock_idp("", json!({})).await;
+        assert!(oidc_client(&format!("{issuer}/")).discover().await.is_ok());
+
+        // Same server under another name advertises a different issuer
+        let localhost = issuer.replace("127.0.0.1", "localhost");
+        assert!(oidc_client(&localhost).discover().await.is_err());
+    }
+
+    #[test]
+    fn test_oidc_flow() {
+        let secrets = Secrets {
+            jwt_secret: "test-secret".to_string(),
+            database_url: String::new(),
+            turso_auth_token: None,
+            totp_encryption_key: None,
+            oidc_client_secret: None,
+            smtp_password: None,
+        };
+        let flow = generate_oidc_flow();
+        assert_ne!(flow.state, flow.nonce);
+        assert_eq!(flow.code_verifier.len(), 43);
+
+        let token = create_oidc_flow_token(&secrets, &flow).unwrap();
+        // A flow is not a login token
+        assert!(!verify_jwt(&token, &secrets.jwt_secret));
+
+        let mut headers = HeaderMap::new();
+        let cookie = format!("_csrf_token=abc; {OIDC_FLOW_COOKIE_NAME}{token}");
+        headers.insert(COOKIE, HeaderValue::from_str(&cookie).unwrap());
+        assert_eq!(get_oidc_flow(&headers, &secrets), Some(flow));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(COOKIE, HeaderValue::from_static("oidc_flow="));
+        assert_eq!(get_oidc_flow(&headers, &secrets), None);
+    }
+}
@@ -1,31 +1,77 @@
+use crate::handler::admin::audit::{client_ip, record_audit_log, AuditContext};
 use crate::handler::auth::csrf::{
     csrf_clear_cookie_header, csrf_set_cookie_header, generate_csrf_token, verify_csrf_token,
 };
-use crate::handler::auth::displays::{get_login_retry, get_login_sucess, get_logout};
+use crate::handler::auth::displays::{
+    get_login_email_sent, get_login_locked, get_login_oidc_result, get_login_reset_result,
+    get_login_retry, get_login_sucess, get_login_totp, get_logout,
+};
+use crate::handler::auth::email_link::{
+    consume_email_link, find_email_link_user, is_email_link_enabled, send_email_link,
+    EMAIL_LINK_INVALID,
+};
+use crate::handler::auth::lockout::{
+    find_login_lockout_seconds, lockout_message, register_failed_login, reset_failed_logins,
+};
+use crate::handler::auth::oidc::{
+    create_oidc_flow_token, generate_oidc_flow, get_oidc_flow, oidc_flow_clear_cookie_header,
+    oidc_flow_set_cookie_header, OidcClient,
+};
+use crate::handler::auth::session::{
+    append_session_clear_cookies, append_session_cookies, create_session, get_auth_session,
+    request_user_agent,
+};
+use crate::handler::auth::totp::{
+    create_totp_challenge, get_totp_challenge, totp_challenge_clear_cookie_header,
+    totp_challenge_set_cookie_header, verify_totp_or_recovery_code,
+};
 use crate::handler::auth::{
-    create_jwt, is_auth_verified, is_password_match, process_login_body, sanitize_email,
-    sanitize_password,
+    get_auth_user_id, hash_password, is_auth_verified, is_password_match,
+    is_password_rehash_needed, process_email_link_body, process_email_token_body,
+    process_login_body, process_totp_body, sanitize_email, sanitize_password,
+};
+use crate::handler::status::{
+    get_401_unauthorized, get_404_not_found, get_500_internal_server_error,
+};
+use crate::handler::{HX_REDIRECT, HX_RETARGET};
+use crate::model::audit_logs::{
+    AUDIT_ACTION_LOGIN, AUDIT_ACTION_LOGIN_FAILED, AUDIT_ACTION_LOGOUT, AUDIT_ACTION_UPDATE,
+    AUDIT_ENTITY_USER,
+};
+use crate::model::auth::{
+    EmailTokenPurpose, LoginActivity, PasswordPolicy, User, UserCommandStatus,
 };
-use crate::handler::status::get_401_unauthorized;
-use crate::handler::HX_REDIRECT;
 use crate::model::axum::AppState;
+use crate::model::oidc::{authorization_url, OidcCallbackParams, OidcConfig};
 use crate::repo::auth::AuthRepo;
 use axum::debug_handler;
-use axum::extract::State;
+use axum::extract::{ConnectInfo, Query, State};
 use axum::http::header::SET_COOKIE;
 use axum::http::HeaderMap;
-use axum::response::IntoResponse;
-use tracing::warn;
+use axum::response::{Html, IntoResponse, Redirect, Response};
+use chrono::{SecondsFormat, Utc};
+use std::net::SocketAddr;
+use tracing::{error, info, warn};
+
+/// Shown when the single sign-on fails for a reason the User cannot fix
+const OIDC_LOGIN_FAILED: &str = "Failed to log in with single sign-on. Please try again.";
 
 /// post_login
 /// Serve POST login endpoint.
 /// Verify if submitted email is valid and password matched with the hashed password.
-/// If success, redirect user to /admin/blogs
-/// If failed, inform user the email and password combination is wrong.
+/// If success, redirect user to /admin/blogs, or ask the TOTP code first if the
+/// User enabled two-factor authentication.
+/// If failed, inform user the email and password combination is wrong. Emails
+/// with too many consecutive failed logins are locked out for a while.
 #[debug_handler]
-pub async fn post_login(State(app_state): State<AppState>, body: String) -> impl IntoResponse {
+pub async fn post_login(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
     let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
-    let (email, password) = process_login_body(&body).unwrap();
+    let (email, password, remember) = process_login_body(&body).unwrap();
     let sanitized_email = sanitize_email(&email);
     let sanitized_password = sanitize_password(&password);
     if sanitized_email.is_none() {
@@ -33,65 +79,656 @@ pub async fn post_login(State(app_state): State<AppState>, body: String) -> impl
         return get_login_retry(None).await;
     }
 
-    let get_user_result = auth_uc.find_user_by_email(sanitized_email.unwrap()).await;
+    let email = sanitized_email.unwrap();
+    // The User isn't known until the password is verified
+    let audit_context = AuditContext {
+        user_id: String::new(),
+        client_ip: client_ip(&headers, addr, &app_state.config.trusted_proxies),
+    };
+    let user_agent = request_user_agent(&headers);
+    // Checked before the User so a lockout doesn't tell whether the email exists
+    if let Some(lockout_seconds) = find_login_lockout_seconds(&app_state, &email).await {
+        warn!("Rendering login locked. Email is locked out");
+        let reason = Some("locked out");
+        record_login_attempt(&app_state, &audit_context, email, &user_agent, reason).await;
+        return get_login_locked(lockout_message(lockout_seconds)).await;
+    }
+
+    let get_user_result = auth_uc.find_user_by_email(email.clone()).await;
     if get_user_result.is_none() {
         warn!("Rendering login retry. Cannot find User");
+        register_failed_login(&app_state, &email).await;
+        let reason = Some("unknown email");
+        record_login_attempt(&app_state, &audit_context, email, &user_agent, reason).await;
         return get_login_retry(None).await;
     }
 
-    let hashed_password = get_user_result.unwrap().hashed_password;
-    if !is_password_match(&sanitized_password, &hashed_password) {
+    let user = get_user_result.unwrap();
+    if !is_password_match(&sanitized_password, &user.hashed_password) {
         warn!("Rendering login retry. Password is wrong");
+        register_failed_login(&app_state, &email).await;
+        let reason = Some("wrong password");
+        record_login_attempt(&app_state, &audit_context, email, &user_agent, reason).await;
         return get_login_retry(None).await;
     }
 
-    // Create JWT (Claim and) Token
-    let token = create_jwt(&app_state.config.secrets.jwt_secret);
-    if token.is_none() {
-        warn!("Rendering login retry. Failed to generate JWT Token");
+    // Upgrade hashes made with older Argon2 parameters while the password is known
+    if is_password_rehash_needed(&user.hashed_password) {
+        let rehash_result = match hash_password(&sanitized_password) {
+            Some(hashed_password) => {
+                auth_uc
+                    .update_user(user.id.clone(), None, Some(hashed_password), None)
+                    .await
+            }
+            None => None,
+        };
+        if rehash_result.is_some() {
+            info!("Rehashed password of User {}.", &user.id);
+        } else {
+            warn!("Failed to rehash password of User {}.", &user.id);
+        }
+    }
+
+    // The JWT is only issued once the TOTP code is verified in post_login_totp
+    if user.totp_enabled {
+        info!("Password of User {} verified. Asking TOTP code.", &user.id);
+        return ask_totp_code(&app_state, &user, remember).await;
+    }
+
+    complete_login(
+        &app_state,
+        user,
+        audit_context,
+        email,
+        remember,
+        user_agent,
+        HeaderMap::new(),
+    )
+    .await
+}
+
+/// Start the TOTP challenge of a verified User and render the TOTP form in
+/// place of the login form
+async fn ask_totp_code(
+    app_state: &AppState,
+    user: &User,
+    remember: bool,
+) -> (HeaderMap, Html<String>) {
+    let secrets = &app_state.config.secrets;
+    let Some(challenge) = create_totp_challenge(secrets, &user.id, remember) else {
+        warn!("Rendering login retry. Failed to generate TOTP challenge");
         return get_login_retry(None).await;
+    };
+    let mut header_map = HeaderMap::new();
+    header_map.insert(
+        SET_COOKIE,
+        totp_challenge_set_cookie_header(&challenge)
+            .parse()
+            .unwrap(),
+    );
+    header_map.insert(HX_RETARGET, "#login_main_section_target".parse().unwrap());
+    get_login_totp(header_map, "").await
+}
+
+/// post_login_email
+/// Serve POST login email endpoint.
+/// Send a single-use login or password reset link to the email if it belongs
+/// to a User. The response is the same either way so it doesn't tell whether
+/// the email has an account.
+#[debug_handler]
+pub async fn post_login_email(State(app_state): State<AppState>, body: String) -> Html<String> {
+    if !is_email_link_enabled(&app_state).await {
+        return get_404_not_found().await;
     }
+    let (email, purpose) = process_email_link_body(&body);
+    let Some(purpose) = EmailTokenPurpose::parse(&purpose) else {
+        warn!("Rendering login email sent. Unknown purpose {}", &purpose);
+        return get_login_email_sent("Please choose which link to send.").await;
+    };
+    let Some(email) = sanitize_email(&email) else {
+        warn!("Rendering login email sent. Failed email sanitation");
+        return get_login_email_sent("Please enter a valid email.").await;
+    };
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    if let Some(user) = auth_uc.find_user_by_email(email).await {
+        // Sent in the background so the response time doesn't tell either
+        tokio::spawn(async move {
+            send_email_link(&app_state, &user, purpose).await;
+        });
+    } else {
+        info!("Requested {} link of an unknown email.", purpose.as_str());
+    }
+    get_login_email_sent("").await
+}
+
+/// post_login_email_verify
+/// Serve POST login email verify endpoint.
+/// Use up the login link sent by email and log in its User, or ask the TOTP
+/// code first if the User enabled two-factor authentication.
+#[debug_handler]
+pub async fn post_login_email_verify(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    if !is_email_link_enabled(&app_state).await {
+        return (HeaderMap::new(), get_404_not_found().await);
+    }
+    let (token, _, _) = process_email_token_body(&body);
+    let Some(user) = consume_email_link(&app_state, &token, EmailTokenPurpose::Login).await else {
+        warn!("Rendering login locked. Login link is invalid");
+        return get_login_locked(EMAIL_LINK_INVALID.to_string()).await;
+    };
+
+    if user.totp_enabled {
+        info!(
+            "Login link of User {} verified. Asking TOTP code.",
+            &user.id
+        );
+        return ask_totp_code(&app_state, &user, false).await;
+    }
+
+    let audit_context = AuditContext {
+        user_id: String::new(),
+        client_ip: client_ip(&headers, addr, &app_state.config.trusted_proxies),
+    };
+    let user_agent = request_user_agent(&headers);
+    let email = user.email.clone();
+    complete_login(
+        &app_state,
+        user,
+        audit_context,
+        email,
+        false,
+        user_agent,
+        HeaderMap::new(),
+    )
+    .await
+}
+
+/// post_login_reset
+/// Serve POST login reset endpoint.
+/// Set the new password of the User of a password reset link sent by email.
+/// The new password has to follow the PasswordPolicy. Once it is set, the link
+/// is used up and every Session and pending link of the User is revoked.
+#[debug_handler]
+pub async fn post_login_reset(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    if !is_email_link_enabled(&app_state).await {
+        return (HeaderMap::new(), get_404_not_found().await);
+    }
+    let purpose = EmailTokenPurpose::PasswordReset;
+    let invalid_link = vec![EMAIL_LINK_INVALID.to_string()];
+    let (token, new_password, confirm_password) = process_email_token_body(&body);
+    // Checked before the link is used up so a rejected password can be retried
+    if find_email_link_user(&app_state, &token, purpose)
+        .await
+        .is_none()
+    {
+        warn!("Rendering login reset result. Password reset link is invalid");
+        return get_login_reset_result(HeaderMap::new(), invalid_link).await;
+    }
+    let new_password = sanitize_password(&new_password);
+    let confirm_password = sanitize_password(&confirm_password);
+    let errors = if new_password != confirm_password {
+        vec!["New passwords do not match.".to_string()]
+    } else {
+        PasswordPolicy::from(&app_state.config).check(&new_password)
+    };
+    if !errors.is_empty() {
+        info!("Rejected password reset. {}", errors.join(" "));
+        return get_login_reset_result(HeaderMap::new(), errors).await;
+    }
+
+    let Some(user) = consume_email_link(&app_state, &token, purpose).await else {
+        warn!("Rendering login reset result. Password reset link was used meanwhile");
+        return get_login_reset_result(HeaderMap::new(), invalid_link).await;
+    };
+    let Some(hashed_password) = hash_password(&new_password) else {
+        return (HeaderMap::new(), get_500_internal_server_error());
+    };
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let update_result = auth_uc
+        .update_user(user.id.clone(), None, Some(hashed_password), None)
+        .await;
+    if update_result != Some(UserCommandStatus::Updated) {
+        error!("Failed to reset the password of User {}.", &user.id);
+        return (HeaderMap::new(), get_500_internal_server_error());
+    }
+
+    // Whoever knew the old password is logged out and cannot use older links
+    if auth_uc
+        .delete_user_sessions(user.id.clone())
+        .await
+        .is_none()
+    {
+        warn!("Failed to revoke Sessions of User {}.", &user.id);
+    }
+    if auth_uc
+        .delete_user_email_tokens(user.id.clone())
+        .await
+        .is_none()
+    {
+        warn!("Failed to revoke email tokens of User {}.", &user.id);
+    }
+    reset_failed_logins(&app_state, &user.email).await;
+    let audit_context = AuditContext {
+        user_id: user.id.clone(),
+        client_ip: client_ip(&headers, addr, &app_state.config.trusted_proxies),
+    };
+    record_audit_log(
+        &app_state,
+        &audit_context,
+        AUDIT_ACTION_UPDATE,
+        AUDIT_ENTITY_USER,
+        user.email,
+        String::new(),
+        "password reset by email".to_string(),
+    )
+    .await;
+    info!("Reset the password of User {}.", &user.id);
 
-    let csrf_token = generate_csrf_token();
     let mut header_map = HeaderMap::new();
-    let jwt_token_cookie = format!(
-        "token={}; Secure; HttpOnly; SameSite=Strict",
-        token.unwrap()
+    header_map.insert(HX_RETARGET, "#login_main_section_target".parse().unwrap());
+    get_login_reset_result(header_map, Vec::new()).await
+}
+
+/// post_login_totp
+/// Serve POST login TOTP endpoint.
+/// Second login step of Users with two-factor authentication. Verify the TOTP
+/// code, or consume a recovery code, of the User in the `totp_challenge` cookie.
+#[debug_handler]
+pub async fn post_login_totp(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    body: String,
+) -> impl IntoResponse {
+    let expired = "Your login expired. Please reload the page and log in again.";
+    let secrets = &app_state.config.secrets;
+    let Some(challenge) = get_totp_challenge(&headers, secrets) else {
+        warn!("Rendering login TOTP. Challenge is missing or expired");
+        return get_login_totp(HeaderMap::new(), expired).await;
+    };
+    let user_id = challenge.sub;
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let user = auth_uc.find_user_by_id(user_id.clone()).await;
+    let user_totp = auth_uc
+        .find_user_totp(user_id.clone())
+        .await
+        .filter(|user_totp| user_totp.enabled);
+    // The User or their TOTP may be removed while the challenge is pending
+    let (Some(user), Some(mut user_totp)) = (user, user_totp) else {
+        warn!("Rendering login TOTP. User {} has no TOTP", &user_id);
+        return get_login_totp(HeaderMap::new(), expired).await;
+    };
+
+    let audit_context = AuditContext {
+        user_id: String::new(),
+        client_ip: client_ip(&headers, addr, &app_state.config.trusted_proxies),
+    };
+    let user_agent = request_user_agent(&headers);
+    // Wrong codes count towards the lockout so codes cannot be guessed either
+    if let Some(lockout_seconds) = find_login_lockout_seconds(&app_state, &user.email).await {
+        warn!("Rendering login TOTP. Email is locked out");
+        let email = user.email;
+        let reason = Some("locked out");
+        record_login_attempt(&app_state, &audit_context, email, &user_agent, reason).await;
+        return get_login_totp(HeaderMap::new(), &lockout_message(lockout_seconds)).await;
+    }
+    let code = process_totp_body(&body);
+    let now = Utc::now().timestamp();
+    if !verify_totp_or_recovery_code(secrets, &mut user_totp, &code, now) {
+        warn!("Rendering login TOTP. Code is wrong");
+        register_failed_login(&app_state, &user.email).await;
+        let email = user.email;
+        let reason = Some("wrong totp code");
+        record_login_attempt(&app_state, &audit_context, email, &user_agent, reason).await;
+        return get_login_totp(HeaderMap::new(), "The code is incorrect. Please try again.").await;
+    }
+    // Store the used step or recovery code so it cannot be replayed
+    if auth_uc.upsert_user_totp(user_totp).await.is_none() {
+        warn!("Rendering login TOTP. Failed to store used TOTP code");
+        return get_login_totp(HeaderMap::new(), expired).await;
+    }
+
+    let mut header_map = HeaderMap::new();
+    header_map.insert(
+        SET_COOKIE,
+        totp_challenge_clear_cookie_header().parse().unwrap(),
     );
-    header_map.insert(SET_COOKIE, jwt_token_cookie.parse().unwrap());
-    header_map.append(
+    let email = user.email.clone();
+    complete_login(
+        &app_state,
+        user,
+        audit_context,
+        email,
+        challenge.remember,
+        user_agent,
+        header_map,
+    )
+    .await
+}
+
+/// get_login_oidc
+/// Serve GET login OIDC endpoint.
+/// Start the OpenID Connect authorization code flow with PKCE. The state, nonce
+/// and code verifier are kept in the `oidc_flow` cookie until the identity
+/// provider redirects back to get_login_oidc_callback.
+pub async fn get_login_oidc(State(app_state): State<AppState>) -> Response {
+    let Some(oidc_config) = OidcConfig::from_config(&app_state.config) else {
+        return get_404_not_found().await.into_response();
+    };
+    let client = OidcClient::new(oidc_config);
+    let metadata = match client.discover().await {
+        Ok(metadata) => metadata,
+        Err(err) => {
+            warn!("Rendering login OIDC. Failed to discover identity provider. {err}");
+            return get_login_oidc_result(HeaderMap::new(), OIDC_LOGIN_FAILED, false)
+                .await
+                .into_response();
+        }
+    };
+
+    let flow = generate_oidc_flow();
+    let Some(flow_token) = create_oidc_flow_token(&app_state.config.secrets, &flow) else {
+        warn!("Rendering login OIDC. Failed to generate OIDC flow");
+        return get_login_oidc_result(HeaderMap::new(), OIDC_LOGIN_FAILED, false)
+            .await
+            .into_response();
+    };
+    let mut header_map = HeaderMap::new();
+    header_map.insert(
         SET_COOKIE,
-        csrf_set_cookie_header(&csrf_token).parse().unwrap(),
+        oidc_flow_set_cookie_header(&flow_token).parse().unwrap(),
     );
+    info!("Redirecting to identity provider {}.", &metadata.issuer);
+    let redirect = Redirect::to(&authorization_url(&metadata, client.oidc_config(), &flow));
+    (header_map, redirect).into_response()
+}
+
+/// get_login_oidc_callback
+/// Serve GET login OIDC callback endpoint.
+/// Exchange the authorization code for an ID token and log in the User whose
+/// email matches the verified email of the ID token. Users with two-factor
+/// authentication are asked their TOTP code first.
+pub async fn get_login_oidc_callback(
+    State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Query(params): Query<OidcCallbackParams>,
+) -> Response {
+    let Some(oidc_config) = OidcConfig::from_config(&app_state.config) else {
+        return get_404_not_found().await.into_response();
+    };
+    // A flow is only used once
+    let mut header_map = HeaderMap::new();
+    header_map.insert(SET_COOKIE, oidc_flow_clear_cookie_header().parse().unwrap());
+
+    let secrets = &app_state.config.secrets;
+    let Some(flow) = get_oidc_flow(&headers, secrets) else {
+        warn!("Rendering login OIDC. Flow is missing or expired");
+        let expired = "Your login expired. Please try again.";
+        return get_login_oidc_result(header_map, expired, false)
+            .await
+            .into_response();
+    };
+    if let Some(error) = &params.error {
+        warn!(
+            "Rendering login OIDC. Identity provider returned {}. {}",
+            error,
+            params.error_description.clone().unwrap_or_default()
+        );
+        return get_login_oidc_result(header_map, OIDC_LOGIN_FAILED, false)
+            .await
+            .into_response();
+    }
+    let code = match params.code {
+        Some(code) if params.state.as_deref() == Some(flow.state.as_str()) => code,
+        _ => {
+            warn!("Rendering login OIDC. State doesn't match or code is missing");
+            return get_login_oidc_result(header_map, OIDC_LOGIN_FAILED, false)
+                .await
+                .into_response();
+        }
+    };
+    let claims = match OidcClient::new(oidc_config)
+        .authenticate(&code, &flow)
+        .await
+    {
+        Ok(claims) => claims,
+        Err(err) => {
+            warn!("Rendering login OIDC. {err}");
+            return get_login_oidc_result(header_map, OIDC_LOGIN_FAILED, false)
+                .await
+                .into_response();
+        }
+    };
+
+    let not_allowed = "Your account is not allowed to access the admin pages.";
+    let audit_context = AuditContext {
+        user_id: String::new(),
+        client_ip: client_ip(&headers, addr, &app_state.config.trusted_proxies),
+    };
+    let user_agent = request_user_agent(&headers);
+    let Some(email) = claims.verified_email() else {
+        warn!(
+            "Rendering login OIDC. Email of {} isn't verified",
+            &claims.sub
+        );
+        let email = claims.email.unwrap_or_default();
+        let reason = Some("unverified email");
+        record_login_attempt(&app_state, &audit_context, email, &user_agent, reason).await;
+        return get_login_oidc_result(header_map, not_allowed, false)
+            .await
+            .into_response();
+    };
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let user = match sanitize_email(&email) {
+        Some(email) => auth_uc.find_user_by_email(email).await,
+        None => None,
+    };
+    let Some(user) = user else {
+        warn!("Rendering login OIDC. Cannot find User");
+        let reason = Some("unknown email");
+        record_login_attempt(&app_state, &audit_context, email, &user_agent, reason).await;
+        return get_login_oidc_result(header_map, not_allowed, false)
+            .await
+            .into_response();
+    };
+
+    if user.totp_enabled {
+        let Some(challenge) = create_totp_challenge(secrets, &user.id, false) else {
+            warn!("Rendering login OIDC. Failed to generate TOTP challenge");
+            return get_login_oidc_result(header_map, OIDC_LOGIN_FAILED, false)
+                .await
+                .into_response();
+        };
+        header_map.append(
+            SET_COOKIE,
+            totp_challenge_set_cookie_header(&challenge)
+                .parse()
+                .unwrap(),
+        );
+        info!(
+            "OIDC login of User {} verified. Asking TOTP code.",
+            &user.id
+        );
+        return get_login_oidc_result(header_map, "", true)
+            .await
+            .into_response();
+    }
+
+    let email = user.email.clone();
+    if !set_login_cookies(
+        &app_state,
+        user,
+        audit_context,
+        email,
+        false,
+        user_agent,
+        &mut header_map,
+    )
+    .await
+    {
+        return get_login_oidc_result(header_map, OIDC_LOGIN_FAILED, false)
+            .await
+            .into_response();
+    }
+    (header_map, Redirect::to("/admin")).into_response()
+}
+
+/// Issue the JWT and CSRF cookies of a verified User and redirect to /admin
+/// `header_map` holds response headers set by the login step.
+async fn complete_login(
+    app_state: &AppState,
+    user: User,
+    audit_context: AuditContext,
+    email: String,
+    remember: bool,
+    user_agent: String,
+    mut header_map: HeaderMap,
+) -> (HeaderMap, Html<String>) {
+    if !set_login_cookies(
+        app_state,
+        user,
+        audit_context,
+        email,
+        remember,
+        user_agent,
+        &mut header_map,
+    )
+    .await
+    {
+        return get_login_retry(None).await;
+    }
     header_map.insert(HX_REDIRECT, "/admin".parse().unwrap());
 
     // Render HTML with header to set JWT Token in header
     get_login_sucess(Some(header_map)).await
 }
 
+/// Create the Session of a verified User, append its token and CSRF cookies,
+/// record the login and reset the failed logins of the email
+/// Return false if the Session cannot be created.
+async fn set_login_cookies(
+    app_state: &AppState,
+    user: User,
+    mut audit_context: AuditContext,
+    email: String,
+    remember: bool,
+    user_agent: String,
+    header_map: &mut HeaderMap,
+) -> bool {
+    // Create the Session, its JWT (Claim and) Token and refresh token
+    let client_ip = audit_context.client_ip.clone();
+    let Some(tokens) =
+        create_session(app_state, &user, remember, user_agent.clone(), client_ip).await
+    else {
+        warn!("Failed to create Session");
+        return false;
+    };
+
+    let csrf_token = generate_csrf_token();
+    append_session_cookies(header_map, &app_state.config, &tokens);
+    header_map.append(
+        SET_COOKIE,
+        csrf_set_cookie_header(&csrf_token).parse().unwrap(),
+    );
+    audit_context.user_id = user.id;
+    reset_failed_logins(app_state, &email).await;
+    record_login_attempt(app_state, &audit_context, email, &user_agent, None).await;
+    true
+}
+
+/// Record a login attempt to be shown in the admin dashboard, the login history
+/// of the account and the audit log
+/// `failure` is the reason a login was rejected. `None` if it succeeded.
+async fn record_login_attempt(
+    app_state: &AppState,
+    audit_context: &AuditContext,
+    email: String,
+    user_agent: &str,
+    failure: Option<&str>,
+) {
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    let login_activity = LoginActivity {
+        email: email.clone(),
+        succeeded: failure.is_none(),
+        reason: failure.unwrap_or_default().to_string(),
+        client_ip: audit_context.client_ip.clone(),
+        user_agent: user_agent.to_string(),
+        created_at: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+    };
+    if auth_uc.add_login_activity(login_activity).await.is_none() {
+        warn!("Failed to record login activity.");
+    }
+
+    let action = match failure {
+        Some(_) => AUDIT_ACTION_LOGIN_FAILED,
+        None => AUDIT_ACTION_LOGIN,
+    };
+    record_audit_log(
+        app_state,
+        audit_context,
+        action,
+        AUDIT_ENTITY_USER,
+        email,
+        String::new(),
+        failure.unwrap_or_default().to_string(),
+    )
+    .await;
+}
+
 /// delete_logout
 /// Serve DELETE logout endpoint.
-/// Remove JWT from client cookie
+/// Delete the Session and remove JWT from client cookie
 pub async fn delete_logout(
     State(app_state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
 ) -> impl IntoResponse {
     let mut resp_headers = HeaderMap::new();
 
-    if !is_auth_verified(headers.clone(), &app_state.config.secrets.jwt_secret)
-        || !verify_csrf_token(&headers)
-    {
+    if !is_auth_verified(headers.clone(), &app_state).await || !verify_csrf_token(&headers) {
         let unauthorized = get_401_unauthorized().await;
         return (resp_headers, unauthorized);
     }
 
+    // Login events are recorded by email so the logout is recorded the same way
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    if let Some((_, session)) = get_auth_session(&headers, &app_state).await {
+        if auth_uc.delete_session(session.id.clone()).await.is_none() {
+            warn!("Failed to delete Session {}.", &session.id);
+        }
+    }
+    let email = match get_auth_user_id(&headers, &app_state.config.secrets.jwt_secret) {
+        Some(user_id) => auth_uc
+            .find_user_by_id(user_id)
+            .await
+            .map(|user| user.email),
+        None => None,
+    };
+    record_audit_log(
+        &app_state,
+        &AuditContext::from_request(&app_state, &headers, addr),
+        AUDIT_ACTION_LOGOUT,
+        AUDIT_ENTITY_USER,
+        email.unwrap_or_default(),
+        String::new(),
+        String::new(),
+    )
+    .await;
+
     // Redirect User if token present to Admin Blogs
-    resp_headers.insert(
-        SET_COOKIE,
-        "token=; Secure; HttpOnly; SameSite=Strict; Max-Age=0"
-            .parse()
-            .unwrap(),
-    );
+    append_session_clear_cookies(&mut resp_headers);
     resp_headers.append(SET_COOKIE, csrf_clear_cookie_header().parse().unwrap());
     resp_headers.insert(HX_REDIRECT, "/".parse().unwrap());
 
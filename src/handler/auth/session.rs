@@ -0,0 +1,592 @@
+use crate::config::Config;
+use crate::handler::auth::{
+    create_jwt, decode_jwt, extract_cookie_from_cookies, get_auth_claims, get_auth_token,
+    JWT_COOKIE_NAME,
+};
+use crate::model::auth::{Claims, Session, User};
+use crate::model::axum::AppState;
+use crate::repo::auth::AuthRepo;
+use axum::extract::{Request, State};
+use axum::http::header::{COOKIE, SET_COOKIE, USER_AGENT};
+use axum::http::{HeaderMap, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use chrono::{Duration, SecondsFormat, Utc};
+use ring::digest::{digest, SHA256};
+use ring::rand::{SecureRandom, SystemRandom};
+use tracing::{debug, info, warn};
+
+/// Name of the refresh token cookie as it appears in the `Cookie` header.
+pub const REFRESH_COOKIE_NAME: &str = "refresh_token=";
+/// Max characters of a user agent stored in a Session
+const USER_AGENT_MAX_LENGTH: usize = 512;
+
+/// SessionTokens
+/// Tokens of an active Session to be set as cookies:
+/// - access_token: Short-lived JWT of the `token` cookie
+/// - refresh_token: `<session id>.<secret>` of the `refresh_token` cookie
+/// - remember: Keep the refresh cookie after the browser is closed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionTokens {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub remember: bool,
+}
+
+/// Generate `length` random bytes formatted as hex
+pub fn generate_random_hex(length: usize) -> String {
+    let rng = SystemRandom::new();
+    let mut bytes = vec![0u8; length];
+    rng.fill(&mut bytes)
+        .expect("Failed to generate Session secret");
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Hash a refresh token to be stored in its Session
+pub fn hash_refresh_token(token: &str) -> String {
+    digest(&SHA256, token.as_bytes())
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Return the user agent of a request, truncated to be stored
+pub fn request_user_agent(headers: &HeaderMap) -> String {
+    headers
+        .get(USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .chars()
+        .take(USER_AGENT_MAX_LENGTH)
+        .collect()
+}
+
+/// Expire date time of a Session active now, in RFC 3339
+fn session_expire(config: &Config, remember: bool) -> String {
+    let ttl = match remember {
+        true => config.remember_me_ttl,
+        false => config.session_ttl,
+    };
+    (Utc::now() + Duration::seconds(ttl as i64)).to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Create the Session of a verified User and return its tokens
+pub async fn create_session(
+    app_state: &AppState,
+    user: &User,
+    remember: bool,
+    user_agent: String,
+    client_ip: String,
+) -> Option<SessionTokens> {
+    let config = &app_state.config;
+    let session_id = generate_random_hex(16);
+    let refresh_token = format!("{}.{}", &session_id, generate_random_hex(32));
+    let access_token = create_jwt(
+        &config.secrets.jwt_secret,
+        &user.id,
+        user.role,
+        &session_id,
+        config.access_token_ttl,
+    )?;
+    let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+    let session = Session {
+        id: session_id,
+        user_id: user.id.clone(),
+        token: hash_refresh_token(&refresh_token),
+        expire: session_expire(config, remember),
+        user_agent,
+        client_ip,
+        created_at: now.clone(),
+        last_seen_at: now,
+        remember,
+    };
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+    auth_uc.add_session(session).await?;
+    Some(SessionTokens {
+        access_token,
+        refresh_token,
+        remember,
+    })
+}
+
+/// refresh_session
+/// Issue a new access token of the Session in the `refresh_token` cookie and
+/// extend the Session. The role is read again so role changes apply.
+/// Return None if the Session was revoked or expired.
+pub async fn refresh_session(header: &HeaderMap, app_state: &AppState) -> Option<SessionTokens> {
+    let refresh_token = header
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find_map(|cookies| extract_cookie_from_cookies(cookies, REFRESH_COOKIE_NAME))?;
+    let (session_id, _) = refresh_token.split_once('.')?;
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone()?;
+    let Some(session) = auth_uc.find_session(session_id.to_string()).await else {
+        info!("Session {} of the refresh token is revoked.", session_id);
+        return None;
+    };
+    if session.token != hash_refresh_token(&refresh_token) || session.is_expired(Utc::now()) {
+        warn!(
+            "Refresh token doesn't match Session {} or it expired.",
+            &session.id
+        );
+        return None;
+    }
+    let Some(user) = auth_uc.find_user_by_id(session.user_id.clone()).await else {
+        warn!(
+            "User {} of Session {} is removed.",
+            &session.user_id, &session.id
+        );
+        return None;
+    };
+
+    let config = &app_state.config;
+    let access_token = create_jwt(
+        &config.secrets.jwt_secret,
+        &user.id,
+        user.role,
+        &session.id,
+        config.access_token_ttl,
+    )?;
+    let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+    let expire = session_expire(config, session.remember);
+    auth_uc
+        .extend_session(session.id.clone(), expire, now)
+        .await?;
+    debug!("Refreshed access token of Session {}.", &session.id);
+    Some(SessionTokens {
+        access_token,
+        refresh_token,
+        remember: session.remember,
+    })
+}
+
+/// Whether the access token of the `token` cookie is valid for more than half
+/// of its lifetime. Older tokens are refreshed so active users stay logged in.
+fn is_access_token_fresh(header: &HeaderMap, config: &Config) -> bool {
+    let now = Utc::now().timestamp() as usize;
+    get_auth_claims(header, &config.secrets.jwt_secret)
+        .is_some_and(|claims| claims.exp > now + config.access_token_ttl as usize / 2)
+}
+
+/// Replace the `token` cookie of a request with a refreshed access token
+fn replace_access_token_cookie(header: &mut HeaderMap, access_token: &str) {
+    let mut cookies: Vec<String> = header
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split("; "))
+        .filter(|cookie| !cookie.starts_with(JWT_COOKIE_NAME))
+        .map(|cookie| cookie.to_string())
+        .collect();
+    cookies.push(format!("{JWT_COOKIE_NAME}{access_token}"));
+    match HeaderValue::from_str(&cookies.join("; ")) {
+        Ok(value) => {
+            header.insert(COOKIE, value);
+        }
+        Err(err) => warn!("Failed to replace the access token cookie. {}", err),
+    }
+}
+
+/// refresh_access_token
+/// Middleware reissuing the access token of admin requests on activity
+/// A missing, expired or half-expired access token is replaced with one of the
+/// Session in the `refresh_token` cookie, both for the handler and the browser.
+pub async fn refresh_access_token(
+    State(app_state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let refreshed = match is_access_token_fresh(request.headers(), &app_state.config) {
+        true => None,
+        false => refresh_session(request.headers(), &app_state).await,
+    };
+    if let Some(tokens) = &refreshed {
+        replace_access_token_cookie(request.headers_mut(), &tokens.access_token);
+    }
+
+    let mut response = next.run(request).await;
+    let Some(tokens) = refreshed else {
+        return response;
+    };
+    // Handlers logging in or out set the cookies themselves
+    let sets_access_token = response
+        .headers()
+        .get_all(SET_COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .any(|value| value.starts_with(JWT_COOKIE_NAME));
+    if !sets_access_token {
+        append_session_cookies(response.headers_mut(), &app_state.config, &tokens);
+    }
+    response
+}
+
+/// get_auth_session
+/// Return the Claims and the active Session of the `token` cookie
+/// Tokens without a Session, of a revoked or expired Session, or of another
+/// User return None. The last seen time is updated at most once a minute.
+pub async fn get_auth_session(
+    header: &HeaderMap,
+    app_state: &AppState,
+) -> Option<(Claims, Session)> {
+    let token = get_auth_token(header)?;
+    let claims = decode_jwt(&token, &app_state.config.secrets.jwt_secret)?;
+    if claims.sid.is_empty() {
+        info!("Token of User {} has no Session.", &claims.sub);
+        return None;
+    }
+
+    let auth_uc = app_state.auth_db_usecase.lock().await.clone()?;
+    let Some(session) = auth_uc.find_session(claims.sid.clone()).await else {
+        info!("Session {} is revoked.", &claims.sid);
+        return None;
+    };
+    let now = Utc::now();
+    if session.user_id != claims.sub || session.is_expired(now) {
+        warn!("Token doesn't match Session {} or it expired.", &session.id);
+        return None;
+    }
+
+    if session.is_last_seen_stale(now) {
+        let last_seen_at = now.to_rfc3339_opts(SecondsFormat::Secs, true);
+        if auth_uc
+            .update_session_last_seen(session.id.clone(), last_seen_at)
+            .await
+            .is_none()
+        {
+            warn!("Failed to update last seen of Session {}.", &session.id);
+        }
+    }
+    Some((claims, session))
+}
+
+/// Append the `token` and `refresh_token` cookies of a Session
+/// The refresh cookie only outlives the browser if "remember me" was checked.
+pub fn append_session_cookies(header: &mut HeaderMap, config: &Config, tokens: &SessionTokens) {
+    let access_cookie = format!(
+        "{}{}; Secure; HttpOnly; SameSite=Strict; Path=/; Max-Age={}",
+        JWT_COOKIE_NAME, tokens.access_token, config.access_token_ttl
+    );
+    let refresh_max_age = match tokens.remember {
+        true => format!("; Max-Age={}", config.remember_me_ttl),
+        false => String::new(),
+    };
+    let refresh_cookie = format!(
+        "{}{}; Secure; HttpOnly; SameSite=Strict; Path=/{}",
+        REFRESH_COOKIE_NAME, tokens.refresh_token, refresh_max_age
+    );
+    header.append(SET_COOKIE, access_cookie.parse().unwrap());
+    header.append(SET_COOKIE, refresh_cookie.parse().unwrap());
+}
+
+/// Append headers clearing the `token` and `refresh_token` cookies
+pub fn append_session_clear_cookies(header: &mut HeaderMap) {
+    for cookie_name in [JWT_COOKIE_NAME, REFRESH_COOKIE_NAME] {
+        let clear_cookie =
+            format!("{cookie_name}; Secure; HttpOnly; SameSite=Strict; Path=/; Max-Age=0");
+        header.append(SET_COOKIE, clear_cookie.parse().unwrap());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::handler::auth::is_auth_verified;
+    use crate::model::auth::UserRole;
+    use crate::state::state_factory;
+    use axum::body::{to_bytes, Body};
+    use axum::middleware::from_fn_with_state;
+    use axum::routing::get;
+    use axum::Router;
+    use chrono::DateTime;
+    use tower::ServiceExt;
+
+    async fn app_state() -> AppState {
+        let mut config = Config::default();
+        config.secrets.database_url = ":memory:".to_string();
+        state_factory(config).await
+    }
+
+    /// Store and return `user-1`
+    async fn add_user(app_state: &AppState, role: UserRole) -> User {
+        let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+        auth_uc
+            .add_user(
+                "user-1".to_string(),
+                "me@example.com".to_string(),
+                String::new(),
+                role,
+            )
+            .await
+            .unwrap();
+        auth_uc.find_user_by_id("user-1".to_string()).await.unwrap()
+    }
+
+    fn headers(cookie: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(COOKIE, HeaderValue::from_str(cookie).unwrap());
+        headers.insert(USER_AGENT, HeaderValue::from_static("curl/8.5.0"));
+        headers
+    }
+
+    fn access_headers(tokens: &SessionTokens) -> HeaderMap {
+        headers(&format!("_csrf_token=abc; token={}", tokens.access_token))
+    }
+
+    fn refresh_headers(tokens: &SessionTokens) -> HeaderMap {
+        headers(&format!(
+            "_csrf_token=abc; refresh_token={}",
+            tokens.refresh_token
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_session_lifecycle() {
+        let app_state = app_state().await;
+        let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+        let user = add_user(&app_state, UserRole::Editor).await;
+        let tokens = create_session(
+            &app_state,
+            &user,
+            false,
+            "curl/8.5.0".to_string(),
+            "::1".to_string(),
+        )
+        .await
+        .unwrap();
+
+        let (claims, session) = get_auth_session(&access_headers(&tokens), &app_state)
+            .await
+            .unwrap();
+        assert_eq!(claims.sub, "user-1");
+        assert_eq!(session.id, claims.sid);
+        assert_eq!(session.token, hash_refresh_token(&tokens.refresh_token));
+        assert!(tokens
+            .refresh_token
+            .starts_with(&format!("{}.", session.id)));
+        assert_eq!(session.device(), "curl");
+        assert!(!session.remember);
+        assert!(is_auth_verified(access_headers(&tokens), &app_state).await);
+
+        // Revoked Sessions reject their token even though the JWT is valid
+        auth_uc.delete_session(session.id).await.unwrap();
+        assert!(!is_auth_verified(access_headers(&tokens), &app_state).await);
+        assert!(refresh_session(&refresh_headers(&tokens), &app_state)
+            .await
+            .is_none());
+
+        let first = create_session(&app_state, &user, false, String::new(), String::new())
+            .await
+            .unwrap();
+        let second = create_session(&app_state, &user, true, String::new(), String::new())
+            .await
+            .unwrap();
+        let sessions = auth_uc.find_user_sessions(user.id.clone()).await.unwrap();
+        assert_eq!(sessions.len(), 2);
+        auth_uc.delete_user_sessions(user.id.clone()).await.unwrap();
+        assert!(!is_auth_verified(access_headers(&first), &app_state).await);
+        assert!(!is_auth_verified(access_headers(&second), &app_state).await);
+    }
+
+    #[tokio::test]
+    async fn test_get_auth_session_rejects_token() {
+        let app_state = app_state().await;
+        let secret = &app_state.config.secrets.jwt_secret;
+        let user = add_user(&app_state, UserRole::Viewer).await;
+        let tokens = create_session(&app_state, &user, false, String::new(), String::new())
+            .await
+            .unwrap();
+        let (claims, _) = get_auth_session(&access_headers(&tokens), &app_state)
+            .await
+            .unwrap();
+
+        // A token of another User cannot borrow an existing Session
+        let borrowed = create_jwt(secret, "user-2", UserRole::Owner, &claims.sid, 900).unwrap();
+        assert!(
+            get_auth_session(&headers(&format!("token={borrowed}")), &app_state)
+                .await
+                .is_none()
+        );
+        // Tokens issued before Sessions were added
+        let legacy = create_jwt(secret, "user-1", UserRole::Owner, "", 900).unwrap();
+        assert!(
+            get_auth_session(&headers(&format!("token={legacy}")), &app_state)
+                .await
+                .is_none()
+        );
+        assert!(get_auth_session(&HeaderMap::new(), &app_state)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_session() {
+        let app_state = app_state().await;
+        let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+        let user = add_user(&app_state, UserRole::Viewer).await;
+        let tokens = create_session(&app_state, &user, true, String::new(), String::new())
+            .await
+            .unwrap();
+        let session_id = tokens.refresh_token.split_once('.').unwrap().0.to_string();
+        // Pretend the Session is about to expire
+        auth_uc
+            .extend_session(
+                session_id.clone(),
+                (Utc::now() + Duration::seconds(5)).to_rfc3339_opts(SecondsFormat::Secs, true),
+                String::new(),
+            )
+            .await
+            .unwrap();
+
+        // Role changes apply on the next refresh
+        auth_uc
+            .update_user("user-1".to_string(), None, None, Some(UserRole::Editor))
+            .await
+            .unwrap();
+        let refreshed = refresh_session(&refresh_headers(&tokens), &app_state)
+            .await
+            .unwrap();
+        assert_eq!(refreshed.refresh_token, tokens.refresh_token);
+        assert!(refreshed.remember);
+        let (claims, session) = get_auth_session(&access_headers(&refreshed), &app_state)
+            .await
+            .unwrap();
+        assert_eq!(claims.role, UserRole::Editor);
+        // Remembered Sessions are extended by REMEMBER_ME_TTL_SECOND
+        let expire = DateTime::parse_from_rfc3339(&session.expire).unwrap();
+        assert!(expire > Utc::now() + Duration::days(29));
+
+        let guessed = SessionTokens {
+            refresh_token: format!("{session_id}.{}", "0".repeat(64)),
+            ..tokens.clone()
+        };
+        assert!(refresh_session(&refresh_headers(&guessed), &app_state)
+            .await
+            .is_none());
+        assert!(
+            refresh_session(&headers("refresh_token=garbage"), &app_state)
+                .await
+                .is_none()
+        );
+    }
+
+    async fn run_refresh(app_state: &AppState, headers: HeaderMap) -> (Vec<String>, String) {
+        let app = Router::new()
+            .route(
+                "/",
+                get(
+                    |State(app_state): State<AppState>, headers: HeaderMap| async move {
+                        is_auth_verified(headers, &app_state).await.to_string()
+                    },
+                ),
+            )
+            .layer(from_fn_with_state(app_state.clone(), refresh_access_token))
+            .with_state(app_state.clone());
+        let mut request = Request::builder().uri("/").body(Body::empty()).unwrap();
+        *request.headers_mut() = headers;
+        let response = app.oneshot(request).await.unwrap();
+        let set_cookies = response
+            .headers()
+            .get_all(SET_COOKIE)
+            .iter()
+            .map(|value| value.to_str().unwrap().to_string())
+            .collect();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (set_cookies, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_access_token() {
+        let app_state = app_state().await;
+        let user = add_user(&app_state, UserRole::Owner).await;
+        let tokens = create_session(&app_state, &user, false, String::new(), String::new())
+            .await
+            .unwrap();
+
+        // Fresh access tokens are kept
+        let (set_cookies, body) = run_refresh(&app_state, access_headers(&tokens)).await;
+        assert!(set_cookies.is_empty());
+        assert_eq!(body, "true");
+
+        // The access token expired and the browser dropped it
+        let (set_cookies, body) = run_refresh(&app_state, refresh_headers(&tokens)).await;
+        assert_eq!(body, "true");
+        assert_eq!(set_cookies.len(), 2);
+        assert!(set_cookies[0].starts_with("token="));
+        assert!(set_cookies[0].ends_with("; Path=/; Max-Age=900"));
+        // Not remembered, the refresh cookie ends with the browser session
+        assert!(set_cookies[1].starts_with("refresh_token="));
+        assert!(!set_cookies[1].contains("Max-Age"));
+
+        let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+        auth_uc
+            .delete_user_sessions("user-1".to_string())
+            .await
+            .unwrap();
+        let (set_cookies, body) = run_refresh(&app_state, refresh_headers(&tokens)).await;
+        assert!(set_cookies.is_empty());
+        assert_eq!(body, "false");
+    }
+
+    #[test]
+    fn test_append_session_cookies() {
+        let config = Config::default();
+        let tokens = SessionTokens {
+            access_token: "jwt".to_string(),
+            refresh_token: "session-1.secret".to_string(),
+            remember: true,
+        };
+        let mut header = HeaderMap::new();
+        append_session_cookies(&mut header, &config, &tokens);
+        let set_cookies: Vec<&str> = header
+            .get_all(SET_COOKIE)
+            .iter()
+            .map(|value| value.to_str().unwrap())
+            .collect();
+        assert_eq!(
+            set_cookies,
+            vec![
+                "token=jwt; Secure; HttpOnly; SameSite=Strict; Path=/; Max-Age=900",
+                "refresh_token=session-1.secret; Secure; HttpOnly; SameSite=Strict; Path=/; Max-Age=2592000",
+            ]
+        );
+
+        let mut header = HeaderMap::new();
+        append_session_clear_cookies(&mut header);
+        assert_eq!(header.get_all(SET_COOKIE).iter().count(), 2);
+    }
+
+    #[test]
+    fn test_replace_access_token_cookie() {
+        let mut header = headers("token=old; _csrf_token=abc");
+        header.append(COOKIE, HeaderValue::from_static("other=1"));
+        replace_access_token_cookie(&mut header, "new");
+        assert_eq!(
+            header.get(COOKIE).unwrap(),
+            "_csrf_token=abc; other=1; token=new"
+        );
+        assert_eq!(header.get_all(COOKIE).iter().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_expired_sessions() {
+        let app_state = app_state().await;
+        let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+        let user = add_user(&app_state, UserRole::Owner).await;
+        let tokens = create_session(&app_state, &user, false, String::new(), String::new())
+            .await
+            .unwrap();
+
+        let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+        auth_uc.delete_expired_sessions(now).await.unwrap();
+        assert!(is_auth_verified(access_headers(&tokens), &app_state).await);
+
+        let later = (Utc::now() + Duration::seconds(app_state.config.session_ttl as i64 + 1))
+            .to_rfc3339_opts(SecondsFormat::Secs, true);
+        auth_uc.delete_expired_sessions(later).await.unwrap();
+        assert!(!is_auth_verified(access_headers(&tokens), &app_state).await);
+    }
+}
@@ -0,0 +1,274 @@
+use crate::config::Secrets;
+use crate::handler::auth::extract_cookie_from_cookies;
+use crate::model::totp::{
+    hash_recovery_code, verify_totp_code, TotpChallengeClaims, UserTotp, RECOVERY_CODES_COUNT,
+    TOTP_CHALLENGE_TTL, TOTP_SECRET_LENGTH,
+};
+use axum::http::{header::COOKIE, HeaderMap};
+use jsonwebtoken::{
+    decode as jwt_decode, encode as jwt_encode, DecodingKey, EncodingKey, Header, Validation,
+};
+use qrcode::render::svg;
+use qrcode::QrCode;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::digest::{digest, SHA256};
+use ring::rand::{SecureRandom, SystemRandom};
+use tracing::{debug, error, info, warn};
+
+/// Name of the TOTP challenge cookie as it appears in the `Cookie` header.
+pub const TOTP_CHALLENGE_COOKIE_NAME: &str = "totp_challenge=";
+
+/// Key of the AES-256-GCM encryption of TOTP secrets
+/// Derived from TOTP_ENCRYPTION_KEY, or JWT_SECRET if it isn't set.
+fn totp_encryption_key(secrets: &Secrets) -> LessSafeKey {
+    let key_material = secrets
+        .totp_encryption_key
+        .as_ref()
+        .unwrap_or(&secrets.jwt_secret);
+    let key = digest(&SHA256, format!("totp-secret:{key_material}").as_bytes());
+    LessSafeKey::new(UnboundKey::new(&AES_256_GCM, key.as_ref()).expect("Invalid TOTP key"))
+}
+
+/// Signing secret of TOTP challenge tokens
+/// Differs from the JWT secret so a challenge cannot be used as a login token.
+fn totp_challenge_secret(secrets: &Secrets) -> String {
+    format!("totp-challenge:{}", secrets.jwt_secret)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Generate a random TOTP secret
+pub fn generate_totp_secret() -> Vec<u8> {
+    let rng = SystemRandom::new();
+    let mut secret = vec![0u8; TOTP_SECRET_LENGTH];
+    rng.fill(&mut secret)
+        .expect("Failed to generate TOTP secret");
+    secret
+}
+
+/// Generate recovery codes formatted as `xxxxx-xxxxx`
+/// Return the codes to be shown once and their hashes to be stored.
+pub fn generate_recovery_codes() -> (Vec<String>, Vec<String>) {
+    let rng = SystemRandom::new();
+    let codes: Vec<String> = (0..RECOVERY_CODES_COUNT)
+        .map(|_| {
+            let mut bytes = [0u8; 5];
+            rng.fill(&mut bytes)
+                .expect("Failed to generate recovery code");
+            let code = to_hex(&bytes);
+            format!("{}-{}", &code[..5], &code[5..])
+        })
+        .collect();
+    let hashed_codes = codes.iter().map(|code| hash_recovery_code(code)).collect();
+    (codes, hashed_codes)
+}
+
+/// Encrypt a TOTP secret to be stored as hex of the nonce and the sealed secret
+pub fn encrypt_totp_secret(secrets: &Secrets, totp_secret: &[u8]) -> Option<String> {
+    let rng = SystemRandom::new();
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill(&mut nonce).ok()?;
+    let mut sealed = totp_secret.to_vec();
+    if let Err(err) = totp_encryption_key(secrets).seal_in_place_append_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::empty(),
+        &mut sealed,
+    ) {
+        error!("Failed to encrypt TOTP secret. {:?}", err);
+        return None;
+    }
+    Some(format!("{}{}", to_hex(&nonce), to_hex(&sealed)))
+}
+
+/// Decrypt a TOTP secret stored by [`encrypt_totp_secret`]
+/// Return None if the secret was encrypted with another key.
+pub fn decrypt_totp_secret(secrets: &Secrets, encrypted_secret: &str) -> Option<Vec<u8>> {
+    let bytes = from_hex(encrypted_secret)?;
+    if bytes.len() <= NONCE_LEN {
+        return None;
+    }
+    let (nonce, sealed) = bytes.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).ok()?;
+    let mut sealed = sealed.to_vec();
+    match totp_encryption_key(secrets).open_in_place(nonce, Aad::empty(), &mut sealed) {
+        Ok(totp_secret) => Some(totp_secret.to_vec()),
+        Err(_) => {
+            warn!("Failed to decrypt TOTP secret. Was the TOTP key changed?");
+            None
+        }
+    }
+}
+
+/// Verify a TOTP code, or consume a recovery code, of a UserTotp
+/// The matched step or the remaining recovery codes are updated in `user_totp`
+/// and have to be stored so the code cannot be replayed.
+pub fn verify_totp_or_recovery_code(
+    secrets: &Secrets,
+    user_totp: &mut UserTotp,
+    code: &str,
+    unix_time: i64,
+) -> bool {
+    let verified_step =
+        decrypt_totp_secret(secrets, &user_totp.encrypted_secret).and_then(|totp_secret| {
+            verify_totp_code(&totp_secret, code, unix_time, user_totp.last_step)
+        });
+    if let Some(step) = verified_step {
+        user_totp.last_step = Some(step);
+        return true;
+    }
+    if user_totp.use_recovery_code(code) {
+        info!(
+            "User {} used a recovery code. {} left.",
+            &user_totp.user_id,
+            user_totp.recovery_codes.len()
+        );
+        return true;
+    }
+    false
+}
+
+/// Render an `otpauth://` URI as an inline SVG QR code
+pub fn render_totp_qr_svg(uri: &str) -> Option<String> {
+    match QrCode::new(uri.as_bytes()) {
+        Ok(code) => Some(
+            code.render::<svg::Color>()
+                .min_dimensions(200, 200)
+                .quiet_zone(true)
+                .build(),
+        ),
+        Err(err) => {
+            error!("Failed to render TOTP QR code. {:?}", err);
+            None
+        }
+    }
+}
+
+/// Create the TOTP challenge token of a User whose password is verified
+pub fn create_totp_challenge(secrets: &Secrets, user_id: &str, remember: bool) -> Option<String> {
+    let now = chrono::Utc::now().timestamp() as usize;
+    let claims = TotpChallengeClaims {
+        sub: user_id.to_string(),
+        remember,
+        exp: now + TOTP_CHALLENGE_TTL,
+        iat: now,
+    };
+    match jwt_encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(totp_challenge_secret(secrets).as_ref()),
+    ) {
+        Ok(token) => Some(token),
+        Err(e) => {
+            error!("Failed to create TOTP challenge. {:?}", e);
+            None
+        }
+    }
+}
+
+/// Return the Claims of the verified `totp_challenge` cookie
+pub fn get_totp_challenge(header: &HeaderMap, secrets: &Secrets) -> Option<TotpChallengeClaims> {
+    let token = header
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find_map(|cookies| extract_cookie_from_cookies(cookies, TOTP_CHALLENGE_COOKIE_NAME))
+        .filter(|token| !token.is_empty())?;
+    match jwt_decode::<TotpChallengeClaims>(
+        &token,
+        &DecodingKey::from_secret(totp_challenge_secret(secrets).as_ref()),
+        &Validation::default(),
+    ) {
+        Ok(data) => Some(data.claims),
+        Err(e) => {
+            debug!("Failed to verify TOTP challenge. {:?}", e);
+            None
+        }
+    }
+}
+
+/// Build the Set-Cookie header value of the TOTP challenge
+pub fn totp_challenge_set_cookie_header(token: &str) -> String {
+    format!(
+        "{}{}; Secure; HttpOnly; SameSite=Strict; Max-Age={}",
+        TOTP_CHALLENGE_COOKIE_NAME, token, TOTP_CHALLENGE_TTL
+    )
+}
+
+/// Build the Set-Cookie header value to clear the TOTP challenge
+pub fn totp_challenge_clear_cookie_header() -> String {
+    format!("{TOTP_CHALLENGE_COOKIE_NAME}; Secure; HttpOnly; SameSite=Strict; Max-Age=0")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::handler::auth::verify_jwt;
+    use axum::http::HeaderValue;
+
+    fn secrets(totp_encryption_key: Option<&str>) -> Secrets {
+        Secrets {
+            jwt_secret: "test-secret".to_string(),
+            database_url: String::new(),
+            turso_auth_token: None,
+            totp_encryption_key: totp_encryption_key.map(|val| val.to_string()),
+            oidc_client_secret: None,
+            smtp_password: None,
+        }
+    }
+
+    #[test]
+    fn test_encrypt_totp_secret() {
+        let totp_secret = generate_totp_secret();
+        let encrypted = encrypt_totp_secret(&secrets(Some("key-1")), &totp_secret).unwrap();
+        assert!(!encrypted.contains(&to_hex(&totp_secret)));
+        assert_eq!(
+            decrypt_totp_secret(&secrets(Some("key-1")), &encrypted),
+            Some(totp_secret.clone())
+        );
+        assert_eq!(
+            decrypt_totp_secret(&secrets(Some("key-2")), &encrypted),
+            None
+        );
+        assert_eq!(decrypt_totp_secret(&secrets(None), &encrypted), None);
+        assert_eq!(decrypt_totp_secret(&secrets(None), "not-hex"), None);
+    }
+
+    #[test]
+    fn test_generate_recovery_codes() {
+        let (codes, hashed_codes) = generate_recovery_codes();
+        assert_eq!(codes.len(), RECOVERY_CODES_COUNT);
+        assert!(codes.iter().all(|code| code.len() == 11));
+        assert_eq!(hashed_codes[0], hash_recovery_code(&codes[0]));
+    }
+
+    #[test]
+    fn test_totp_challenge() {
+        let secrets = secrets(None);
+        let token = create_totp_challenge(&secrets, "user-1", true).unwrap();
+        // A challenge is not a login token
+        assert!(!verify_jwt(&token, &secrets.jwt_secret));
+
+        let mut headers = HeaderMap::new();
+        let cookie = format!("_csrf_token=abc; {TOTP_CHALLENGE_COOKIE_NAME}{token}");
+        headers.insert(COOKIE, HeaderValue::from_str(&cookie).unwrap());
+        let claims = get_totp_challenge(&headers, &secrets).unwrap();
+        assert_eq!(claims.sub, "user-1");
+        assert!(claims.remember);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(COOKIE, HeaderValue::from_static("totp_challenge="));
+        assert!(get_totp_challenge(&headers, &secrets).is_none());
+    }
+}
@@ -0,0 +1,65 @@
+use crate::handler::status::get_404_not_found;
+use crate::model::axum::AppState;
+use crate::model::media::{find_media_variant_origin, is_valid_media_key};
+use crate::repo::media::{MediaDisplayRepo, MediaStorageRepo};
+use axum::debug_handler;
+use axum::extract::{Path, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use tracing::{info, warn};
+
+/// get_media
+/// Serve an uploaded media file under endpoint /media/{key}
+/// Uploaded files are sandboxed so they can't run scripts on this site.
+/// Resized and WebP variants of images are served with their own type.
+#[debug_handler]
+pub async fn get_media(Path(key): Path<String>, State(app_state): State<AppState>) -> Response {
+    if !is_valid_media_key(&key) {
+        warn!("Media key {} is not valid.", &key);
+        return get_404_not_found().await.into_response();
+    }
+
+    let media_db_uc = app_state
+        .media_db_usecase
+        .lock()
+        .await
+        .clone()
+        .expect("Failed to lock Media DB Usecase");
+    let Some(media_storage_uc) = app_state.media_storage_usecase.lock().await.clone() else {
+        warn!("Media storage is not configured.");
+        return get_404_not_found().await.into_response();
+    };
+
+    // Image variants aren't stored in the database, so look up their original
+    let (origin_key, variant_content_type) = match find_media_variant_origin(&key) {
+        Some((origin_key, content_type)) => (origin_key, Some(content_type)),
+        None => (key.clone(), None),
+    };
+    let Some(media_file) = media_db_uc.find(origin_key.clone()).await else {
+        info!("Failed to find Media File with key {}.", &origin_key);
+        return get_404_not_found().await.into_response();
+    };
+    let content_type = variant_content_type.unwrap_or(media_file.content_type);
+    let Some(data) = media_storage_uc.download(key.clone()).await else {
+        warn!("Failed to download Media File with key {}.", &key);
+        return get_404_not_found().await.into_response();
+    };
+
+    (
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+            (
+                header::CONTENT_SECURITY_POLICY,
+                "default-src 'none'; style-src 'unsafe-inline'; sandbox".to_string(),
+            ),
+            // Keys are unique per upload so the content never changes
+            (
+                header::CACHE_CONTROL,
+                "public, max-age=31536000, immutable".to_string(),
+            ),
+        ],
+        data,
+    )
+        .into_response()
+}
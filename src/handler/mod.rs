@@ -3,13 +3,20 @@ use axum::http::HeaderName;
 pub mod admin;
 pub mod auth;
 pub mod blogs;
+pub mod media;
 pub mod profile;
+pub mod projects;
+pub mod resume;
 pub mod status;
 pub mod talks;
 pub mod version;
 
 /// HTMX header to redirect client to specific path
 pub const HX_REDIRECT: HeaderName = HeaderName::from_static("hx-redirect");
+/// HTMX header to swap the response into another element than the request target
+pub const HX_RETARGET: HeaderName = HeaderName::from_static("hx-retarget");
+/// HTMX header sent with every request made by HTMX
+pub const HX_REQUEST: HeaderName = HeaderName::from_static("hx-request");
 
 // Note: In axum [example](https://docs.rs/axum/latest/axum/response/index.html#building-responses)
 // They show an example to return Html<&'static str>
@@ -1,14 +1,55 @@
-use crate::handler::status::get_500_internal_server_error;
-use crate::model::templates::ProfileTemplate;
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::axum::AppState;
+use crate::model::profiles::Profile;
+use crate::repo::profiles::{ProfileCacheOperationRepo, ProfileDisplayRepo};
 use askama::Template;
+use axum::debug_handler;
+use axum::extract::State;
 use axum::response::Html;
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 /// get_profile
 /// Serve Profile/Biography HTML file
-pub async fn get_profile() -> Html<String> {
-    let profile = ProfileTemplate.render();
-    match profile {
+/// Render the latest Profile version
+#[debug_handler]
+pub async fn get_profile(State(app_state): State<AppState>) -> Html<String> {
+    // Setup usecases
+    let profile_db_uc = app_state
+        .profile_db_usecase
+        .lock()
+        .await
+        .clone()
+        .expect("Failed to lock Profile DB Usecase");
+    let profile_cache_uc_opt = app_state.profile_cache_usecase.lock().await.clone();
+
+    // Get Data from Cache. If not cached, get data from database
+    let cache_result = match &profile_cache_uc_opt {
+        Some(profile_cache_uc) => profile_cache_uc.find_latest().await,
+        None => None,
+    };
+    let profile = match cache_result {
+        Some(profile) => profile,
+        None => {
+            let Some(profile) = profile_db_uc.find_latest().await else {
+                error!("Failed to find the latest Profile.");
+                return get_404_not_found().await;
+            };
+
+            // Insert cache
+            if let Some(mut profile_cache_uc) = profile_cache_uc_opt {
+                debug!("Caching profile {}", &profile.version);
+                let _ = profile_cache_uc.insert(profile.clone()).await;
+            }
+            profile
+        }
+    };
+
+    render_profile(&profile)
+}
+
+/// Render a Profile into profile.html
+fn render_profile(profile: &Profile) -> Html<String> {
+    match profile.to_template().render() {
         Ok(res) => {
             info!("Profile askama template rendered.");
             Html(res)
@@ -23,12 +64,16 @@ pub async fn get_profile() -> Html<String> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::database::turso::profiles::seed_profile;
 
     #[tokio::test]
     async fn test_get_profile_renders_template() {
-        let html = get_profile().await;
+        let html = render_profile(&seed_profile());
         assert!(html
             .0
             .contains("Husni Naufal Zuhdi - Site Reliability Engineer"));
+        assert!(html
+            .0
+            .contains(r#"<a href="https://accelbyte.io/about-us" target="_blank">Accelbyte</a>"#));
     }
 }
@@ -0,0 +1,140 @@
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::axum::AppState;
+use crate::model::projects::ProjectsParams;
+use crate::repo::projects::{ProjectCacheOperationRepo, ProjectDisplayRepo};
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::{Path, Query, State};
+use axum::response::Html;
+use tracing::{debug, error, info, warn};
+
+/// get_projects
+/// Serve projects HTML file
+/// Accepted parameters:
+/// - start: initial projects pagination
+/// - end: end of projects pagination
+/// - status: only show projects with this status
+/// - tags: only show projects with any of these comma separated tech tags
+#[debug_handler]
+pub async fn get_projects(
+    State(app_state): State<AppState>,
+    params: Query<ProjectsParams>,
+) -> Html<String> {
+    // Setup usecases
+    let project_db_uc = app_state
+        .project_db_usecase
+        .lock()
+        .await
+        .clone()
+        .expect("Failed to lock Project DB Usecase");
+    let project_cache_uc_opt = app_state.project_cache_usecase.lock().await.clone();
+
+    // Sanitize Params
+    let sanitized_params = params.sanitize();
+
+    // Get Data from Cache. If not cached, get data from database
+    let cache_result = match &project_cache_uc_opt {
+        Some(project_cache_uc) => {
+            project_cache_uc
+                .find_projects(sanitized_params.clone())
+                .await
+        }
+        None => None,
+    };
+    let projects = match cache_result {
+        Some(projects) => projects,
+        None => {
+            let Some(projects) = project_db_uc.find_projects(sanitized_params.clone()).await else {
+                error!(
+                    "Failed to find projects started at {} and ended at {}.",
+                    sanitized_params.start.unwrap(),
+                    sanitized_params.end.unwrap()
+                );
+                return get_500_internal_server_error();
+            };
+
+            // Insert cache
+            if let Some(mut project_cache_uc) = project_cache_uc_opt {
+                for project in projects.projects.clone() {
+                    debug!("Caching project {}", &project.id);
+                    let _ = project_cache_uc.insert(project).await;
+                }
+            }
+            projects
+        }
+    };
+
+    match projects.to_template(&sanitized_params).render() {
+        Ok(res) => {
+            info!("Projects askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render projects.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_project
+/// Serve project HTML file
+/// Render a single project with its markdown body
+#[debug_handler]
+pub async fn get_project(
+    Path(path): Path<String>,
+    State(app_state): State<AppState>,
+) -> Html<String> {
+    // Sanitize `path`
+    let id = match path.parse::<i64>() {
+        Ok(val) => {
+            debug!("Successfully parse path {} into {} i64", &path, &val);
+            val
+        }
+        Err(err) => {
+            warn!("Failed to parse path {} to i64. Err: {}", &path, err);
+            return get_404_not_found().await;
+        }
+    };
+
+    // Setup usecases
+    let project_db_uc = app_state
+        .project_db_usecase
+        .lock()
+        .await
+        .clone()
+        .expect("Failed to lock Project DB Usecase");
+    let project_cache_uc_opt = app_state.project_cache_usecase.lock().await.clone();
+
+    // Get Data from Cache. If not cached, get data from database
+    let cache_result = match &project_cache_uc_opt {
+        Some(project_cache_uc) => project_cache_uc.find(id).await,
+        None => None,
+    };
+    let project = match cache_result {
+        Some(project) => project,
+        None => {
+            let Some(project) = project_db_uc.find(id).await else {
+                info!("Failed to find Project with Id {}.", &id);
+                return get_404_not_found().await;
+            };
+
+            // Insert cache
+            if let Some(mut project_cache_uc) = project_cache_uc_opt {
+                debug!("Caching project {}", &id);
+                let _ = project_cache_uc.insert(project.clone()).await;
+            }
+            project
+        }
+    };
+
+    match project.to_template().render() {
+        Ok(res) => {
+            info!("Project ID {} askama template rendered.", &id);
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render project.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::handler::status::{get_404_not_found, get_500_internal_server_error};
+use crate::model::axum::AppState;
+use crate::model::resume::Resume;
+use crate::repo::resume::{ResumeCacheOperationRepo, ResumeDisplayRepo};
+use askama::Template;
+use axum::debug_handler;
+use axum::extract::State;
+use axum::http::header;
+use axum::response::{Html, IntoResponse, Response};
+use tracing::{debug, error, info};
+
+/// get_resume
+/// Serve Resume HTML file
+/// Contains a print stylesheet so it can be printed as a CV
+#[debug_handler]
+pub async fn get_resume(State(app_state): State<AppState>) -> Html<String> {
+    let Some(resume) = find_resume(&app_state).await else {
+        return get_404_not_found().await;
+    };
+
+    match resume.to_template().render() {
+        Ok(res) => {
+            info!("Resume askama template rendered.");
+            Html(res)
+        }
+        Err(err) => {
+            error!("Failed to render resume.html. {}", err);
+            get_500_internal_server_error()
+        }
+    }
+}
+
+/// get_resume_json
+/// Serve Resume in the JSON Resume schema
+/// Reference: https://jsonresume.org/schema
+#[debug_handler]
+pub async fn get_resume_json(State(app_state): State<AppState>) -> Response {
+    let Some(resume) = find_resume(&app_state).await else {
+        return get_404_not_found().await.into_response();
+    };
+
+    (
+        [(header::CONTENT_TYPE, "application/json")],
+        resume.to_json_resume().to_string(),
+    )
+        .into_response()
+}
+
+/// Find the Resume from cache. If not cached, find it from database
+/// Return `None` if the Resume hasn't been written yet
+async fn find_resume(app_state: &AppState) -> Option<Resume> {
+    // Setup usecases
+    let resume_db_uc = app_state
+        .resume_db_usecase
+        .lock()
+        .await
+        .clone()
+        .expect("Failed to lock Resume DB Usecase");
+    let resume_cache_uc_opt = app_state.resume_cache_usecase.lock().await.clone();
+
+    // Get Data from Cache. If not cached, get data from database
+    if let Some(resume_cache_uc) = &resume_cache_uc_opt {
+        if let Some(resume) = resume_cache_uc.find().await {
+            return Some(resume);
+        }
+    }
+    let Some(resume) = resume_db_uc.find().await else {
+        info!("Resume is not available.");
+        return None;
+    };
+
+    // Insert cache
+    if let Some(mut resume_cache_uc) = resume_cache_uc_opt {
+        debug!("Caching resume");
+        let _ = resume_cache_uc.insert(resume.clone()).await;
+    }
+    Some(resume)
+}
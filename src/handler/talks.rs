@@ -1,7 +1,8 @@
 use crate::handler::status::get_500_internal_server_error;
-use crate::repo::talks::TalkDisplayRepo;
+use crate::repo::talks::{TalkCacheOperationRepo, TalkDisplayRepo};
 use askama::Template;
-use axum::response::Html;
+use axum::http::header;
+use axum::response::{Html, IntoResponse, Response};
 
 use crate::model::axum::AppState;
 use crate::model::talks::TalksParams;
@@ -28,6 +29,7 @@ pub async fn get_talks(
 
     // Sanitize Params
     let sanitized_params = params.sanitize();
+    let today = chrono::Local::now().date_naive();
 
     // Get Data from Cache
     let cache_result = if cache_is_enabled {
@@ -41,7 +43,10 @@ pub async fn get_talks(
     };
     // If cache hit, return early
     if let Some(res) = cache_result {
-        let talks_res = res.sanitize().to_template().render();
+        let talks_res = res
+            .sanitize()
+            .to_template(today, &sanitized_params)
+            .render();
         if talks_res.is_err() {
             error!(
                 "Failed to render get_talks.html. {}",
@@ -84,7 +89,11 @@ pub async fn get_talks(
     }
 
     // Render Talks
-    let talks_res = db_result.unwrap().sanitize().to_template().render();
+    let talks_res = db_result
+        .unwrap()
+        .sanitize()
+        .to_template(today, &sanitized_params)
+        .render();
     if talks_res.is_err() {
         error!(
             "Failed to render get_talks.html. {}",
@@ -96,6 +105,62 @@ pub async fn get_talks(
     Html(talks_res.unwrap())
 }
 
+/// get_talks_calendar
+/// Serve talks iCalendar feed under /talks/calendar.ics
+/// Every talk is published as an all-day VEVENT.
+#[debug_handler]
+pub async fn get_talks_calendar(State(app_state): State<AppState>) -> Response {
+    // Setup usecases
+    let talk_db_uc = app_state
+        .talk_db_usecase
+        .lock()
+        .await
+        .clone()
+        .expect("Failed to lock Talk DB Usecase");
+    let talk_cache_uc_opt = app_state.talk_cache_usecase.lock().await.clone();
+
+    // Get Data from Cache. If cache hit, return early
+    if let Some(talk_cache_uc) = &talk_cache_uc_opt {
+        if let Some(calendar) = talk_cache_uc.find_calendar().await {
+            info!("Talks calendar served from cache.");
+            return calendar_response(calendar);
+        }
+    }
+
+    // If not, get every talk from database. The default pagination would
+    // leave talks out of the feed.
+    let Some(talks) = talk_db_uc
+        .talk_display_repo
+        .find_talks(TalksParams::all())
+        .await
+    else {
+        error!("Failed to find talks for the talks calendar.");
+        return get_500_internal_server_error().into_response();
+    };
+    let calendar = talks.to_ical(chrono::Utc::now());
+
+    // Insert cache
+    if let Some(mut talk_cache_uc) = talk_cache_uc_opt {
+        debug!("Caching talks calendar");
+        let _ = talk_cache_uc
+            .talk_operation_repo
+            .insert_calendar(calendar.clone())
+            .await;
+    }
+
+    info!("Talks calendar generated.");
+    calendar_response(calendar)
+}
+
+/// Build iCalendar response with `text/calendar` content type
+fn calendar_response(calendar: String) -> Response {
+    (
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        calendar,
+    )
+        .into_response()
+}
+
 //#[cfg(test)]
 //mod test {
 //    use crate::config;
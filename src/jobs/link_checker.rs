@@ -0,0 +1,411 @@
+use crate::model::axum::AppState;
+use crate::model::blogs::BlogsParams;
+use crate::model::link_checks::{
+    classify_link, InternalLink, LinkCheck, LinkCheckCommandStatus, LinkChecks, LinkSource,
+    LinkTarget, LINK_SOURCE_BLOG, LINK_SOURCE_TALK,
+};
+use crate::model::media::find_media_variant_origin;
+use crate::model::talks::TalksParams;
+use crate::repo::blogs::BlogDisplayRepo;
+use crate::repo::link_checks::LinkCheckOperationRepo;
+use crate::repo::media::MediaDisplayRepo;
+use crate::repo::projects::ProjectDisplayRepo;
+use crate::repo::talks::TalkDisplayRepo;
+use chrono::Utc;
+use reqwest::Client;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio::time::{interval_at, Instant, MissedTickBehavior};
+use tracing::{info, warn};
+
+/// Timeout of a single external link request
+const LINK_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+/// Max external links requested at the same time
+const LINK_CHECK_CONCURRENCY: usize = 8;
+/// Directory served under /statics
+const STATICS_PATH: &str = "./statics/favicon";
+
+/// Set while a link check is running so runs never overlap
+static LINK_CHECK_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// ExternalLinkChecker
+/// Check HTTP(S) links with a request timeout and a concurrency limit
+#[derive(Debug, Clone)]
+pub struct ExternalLinkChecker {
+    client: Client,
+    concurrency: usize,
+}
+
+impl Default for ExternalLinkChecker {
+    fn default() -> Self {
+        Self::new(LINK_CHECK_TIMEOUT, LINK_CHECK_CONCURRENCY)
+    }
+}
+
+impl ExternalLinkChecker {
+    pub fn new(timeout: Duration, concurrency: usize) -> Self {
+        let client = Client::builder()
+            .timeout(timeout)
+            .user_agent(concat!(
+                "husni-portfolio-link-checker/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()
+            .expect("Failed to build link checker HTTP client");
+        Self {
+            client,
+            concurrency: concurrency.max(1),
+        }
+    }
+    /// Check an external link with HTTP HEAD
+    /// Fallback to GET if HEAD fails since some servers don't support it.
+    /// Return the response status if the link works.
+    /// Otherwise, return the reason it is broken.
+    pub async fn check(&self, url: &str) -> Result<String, String> {
+        let res = match self.client.head(url).send().await {
+            Ok(res) if res.status().is_success() => Ok(res),
+            Ok(_) => self.client.get(url).send().await,
+            Err(err) if err.is_timeout() => Err(err),
+            Err(_) => self.client.get(url).send().await,
+        };
+        match res {
+            Ok(res) if res.status().is_success() => Ok(res.status().to_string()),
+            Ok(res) => Err(res.status().to_string()),
+            Err(err) if err.is_timeout() => Err("Request timed out.".to_string()),
+            Err(err) if err.is_connect() => Err("Failed to connect.".to_string()),
+            Err(err) => {
+                warn!("Failed to request link {}. {}", url, err);
+                Err("Request failed.".to_string())
+            }
+        }
+    }
+    /// Check external links concurrently
+    /// Return the check result of every link.
+    pub async fn check_all(&self, urls: Vec<String>) -> HashMap<String, Result<String, String>> {
+        let semaphore = Arc::new(Semaphore::new(self.concurrency));
+        let mut checks = JoinSet::new();
+        for url in urls {
+            let checker = self.clone();
+            let semaphore = semaphore.clone();
+            checks.spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("Link check semaphore is closed");
+                let result = checker.check(&url).await;
+                (url, result)
+            });
+        }
+
+        let mut results = HashMap::new();
+        while let Some(joined) = checks.join_next().await {
+            match joined {
+                Ok((url, result)) => {
+                    results.insert(url, result);
+                }
+                Err(err) => warn!("Failed to join a link check. {}", err),
+            }
+        }
+        results
+    }
+}
+
+/// Check if a link check is running
+pub fn is_link_check_running() -> bool {
+    LINK_CHECK_RUNNING.load(Ordering::SeqCst)
+}
+
+/// LinkCheckRunningGuard
+/// Clear `LINK_CHECK_RUNNING` when dropped, so a panicked or cancelled
+/// link check doesn't block the next ones.
+struct LinkCheckRunningGuard;
+
+impl LinkCheckRunningGuard {
+    /// Set `LINK_CHECK_RUNNING`. Return None if a link check is already running.
+    fn acquire() -> Option<Self> {
+        match LINK_CHECK_RUNNING.swap(true, Ordering::SeqCst) {
+            true => None,
+            false => Some(Self),
+        }
+    }
+}
+
+impl Drop for LinkCheckRunningGuard {
+    fn drop(&mut self) {
+        LINK_CHECK_RUNNING.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Check links of all Blogs and Talks then store the results
+/// Return None if another check is running or the results can't be stored.
+pub async fn run_link_check(
+    app_state: &AppState,
+    checker: &ExternalLinkChecker,
+) -> Option<LinkCheckCommandStatus> {
+    let Some(_running) = LinkCheckRunningGuard::acquire() else {
+        info!("A link check is already running.");
+        return None;
+    };
+
+    info!("Starting link check.");
+    let link_checks = check_links(app_state, checker).await;
+    let broken_count = link_checks.iter().filter(|val| val.is_broken).count();
+    info!(
+        "Link check is finished. {} of {} links are broken.",
+        broken_count,
+        link_checks.len()
+    );
+
+    match app_state.link_check_db_usecase.lock().await.clone() {
+        Some(mut link_check_db_uc) => link_check_db_uc.replace(LinkChecks { link_checks }).await,
+        None => {
+            warn!("Link check results can't be stored without a database.");
+            None
+        }
+    }
+}
+
+/// Run the link check every LINK_CHECK_INTERVAL_SECOND in the background
+/// The first check starts after one interval. Disabled if the interval is 0.
+pub fn spawn_link_checker(app_state: AppState) {
+    let period = app_state.config.link_check_interval;
+    if period == 0 {
+        info!("Background link checker is disabled.");
+        return;
+    }
+
+    tokio::spawn(async move {
+        let checker = ExternalLinkChecker::default();
+        let period = Duration::from_secs(period);
+        let mut ticker = interval_at(Instant::now() + period, period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            run_link_check(&app_state, &checker).await;
+        }
+    });
+}
+
+/// Check every link of Blogs and Talks
+/// External links are requested once even if they are used in many sources.
+async fn check_links(app_state: &AppState, checker: &ExternalLinkChecker) -> Vec<LinkCheck> {
+    let sources = find_link_sources(app_state).await;
+
+    let mut external_urls: Vec<String> = Vec::new();
+    for url in sources.iter().flat_map(|source| source.urls.iter()) {
+        if let Some(LinkTarget::External(url)) = classify_link(url) {
+            if !external_urls.contains(&url) {
+                external_urls.push(url);
+            }
+        }
+    }
+    let external_results = checker.check_all(external_urls).await;
+
+    let checked_at = Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    let mut link_checks = Vec::new();
+    for source in sources {
+        for url in source.urls {
+            let result = match classify_link(&url) {
+                Some(LinkTarget::External(external_url)) => external_results
+                    .get(&external_url)
+                    .cloned()
+                    .unwrap_or_else(|| Err("Link wasn't checked.".to_string())),
+                Some(LinkTarget::Internal(internal_link)) => {
+                    check_internal_link(app_state, &internal_link).await
+                }
+                None => continue,
+            };
+            let is_broken = result.is_err();
+            link_checks.push(LinkCheck {
+                source_type: source.source_type.clone(),
+                source_id: source.source_id,
+                source_name: source.source_name.clone(),
+                url,
+                is_broken,
+                detail: result.unwrap_or_else(|err| err),
+                checked_at: checked_at.clone(),
+            });
+        }
+    }
+    link_checks
+}
+
+/// Check an internal link against the public routes and the repos it points to
+async fn check_internal_link(app_state: &AppState, link: &InternalLink) -> Result<String, String> {
+    let exists = match link {
+        InternalLink::Page => true,
+        InternalLink::Blog(id) => {
+            let blog_db_uc = app_state.blog_db_usecase.lock().await.clone();
+            blog_db_uc.find(*id).await.is_some()
+        }
+        InternalLink::Project(id) => match app_state.project_db_usecase.lock().await.clone() {
+            Some(project_db_uc) => project_db_uc.find(*id).await.is_some(),
+            None => false,
+        },
+        InternalLink::Media(key) => match app_state.media_db_usecase.lock().await.clone() {
+            Some(media_db_uc) => {
+                let origin_key = find_media_variant_origin(key)
+                    .map(|(origin_key, _)| origin_key)
+                    .unwrap_or(key.clone());
+                media_db_uc.find(origin_key).await.is_some()
+            }
+            None => false,
+        },
+        InternalLink::Static(file) => Path::new(STATICS_PATH).join(file).is_file(),
+        InternalLink::Unknown => false,
+    };
+
+    if exists {
+        Ok("OK".to_string())
+    } else {
+        Err(match link {
+            InternalLink::Blog(id) => format!("Blog {id} doesn't exist."),
+            InternalLink::Project(id) => format!("Project {id} doesn't exist."),
+            InternalLink::Media(_) => "Media file doesn't exist.".to_string(),
+            InternalLink::Static(_) => "Static file doesn't exist.".to_string(),
+            _ => "Page doesn't exist.".to_string(),
+        })
+    }
+}
+
+/// Find links of all Blogs and Talks
+async fn find_link_sources(app_state: &AppState) -> Vec<LinkSource> {
+    let mut sources = Vec::new();
+
+    let blog_db_uc = app_state.blog_db_usecase.lock().await.clone();
+    let blogs = blog_db_uc
+        .find_blogs(BlogsParams::all())
+        .await
+        .unwrap_or_default();
+    for blog in blogs {
+        sources.push(LinkSource {
+            source_type: LINK_SOURCE_BLOG.to_string(),
+            source_id: blog.id,
+            source_name: blog.name.clone().unwrap_or_default(),
+            urls: blog.links(),
+        });
+    }
+
+    let talk_db_uc_opt = app_state.talk_db_usecase.lock().await.clone();
+    if let Some(talk_db_uc) = talk_db_uc_opt {
+        let talks = talk_db_uc.find_talks(TalksParams::all()).await;
+        for talk in talks.map(|val| val.talks).unwrap_or_default() {
+            sources.push(LinkSource {
+                source_type: LINK_SOURCE_TALK.to_string(),
+                source_id: talk.id,
+                source_name: talk.name.clone(),
+                urls: talk.links(),
+            });
+        }
+    }
+
+    sources
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use axum::http::StatusCode;
+    use axum::routing::get;
+    use axum::Router;
+
+    /// Serve a local HTTP stand-in for external sites
+    async fn serve_stand_in() -> String {
+        let app = Router::new()
+            .route("/ok", get(|| async { "ok" }))
+            .route(
+                "/missing",
+                get(|| async { (StatusCode::NOT_FOUND, "missing") }),
+            )
+            .route(
+                "/no-head",
+                get(|| async { "ok" }).head(|| async { StatusCode::METHOD_NOT_ALLOWED }),
+            )
+            .route(
+                "/slow",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    "slow"
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{addr}")
+    }
+
+    #[tokio::test]
+    async fn test_external_link_checker_check() {
+        let base = serve_stand_in().await;
+        let checker = ExternalLinkChecker::new(Duration::from_millis(500), 2);
+
+        assert_eq!(
+            checker.check(&format!("{base}/ok")).await,
+            Ok("200 OK".to_string())
+        );
+        assert_eq!(
+            checker.check(&format!("{base}/no-head")).await,
+            Ok("200 OK".to_string())
+        );
+        assert_eq!(
+            checker.check(&format!("{base}/missing")).await,
+            Err("404 Not Found".to_string())
+        );
+        assert_eq!(
+            checker.check(&format!("{base}/slow")).await,
+            Err("Request timed out.".to_string())
+        );
+
+        // Bind then drop a listener to get a port nobody listens on
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let closed = listener.local_addr().unwrap();
+        drop(listener);
+        assert_eq!(
+            checker.check(&format!("http://{closed}/ok")).await,
+            Err("Failed to connect.".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_external_link_checker_check_all() {
+        let base = serve_stand_in().await;
+        let checker = ExternalLinkChecker::new(Duration::from_millis(500), 2);
+        let urls: Vec<String> = ["/ok", "/missing", "/no-head", "/slow", "/slow"]
+            .iter()
+            .map(|path| format!("{base}{path}"))
+            .collect();
+
+        let started = std::time::Instant::now();
+        let results = checker.check_all(urls).await;
+        // Two slow links are checked at the same time and both time out
+        assert!(started.elapsed() < Duration::from_secs(3));
+
+        assert_eq!(results.len(), 4);
+        assert!(results[&format!("{base}/ok")].is_ok());
+        assert!(results[&format!("{base}/no-head")].is_ok());
+        assert!(results[&format!("{base}/missing")].is_err());
+        assert!(results[&format!("{base}/slow")].is_err());
+    }
+
+    #[test]
+    fn test_link_check_running_guard() {
+        let running = LinkCheckRunningGuard::acquire().unwrap();
+        assert!(is_link_check_running());
+        assert!(LinkCheckRunningGuard::acquire().is_none());
+        drop(running);
+        assert!(!is_link_check_running());
+
+        // A panicked link check releases the flag while unwinding
+        let panicked = std::panic::catch_unwind(|| {
+            let _running = LinkCheckRunningGuard::acquire().unwrap();
+            panic!("Failed to store link checks.");
+        });
+        assert!(panicked.is_err());
+        assert!(!is_link_check_running());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod link_checker;
+pub mod session_cleanup;
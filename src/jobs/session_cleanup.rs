@@ -0,0 +1,38 @@
+use crate::model::axum::AppState;
+use crate::repo::auth::AuthRepo;
+use chrono::{SecondsFormat, Utc};
+use std::time::Duration;
+use tokio::time::{interval, MissedTickBehavior};
+use tracing::{error, warn};
+
+/// Interval between two cleanups of expired Sessions and email tokens
+const SESSION_CLEANUP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Delete Sessions and email tokens which expired before now
+/// Expired Sessions and links are already rejected when they are used, the
+/// cleanup only keeps the tables small.
+pub async fn run_session_cleanup(app_state: &AppState) {
+    let Some(auth_uc) = app_state.auth_db_usecase.lock().await.clone() else {
+        error!("Failed to lock Auth Usecase Mutex.");
+        return;
+    };
+    let now = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+    if auth_uc.delete_expired_sessions(now.clone()).await.is_none() {
+        warn!("Failed to delete expired Sessions.");
+    }
+    if auth_uc.delete_expired_email_tokens(now).await.is_none() {
+        warn!("Failed to delete expired email tokens.");
+    }
+}
+
+/// Delete expired Sessions and email tokens on start and every SESSION_CLEANUP_INTERVAL in the background
+pub fn spawn_session_cleanup(app_state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = interval(SESSION_CLEANUP_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            run_session_cleanup(&app_state).await;
+        }
+    });
+}
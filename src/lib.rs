@@ -1,10 +1,14 @@
 pub mod cache;
+pub mod cli;
 pub mod config;
 pub mod database;
 pub mod handler;
+pub mod jobs;
+pub mod mailer;
 pub mod model;
 pub mod repo;
 pub mod routes;
 pub mod state;
+pub mod storage;
 pub mod usecase;
 pub mod utils;
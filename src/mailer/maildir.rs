@@ -0,0 +1,100 @@
+use crate::mailer::build_message;
+use crate::model::mail::{Mail, MailCommandStatus};
+use crate::repo::mail::MailRepo;
+use async_trait::async_trait;
+use chrono::Utc;
+use lettre::message::Mailbox;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::path::PathBuf;
+use tracing::{debug, error, info};
+
+/// Write emails into a local maildir instead of sending them
+/// Meant for local development, where mail clients can read the maildir.
+#[derive(Clone)]
+pub struct MaildirMailer {
+    pub from: Mailbox,
+    pub path: PathBuf,
+}
+
+impl MaildirMailer {
+    /// Create new MaildirMailer and its `tmp`, `new` and `cur` directories
+    /// if they don't exist yet
+    pub async fn new(from: Mailbox, path: String) -> Self {
+        info!("Setting up Maildir Mailer at {}", &path);
+        let path = PathBuf::from(path);
+        for dir in ["tmp", "new", "cur"] {
+            tokio::fs::create_dir_all(path.join(dir))
+                .await
+                .expect("Failed to create maildir.");
+        }
+        Self { from, path }
+    }
+    /// Unique file name of a new email
+    fn file_name() -> String {
+        let mut bytes = [0u8; 8];
+        SystemRandom::new()
+            .fill(&mut bytes)
+            .expect("Failed to generate mail file name");
+        let random: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("{}.{}.portfolio", Utc::now().timestamp(), random)
+    }
+}
+
+#[async_trait]
+impl MailRepo for MaildirMailer {
+    async fn send(&self, mail: Mail) -> Option<MailCommandStatus> {
+        let to = mail.to.clone();
+        let message = build_message(&self.from, mail)?;
+        // Written into `tmp` first so readers never see a partial email
+        let file_name = Self::file_name();
+        let tmp_path = self.path.join("tmp").join(&file_name);
+        let new_path = self.path.join("new").join(&file_name);
+        debug!("Writing mail to {} into {:?}", &to, &new_path);
+        if let Err(err) = tokio::fs::write(&tmp_path, message.formatted()).await {
+            error!("Failed to write mail file {:?}. {}", &tmp_path, err);
+            return None;
+        }
+        match tokio::fs::rename(&tmp_path, &new_path).await {
+            Ok(()) => Some(MailCommandStatus::Sent),
+            Err(err) => {
+                error!("Failed to deliver mail file {:?}. {}", &new_path, err);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_maildir_mailer_send() {
+        let path = std::env::temp_dir().join(format!("maildir-test-{}", std::process::id()));
+        let from = "Portfolio <noreply@example.com>".parse().unwrap();
+        let mailer = MaildirMailer::new(from, path.to_string_lossy().to_string()).await;
+
+        let mail = Mail {
+            to: "me@example.com".to_string(),
+            subject: "Hello".to_string(),
+            body: "Hello there".to_string(),
+        };
+        assert_eq!(mailer.send(mail).await, Some(MailCommandStatus::Sent));
+
+        let mut entries = tokio::fs::read_dir(path.join("new")).await.unwrap();
+        let entry = entries.next_entry().await.unwrap().unwrap();
+        let content = tokio::fs::read_to_string(entry.path()).await.unwrap();
+        assert!(content.contains("To: me@example.com"));
+        assert!(content.contains("Subject: Hello"));
+        assert!(content.contains("Hello there"));
+        assert!(entries.next_entry().await.unwrap().is_none());
+
+        let mail = Mail {
+            to: "not an address".to_string(),
+            subject: "Hello".to_string(),
+            body: String::new(),
+        };
+        assert_eq!(mailer.send(mail).await, None);
+        let _ = tokio::fs::remove_dir_all(path).await;
+    }
+}
@@ -0,0 +1,33 @@
+pub mod maildir;
+pub mod smtp;
+
+use crate::model::mail::Mail;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::Message;
+use tracing::warn;
+
+/// Build the RFC 5322 message of a Mail sent by `from`
+/// Return None if the recipient is not a valid address.
+fn build_message(from: &Mailbox, mail: Mail) -> Option<Message> {
+    let to = match mail.to.parse::<Mailbox>() {
+        Ok(to) => to,
+        Err(err) => {
+            warn!("Mail recipient {} is not valid. {}", &mail.to, err);
+            return None;
+        }
+    };
+    match Message::builder()
+        .from(from.clone())
+        .to(to)
+        .subject(mail.subject)
+        .header(ContentType::TEXT_PLAIN)
+        .body(mail.body)
+    {
+        Ok(message) => Some(message),
+        Err(err) => {
+            warn!("Failed to build mail to {}. {}", &mail.to, err);
+            None
+        }
+    }
+}
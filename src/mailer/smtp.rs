@@ -0,0 +1,62 @@
+use crate::mailer::build_message;
+use crate::model::mail::{Mail, MailCommandStatus};
+use crate::repo::mail::MailRepo;
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+use tracing::{debug, error, info};
+
+/// Port of SMTP submission over implicit TLS
+const SMTPS_PORT: u16 = 465;
+
+/// Send emails through an SMTP server
+#[derive(Clone)]
+pub struct SmtpMailer {
+    pub from: Mailbox,
+    pub transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    /// Create new SmtpMailer of a server
+    /// Port 465 uses implicit TLS. Other ports upgrade with STARTTLS.
+    pub fn new(
+        from: Mailbox,
+        host: String,
+        port: u16,
+        credentials: Option<(String, String)>,
+    ) -> Self {
+        info!("Setting up SMTP Mailer for {}:{}", &host, port);
+        let builder = if port == SMTPS_PORT {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(&host)
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&host)
+        }
+        .expect("Failed to build SMTP transport")
+        .port(port);
+        let builder = match credentials {
+            Some((username, password)) => builder.credentials(Credentials::new(username, password)),
+            None => builder,
+        };
+        Self {
+            from,
+            transport: builder.build(),
+        }
+    }
+}
+
+#[async_trait]
+impl MailRepo for SmtpMailer {
+    async fn send(&self, mail: Mail) -> Option<MailCommandStatus> {
+        let to = mail.to.clone();
+        let message = build_message(&self.from, mail)?;
+        debug!("Sending mail to {} through SMTP", &to);
+        match self.transport.send(message).await {
+            Ok(_) => Some(MailCommandStatus::Sent),
+            Err(err) => {
+                error!("Failed to send mail to {}. {}", &to, err);
+                None
+            }
+        }
+    }
+}
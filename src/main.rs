@@ -1,4 +1,9 @@
+use clap::Parser;
+use husni_portfolio::cli::{create_auth_usecase, prompt_password, run_user_command, Cli, Command};
 use husni_portfolio::config::Config;
+use husni_portfolio::jobs::link_checker::spawn_link_checker;
+use husni_portfolio::jobs::session_cleanup::spawn_session_cleanup;
+use husni_portfolio::model::auth::PasswordPolicy;
 use husni_portfolio::routes::main_route;
 use husni_portfolio::state::state_factory;
 use tracing::info;
@@ -8,9 +13,31 @@ use tracing::info;
 async fn main() -> std::io::Result<()> {
     // Setup Config
     dotenvy::dotenv().ok();
+    let cli = Cli::parse();
     let config = Config::from_envar().await;
     let endpoint = format!("{}:{}", &config.svc_endpoint, &config.svc_port);
 
+    // Run a subcommand instead of the web application
+    if let Some(Command::User { command }) = cli.command {
+        tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::WARN)
+            .with_writer(std::io::stderr)
+            .init();
+        let Some(auth_uc) = create_auth_usecase(&config).await else {
+            eprintln!("Error: DATA_SOURCE must be sqlite or turso");
+            std::process::exit(1);
+        };
+        let policy = PasswordPolicy::from(&config);
+        match run_user_command(&auth_uc, command, &policy, prompt_password).await {
+            Ok(message) => println!("{message}"),
+            Err(err) => {
+                eprintln!("Error: {err}");
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
     // Initialize Tracing
     tracing_subscriber::fmt()
         .with_max_level(config.log_level)
@@ -18,6 +45,8 @@ async fn main() -> std::io::Result<()> {
 
     // Init app state
     let app_state = state_factory(config).await;
+    spawn_link_checker(app_state.clone());
+    spawn_session_cleanup(app_state.clone());
     info!("Starting HTTP Server at http://{}", endpoint);
     let app = main_route(app_state);
 
@@ -0,0 +1,273 @@
+use crate::model::templates_admin::AdminListAuditLogsTemplate;
+use chrono::{Days, NaiveDate};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use urlencoding::encode;
+
+/// Action of an added admin entity
+pub const AUDIT_ACTION_CREATE: &str = "create";
+/// Action of an edited admin entity
+pub const AUDIT_ACTION_UPDATE: &str = "update";
+/// Action of a deleted admin entity
+pub const AUDIT_ACTION_DELETE: &str = "delete";
+/// Action of a successful login
+pub const AUDIT_ACTION_LOGIN: &str = "login";
+/// Action of a rejected login
+pub const AUDIT_ACTION_LOGIN_FAILED: &str = "login_failed";
+/// Action of a logout
+pub const AUDIT_ACTION_LOGOUT: &str = "logout";
+
+pub const AUDIT_ENTITY_BLOG: &str = "blog";
+pub const AUDIT_ENTITY_TALK: &str = "talk";
+pub const AUDIT_ENTITY_TAG: &str = "tag";
+pub const AUDIT_ENTITY_BLOG_TAG_MAPPING: &str = "blog_tag_mapping";
+pub const AUDIT_ENTITY_TALK_TAG_MAPPING: &str = "talk_tag_mapping";
+pub const AUDIT_ENTITY_USER: &str = "user";
+pub const AUDIT_ENTITY_PROJECT: &str = "project";
+pub const AUDIT_ENTITY_ORGANIZATION: &str = "organization";
+pub const AUDIT_ENTITY_PROFILE: &str = "profile";
+pub const AUDIT_ENTITY_RESUME: &str = "resume";
+pub const AUDIT_ENTITY_MEDIA: &str = "media";
+
+/// Entity types which can be picked in the audit log filter
+pub const AUDIT_ENTITY_TYPES: [&str; 11] = [
+    AUDIT_ENTITY_BLOG,
+    AUDIT_ENTITY_TALK,
+    AUDIT_ENTITY_TAG,
+    AUDIT_ENTITY_BLOG_TAG_MAPPING,
+    AUDIT_ENTITY_TALK_TAG_MAPPING,
+    AUDIT_ENTITY_USER,
+    AUDIT_ENTITY_PROJECT,
+    AUDIT_ENTITY_ORGANIZATION,
+    AUDIT_ENTITY_PROFILE,
+    AUDIT_ENTITY_RESUME,
+    AUDIT_ENTITY_MEDIA,
+];
+
+/// Maximum number of AuditLogs shown in the admin audit page.
+/// The CSV export is not limited.
+pub const AUDIT_LOGS_LIST_LIMIT: i64 = 200;
+
+/// AuditLog
+/// Record of a state-changing request with fields:
+/// - created_at: Event time in RFC 3339
+/// - user_id: User Identifier of the actor. Empty if unknown
+/// - client_ip: IP address of the actor
+/// - action: One of the `AUDIT_ACTION_*` constants
+/// - entity_type: One of the `AUDIT_ENTITY_*` constants
+/// - entity_id: Identifier of the changed entity
+/// - before: Summary of the entity before the change. Empty on create
+/// - after: Summary of the entity after the change. Empty on delete
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuditLog {
+    pub created_at: String,
+    pub user_id: String,
+    pub client_ip: String,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// AuditLogs
+/// AuditLogs ordered from the newest
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuditLogs {
+    pub audit_logs: Vec<AuditLog>,
+}
+
+impl AuditLogs {
+    /// Convert AuditLogs to (Askama) AdminListAuditLogsTemplate
+    /// `params` are kept to export the same AuditLogs as CSV.
+    pub fn to_admin_list_template(&self, params: &AuditLogsParams) -> AdminListAuditLogsTemplate {
+        AdminListAuditLogsTemplate {
+            audit_logs: self.audit_logs.clone(),
+            is_truncated: self.audit_logs.len() as i64 >= AUDIT_LOGS_LIST_LIMIT,
+            export_query: params.to_query(),
+        }
+    }
+    /// Convert AuditLogs to CSV with a header row
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "created_at,user_id,client_ip,action,entity_type,entity_id,before,after\r\n",
+        );
+        for audit_log in &self.audit_logs {
+            let fields = [
+                &audit_log.created_at,
+                &audit_log.user_id,
+                &audit_log.client_ip,
+                &audit_log.action,
+                &audit_log.entity_type,
+                &audit_log.entity_id,
+                &audit_log.before,
+                &audit_log.after,
+            ];
+            let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+}
+
+/// Escape a CSV field
+/// Values which spreadsheets evaluate as formulas are prefixed with `'`.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// AuditLogsParams
+/// Axum parameters query for filters
+/// - entity_type: (Optional) only return AuditLogs of this entity type
+/// - start_date: (Optional) only return AuditLogs from this date (YYYY-MM-DD)
+/// - end_date: (Optional) only return AuditLogs until this date (YYYY-MM-DD), inclusive
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuditLogsParams {
+    pub entity_type: Option<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+}
+
+impl AuditLogsParams {
+    /// Sanitize AuditLogsParams by dropping unknown entity types and invalid dates
+    pub fn sanitize(&self) -> Self {
+        let entity_type = self
+            .entity_type
+            .as_ref()
+            .map(|val| val.trim().to_string())
+            .filter(|val| AUDIT_ENTITY_TYPES.contains(&val.as_str()));
+        let parse_date = |date: &Option<String>| {
+            let date = date.as_ref()?.trim();
+            match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                Ok(val) => Some(val.format("%Y-%m-%d").to_string()),
+                Err(_) => {
+                    debug!("AuditLogsParams: ignore invalid date {date}");
+                    None
+                }
+            }
+        };
+
+        Self {
+            entity_type,
+            start_date: parse_date(&self.start_date),
+            end_date: parse_date(&self.end_date),
+        }
+    }
+    /// Return the `created_at` lower bound and the exclusive upper bound of
+    /// sanitized params. The upper bound is the day after `end_date`.
+    pub fn created_at_range(&self) -> (Option<String>, Option<String>) {
+        let end = self
+            .end_date
+            .as_ref()
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+            .and_then(|date| date.checked_add_days(Days::new(1)))
+            .map(|date| date.format("%Y-%m-%d").to_string());
+        (self.start_date.clone(), end)
+    }
+    /// Encode sanitized params back to a query string
+    pub fn to_query(&self) -> String {
+        [
+            ("entity_type", &self.entity_type),
+            ("start_date", &self.start_date),
+            ("end_date", &self.end_date),
+        ]
+        .iter()
+        .filter_map(|(key, val)| val.as_ref().map(|val| format!("{key}={}", encode(val))))
+        .collect::<Vec<String>>()
+        .join("&")
+    }
+}
+
+/// AuditLogCommandStatus
+/// Status of AuditLog Command Operations:
+/// - Stored
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AuditLogCommandStatus {
+    Stored,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn audit_log(before: &str, after: &str) -> AuditLog {
+        AuditLog {
+            created_at: "2026-10-19T12:00:00Z".to_string(),
+            user_id: "user-1".to_string(),
+            client_ip: "127.0.0.1".to_string(),
+            action: AUDIT_ACTION_UPDATE.to_string(),
+            entity_type: AUDIT_ENTITY_BLOG.to_string(),
+            entity_id: "1".to_string(),
+            before: before.to_string(),
+            after: after.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_audit_logs_params_sanitize() {
+        let params = AuditLogsParams {
+            entity_type: Some(" blog ".to_string()),
+            start_date: Some("2026-10-01".to_string()),
+            end_date: Some("2026-10-31".to_string()),
+        }
+        .sanitize();
+        assert_eq!(params.entity_type, Some("blog".to_string()));
+        assert_eq!(
+            params.created_at_range(),
+            (
+                Some("2026-10-01".to_string()),
+                Some("2026-11-01".to_string())
+            )
+        );
+        assert_eq!(
+            params.to_query(),
+            "entity_type=blog&start_date=2026-10-01&end_date=2026-10-31"
+        );
+
+        let params = AuditLogsParams {
+            entity_type: Some("users; DROP TABLE".to_string()),
+            start_date: Some("2026-13-01".to_string()),
+            end_date: Some(String::new()),
+        }
+        .sanitize();
+        assert_eq!(params, AuditLogsParams::default());
+        assert_eq!(params.created_at_range(), (None, None));
+        assert_eq!(params.to_query(), "");
+    }
+
+    #[test]
+    fn test_audit_logs_to_csv() {
+        let audit_logs = AuditLogs {
+            audit_logs: vec![
+                audit_log("name=Hello, World", "name=\"Hi\""),
+                audit_log("", "=HYPERLINK(\"x\")"),
+            ],
+        };
+
+        let csv = audit_logs.to_csv();
+        let rows: Vec<&str> = csv.split("\r\n").collect();
+        assert_eq!(
+            rows[0],
+            "created_at,user_id,client_ip,action,entity_type,entity_id,before,after"
+        );
+        assert_eq!(
+            rows[1],
+            "2026-10-19T12:00:00Z,user-1,127.0.0.1,update,blog,1,\"name=Hello, World\",\"name=\"\"Hi\"\"\""
+        );
+        assert_eq!(
+            rows[2],
+            "2026-10-19T12:00:00Z,user-1,127.0.0.1,update,blog,1,,\"'=HYPERLINK(\"\"x\"\")\""
+        );
+        assert_eq!(rows[3], "");
+    }
+}
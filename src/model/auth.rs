@@ -1,27 +1,364 @@
+use crate::config::Config;
+use crate::model::templates_admin::{AdminGetUserTemplate, AdminListUsersTemplate};
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 /// User
 /// Requiered to have:
 /// - auto-generated user id
 /// - email
 /// - hashed_password
+/// - role
+/// - totp_enabled: whether the login requires a TOTP code
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct User {
     pub id: String,
     pub email: String,
     pub hashed_password: String,
+    pub role: UserRole,
+    pub totp_enabled: bool,
 }
 
+impl User {
+    /// Convert User to (Askama) AdminGetUserTemplate
+    pub fn to_admin_template(&self) -> AdminGetUserTemplate {
+        AdminGetUserTemplate {
+            id: self.id.clone(),
+            email: self.email.clone(),
+            role: self.role.to_string(),
+            totp_enabled: self.totp_enabled,
+        }
+    }
+    /// Single line summary of the User to be stored in the audit log
+    pub fn audit_summary(&self) -> String {
+        format!("email={}; role={}", self.email, self.role)
+    }
+}
+
+/// Users
+/// Every registered User ordered by email
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Users {
+    pub users: Vec<User>,
+}
+
+impl Users {
+    /// Convert Users to (Askama) AdminListUsersTemplate
+    pub fn to_admin_list_template(&self) -> AdminListUsersTemplate {
+        AdminListUsersTemplate {
+            users: self.users.clone(),
+        }
+    }
+}
+
+/// UserRole
+/// Role of a User in the admin page:
+/// - Owner: manage every content and the Users
+/// - Editor: add and edit contents, but not delete them
+/// - Viewer: read-only access
+///
+/// Defaults to the least privileged Viewer, e.g. for tokens issued before roles were added.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    Owner,
+    Editor,
+    #[default]
+    Viewer,
+}
+
+/// Every UserRole, to be picked in the admin users form
+pub const USER_ROLES: [UserRole; 3] = [UserRole::Owner, UserRole::Editor, UserRole::Viewer];
+
+impl UserRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserRole::Owner => "owner",
+            UserRole::Editor => "editor",
+            UserRole::Viewer => "viewer",
+        }
+    }
+    /// Parse a stored or submitted role. Return None for unknown roles.
+    pub fn parse(role: &str) -> Option<Self> {
+        USER_ROLES
+            .into_iter()
+            .find(|val| val.as_str() == role.trim())
+    }
+    /// Whether the role is granted `permission`
+    pub fn is_permitted(&self, permission: Permission) -> bool {
+        match permission {
+            Permission::Edit => matches!(self, UserRole::Owner | UserRole::Editor),
+            Permission::Delete | Permission::ManageUsers | Permission::ViewAudit => {
+                *self == UserRole::Owner
+            }
+        }
+    }
+}
+
+impl Display for UserRole {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Permission
+/// Admin operations which are not granted to every role. Reading the admin
+/// pages only requires to be logged in.
+/// - Edit: add and edit contents
+/// - Delete: delete contents
+/// - ManageUsers: invite, edit and remove Users
+/// - ViewAudit: read the audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    Edit,
+    Delete,
+    ManageUsers,
+    ViewAudit,
+}
+
+/// Seconds between updates of the last seen time of a session
+pub const SESSION_LAST_SEEN_INTERVAL: i64 = 60;
+
 /// Session
 /// Active client session for specific User and device. Contains:
-/// - auto-generated session id
+/// - auto-generated session id, referenced by the `sid` JWT claim
 /// - user id
-/// - auto-generated sesion token
-/// - auto-generated expire date time in UTC+0
+/// - SHA-256 hash of the refresh token of the `refresh_token` cookie
+/// - expire date time in RFC 3339. Extended whenever the access token is refreshed
+/// - user agent and IP of the client that logged in
+/// - created and last seen date time in RFC 3339
+/// - remember: "remember me" was checked, the session outlives the browser
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Session {
     pub id: String,
     pub user_id: String,
     pub token: String,
     pub expire: String,
+    pub user_agent: String,
+    pub client_ip: String,
+    pub created_at: String,
+    pub last_seen_at: String,
+    pub remember: bool,
+}
+
+impl Session {
+    /// Whether the session expired at `now`. Unparsable dates count as expired.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        DateTime::parse_from_rfc3339(&self.expire).map_or(true, |expire| expire <= now)
+    }
+    /// Whether the last seen time is older than SESSION_LAST_SEEN_INTERVAL
+    pub fn is_last_seen_stale(&self, now: DateTime<Utc>) -> bool {
+        DateTime::parse_from_rfc3339(&self.last_seen_at).map_or(true, |last_seen| {
+            (now - last_seen.with_timezone(&Utc)).num_seconds() >= SESSION_LAST_SEEN_INTERVAL
+        })
+    }
+    /// Short description of the browser and OS of the user agent
+    /// e.g. `Firefox on Linux`
+    pub fn device(&self) -> String {
+        user_agent_device(&self.user_agent)
+    }
+}
+
+/// Short description of the browser and OS of a user agent
+fn user_agent_device(user_agent: &str) -> String {
+    // Order matters since Chromium based browsers also mention Chrome and Safari
+    let browser = [
+        ("Edg/", "Edge"),
+        ("OPR/", "Opera"),
+        ("Firefox/", "Firefox"),
+        ("Chrome/", "Chrome"),
+        ("Safari/", "Safari"),
+        ("curl/", "curl"),
+    ]
+    .iter()
+    .find(|(pattern, _)| user_agent.contains(pattern))
+    .map(|(_, name)| *name);
+    let os = [
+        ("Android", "Android"),
+        ("iPhone", "iOS"),
+        ("iPad", "iOS"),
+        ("Windows", "Windows"),
+        ("Mac OS X", "macOS"),
+        ("Linux", "Linux"),
+    ]
+    .iter()
+    .find(|(pattern, _)| user_agent.contains(pattern))
+    .map(|(_, name)| *name);
+    match (browser, os) {
+        (Some(browser), Some(os)) => format!("{browser} on {os}"),
+        (Some(name), None) | (None, Some(name)) => name.to_string(),
+        (None, None) if user_agent.is_empty() => "Unknown device".to_string(),
+        (None, None) => user_agent.to_string(),
+    }
+}
+
+/// Number of recent login attempts shown in the login history of an account
+pub const ACCOUNT_LOGIN_ACTIVITIES_LIMIT: i64 = 20;
+
+/// LoginActivity
+/// Login attempt to the admin page. Contains:
+/// - email submitted in the login form
+/// - whether the login succeeded
+/// - reason the login was rejected. Empty if it succeeded
+/// - IP and user agent of the client
+/// - attempt date time in RFC 3339
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LoginActivity {
+    pub email: String,
+    pub succeeded: bool,
+    pub reason: String,
+    pub client_ip: String,
+    pub user_agent: String,
+    pub created_at: String,
+}
+
+impl LoginActivity {
+    /// Short description of the browser and OS of the user agent
+    pub fn device(&self) -> String {
+        user_agent_device(&self.user_agent)
+    }
+}
+
+/// LoginLockout
+/// Consecutive failed logins of an email. Kept for unknown emails as well so
+/// a lockout doesn't tell whether an account exists. Contains:
+/// - email submitted in the login form
+/// - failed_count: failed logins since the last successful one
+/// - locked_until: date time in RFC 3339 until which logins are rejected.
+///   Empty if the email was never locked out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginLockout {
+    pub email: String,
+    pub failed_count: u64,
+    pub locked_until: String,
+}
+
+impl LoginLockout {
+    /// Seconds left of the lockout at `now`. None if logins are accepted.
+    pub fn remaining_seconds(&self, now: DateTime<Utc>) -> Option<i64> {
+        let locked_until = DateTime::parse_from_rfc3339(&self.locked_until).ok()?;
+        let remaining = (locked_until.with_timezone(&Utc) - now).num_seconds();
+        (remaining > 0).then_some(remaining)
+    }
+}
+
+/// LoginLockoutPolicy
+/// Exponential backoff of consecutive failed logins of an email:
+/// - threshold: failed logins before the email is locked out. 0 disables lockouts
+/// - base_seconds: first lockout, doubled on every further failed login
+/// - max_seconds: longest lockout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginLockoutPolicy {
+    pub threshold: u64,
+    pub base_seconds: u64,
+    pub max_seconds: u64,
+}
+
+impl From<&Config> for LoginLockoutPolicy {
+    fn from(config: &Config) -> Self {
+        Self {
+            threshold: config.login_lockout_threshold,
+            base_seconds: config.login_lockout_base,
+            max_seconds: config.login_lockout_max,
+        }
+    }
+}
+
+impl LoginLockoutPolicy {
+    /// Lockout after `failed_count` consecutive failed logins, in second
+    /// 0 if the threshold isn't reached.
+    pub fn lockout_seconds(&self, failed_count: u64) -> u64 {
+        if self.threshold == 0 || failed_count < self.threshold {
+            return 0;
+        }
+        let doublings = (failed_count - self.threshold).min(32) as u32;
+        self.base_seconds
+            .saturating_mul(2_u64.pow(doublings))
+            .min(self.max_seconds)
+    }
+    /// End of the lockout after `failed_count` consecutive failed logins at `now`
+    /// in RFC 3339. None if the threshold isn't reached.
+    pub fn locked_until(&self, failed_count: u64, now: DateTime<Utc>) -> Option<String> {
+        let lockout_seconds = self.lockout_seconds(failed_count);
+        if lockout_seconds == 0 {
+            return None;
+        }
+        let locked_until = now + chrono::Duration::seconds(lockout_seconds as i64);
+        Some(locked_until.to_rfc3339_opts(SecondsFormat::Secs, true))
+    }
+}
+
+/// AuthUser
+/// Logged in User of an admin request and its active Session. Set by the
+/// admin auth middleware and taken by handlers as an extractor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthUser {
+    pub user: User,
+    pub session: Session,
+}
+
+impl AuthUser {
+    /// Whether the current role of the User is granted `permission`
+    pub fn is_permitted(&self, permission: Permission) -> bool {
+        self.user.role.is_permitted(permission)
+    }
+}
+
+/// EmailTokenPurpose
+/// What a link sent by email is for:
+/// - Login: log in without the password
+/// - PasswordReset: set a new password
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailTokenPurpose {
+    Login,
+    PasswordReset,
+}
+
+impl EmailTokenPurpose {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailTokenPurpose::Login => "login",
+            EmailTokenPurpose::PasswordReset => "password_reset",
+        }
+    }
+    /// Parse a stored or submitted purpose. Return None for unknown purposes.
+    pub fn parse(purpose: &str) -> Option<Self> {
+        [EmailTokenPurpose::Login, EmailTokenPurpose::PasswordReset]
+            .into_iter()
+            .find(|val| val.as_str() == purpose.trim())
+    }
+}
+
+/// EmailToken
+/// Pending link sent by email. Deleted once it is used so a link works only
+/// once. Contains:
+/// - auto-generated token id, referenced by the `jti` claim of the link
+/// - user id
+/// - purpose of the link
+/// - expire date time in RFC 3339
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailToken {
+    pub id: String,
+    pub user_id: String,
+    pub purpose: EmailTokenPurpose,
+    pub expire: String,
+}
+
+impl EmailToken {
+    /// Whether the token expired at `now`. Unparsable dates count as expired.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        DateTime::parse_from_rfc3339(&self.expire).map_or(true, |expire| expire <= now)
+    }
+}
+
+/// EmailTokenParams
+/// Query parameters of the links sent by email
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EmailTokenParams {
+    #[serde(default)]
+    pub token: String,
 }
 
 /// UserCommandStatus
@@ -39,18 +376,293 @@ pub enum UserCommandStatus {
 /// SessionCommandStatus
 /// Status of Session Command Operations:
 /// - Stored
+/// - Updated
 /// - Deleted
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum SessionCommandStatus {
+    Stored,
+    Updated,
+    Deleted,
+}
+
+/// EmailTokenCommandStatus
+/// Status of EmailToken Command Operations:
+/// - Stored
+/// - Deleted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EmailTokenCommandStatus {
     Stored,
     Deleted,
 }
 
+/// LoginActivityCommandStatus
+/// Status of LoginActivity Command Operations:
+/// - Stored
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LoginActivityCommandStatus {
+    Stored,
+}
+
+/// LoginLockoutCommandStatus
+/// Status of LoginLockout Command Operations:
+/// - Stored
+/// - Deleted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LoginLockoutCommandStatus {
+    Stored,
+    Deleted,
+}
+
+/// Bundled list of common breached passwords. Lines starting with `#` are comments.
+const PASSWORD_DENYLIST: &str = include_str!("password_denylist.txt");
+/// Longest accepted password to keep hashing cheap
+pub const PASSWORD_MAX_LENGTH: usize = 128;
+
+/// PasswordPolicy
+/// Rules of a new admin password:
+/// - min_length: Minimum number of characters
+/// - use_denylist: Reject passwords found in the bundled breached-password list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    pub min_length: usize,
+    pub use_denylist: bool,
+}
+
+impl From<&Config> for PasswordPolicy {
+    fn from(config: &Config) -> Self {
+        Self {
+            min_length: config.password_min_length,
+            use_denylist: config.password_denylist,
+        }
+    }
+}
+
+impl PasswordPolicy {
+    /// Check a sanitized password against the policy
+    /// Return every violated rule. Empty if the password is accepted.
+    pub fn check(&self, password: &str) -> Vec<String> {
+        let mut errors = Vec::new();
+        let length = password.chars().count();
+        if length == 0 {
+            errors.push("Password cannot be empty.".to_string());
+        } else if length < self.min_length {
+            errors.push(format!(
+                "Password must have at least {} characters.",
+                self.min_length
+            ));
+        }
+        if length > PASSWORD_MAX_LENGTH {
+            errors.push(format!(
+                "Password must have at most {PASSWORD_MAX_LENGTH} characters."
+            ));
+        }
+        if self.use_denylist && is_password_denylisted(password) {
+            errors.push("Password is too common. It was found in breached passwords.".to_string());
+        }
+        errors
+    }
+}
+
+/// Whether a password is in the bundled breached-password list, ignoring case
+fn is_password_denylisted(password: &str) -> bool {
+    let password = password.to_lowercase();
+    PASSWORD_DENYLIST
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .any(|line| line.trim() == password)
+}
+
 /// Claims
 /// JWT claims
+/// - sub: Id of the logged in User. Empty for tokens issued before it was added.
+/// - role: Role of the logged in User. Viewer for tokens issued before it was added.
+/// - sid: Id of the Session. Empty for tokens issued before sessions were added.
+///
 /// Reference: https://github.com/Keats/jsonwebtoken?tab=readme-ov-file#claims
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
+    #[serde(default)]
+    pub sub: String,
+    #[serde(default)]
+    pub role: UserRole,
+    #[serde(default)]
+    pub sid: String,
     pub exp: usize,
     pub iat: usize,
 }
+
+/// EmailTokenClaims
+/// Claims of the signed token of a link sent by email
+/// - sub: Id of the User
+/// - purpose: What the link is for
+/// - jti: Id of the EmailToken, consumed when the link is used
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmailTokenClaims {
+    pub sub: String,
+    pub purpose: EmailTokenPurpose,
+    pub jti: String,
+    pub exp: usize,
+    pub iat: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_user_role_parse() {
+        assert_eq!(UserRole::parse("owner"), Some(UserRole::Owner));
+        assert_eq!(UserRole::parse(" editor "), Some(UserRole::Editor));
+        assert_eq!(UserRole::parse("viewer"), Some(UserRole::Viewer));
+        assert_eq!(UserRole::parse("admin"), None);
+        assert_eq!(UserRole::default(), UserRole::Viewer);
+    }
+
+    #[test]
+    fn test_user_role_is_permitted() {
+        let permissions = [
+            Permission::Edit,
+            Permission::Delete,
+            Permission::ManageUsers,
+            Permission::ViewAudit,
+        ];
+        assert!(permissions
+            .iter()
+            .all(|permission| UserRole::Owner.is_permitted(*permission)));
+        assert!(UserRole::Editor.is_permitted(Permission::Edit));
+        assert!(!UserRole::Editor.is_permitted(Permission::Delete));
+        assert!(!UserRole::Editor.is_permitted(Permission::ManageUsers));
+        assert!(!permissions
+            .iter()
+            .any(|permission| UserRole::Viewer.is_permitted(*permission)));
+    }
+
+    #[test]
+    fn test_password_policy_check() {
+        let policy = PasswordPolicy {
+            min_length: 12,
+            use_denylist: true,
+        };
+        assert!(policy.check("tr0ub4dor&3-horse").is_empty());
+        assert_eq!(policy.check("short").len(), 1);
+        assert_eq!(policy.check("Password1234").len(), 1);
+        assert_eq!(policy.check("qwerty").len(), 2);
+        assert_eq!(
+            policy.check(""),
+            vec!["Password cannot be empty.".to_string()]
+        );
+        assert_eq!(policy.check(&"a".repeat(PASSWORD_MAX_LENGTH + 1)).len(), 1);
+
+        let policy = PasswordPolicy {
+            min_length: 4,
+            use_denylist: false,
+        };
+        assert!(policy.check("qwerty").is_empty());
+        assert_eq!(policy.check("").len(), 1);
+    }
+
+    #[test]
+    fn test_email_token_purpose_parse() {
+        assert_eq!(
+            EmailTokenPurpose::parse("login"),
+            Some(EmailTokenPurpose::Login)
+        );
+        assert_eq!(
+            EmailTokenPurpose::parse("password_reset"),
+            Some(EmailTokenPurpose::PasswordReset)
+        );
+        assert_eq!(EmailTokenPurpose::parse("admin"), None);
+    }
+
+    #[test]
+    fn test_claims_without_role() {
+        let claims: Claims = serde_json::from_str(r#"{"exp": 2, "iat": 1}"#).unwrap();
+        assert_eq!(claims.sub, "");
+        assert_eq!(claims.role, UserRole::Viewer);
+        assert_eq!(claims.sid, "");
+    }
+
+    fn session(user_agent: &str) -> Session {
+        Session {
+            id: "session-1".to_string(),
+            user_id: "user-1".to_string(),
+            token: String::new(),
+            expire: "2026-01-01T03:00:00Z".to_string(),
+            user_agent: user_agent.to_string(),
+            client_ip: "127.0.0.1".to_string(),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            last_seen_at: "2026-01-01T00:00:00Z".to_string(),
+            remember: false,
+        }
+    }
+
+    #[test]
+    fn test_session_times() {
+        let session = session("");
+        let at = |val: &str| val.parse::<DateTime<Utc>>().unwrap();
+        assert!(!session.is_expired(at("2026-01-01T02:59:59Z")));
+        assert!(session.is_expired(at("2026-01-01T03:00:00Z")));
+        assert!(!session.is_last_seen_stale(at("2026-01-01T00:00:59Z")));
+        assert!(session.is_last_seen_stale(at("2026-01-01T00:01:00Z")));
+
+        let mut broken = session.clone();
+        broken.expire = String::new();
+        assert!(broken.is_expired(at("2026-01-01T00:00:00Z")));
+    }
+
+    #[test]
+    fn test_login_lockout_policy() {
+        let policy = LoginLockoutPolicy {
+            threshold: 3,
+            base_seconds: 60,
+            max_seconds: 600,
+        };
+        assert_eq!(policy.lockout_seconds(2), 0);
+        assert_eq!(policy.lockout_seconds(3), 60);
+        assert_eq!(policy.lockout_seconds(4), 120);
+        assert_eq!(policy.lockout_seconds(6), 480);
+        assert_eq!(policy.lockout_seconds(7), 600);
+        assert_eq!(policy.lockout_seconds(u64::MAX), 600);
+
+        let now = "2026-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(policy.locked_until(2, now), None);
+        let mut lockout = LoginLockout {
+            email: "admin@example.com".to_string(),
+            failed_count: 2,
+            locked_until: String::new(),
+        };
+        assert_eq!(lockout.remaining_seconds(now), None);
+        lockout.failed_count = 3;
+        lockout.locked_until = policy.locked_until(3, now).unwrap();
+        assert_eq!(lockout.locked_until, "2026-01-01T00:01:00Z");
+        assert_eq!(lockout.remaining_seconds(now), Some(60));
+        let later = now + chrono::Duration::seconds(60);
+        assert_eq!(lockout.remaining_seconds(later), None);
+
+        let disabled = LoginLockoutPolicy {
+            threshold: 0,
+            ..policy
+        };
+        assert_eq!(disabled.lockout_seconds(100), 0);
+    }
+
+    #[test]
+    fn test_session_device() {
+        assert_eq!(
+            session("Mozilla/5.0 (X11; Linux x86_64; rv:131.0) Gecko/20100101 Firefox/131.0")
+                .device(),
+            "Firefox on Linux"
+        );
+        assert_eq!(
+            session("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/130.0.0.0 Safari/537.36 Edg/130.0.0.0").device(),
+            "Edge on Windows"
+        );
+        assert_eq!(
+            session("Mozilla/5.0 (iPhone; CPU iPhone OS 18_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/18.0 Mobile/15E148 Safari/604.1").device(),
+            "Safari on iOS"
+        );
+        assert_eq!(session("curl/8.5.0").device(), "curl");
+        assert_eq!(session("my-bot").device(), "my-bot");
+        assert_eq!(session("").device(), "Unknown device");
+    }
+}
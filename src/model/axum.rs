@@ -1,8 +1,19 @@
 use crate::config::Config;
+use crate::usecase::audit_logs::AuditLogDBUseCase;
 use crate::usecase::auth::AuthDBUseCase;
+use crate::usecase::blog_drafts::BlogDraftDBUseCase;
 use crate::usecase::blog_tag_mappings::{BlogTagMappingCacheUseCase, BlogTagMappingDBUseCase};
 use crate::usecase::blogs::{BlogCacheUseCase, BlogDBUseCase};
+use crate::usecase::dashboard::{DashboardCacheUseCase, DashboardDBUseCase};
+use crate::usecase::link_checks::LinkCheckDBUseCase;
+use crate::usecase::mail::MailUseCase;
+use crate::usecase::media::{MediaDBUseCase, MediaStorageUseCase};
+use crate::usecase::organizations::OrganizationDBUseCase;
+use crate::usecase::profiles::{ProfileCacheUseCase, ProfileDBUseCase};
+use crate::usecase::projects::{ProjectCacheUseCase, ProjectDBUseCase};
+use crate::usecase::resume::{ResumeCacheUseCase, ResumeDBUseCase};
 use crate::usecase::tags::{TagCacheUseCase, TagDBUseCase};
+use crate::usecase::talk_tag_mappings::{TalkTagMappingCacheUseCase, TalkTagMappingDBUseCase};
 use crate::usecase::talks::{TalkCacheUseCase, TalkDBUseCase};
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -19,8 +30,25 @@ pub struct AppState {
     pub tag_db_usecase: Arc<Mutex<Option<TagDBUseCase>>>,
     pub blog_tag_mapping_db_usecase: Arc<Mutex<Option<BlogTagMappingDBUseCase>>>,
     pub auth_db_usecase: Arc<Mutex<Option<AuthDBUseCase>>>,
+    pub organization_db_usecase: Arc<Mutex<Option<OrganizationDBUseCase>>>,
+    pub talk_tag_mapping_db_usecase: Arc<Mutex<Option<TalkTagMappingDBUseCase>>>,
+    pub project_db_usecase: Arc<Mutex<Option<ProjectDBUseCase>>>,
+    pub profile_db_usecase: Arc<Mutex<Option<ProfileDBUseCase>>>,
+    pub resume_db_usecase: Arc<Mutex<Option<ResumeDBUseCase>>>,
+    pub media_db_usecase: Arc<Mutex<Option<MediaDBUseCase>>>,
+    pub blog_draft_db_usecase: Arc<Mutex<Option<BlogDraftDBUseCase>>>,
+    pub link_check_db_usecase: Arc<Mutex<Option<LinkCheckDBUseCase>>>,
+    pub dashboard_db_usecase: Arc<Mutex<Option<DashboardDBUseCase>>>,
+    pub audit_log_db_usecase: Arc<Mutex<Option<AuditLogDBUseCase>>>,
     pub talk_cache_usecase: Arc<Mutex<Option<TalkCacheUseCase>>>,
     pub tag_cache_usecase: Arc<Mutex<Option<TagCacheUseCase>>>,
     pub blog_cache_usecase: Arc<Mutex<Option<BlogCacheUseCase>>>,
     pub blog_tag_mapping_cache_usecase: Arc<Mutex<Option<BlogTagMappingCacheUseCase>>>,
+    pub talk_tag_mapping_cache_usecase: Arc<Mutex<Option<TalkTagMappingCacheUseCase>>>,
+    pub project_cache_usecase: Arc<Mutex<Option<ProjectCacheUseCase>>>,
+    pub profile_cache_usecase: Arc<Mutex<Option<ProfileCacheUseCase>>>,
+    pub resume_cache_usecase: Arc<Mutex<Option<ResumeCacheUseCase>>>,
+    pub dashboard_cache_usecase: Arc<Mutex<Option<DashboardCacheUseCase>>>,
+    pub media_storage_usecase: Arc<Mutex<Option<MediaStorageUseCase>>>,
+    pub mail_usecase: Arc<Mutex<Option<MailUseCase>>>,
 }
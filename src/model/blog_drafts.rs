@@ -0,0 +1,123 @@
+use crate::model::blogs::Blog;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// BlogDraft
+/// Autosaved in-progress edit of a Blog by a User with fields:
+/// - blog_id: Edited Blog Identifier
+/// - user_id: Editing User Identifier
+/// - name: Draft Blog name
+/// - body: Draft Blog markdown body
+/// - tags: Draft Blog tags
+/// - updated_at: Last autosave time in RFC 3339
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BlogDraft {
+    pub blog_id: i64,
+    pub user_id: String,
+    pub name: String,
+    pub body: String,
+    pub tags: Vec<String>,
+    pub updated_at: String,
+}
+
+impl BlogDraft {
+    /// Create a BlogDraft from an edited Blog
+    /// Empty tags from the edit form are dropped.
+    pub fn from_blog(blog: &Blog, user_id: String, now: DateTime<Utc>) -> Self {
+        Self {
+            blog_id: blog.id,
+            user_id,
+            name: blog.name.clone().unwrap_or_default(),
+            body: blog.body.clone().unwrap_or_default(),
+            tags: non_empty_tags(blog),
+            updated_at: now.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        }
+    }
+    /// Check if the BlogDraft has the same content as the saved Blog
+    /// Tags are compared regardless of their order.
+    pub fn is_unchanged_from(&self, blog: &Blog) -> bool {
+        let mut draft_tags = self.tags.clone();
+        let mut blog_tags = non_empty_tags(blog);
+        draft_tags.sort();
+        blog_tags.sort();
+
+        Some(&self.name) == blog.name.as_ref()
+            && Some(&self.body) == blog.body.as_ref()
+            && draft_tags == blog_tags
+    }
+}
+
+/// Get non-empty tags of a Blog
+fn non_empty_tags(blog: &Blog) -> Vec<String> {
+    blog.tags
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+/// BlogDraftCommandStatus
+/// Status of BlogDraft Command Operations:
+/// - Saved
+/// - Deleted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BlogDraftCommandStatus {
+    Saved,
+    Deleted,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sample_blog() -> Blog {
+        Blog {
+            id: 1,
+            name: Some("My Blog".to_string()),
+            source: None,
+            filename: None,
+            body: Some("# Hello".to_string()),
+            tags: Some(vec!["".to_string(), "rust".to_string(), "go".to_string()]),
+        }
+    }
+
+    #[test]
+    fn test_blog_draft_from_blog() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 19, 12, 0, 0).unwrap();
+        let draft = BlogDraft::from_blog(&sample_blog(), "user-1".to_string(), now);
+        assert_eq!(
+            draft,
+            BlogDraft {
+                blog_id: 1,
+                user_id: "user-1".to_string(),
+                name: "My Blog".to_string(),
+                body: "# Hello".to_string(),
+                tags: vec!["rust".to_string(), "go".to_string()],
+                updated_at: "2026-10-19T12:00:00Z".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_blog_draft_is_unchanged_from() {
+        let draft = BlogDraft::from_blog(&sample_blog(), "user-1".to_string(), Utc::now());
+        let saved = Blog {
+            tags: Some(vec!["go".to_string(), "rust".to_string()]),
+            ..sample_blog()
+        };
+        assert!(draft.is_unchanged_from(&saved));
+
+        let edited = Blog {
+            body: Some("# Hello, World".to_string()),
+            ..saved.clone()
+        };
+        assert!(!draft.is_unchanged_from(&edited));
+        let retagged = Blog {
+            tags: Some(vec!["go".to_string()]),
+            ..saved
+        };
+        assert!(!draft.is_unchanged_from(&retagged));
+    }
+}
@@ -1,6 +1,10 @@
-use crate::model::templates::{BlogMetadataTemplate, BlogTemplate};
+use crate::model::templates::{BlogMetadataTemplate, BlogPreviewTemplate, BlogTemplate};
 use crate::utils::{convert_markdown_to_html, remove_whitespace};
+use markdown::mdast::Node;
+use markdown::{to_mdast, ParseOptions};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fmt::Display;
 
 /// BlogCommandStatus
@@ -82,6 +86,124 @@ impl Blog {
             tags: self.tags.clone().unwrap(),
         }
     }
+    /// Convert to `BlogPreviewTemplate`
+    /// Render the body like `as_template` without the page layout
+    pub fn as_preview_template(&self) -> BlogPreviewTemplate {
+        let template = self.as_template();
+        BlogPreviewTemplate {
+            id: template.id,
+            name: template.name,
+            filename: template.filename,
+            body: template.body,
+            tags: template.tags,
+        }
+    }
+    /// Get Blog Ids linked from the Blog body with relative `/blogs/{id}` links
+    pub fn linked_blog_ids(&self) -> Vec<i64> {
+        let mut blog_ids = Vec::new();
+        for node in blog_body_nodes(self.body.as_deref().unwrap_or_default()) {
+            if let Node::Link(link) = node {
+                if let Some(blog_id) = parse_blog_link(&link.url) {
+                    if !blog_ids.contains(&blog_id) {
+                        blog_ids.push(blog_id);
+                    }
+                }
+            }
+        }
+        blog_ids
+    }
+    /// Get every link and image URL in the body in document order
+    pub fn links(&self) -> Vec<String> {
+        let mut links: Vec<String> = Vec::new();
+        for node in blog_body_nodes(self.body.as_deref().unwrap_or_default()) {
+            let url = match node {
+                Node::Link(link) => link.url,
+                Node::Image(image) => image.url,
+                _ => continue,
+            };
+            if !url.trim().is_empty() && !links.contains(&url) {
+                links.push(url);
+            }
+        }
+        links
+    }
+    /// Summarize the Blog for the audit log
+    pub fn audit_summary(&self) -> String {
+        format!(
+            "name={}; body={} chars; tags={}",
+            self.name.as_deref().unwrap_or_default(),
+            self.body.as_deref().unwrap_or_default().chars().count(),
+            self.tags.clone().unwrap_or_default().join(",")
+        )
+    }
+    /// Lint the Blog before publishing it
+    /// `blog_ids` are the existing Blogs linked from the body
+    /// and `tags` are all of the existing Tag names.
+    pub fn lint(&self, blog_ids: &[i64], tags: &[String]) -> BlogLint {
+        let mut lint = BlogLint::default();
+        let mut heading_ids = HashSet::new();
+        let mut heading_depth = None;
+
+        for node in blog_body_nodes(self.body.as_deref().unwrap_or_default()) {
+            match &node {
+                Node::Image(image) if image.alt.trim().is_empty() => {
+                    lint.warnings
+                        .push(format!("Image '{}' has no alt text.", image.url));
+                }
+                Node::Link(link) => {
+                    let text = node.to_string();
+                    if link.url.trim().is_empty() {
+                        lint.errors.push(format!("Link '{text}' has no URL."));
+                    } else if text.trim().is_empty() {
+                        lint.errors
+                            .push(format!("Link to '{}' has no text.", link.url));
+                    }
+                    match parse_blog_link(&link.url) {
+                        Some(blog_id) if blog_id != self.id && !blog_ids.contains(&blog_id) => {
+                            lint.errors.push(format!(
+                                "Link to '{}' points to a blog that doesn't exist.",
+                                link.url
+                            ));
+                        }
+                        _ => {}
+                    }
+                }
+                Node::Heading(heading) => {
+                    let text = node.to_string();
+                    let heading_id = heading_slug(&text);
+                    if !heading_ids.insert(heading_id.clone()) {
+                        lint.warnings.push(format!(
+                            "Heading '{text}' has a duplicate id '{heading_id}'."
+                        ));
+                    }
+                    match heading_depth {
+                        Some(depth) if heading.depth > depth + 1 => {
+                            lint.warnings.push(format!(
+                                "Heading '{}' skips from h{} to h{}.",
+                                text, depth, heading.depth
+                            ));
+                        }
+                        _ => {}
+                    }
+                    heading_depth = Some(heading.depth);
+                }
+                Node::Html(html) if html.value.to_lowercase().contains("<script") => {
+                    lint.errors.push(
+                        "Raw <script> is not allowed and would be removed from the blog."
+                            .to_string(),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        for tag in self.tags.clone().unwrap_or_default() {
+            if !tag.is_empty() && !tags.contains(&tag) {
+                lint.errors.push(format!("Tag '{tag}' doesn't exist."));
+            }
+        }
+        lint
+    }
     /// Calculate size of Tag in u32
     /// Useful for weighing data size
     pub const fn data_size(&self) -> u32 {
@@ -94,6 +216,52 @@ impl Blog {
     }
 }
 
+/// BlogLint
+/// Problems found in a Blog before publishing it:
+/// - errors: Problems that block publishing
+/// - warnings: Problems that are shown but allowed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlogLint {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Parse a Blog markdown body and list all of its nodes in document order
+fn blog_body_nodes(body: &str) -> Vec<Node> {
+    let Ok(root) = to_mdast(body, &ParseOptions::gfm()) else {
+        return Vec::new();
+    };
+    let mut nodes = Vec::new();
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if let Some(children) = node.children() {
+            stack.extend(children.iter().rev().cloned());
+        }
+        nodes.push(node);
+    }
+    nodes
+}
+
+/// Parse the Blog Id of a relative `/blogs/{id}` link
+fn parse_blog_link(url: &str) -> Option<i64> {
+    let pattern = Regex::new(r"^(?:\.{0,2}/)*blogs/(\d+)(?:[/?#].*)?$").unwrap();
+    pattern.captures(url)?[1].parse().ok()
+}
+
+/// Create the anchor id of a heading like GitHub does
+/// e.g. `Hello, World!` becomes `hello-world`
+fn heading_slug(text: &str) -> String {
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            ' ' => Some('-'),
+            _ => None,
+        })
+        .collect()
+}
+
 /// BlogsParams
 /// Axum Query struct for `/blogs` query parameters
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -104,6 +272,14 @@ pub struct BlogsParams {
 }
 
 impl BlogsParams {
+    /// BlogsParams of every Blog without pagination or filters
+    pub fn all() -> Self {
+        Self {
+            start: Some(0),
+            end: Some(i64::MAX),
+            tags: None,
+        }
+    }
     /// Sanitize BlogsParams
     pub fn sanitize(&self) -> Self {
         let start = self.start.unwrap_or(0);
@@ -207,6 +383,88 @@ mod test {
         assert_eq!(template.tags, vec!["rust".to_string()]);
     }
 
+    #[test]
+    fn test_blog_as_preview_template() {
+        use askama::Template;
+
+        let preview = sample_blog().as_preview_template().render().unwrap();
+        assert!(preview.contains("<h1>Hello</h1>"));
+        assert!(preview.contains("hx-get=\"/blogs?tags=rust\""));
+        assert!(!preview.contains("<html"));
+    }
+
+    #[test]
+    fn test_blog_linked_blog_ids() {
+        let blog = Blog {
+            body: Some(
+                "[a](/blogs/2) [b](../blogs/3#intro) [c](/blogs/2) [d](https://example.com/blogs/4)"
+                    .to_string(),
+            ),
+            ..sample_blog()
+        };
+        assert_eq!(blog.linked_blog_ids(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_blog_links() {
+        let blog = Blog {
+            body: Some(
+                "[a](/blogs/2) ![img](/media/a.png) [b](https://example.com) [c](/blogs/2) [d]()"
+                    .to_string(),
+            ),
+            ..sample_blog()
+        };
+        assert_eq!(
+            blog.links(),
+            vec![
+                "/blogs/2".to_string(),
+                "/media/a.png".to_string(),
+                "https://example.com".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blog_lint() {
+        let body = concat!(
+            "# Hello\n\n",
+            "![](/media/cat.png)\n\n",
+            "[](/blogs/2) [Missing](/blogs/3) [Self](/blogs/1) [No URL]()\n\n",
+            "### Hello\n\n",
+            "## Hello\n\n",
+            "<script>alert(1)</script>\n",
+        );
+        let blog = Blog {
+            body: Some(body.to_string()),
+            tags: Some(vec!["".to_string(), "rust".to_string(), "zig".to_string()]),
+            ..sample_blog()
+        };
+        let lint = blog.lint(&[2], &["rust".to_string()]);
+        assert_eq!(
+            lint.errors,
+            vec![
+                "Link to '/blogs/2' has no text.".to_string(),
+                "Link to '/blogs/3' points to a blog that doesn't exist.".to_string(),
+                "Link 'No URL' has no URL.".to_string(),
+                "Raw <script> is not allowed and would be removed from the blog.".to_string(),
+                "Tag 'zig' doesn't exist.".to_string(),
+            ]
+        );
+        assert_eq!(
+            lint.warnings,
+            vec![
+                "Image '/media/cat.png' has no alt text.".to_string(),
+                "Heading 'Hello' has a duplicate id 'hello'.".to_string(),
+                "Heading 'Hello' skips from h1 to h3.".to_string(),
+                "Heading 'Hello' has a duplicate id 'hello'.".to_string(),
+            ]
+        );
+        assert_eq!(
+            sample_blog().lint(&[], &["rust".to_string()]),
+            BlogLint::default()
+        );
+    }
+
     #[test]
     fn test_blog_data_size() {
         assert!(sample_blog().data_size() > 0);
@@ -244,6 +502,14 @@ mod test {
         assert_eq!(sanitized.tags, Some(String::new()));
     }
 
+    #[test]
+    fn test_blogs_params_all() {
+        let sanitized = BlogsParams::all().sanitize();
+        assert_eq!(sanitized.start, Some(0));
+        assert_eq!(sanitized.end, Some(i64::MAX));
+        assert_eq!(sanitized.tags, Some(String::new()));
+    }
+
     #[test]
     fn test_blogs_params_sanitize_preserves_start_and_end() {
         // Regression: start used to be derived from end.
@@ -0,0 +1,199 @@
+use crate::model::templates_admin::{
+    AdminDashboardCacheTemplate, AdminDashboardContentTemplate, AdminDashboardTagsTemplate,
+};
+use serde::{Deserialize, Serialize};
+
+/// Number of most used tags shown in the admin dashboard
+pub const DASHBOARD_TAGS_LIMIT: usize = 5;
+/// Number of recently edited items shown in the admin dashboard
+pub const DASHBOARD_RECENT_UPDATES_LIMIT: i64 = 5;
+/// Number of recent login attempts shown in the admin dashboard
+pub const DASHBOARD_LOGIN_ACTIVITIES_LIMIT: i64 = 10;
+
+/// ContentStats
+/// Number of stored contents with fields:
+/// - blogs: Number of Blogs
+/// - talks: Number of Talks
+/// - tags: Number of Tags
+/// - projects: Number of Projects
+/// - drafts: Number of autosaved BlogDrafts of all users
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ContentStats {
+    pub blogs: i64,
+    pub talks: i64,
+    pub tags: i64,
+    pub projects: i64,
+    pub drafts: i64,
+}
+
+impl ContentStats {
+    /// Convert ContentStats to (Askama) AdminDashboardContentTemplate
+    pub fn to_admin_template(&self) -> AdminDashboardContentTemplate {
+        AdminDashboardContentTemplate {
+            blogs: self.blogs,
+            talks: self.talks,
+            tags: self.tags,
+            projects: self.projects,
+            drafts: self.drafts,
+        }
+    }
+}
+
+/// TagUsage
+/// Number of Blogs and Talks using a Tag
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TagUsage {
+    pub name: String,
+    pub blogs: i64,
+    pub talks: i64,
+}
+
+impl TagUsage {
+    /// Number of Blogs and Talks using the Tag
+    pub fn total(&self) -> i64 {
+        self.blogs + self.talks
+    }
+}
+
+/// TagUsages
+/// Usage of every Tag
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TagUsages {
+    pub tag_usages: Vec<TagUsage>,
+}
+
+impl TagUsages {
+    /// Convert TagUsages to (Askama) AdminDashboardTagsTemplate
+    /// Take up to `limit` most used Tags and every unused Tag, both sorted by name on ties.
+    pub fn to_admin_template(&self, limit: usize) -> AdminDashboardTagsTemplate {
+        let mut most_used: Vec<TagUsage> = self
+            .tag_usages
+            .iter()
+            .filter(|tag_usage| tag_usage.total() > 0)
+            .cloned()
+            .collect();
+        most_used.sort_by(|a, b| b.total().cmp(&a.total()).then(a.name.cmp(&b.name)));
+        most_used.truncate(limit);
+
+        let mut unused: Vec<String> = self
+            .tag_usages
+            .iter()
+            .filter(|tag_usage| tag_usage.total() == 0)
+            .map(|tag_usage| tag_usage.name.clone())
+            .collect();
+        unused.sort();
+
+        AdminDashboardTagsTemplate { most_used, unused }
+    }
+}
+
+/// RecentUpdate
+/// Recently added or edited Blog or Talk with fields:
+/// - entity_type: `blog` or `talk`
+/// - id: Blog or Talk Identifier
+/// - name: Blog or Talk name
+/// - updated_at: Last update time in RFC 3339
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RecentUpdate {
+    pub entity_type: String,
+    pub id: i64,
+    pub name: String,
+    pub updated_at: String,
+}
+
+/// CacheStats
+/// InMemoryCache usage since the application started with fields:
+/// - hits: Lookups served from the cache
+/// - misses: Lookups which fell back to the database
+/// - entries: Number of entries per cache
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: Vec<CacheEntryCount>,
+}
+
+/// CacheEntryCount
+/// Number of entries in a cache
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CacheEntryCount {
+    pub name: String,
+    pub count: u64,
+}
+
+impl CacheStats {
+    /// Format the ratio of lookups served from the cache
+    /// Return `-` if there was no lookup yet.
+    pub fn hit_ratio(&self) -> String {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            return "-".to_string();
+        }
+        format!("{:.1}%", self.hits as f64 * 100.0 / lookups as f64)
+    }
+    /// Convert CacheStats to (Askama) AdminDashboardCacheTemplate
+    pub fn to_admin_template(&self) -> AdminDashboardCacheTemplate {
+        AdminDashboardCacheTemplate {
+            is_enabled: true,
+            hits: self.hits,
+            misses: self.misses,
+            hit_ratio: self.hit_ratio(),
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tag_usage(name: &str, blogs: i64, talks: i64) -> TagUsage {
+        TagUsage {
+            name: name.to_string(),
+            blogs,
+            talks,
+        }
+    }
+
+    #[test]
+    fn test_tag_usages_to_admin_template() {
+        let tag_usages = TagUsages {
+            tag_usages: vec![
+                tag_usage("rust", 3, 1),
+                tag_usage("go", 0, 0),
+                tag_usage("k8s", 1, 3),
+                tag_usage("aws", 0, 0),
+                tag_usage("gcp", 0, 1),
+            ],
+        };
+
+        let template = tag_usages.to_admin_template(2);
+        let most_used: Vec<(&str, i64)> = template
+            .most_used
+            .iter()
+            .map(|val| (val.name.as_str(), val.total()))
+            .collect();
+        assert_eq!(most_used, vec![("k8s", 4), ("rust", 4)]);
+        assert_eq!(template.unused, vec!["aws".to_string(), "go".to_string()]);
+    }
+
+    #[test]
+    fn test_cache_stats_hit_ratio() {
+        assert_eq!(CacheStats::default().hit_ratio(), "-");
+
+        let cache_stats = CacheStats {
+            hits: 7,
+            misses: 1,
+            entries: vec![CacheEntryCount {
+                name: "Blogs".to_string(),
+                count: 2,
+            }],
+        };
+        assert_eq!(cache_stats.hit_ratio(), "87.5%");
+
+        let template = cache_stats.to_admin_template();
+        assert!(template.is_enabled);
+        assert_eq!(template.hit_ratio, "87.5%");
+        assert_eq!(template.entries, cache_stats.entries);
+    }
+}
@@ -0,0 +1,311 @@
+use crate::model::templates_admin::{
+    AdminBrokenLinkSourceTemplate, AdminBrokenLinkTemplate, AdminListLinkChecksTemplate,
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Source type of links found in a Blog body
+pub const LINK_SOURCE_BLOG: &str = "blog";
+/// Source type of Talk media and organisation links
+pub const LINK_SOURCE_TALK: &str = "talk";
+
+/// Pages served by the router which don't depend on stored data
+const INTERNAL_PAGES: [&str; 11] = [
+    "/",
+    "/version",
+    "/resume",
+    "/resume.json",
+    "/login",
+    "/blogs",
+    "/talks",
+    "/talks/calendar.ics",
+    "/projects",
+    "/theme.js",
+    "/styles.css",
+];
+
+/// LinkCheck
+/// Result of checking a link with fields:
+/// - source_type: `LINK_SOURCE_BLOG` or `LINK_SOURCE_TALK`
+/// - source_id: Blog or Talk Identifier
+/// - source_name: Blog or Talk name
+/// - url: Checked link as written in the source
+/// - is_broken: Whether the link is broken
+/// - detail: Response status or the reason the link is broken
+/// - checked_at: Check time in RFC 3339
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LinkCheck {
+    pub source_type: String,
+    pub source_id: i64,
+    pub source_name: String,
+    pub url: String,
+    pub is_broken: bool,
+    pub detail: String,
+    pub checked_at: String,
+}
+
+/// LinkChecks
+/// Results of the latest link check run
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LinkChecks {
+    pub link_checks: Vec<LinkCheck>,
+}
+
+impl LinkChecks {
+    /// Convert LinkChecks to (Askama) AdminListLinkChecksTemplate
+    /// Broken links are grouped per source in the order they were checked.
+    pub fn to_admin_list_template(&self, notice: String) -> AdminListLinkChecksTemplate {
+        let mut sources: Vec<AdminBrokenLinkSourceTemplate> = Vec::new();
+        for link_check in self.link_checks.iter().filter(|val| val.is_broken) {
+            let link = AdminBrokenLinkTemplate {
+                url: link_check.url.clone(),
+                detail: link_check.detail.clone(),
+                checked_at: link_check.checked_at.clone(),
+            };
+            match sources.iter_mut().find(|source| {
+                source.source_type == link_check.source_type
+                    && source.source_id == link_check.source_id
+            }) {
+                Some(source) => source.links.push(link),
+                None => sources.push(AdminBrokenLinkSourceTemplate {
+                    source_type: link_check.source_type.clone(),
+                    source_id: link_check.source_id,
+                    source_name: link_check.source_name.clone(),
+                    links: vec![link],
+                }),
+            }
+        }
+
+        AdminListLinkChecksTemplate {
+            checked_count: self.link_checks.len(),
+            broken_count: self.link_checks.iter().filter(|val| val.is_broken).count(),
+            last_checked_at: self
+                .link_checks
+                .iter()
+                .map(|val| val.checked_at.clone())
+                .max()
+                .unwrap_or_default(),
+            sources,
+            notice,
+        }
+    }
+}
+
+/// LinkSource
+/// Blog or Talk whose links are checked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkSource {
+    pub source_type: String,
+    pub source_id: i64,
+    pub source_name: String,
+    pub urls: Vec<String>,
+}
+
+/// LinkTarget
+/// Where a checked link points to:
+/// - Internal: Path served by this site
+/// - External: HTTP(S) URL served by another site
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    Internal(InternalLink),
+    External(String),
+}
+
+/// InternalLink
+/// Route of an internal link:
+/// - Page: Page which always exists
+/// - Blog: Blog page by its identifier
+/// - Project: Project page by its identifier
+/// - Media: Media file by its key
+/// - Static: Favicon file served under /statics
+/// - Unknown: Path without a route
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InternalLink {
+    Page,
+    Blog(i64),
+    Project(i64),
+    Media(String),
+    Static(String),
+    Unknown,
+}
+
+/// Classify a link into a `LinkTarget`
+/// Return None for links which can't be checked like anchors and `mailto:`.
+/// Relative paths are resolved from the site root.
+pub fn classify_link(url: &str) -> Option<LinkTarget> {
+    let url = url.trim();
+    if url.is_empty() || url.starts_with('#') {
+        return None;
+    }
+    if let Some(rest) = url.strip_prefix("//") {
+        return Some(LinkTarget::External(format!("https://{rest}")));
+    }
+
+    let scheme_re = Regex::new(r"^([a-zA-Z][a-zA-Z0-9+.-]*):").unwrap();
+    if let Some(caps) = scheme_re.captures(url) {
+        return match caps[1].to_lowercase().as_str() {
+            "http" | "https" => Some(LinkTarget::External(url.to_string())),
+            _ => None,
+        };
+    }
+
+    let mut path = url.split(['?', '#']).next().unwrap_or_default();
+    while let Some(rest) = path.strip_prefix("./").or_else(|| path.strip_prefix("../")) {
+        path = rest;
+    }
+    let path = format!("/{}", path.trim_matches('/'));
+    Some(LinkTarget::Internal(parse_internal_link(&path)))
+}
+
+/// Parse an absolute path into an `InternalLink` based on the public routes
+fn parse_internal_link(path: &str) -> InternalLink {
+    if INTERNAL_PAGES.contains(&path) {
+        return InternalLink::Page;
+    }
+    let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["blogs", id] => id
+            .parse::<i64>()
+            .map_or(InternalLink::Unknown, InternalLink::Blog),
+        ["projects", id] => id
+            .parse::<i64>()
+            .map_or(InternalLink::Unknown, InternalLink::Project),
+        ["media", key] => InternalLink::Media(key.to_string()),
+        ["statics", file] => InternalLink::Static(file.to_string()),
+        _ => InternalLink::Unknown,
+    }
+}
+
+/// Describe how often links are checked in the background
+/// `interval` is LINK_CHECK_INTERVAL_SECOND where 0 disables the checks.
+pub fn describe_link_check_interval(interval: u64) -> String {
+    let (count, unit) = match interval {
+        0 => return "only on demand".to_string(),
+        val if val % 86400 == 0 => (val / 86400, "day"),
+        val if val % 3600 == 0 => (val / 3600, "hour"),
+        val if val % 60 == 0 => (val / 60, "minute"),
+        val => (val, "second"),
+    };
+    match count {
+        1 => format!("every {unit}"),
+        _ => format!("every {count} {unit}s"),
+    }
+}
+
+/// LinkCheckCommandStatus
+/// Status of LinkCheck Command Operations:
+/// - Replaced
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LinkCheckCommandStatus {
+    Replaced,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn link_check(source_type: &str, source_id: i64, url: &str, is_broken: bool) -> LinkCheck {
+        LinkCheck {
+            source_type: source_type.to_string(),
+            source_id,
+            source_name: format!("{source_type} {source_id}"),
+            url: url.to_string(),
+            is_broken,
+            detail: if is_broken { "404 Not Found" } else { "200 OK" }.to_string(),
+            checked_at: format!("2026-10-19T12:00:0{source_id}Z"),
+        }
+    }
+
+    #[test]
+    fn test_classify_link() {
+        let external = |url: &str| Some(LinkTarget::External(url.to_string()));
+        let internal = |link: InternalLink| Some(LinkTarget::Internal(link));
+
+        assert_eq!(
+            classify_link("https://example.com/a"),
+            external("https://example.com/a")
+        );
+        assert_eq!(
+            classify_link("HTTP://example.com"),
+            external("HTTP://example.com")
+        );
+        assert_eq!(
+            classify_link("//example.com/a"),
+            external("https://example.com/a")
+        );
+        assert_eq!(classify_link("mailto:me@example.com"), None);
+        assert_eq!(classify_link("#intro"), None);
+        assert_eq!(classify_link("  "), None);
+
+        assert_eq!(classify_link("/"), internal(InternalLink::Page));
+        assert_eq!(classify_link("/talks/"), internal(InternalLink::Page));
+        assert_eq!(classify_link("/resume?x=1"), internal(InternalLink::Page));
+        assert_eq!(
+            classify_link("../blogs/3#intro"),
+            internal(InternalLink::Blog(3))
+        );
+        assert_eq!(
+            classify_link("/projects/7"),
+            internal(InternalLink::Project(7))
+        );
+        assert_eq!(
+            classify_link("/media/1-a@10x10.png"),
+            internal(InternalLink::Media("1-a@10x10.png".to_string()))
+        );
+        assert_eq!(
+            classify_link("/statics/favicon-32x32.png"),
+            internal(InternalLink::Static("favicon-32x32.png".to_string()))
+        );
+        assert_eq!(classify_link("/blogs/abc"), internal(InternalLink::Unknown));
+        assert_eq!(classify_link("/nowhere"), internal(InternalLink::Unknown));
+    }
+
+    #[test]
+    fn test_describe_link_check_interval() {
+        assert_eq!(describe_link_check_interval(0), "only on demand");
+        assert_eq!(describe_link_check_interval(86400), "every day");
+        assert_eq!(describe_link_check_interval(7200), "every 2 hours");
+        assert_eq!(describe_link_check_interval(90), "every 90 seconds");
+        assert_eq!(describe_link_check_interval(300), "every 5 minutes");
+    }
+
+    #[test]
+    fn test_link_checks_to_admin_list_template() {
+        let link_checks = LinkChecks {
+            link_checks: vec![
+                link_check(LINK_SOURCE_BLOG, 1, "/blogs/9", true),
+                link_check(LINK_SOURCE_BLOG, 1, "https://example.com", false),
+                link_check(LINK_SOURCE_TALK, 1, "https://example.com/gone", true),
+                link_check(LINK_SOURCE_BLOG, 2, "/nowhere", true),
+                link_check(LINK_SOURCE_BLOG, 1, "/media/missing.png", true),
+            ],
+        };
+
+        let template = link_checks.to_admin_list_template("Done.".to_string());
+        assert_eq!(template.checked_count, 5);
+        assert_eq!(template.broken_count, 4);
+        assert_eq!(template.last_checked_at, "2026-10-19T12:00:02Z");
+        assert_eq!(template.notice, "Done.");
+
+        let sources: Vec<(&str, i64, usize)> = template
+            .sources
+            .iter()
+            .map(|val| (val.source_type.as_str(), val.source_id, val.links.len()))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![
+                (LINK_SOURCE_BLOG, 1, 2),
+                (LINK_SOURCE_TALK, 1, 1),
+                (LINK_SOURCE_BLOG, 2, 1)
+            ]
+        );
+        assert_eq!(template.sources[0].links[1].url, "/media/missing.png");
+        assert_eq!(template.sources[0].links[1].detail, "404 Not Found");
+
+        let empty = LinkChecks::default().to_admin_list_template(String::new());
+        assert_eq!(empty.checked_count, 0);
+        assert_eq!(empty.last_checked_at, "");
+        assert!(empty.sources.is_empty());
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// Mail
+/// Plain text email to a single recipient. Contains:
+/// - to: recipient email address
+/// - subject
+/// - body in plain text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// MailCommandStatus
+/// Status of Mail Command Operations:
+/// - Sent
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MailCommandStatus {
+    Sent,
+}
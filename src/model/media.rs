@@ -0,0 +1,832 @@
+use crate::model::templates_admin::{AdminListMediaTemplate, AdminMediaFileTemplate};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+/// MediaFile
+/// Metadata of an uploaded media file with fields:
+/// - key: Storage key. Also used in `/media/{key}`
+/// - filename: Original filename
+/// - content_type: Detected content type
+/// - size: File size in bytes
+/// - created_at: Upload time in RFC 3339
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MediaFile {
+    pub key: String,
+    pub filename: String,
+    pub content_type: String,
+    pub size: i64,
+    pub created_at: String,
+}
+
+impl MediaFile {
+    /// Summarize the MediaFile for the audit log
+    pub fn audit_summary(&self) -> String {
+        format!(
+            "filename={}; content_type={}; size={}",
+            self.filename, self.content_type, self.size
+        )
+    }
+    /// Public URL of the MediaFile
+    pub fn url(&self) -> String {
+        format!("/media/{}", self.key)
+    }
+    /// Check if the MediaFile is an image
+    pub fn is_image(&self) -> bool {
+        self.content_type.starts_with("image/")
+    }
+    /// Markdown snippet to embed the MediaFile in a post.
+    /// Images are embedded, other files are linked.
+    pub fn markdown_snippet(&self) -> String {
+        let alt = self.filename.replace(['[', ']'], "");
+        if self.is_image() {
+            format!("![{}]({})", alt, self.url())
+        } else {
+            format!("[{}]({})", alt, self.url())
+        }
+    }
+    /// Convert MediaFile to (Askama) AdminMediaFileTemplate
+    pub fn to_admin_template(&self) -> AdminMediaFileTemplate {
+        AdminMediaFileTemplate {
+            key: self.key.clone(),
+            filename: self.filename.clone(),
+            content_type: self.content_type.clone(),
+            size: format_size(self.size),
+            created_at: self.created_at.clone(),
+            url: self.url(),
+            is_image: self.is_image(),
+            markdown_snippet: self.markdown_snippet(),
+        }
+    }
+}
+
+/// MediaFiles
+/// Collection of MediaFile
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MediaFiles {
+    pub media_files: Vec<MediaFile>,
+}
+
+impl MediaFiles {
+    /// Convert MediaFiles to (Askama) AdminListMediaTemplate
+    pub fn to_admin_list_template(
+        &self,
+        notice: String,
+        errors: Vec<String>,
+    ) -> AdminListMediaTemplate {
+        AdminListMediaTemplate {
+            media_files: self
+                .media_files
+                .iter()
+                .map(|media_file| media_file.to_admin_template())
+                .collect(),
+            notice,
+            errors,
+        }
+    }
+}
+
+/// MediaUpload
+/// Uploaded file from the admin media library.
+/// Use `validate` to turn it into a `MediaFile`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MediaUpload {
+    pub filename: String,
+    pub data: Vec<u8>,
+}
+
+impl MediaUpload {
+    /// Validate MediaUpload against the max size and build its `MediaFile`
+    /// Return all validation error messages if the upload is invalid.
+    pub fn validate(&self, max_size: usize, now: DateTime<Utc>) -> Result<MediaFile, Vec<String>> {
+        let mut errors = Vec::new();
+
+        let filename = self.filename.trim();
+        if filename.is_empty() {
+            errors.push("Please choose a file to upload.".to_string());
+        } else if self.data.is_empty() {
+            errors.push(format!("File '{filename}' is empty."));
+        }
+        if self.data.len() > max_size {
+            errors.push(format!(
+                "File '{}' is {}. Max size is {}.",
+                filename,
+                format_size(self.data.len() as i64),
+                format_size(max_size as i64)
+            ));
+        }
+
+        if !errors.is_empty() {
+            debug!("MediaUpload: Upload is invalid. {:?}", errors);
+            return Err(errors);
+        }
+        Ok(MediaFile {
+            key: new_media_key(filename, now),
+            filename: filename.to_string(),
+            content_type: detect_content_type(filename, &self.data),
+            size: self.data.len() as i64,
+            created_at: now.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        })
+    }
+}
+
+/// Create a new storage key from a filename
+/// Key is prefixed by the upload time so the same filename can be uploaded twice.
+/// e.g. `20261019120000123-my-image.png`
+pub fn new_media_key(filename: &str, now: DateTime<Utc>) -> String {
+    let mut name = String::new();
+    for c in filename.to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() {
+            name.push(c);
+        } else if c == '.' || c == '_' {
+            if name.ends_with('-') {
+                name.pop();
+            }
+            name.push(c);
+        } else if !name.ends_with('-') {
+            name.push('-');
+        }
+    }
+    let name = name.trim_matches(|c| c == '-' || c == '.');
+    let name = if name.is_empty() { "file" } else { name };
+    format!("{}-{}", now.format("%Y%m%d%H%M%S%3f"), name)
+}
+
+/// Check a media key
+/// Valid keys only contain lowercase alphanumerics, `-`, `_`, `.`, and `@`
+/// and don't start with `.` so they are safe to be used as a filename.
+pub fn is_valid_media_key(key: &str) -> bool {
+    !key.is_empty()
+        && !key.starts_with('.')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "-_.@".contains(c))
+}
+
+/// Widths in pixels of the resized variants generated for uploaded images
+pub const MEDIA_IMAGE_WIDTHS: [u32; 3] = [480, 960, 1600];
+/// Suffix of the dimensions in the key of a processed image without WebP variants
+const MEDIA_IMAGE_NO_WEBP: &str = "_nowebp";
+
+/// MediaImageKey
+/// Parsed key of a processed image. Processed images carry their dimensions
+/// in their key, e.g. `20261019120000123-cat@2000x1500.png`, so pages can
+/// build `srcset` markup without a database lookup. Images without WebP
+/// variants are marked with `MEDIA_IMAGE_NO_WEBP`, e.g. `cat@2000x1500_nowebp.jpg`.
+/// `new_media_key` never produces `@`, so the marker can't come from a filename.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaImageKey {
+    pub base: String,
+    pub extension: String,
+    pub width: u32,
+    pub height: u32,
+    pub webp: bool,
+}
+
+impl MediaImageKey {
+    /// Build the key of a processed image from the key stem of its upload
+    pub fn new(stem: &str, extension: &str, width: u32, height: u32, webp: bool) -> Self {
+        let marker = if webp { "" } else { MEDIA_IMAGE_NO_WEBP };
+        Self {
+            base: format!("{stem}@{width}x{height}{marker}"),
+            extension: extension.to_string(),
+            width,
+            height,
+            webp,
+        }
+    }
+    /// Parse a processed image key. Return None for any other key.
+    pub fn parse(key: &str) -> Option<Self> {
+        let (base, extension) = key.rsplit_once('.')?;
+        if !matches!(extension, "png" | "jpg") {
+            return None;
+        }
+        let (_, size) = base.rsplit_once('@')?;
+        let (size, webp) = match size.strip_suffix(MEDIA_IMAGE_NO_WEBP) {
+            Some(size) => (size, false),
+            None => (size, true),
+        };
+        let (width, height) = size.split_once('x')?;
+        Some(Self {
+            base: base.to_string(),
+            extension: extension.to_string(),
+            width: width.parse().ok().filter(|val| *val > 0)?,
+            height: height.parse().ok().filter(|val| *val > 0)?,
+            webp,
+        })
+    }
+    /// Widths of the resized variants. Images are never upscaled.
+    pub fn variant_widths(&self) -> Vec<u32> {
+        MEDIA_IMAGE_WIDTHS
+            .into_iter()
+            .filter(|width| *width < self.width)
+            .collect()
+    }
+    /// Key of the original image
+    pub fn key(&self) -> String {
+        format!("{}.{}", self.base, self.extension)
+    }
+    /// Key of the variant with the given width, or the full size variant.
+    /// WebP variants keep the original extension, e.g. `cat@2000x1500-480w.png.webp`,
+    /// so variants of PNG and JPEG uploads never collide.
+    pub fn variant_key(&self, width: Option<u32>, webp: bool) -> String {
+        let suffix = if webp { ".webp" } else { "" };
+        match width {
+            Some(width) => format!("{}-{}w.{}{}", self.base, width, self.extension, suffix),
+            None => format!("{}.{}{}", self.base, self.extension, suffix),
+        }
+    }
+    /// Keys of all variants derived from the original image
+    pub fn variant_keys(&self) -> Vec<String> {
+        let mut keys = Vec::new();
+        if self.webp {
+            keys.push(self.variant_key(None, true));
+        }
+        for width in self.variant_widths() {
+            keys.push(self.variant_key(Some(width), false));
+            if self.webp {
+                keys.push(self.variant_key(Some(width), true));
+            }
+        }
+        keys
+    }
+}
+
+/// Find the original image of a variant key
+/// Return the original key and the content type of the variant.
+pub fn find_media_variant_origin(key: &str) -> Option<(String, String)> {
+    let (name, content_type) = match key.strip_suffix(".webp") {
+        Some(name) => (name, "image/webp"),
+        None if key.ends_with(".png") => (key, "image/png"),
+        None if key.ends_with(".jpg") => (key, "image/jpeg"),
+        None => return None,
+    };
+    let (stem, extension) = name.rsplit_once('.')?;
+    let mut bases = vec![stem];
+    if let Some((base, _)) = stem.rsplit_once('-') {
+        bases.push(base);
+    }
+    bases
+        .into_iter()
+        .map(|base| format!("{base}.{extension}"))
+        .find(|origin| {
+            MediaImageKey::parse(origin)
+                .is_some_and(|image_key| image_key.variant_keys().iter().any(|val| val == key))
+        })
+        .map(|origin| (origin, content_type.to_string()))
+}
+
+/// MediaVariant
+/// File derived from an uploaded image and stored next to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaVariant {
+    pub key: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+/// Process an uploaded PNG or JPEG image
+/// The image is decoded and re-encoded, which strips EXIF and other metadata
+/// after applying its orientation. Return the MediaFile with the processed key
+/// and size, the processed image, and its resized and WebP variants.
+/// WebP is encoded lossless, which is often larger than JPEG photos. Since
+/// pages prefer WebP, WebP variants are only kept if each of them is smaller
+/// than its PNG or JPEG twin.
+/// Other files are returned unchanged without variants.
+pub fn process_media_image(
+    media_file: &MediaFile,
+    data: Vec<u8>,
+) -> Result<(MediaFile, Vec<u8>, Vec<MediaVariant>), String> {
+    let (format, extension) = match media_file.content_type.as_str() {
+        "image/png" => (image::ImageFormat::Png, "png"),
+        "image/jpeg" => (image::ImageFormat::Jpeg, "jpg"),
+        _ => return Ok((media_file.clone(), data, Vec::new())),
+    };
+    let error = |err: image::ImageError| {
+        debug!("Failed to process image {}. {}", &media_file.filename, err);
+        format!("Failed to process image '{}'.", &media_file.filename)
+    };
+
+    let mut decoder = image::ImageReader::with_format(std::io::Cursor::new(&data), format)
+        .into_decoder()
+        .map_err(error)?;
+    let orientation = image::ImageDecoder::orientation(&mut decoder).map_err(error)?;
+    let mut img = image::DynamicImage::from_decoder(decoder).map_err(error)?;
+    img.apply_orientation(orientation);
+
+    let stem = match media_file.key.rsplit_once('.') {
+        Some((stem, _)) => stem,
+        None => media_file.key.as_str(),
+    };
+    let mut image_key = MediaImageKey::new(stem, extension, img.width(), img.height(), true);
+
+    // Width, PNG or JPEG, and WebP of the full size image and resized variants
+    let processed = encode_media_image(&img, format).map_err(error)?;
+    let mut encoded = vec![(
+        None,
+        processed.clone(),
+        encode_media_image(&img, image::ImageFormat::WebP).map_err(error)?,
+    )];
+    for width in image_key.variant_widths() {
+        let resized = img.resize(width, u32::MAX, image::imageops::FilterType::Lanczos3);
+        encoded.push((
+            Some(width),
+            encode_media_image(&resized, format).map_err(error)?,
+            encode_media_image(&resized, image::ImageFormat::WebP).map_err(error)?,
+        ));
+    }
+    if encoded
+        .iter()
+        .any(|(_, raster, webp)| webp.len() >= raster.len())
+    {
+        debug!("Skip WebP variants of {} larger than the original.", stem);
+        image_key = MediaImageKey::new(stem, extension, img.width(), img.height(), false);
+    }
+
+    let mut variants = Vec::new();
+    for (width, raster, webp) in encoded {
+        if width.is_some() {
+            variants.push(MediaVariant {
+                key: image_key.variant_key(width, false),
+                content_type: media_file.content_type.clone(),
+                data: raster,
+            });
+        }
+        if image_key.webp {
+            variants.push(MediaVariant {
+                key: image_key.variant_key(width, true),
+                content_type: "image/webp".to_string(),
+                data: webp,
+            });
+        }
+    }
+
+    let media_file = MediaFile {
+        key: image_key.key(),
+        size: processed.len() as i64,
+        ..media_file.clone()
+    };
+    Ok((media_file, processed, variants))
+}
+
+/// Encode an image without metadata
+/// JPEG has no alpha channel and WebP is encoded lossless.
+fn encode_media_image(
+    img: &image::DynamicImage,
+    format: image::ImageFormat,
+) -> Result<Vec<u8>, image::ImageError> {
+    let mut data = Vec::new();
+    match format {
+        image::ImageFormat::Jpeg => {
+            img.to_rgb8()
+                .write_with_encoder(image::codecs::jpeg::JpegEncoder::new_with_quality(
+                    &mut data, 90,
+                ))?
+        }
+        image::ImageFormat::WebP => img
+            .to_rgba8()
+            .write_with_encoder(image::codecs::webp::WebPEncoder::new_lossless(&mut data))?,
+        _ => img.write_with_encoder(image::codecs::png::PngEncoder::new(&mut data))?,
+    }
+    Ok(data)
+}
+
+/// Rewrite images pointing at the media store into responsive markup
+/// `<img>` tags with a processed image key get a `srcset` of the resized
+/// variants, wrapped in a `<picture>` with a WebP `srcset` if the image has
+/// WebP variants, and `width`/`height` attributes to avoid layout shift.
+/// Other images are left unchanged.
+pub fn rewrite_media_images(html: &str) -> String {
+    let img_regex = Regex::new(r"<img\s([^>]*?)/?>").unwrap();
+    let src_regex = Regex::new(r#"(?:^|\s)src="/media/([^"]+)""#).unwrap();
+    img_regex
+        .replace_all(html, |caps: &regex::Captures| {
+            let attributes = caps[1].trim();
+            let Some(image_key) = src_regex
+                .captures(attributes)
+                .and_then(|src| MediaImageKey::parse(&src[1]))
+            else {
+                return caps[0].to_string();
+            };
+
+            let srcset = |webp: bool| {
+                let mut srcset: Vec<String> = image_key
+                    .variant_widths()
+                    .into_iter()
+                    .map(|width| {
+                        format!(
+                            "/media/{} {}w",
+                            image_key.variant_key(Some(width), webp),
+                            width
+                        )
+                    })
+                    .collect();
+                srcset.push(format!(
+                    "/media/{} {}w",
+                    image_key.variant_key(None, webp),
+                    image_key.width
+                ));
+                srcset.join(", ")
+            };
+            let sizes = format!(
+                "(max-width: {width}px) 100vw, {width}px",
+                width = image_key.width
+            );
+            let img = format!(
+                r#"<img {} width="{}" height="{}" loading="lazy" srcset="{}" sizes="{}">"#,
+                attributes,
+                image_key.width,
+                image_key.height,
+                srcset(false),
+                sizes
+            );
+            if !image_key.webp {
+                return img;
+            }
+            format!(
+                r#"<picture><source type="image/webp" srcset="{}" sizes="{}">{}</picture>"#,
+                srcset(true),
+                sizes,
+                img
+            )
+        })
+        .to_string()
+}
+
+/// Detect content type of a file
+/// Common image and document formats are detected from their signature.
+/// Otherwise, the content type is guessed from the filename extension.
+pub fn detect_content_type(filename: &str, data: &[u8]) -> String {
+    let signatures: [(&[u8], &str); 6] = [
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"%PDF-", "application/pdf"),
+        (b"\x00\x00\x01\x00", "image/x-icon"),
+    ];
+    for (signature, content_type) in signatures {
+        if data.starts_with(signature) {
+            return content_type.to_string();
+        }
+    }
+    if data.len() >= 12 && &data[0..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+        return "image/webp".to_string();
+    }
+
+    mime_guess::from_path(filename)
+        .first_or_octet_stream()
+        .essence_str()
+        .to_string()
+}
+
+/// Format size in bytes into a human readable size
+pub fn format_size(size: i64) -> String {
+    match size {
+        val if val >= 1024 * 1024 => format!("{:.1} MiB", val as f64 / (1024.0 * 1024.0)),
+        val if val >= 1024 => format!("{:.1} KiB", val as f64 / 1024.0),
+        val => format!("{val} B"),
+    }
+}
+
+/// MediaFilesParams
+/// Parameters to filter MediaFiles:
+/// - start: initial pagination
+/// - end: end of pagination
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MediaFilesParams {
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+}
+
+impl MediaFilesParams {
+    /// Sanitize MediaFilesParams by checking negative value and set unknown to
+    /// the default values.
+    pub fn sanitize(&self) -> Self {
+        let start = match self.start {
+            Some(val) if val >= 0 => val,
+            _ => {
+                debug!("MediaFilesParams: set default start to 0");
+                0_i64
+            }
+        };
+        let end = match self.end {
+            Some(val) if val >= 0 => val,
+            _ => {
+                debug!("MediaFilesParams: set default end to 100");
+                100_i64
+            }
+        };
+
+        Self {
+            start: Some(start),
+            end: Some(end),
+        }
+    }
+}
+
+/// MediaCommandStatus
+/// Status of Media Command Operations:
+/// - Stored
+/// - Deleted
+/// - Uploaded
+/// - Removed
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MediaCommandStatus {
+    Stored,
+    Deleted,
+    Uploaded,
+    Removed,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 19, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_new_media_key() {
+        assert_eq!(
+            new_media_key("My Cat (1).PNG", now()),
+            "20261019120000000-my-cat-1.png"
+        );
+        assert_eq!(
+            new_media_key("../../etc/passwd", now()),
+            "20261019120000000-etc-passwd"
+        );
+        assert_eq!(new_media_key("???", now()), "20261019120000000-file");
+    }
+
+    #[test]
+    fn test_is_valid_media_key() {
+        assert!(is_valid_media_key("20261019120000000-cat.png"));
+        assert!(!is_valid_media_key(""));
+        assert!(!is_valid_media_key(".env"));
+        assert!(!is_valid_media_key("../secret"));
+        assert!(!is_valid_media_key("a/b.png"));
+        assert!(is_valid_media_key("20261019120000000-cat@2000x1500.png"));
+    }
+
+    #[test]
+    fn test_media_image_key() {
+        assert_eq!(
+            new_media_key("cat@2x.png", now()),
+            "20261019120000000-cat-2x.png"
+        );
+        assert_eq!(MediaImageKey::parse("20261019120000000-cat.png"), None);
+        assert_eq!(MediaImageKey::parse("20261019120000000-cat@0x10.png"), None);
+        assert_eq!(
+            MediaImageKey::parse("20261019120000000-cat@100x10.gif"),
+            None
+        );
+
+        let image_key = MediaImageKey::parse("20261019120000000-cat@1000x500.jpg").unwrap();
+        assert_eq!(image_key.width, 1000);
+        assert_eq!(image_key.height, 500);
+        assert_eq!(image_key.variant_widths(), vec![480, 960]);
+        assert_eq!(
+            image_key.variant_keys(),
+            vec![
+                "20261019120000000-cat@1000x500.jpg.webp",
+                "20261019120000000-cat@1000x500-480w.jpg",
+                "20261019120000000-cat@1000x500-480w.jpg.webp",
+                "20261019120000000-cat@1000x500-960w.jpg",
+                "20261019120000000-cat@1000x500-960w.jpg.webp",
+            ]
+        );
+        assert_eq!(
+            find_media_variant_origin("20261019120000000-cat@1000x500-480w.jpg"),
+            Some((
+                "20261019120000000-cat@1000x500.jpg".to_string(),
+                "image/jpeg".to_string()
+            ))
+        );
+        assert_eq!(
+            find_media_variant_origin("20261019120000000-cat@1000x500.jpg.webp"),
+            Some((
+                "20261019120000000-cat@1000x500.jpg".to_string(),
+                "image/webp".to_string()
+            ))
+        );
+        assert_eq!(
+            find_media_variant_origin("20261019120000000-cat@1000x500-1600w.jpg.webp"),
+            None
+        );
+        assert_eq!(
+            find_media_variant_origin("20261019120000000-cat.webp"),
+            None
+        );
+
+        let image_key = MediaImageKey::parse("20261019120000000-cat@1000x500_nowebp.jpg").unwrap();
+        assert_eq!((image_key.width, image_key.height), (1000, 500));
+        assert!(!image_key.webp);
+        assert_eq!(
+            image_key,
+            MediaImageKey::new("20261019120000000-cat", "jpg", 1000, 500, false)
+        );
+        assert_eq!(
+            image_key.variant_keys(),
+            vec![
+                "20261019120000000-cat@1000x500_nowebp-480w.jpg",
+                "20261019120000000-cat@1000x500_nowebp-960w.jpg",
+            ]
+        );
+        assert_eq!(
+            find_media_variant_origin("20261019120000000-cat@1000x500_nowebp-480w.jpg"),
+            Some((
+                "20261019120000000-cat@1000x500_nowebp.jpg".to_string(),
+                "image/jpeg".to_string()
+            ))
+        );
+        assert_eq!(
+            find_media_variant_origin("20261019120000000-cat@1000x500_nowebp.jpg.webp"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_process_media_image() {
+        let mut data = Vec::new();
+        image::RgbaImage::new(1000, 500)
+            .write_with_encoder(image::codecs::png::PngEncoder::new(&mut data))
+            .unwrap();
+        let media_file = MediaUpload {
+            filename: "cat.png".to_string(),
+            data: data.clone(),
+        }
+        .validate(data.len(), now())
+        .unwrap();
+
+        let (media_file, processed, variants) = process_media_image(&media_file, data).unwrap();
+        assert_eq!(media_file.key, "20261019120000000-cat@1000x500.png");
+        assert_eq!(media_file.size, processed.len() as i64);
+        assert_eq!(
+            variants
+                .iter()
+                .map(|variant| variant.key.clone())
+                .collect::<Vec<String>>(),
+            MediaImageKey::parse(&media_file.key)
+                .unwrap()
+                .variant_keys()
+        );
+        let variant = image::load_from_memory(&variants[1].data).unwrap();
+        assert_eq!((variant.width(), variant.height()), (480, 240));
+        assert_eq!(detect_content_type("", &variants[2].data), "image/webp");
+
+        let invalid = MediaFile {
+            content_type: "image/png".to_string(),
+            ..media_file.clone()
+        };
+        assert_eq!(
+            process_media_image(&invalid, b"\x89PNG\r\n\x1a\n0000".to_vec()).unwrap_err(),
+            "Failed to process image 'cat.png'."
+        );
+        let pdf = MediaFile {
+            content_type: "application/pdf".to_string(),
+            ..media_file
+        };
+        let (_, data, variants) = process_media_image(&pdf, b"%PDF-".to_vec()).unwrap();
+        assert_eq!(data, b"%PDF-".to_vec());
+        assert!(variants.is_empty());
+    }
+
+    #[test]
+    fn test_process_media_image_webp_not_larger() {
+        // Noise compresses badly in lossless WebP, like photos
+        let mut seed = 7_u32;
+        let photo = image::RgbImage::from_fn(1000, 500, |_, _| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            let [r, g, b, _] = seed.to_be_bytes();
+            image::Rgb([r, g, b])
+        });
+        let mut data = Vec::new();
+        photo
+            .write_with_encoder(image::codecs::jpeg::JpegEncoder::new_with_quality(
+                &mut data, 90,
+            ))
+            .unwrap();
+        let mut blank = Vec::new();
+        image::RgbaImage::new(1000, 500)
+            .write_with_encoder(image::codecs::png::PngEncoder::new(&mut blank))
+            .unwrap();
+
+        for (filename, data) in [("photo.jpg", data), ("blank.png", blank)] {
+            let media_file = MediaUpload {
+                filename: filename.to_string(),
+                data: data.clone(),
+            }
+            .validate(data.len(), now())
+            .unwrap();
+            let (media_file, processed, variants) = process_media_image(&media_file, data).unwrap();
+            let image_key = MediaImageKey::parse(&media_file.key).unwrap();
+            assert_eq!(image_key.webp, filename == "blank.png");
+
+            // Every WebP variant is smaller than the image it replaces
+            for webp in variants.iter().filter(|val| val.key.ends_with(".webp")) {
+                let twin = webp.key.strip_suffix(".webp").unwrap();
+                let raster = match variants.iter().find(|val| val.key == twin) {
+                    Some(variant) => &variant.data,
+                    None => &processed,
+                };
+                assert!(webp.data.len() < raster.len(), "{}", webp.key);
+            }
+            let html = format!(r#"<img src="/media/{}">"#, media_file.key);
+            assert_eq!(
+                rewrite_media_images(&html).contains("image/webp"),
+                image_key.webp
+            );
+        }
+    }
+
+    #[test]
+    fn test_rewrite_media_images() {
+        let html = r#"<p><img src="/media/20261019120000000-cat@600x300.png" alt="cat"></p>"#;
+        assert_eq!(
+            rewrite_media_images(html),
+            concat!(
+                r#"<p><picture><source type="image/webp" "#,
+                r#"srcset="/media/20261019120000000-cat@600x300-480w.png.webp 480w, /media/20261019120000000-cat@600x300.png.webp 600w" "#,
+                r#"sizes="(max-width: 600px) 100vw, 600px">"#,
+                r#"<img src="/media/20261019120000000-cat@600x300.png" alt="cat" width="600" height="300" loading="lazy" "#,
+                r#"srcset="/media/20261019120000000-cat@600x300-480w.png 480w, /media/20261019120000000-cat@600x300.png 600w" "#,
+                r#"sizes="(max-width: 600px) 100vw, 600px"></picture></p>"#,
+            )
+        );
+
+        let html = r#"<img src="/media/20261019120000000-cat@600x300_nowebp.jpg">"#;
+        assert_eq!(
+            rewrite_media_images(html),
+            concat!(
+                r#"<img src="/media/20261019120000000-cat@600x300_nowebp.jpg" width="600" height="300" loading="lazy" "#,
+                r#"srcset="/media/20261019120000000-cat@600x300_nowebp-480w.jpg 480w, /media/20261019120000000-cat@600x300_nowebp.jpg 600w" "#,
+                r#"sizes="(max-width: 600px) 100vw, 600px">"#,
+            )
+        );
+
+        let html = r#"<img src="/media/20261019120000000-cat.gif" alt="cat"><img src="https://example.com/a@1x1.png">"#;
+        assert_eq!(rewrite_media_images(html), html);
+    }
+
+    #[test]
+    fn test_detect_content_type() {
+        assert_eq!(
+            detect_content_type("cat.txt", b"\x89PNG\r\n\x1a\n0000"),
+            "image/png"
+        );
+        assert_eq!(
+            detect_content_type("cat", b"RIFF0000WEBPVP8 "),
+            "image/webp"
+        );
+        assert_eq!(detect_content_type("notes.md", b"# Notes"), "text/markdown");
+        assert_eq!(
+            detect_content_type("unknown", b"data"),
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn test_media_upload_validate() {
+        let upload = MediaUpload {
+            filename: "cat.png".to_string(),
+            data: b"\x89PNG\r\n\x1a\n0000".to_vec(),
+        };
+        let media_file = upload.validate(1024, now()).unwrap();
+        assert_eq!(media_file.key, "20261019120000000-cat.png");
+        assert_eq!(media_file.content_type, "image/png");
+        assert_eq!(media_file.size, 12);
+        assert_eq!(media_file.created_at, "2026-10-19T12:00:00Z");
+        assert_eq!(
+            media_file.markdown_snippet(),
+            "![cat.png](/media/20261019120000000-cat.png)"
+        );
+
+        let errors = upload.validate(4, now()).unwrap_err();
+        assert_eq!(
+            errors,
+            vec!["File 'cat.png' is 12 B. Max size is 4 B.".to_string()]
+        );
+        let errors = MediaUpload::default().validate(4, now()).unwrap_err();
+        assert_eq!(errors, vec!["Please choose a file to upload.".to_string()]);
+    }
+
+    #[test]
+    fn test_markdown_snippet_for_files() {
+        let media_file = MediaFile {
+            key: "20261019120000000-slides.pdf".to_string(),
+            filename: "slides [final].pdf".to_string(),
+            content_type: "application/pdf".to_string(),
+            size: 2048,
+            created_at: "2026-10-19T12:00:00Z".to_string(),
+        };
+        assert_eq!(
+            media_file.markdown_snippet(),
+            "[slides final.pdf](/media/20261019120000000-slides.pdf)"
+        );
+        assert_eq!(media_file.to_admin_template().size, "2.0 KiB");
+    }
+}
@@ -1,9 +1,22 @@
+pub mod audit_logs;
 pub mod auth;
 pub mod axum;
+pub mod blog_drafts;
 pub mod blog_tag_mappings;
 pub mod blogs;
+pub mod dashboard;
+pub mod link_checks;
+pub mod mail;
+pub mod media;
+pub mod oidc;
+pub mod organizations;
+pub mod profiles;
+pub mod projects;
+pub mod resume;
 pub mod tags;
+pub mod talk_tag_mappings;
 pub mod talks;
 pub mod templates;
 pub mod templates_admin;
+pub mod totp;
 pub mod version;
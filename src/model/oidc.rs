@@ -0,0 +1,224 @@
+use crate::config::Config;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ring::digest::{digest, SHA256};
+use serde::{Deserialize, Serialize};
+use urlencoding::encode;
+
+/// Scopes requested from the identity provider
+pub const OIDC_SCOPES: &str = "openid email";
+/// Seconds the identity provider has to redirect the browser back
+pub const OIDC_FLOW_TTL: usize = 600;
+
+/// OidcConfig
+/// OpenID Connect client registered at the identity provider.
+/// Only available if OIDC_ISSUER_URL, OIDC_CLIENT_ID and OIDC_REDIRECT_URL are set.
+/// - issuer_url: Issuer serving `/.well-known/openid-configuration`
+/// - client_id: Client Identifier
+/// - client_secret: Client Secret. None for public clients relying on PKCE only
+/// - redirect_url: Callback URL registered at the identity provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OidcConfig {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub redirect_url: String,
+}
+
+impl OidcConfig {
+    /// Build the OidcConfig of a Config. Return None if OIDC login is disabled.
+    pub fn from_config(config: &Config) -> Option<Self> {
+        Some(Self {
+            issuer_url: config.oidc_issuer_url.clone()?,
+            client_id: config.oidc_client_id.clone()?,
+            client_secret: config.secrets.oidc_client_secret.clone(),
+            redirect_url: config.oidc_redirect_url.clone()?,
+        })
+    }
+    /// URL of the discovery document of the issuer
+    pub fn discovery_url(&self) -> String {
+        format!(
+            "{}/.well-known/openid-configuration",
+            self.issuer_url.trim_end_matches('/')
+        )
+    }
+}
+
+/// OidcProviderMetadata
+/// Fields of the discovery document used by the authorization code flow
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct OidcProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+/// OidcTokenResponse
+/// Token endpoint response. Only the ID token is used.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcTokenResponse {
+    pub id_token: String,
+}
+
+/// OidcIdTokenClaims
+/// Claims of a verified ID token
+/// - sub: Subject Identifier at the identity provider
+/// - nonce: Nonce sent in the authorization request
+/// - email_verified: Some providers send it as a string
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcIdTokenClaims {
+    pub iss: String,
+    pub sub: String,
+    pub exp: usize,
+    pub nonce: Option<String>,
+    pub email: Option<String>,
+    pub email_verified: Option<serde_json::Value>,
+}
+
+impl OidcIdTokenClaims {
+    /// Return the email only if the identity provider verified it
+    pub fn verified_email(&self) -> Option<String> {
+        let verified = match &self.email_verified {
+            Some(serde_json::Value::Bool(val)) => *val,
+            Some(serde_json::Value::String(val)) => val == "true",
+            _ => false,
+        };
+        self.email.clone().filter(|_| verified)
+    }
+}
+
+/// OidcFlowClaims
+/// Claims of the `oidc_flow` cookie kept between the authorization request
+/// and the callback.
+/// - state: Binds the callback to this browser
+/// - nonce: Binds the ID token to this login
+/// - code_verifier: PKCE secret sent on the code exchange
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OidcFlowClaims {
+    pub state: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub exp: usize,
+    pub iat: usize,
+}
+
+/// OidcCallbackParams
+/// Query parameters of the redirect back from the identity provider
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OidcCallbackParams {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+/// Compute the S256 PKCE code challenge of a code verifier
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(digest(&SHA256, code_verifier.as_bytes()))
+}
+
+/// Build the authorization request URL the browser is redirected to
+pub fn authorization_url(
+    metadata: &OidcProviderMetadata,
+    oidc_config: &OidcConfig,
+    flow: &OidcFlowClaims,
+) -> String {
+    let separator = match metadata.authorization_endpoint.contains('?') {
+        true => '&',
+        false => '?',
+    };
+    format!(
+        "{}{}response_type=code&client_id={}&redirect_uri={}&scope={}&state={}&nonce={}&code_challenge={}&code_challenge_method=S256",
+        metadata.authorization_endpoint,
+        separator,
+        encode(&oidc_config.client_id),
+        encode(&oidc_config.redirect_url),
+        encode(OIDC_SCOPES),
+        encode(&flow.state),
+        encode(&flow.nonce),
+        pkce_challenge(&flow.code_verifier),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn oidc_config() -> OidcConfig {
+        OidcConfig {
+            issuer_url: "https://idp.example.com/".to_string(),
+            client_id: "portfolio".to_string(),
+            client_secret: None,
+            redirect_url: "https://example.com/login/oidc/callback".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_from_config() {
+        assert_eq!(OidcConfig::from_config(&Config::default()), None);
+
+        let config = Config {
+            oidc_issuer_url: Some("https://idp.example.com".to_string()),
+            oidc_client_id: Some("portfolio".to_string()),
+            oidc_redirect_url: Some("https://example.com/login/oidc/callback".to_string()),
+            ..Config::default()
+        };
+        let result = OidcConfig::from_config(&config).unwrap();
+        assert_eq!(result.client_id, "portfolio");
+        assert_eq!(result.client_secret, None);
+        assert_eq!(
+            result.discovery_url(),
+            "https://idp.example.com/.well-known/openid-configuration"
+        );
+    }
+
+    #[test]
+    fn test_pkce_challenge() {
+        // RFC 7636 Appendix B
+        assert_eq!(
+            pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_authorization_url() {
+        let metadata = OidcProviderMetadata {
+            issuer: "https://idp.example.com/".to_string(),
+            authorization_endpoint: "https://idp.example.com/authorize".to_string(),
+            token_endpoint: "https://idp.example.com/token".to_string(),
+            jwks_uri: "https://idp.example.com/jwks".to_string(),
+        };
+        let flow = OidcFlowClaims {
+            state: "state-1".to_string(),
+            nonce: "nonce-1".to_string(),
+            code_verifier: "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string(),
+            exp: 0,
+            iat: 0,
+        };
+        assert_eq!(
+            authorization_url(&metadata, &oidc_config(), &flow),
+            "https://idp.example.com/authorize?response_type=code&client_id=portfolio&redirect_uri=https%3A%2F%2Fexample.com%2Flogin%2Foidc%2Fcallback&scope=openid%20email&state=state-1&nonce=nonce-1&code_challenge=E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM&code_challenge_method=S256"
+        );
+    }
+
+    #[test]
+    fn test_verified_email() {
+        let mut claims = OidcIdTokenClaims {
+            iss: "https://idp.example.com/".to_string(),
+            sub: "1".to_string(),
+            exp: 0,
+            nonce: None,
+            email: Some("me@example.com".to_string()),
+            email_verified: None,
+        };
+        assert_eq!(claims.verified_email(), None);
+        claims.email_verified = Some(serde_json::Value::Bool(false));
+        assert_eq!(claims.verified_email(), None);
+        claims.email_verified = Some(serde_json::Value::String("true".to_string()));
+        assert_eq!(claims.verified_email(), Some("me@example.com".to_string()));
+        claims.email_verified = Some(serde_json::Value::Bool(true));
+        assert_eq!(claims.verified_email(), Some("me@example.com".to_string()));
+    }
+}
@@ -0,0 +1,271 @@
+use crate::model::templates_admin::{AdminListOrganizationsTemplate, AdminOrganizationTemplate};
+use crate::utils::is_http_link;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+/// Organization
+/// Organization hosting Talks with fields:
+/// - id: Organization Identifier
+/// - name: Organization Name
+/// - url: (Optional) Organization website link
+/// - logo: (Optional) Organization logo link
+/// - description: (Optional) Short description of the Organization
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Organization {
+    pub id: i64,
+    pub name: String,
+    pub url: Option<String>,
+    pub logo: Option<String>,
+    pub description: Option<String>,
+}
+
+impl Organization {
+    /// Summarize the Organization for the audit log
+    pub fn audit_summary(&self) -> String {
+        format!(
+            "name={}; url={}; logo={}; description={} chars",
+            self.name,
+            self.url.as_deref().unwrap_or_default(),
+            self.logo.as_deref().unwrap_or_default(),
+            self.description
+                .as_deref()
+                .unwrap_or_default()
+                .chars()
+                .count()
+        )
+    }
+    /// Convert Organization to (Askama) AdminOrganizationTemplate
+    pub fn to_admin_template(&self) -> AdminOrganizationTemplate {
+        debug!(
+            "Organization: Constructing AdminOrganizationTemplate for Organization Id {}",
+            self.id
+        );
+        AdminOrganizationTemplate {
+            id: self.id,
+            name: self.name.clone(),
+            url: self.url.clone().unwrap_or_default(),
+            logo: self.logo.clone().unwrap_or_default(),
+            description: self.description.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Organizations
+/// Vector of Organization
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Organizations {
+    pub organizations: Vec<Organization>,
+}
+
+impl Organizations {
+    /// Convert Organizations to Vector of (Askama) AdminOrganizationTemplate
+    pub fn to_admin_templates(&self) -> Vec<AdminOrganizationTemplate> {
+        self.organizations
+            .iter()
+            .map(|organization| organization.to_admin_template())
+            .collect()
+    }
+    /// Convert Organizations to (Askama) AdminListOrganizationsTemplate
+    pub fn to_admin_list_template(&self) -> AdminListOrganizationsTemplate {
+        AdminListOrganizationsTemplate {
+            organizations: self.to_admin_templates(),
+        }
+    }
+}
+
+/// OrganizationForm
+/// Raw Organization fields submitted from the admin add/edit forms.
+/// Use `validate` to turn it into an `Organization`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrganizationForm {
+    pub id: i64,
+    pub name: String,
+    pub url: String,
+    pub logo: String,
+    pub description: String,
+}
+
+impl OrganizationForm {
+    /// Validate OrganizationForm and convert it into an `Organization`
+    /// Return all validation error messages if any field is invalid:
+    /// - name must not be empty
+    /// - url and logo must be http(s) links if set
+    pub fn validate(&self) -> Result<Organization, Vec<String>> {
+        let mut errors = Vec::new();
+
+        let name = self.name.trim();
+        if name.is_empty() {
+            errors.push("Organization name is required.".to_string());
+        }
+        let url = self.url.trim();
+        if !is_http_link(url) {
+            errors.push("Organization link must start with http:// or https://.".to_string());
+        }
+        let logo = self.logo.trim();
+        if !is_http_link(logo) && !logo.starts_with('/') {
+            errors.push(
+                "Organization logo must be a path or start with http:// or https://.".to_string(),
+            );
+        }
+        let non_empty = |val: &str| Some(val.to_string()).filter(|val| !val.is_empty());
+
+        if !errors.is_empty() {
+            debug!(
+                "OrganizationForm: Organization Id {} is invalid. {:?}",
+                self.id, errors
+            );
+            return Err(errors);
+        }
+        Ok(Organization {
+            id: self.id,
+            name: name.to_string(),
+            url: non_empty(url),
+            logo: non_empty(logo),
+            description: non_empty(self.description.trim()),
+        })
+    }
+    /// Convert OrganizationForm to (Askama) AdminOrganizationTemplate
+    /// Used to re-render the admin forms with the submitted values
+    pub fn to_admin_template(&self) -> AdminOrganizationTemplate {
+        AdminOrganizationTemplate {
+            id: self.id,
+            name: self.name.clone(),
+            url: self.url.clone(),
+            logo: self.logo.clone(),
+            description: self.description.clone(),
+        }
+    }
+}
+
+/// OrganizationsParams
+/// Axum Query struct for `/admin/talks/organizations/list` query parameters
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OrganizationsParams {
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+}
+
+impl OrganizationsParams {
+    /// Sanitize OrganizationsParams by checking negative value and set unknown
+    /// to the default values
+    pub fn sanitize(&self) -> Self {
+        let start = match self.start {
+            Some(val) if val >= 0 => val,
+            _ => {
+                debug!("OrganizationsParams: set default start to 0");
+                0_i64
+            }
+        };
+        let end = match self.end {
+            Some(val) if val >= 0 => val,
+            _ => {
+                debug!("OrganizationsParams: set default end to 100");
+                100_i64
+            }
+        };
+
+        Self {
+            start: Some(start),
+            end: Some(end),
+        }
+    }
+}
+
+/// OrganizationCommandStatus
+/// Status of Organization Command Operations:
+/// - Stored
+/// - Updated
+/// - Deleted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OrganizationCommandStatus {
+    Stored,
+    Updated,
+    Deleted,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample_organization() -> Organization {
+        Organization {
+            id: 1,
+            name: "Org".to_string(),
+            url: Some("https://example.com/org".to_string()),
+            logo: None,
+            description: Some("An organization".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_organization_to_admin_template() {
+        let template = sample_organization().to_admin_template();
+        assert_eq!(template.id, 1);
+        assert_eq!(template.name, "Org");
+        assert_eq!(template.url, "https://example.com/org");
+        assert_eq!(template.logo, "");
+        assert_eq!(template.description, "An organization");
+    }
+
+    #[test]
+    fn test_organization_audit_summary() {
+        assert_eq!(
+            sample_organization().audit_summary(),
+            "name=Org; url=https://example.com/org; logo=; description=15 chars"
+        );
+    }
+
+    #[test]
+    fn test_organizations_to_admin_list_template() {
+        let template = Organizations {
+            organizations: vec![sample_organization()],
+        }
+        .to_admin_list_template();
+        assert_eq!(template.organizations.len(), 1);
+        assert_eq!(template.organizations[0].id, 1);
+    }
+
+    #[test]
+    fn test_organization_form_validate() {
+        let form = OrganizationForm {
+            id: 2,
+            name: " Org ".to_string(),
+            url: "https://example.com/org".to_string(),
+            logo: "/statics/org.png".to_string(),
+            description: "  ".to_string(),
+        };
+        let organization = form.validate().unwrap();
+        assert_eq!(organization.name, "Org");
+        assert_eq!(
+            organization.url,
+            Some("https://example.com/org".to_string())
+        );
+        assert_eq!(organization.logo, Some("/statics/org.png".to_string()));
+        assert_eq!(organization.description, None);
+    }
+
+    #[test]
+    fn test_organization_form_validate_errors() {
+        let form = OrganizationForm {
+            id: 2,
+            name: "".to_string(),
+            url: "example.com".to_string(),
+            logo: "javascript:alert(1)".to_string(),
+            description: "".to_string(),
+        };
+        let errors = form.validate().unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0], "Organization name is required.");
+    }
+
+    #[test]
+    fn test_organizations_params_sanitize() {
+        let params = OrganizationsParams {
+            start: Some(-1),
+            end: None,
+        }
+        .sanitize();
+        assert_eq!(params.start, Some(0));
+        assert_eq!(params.end, Some(100));
+    }
+}
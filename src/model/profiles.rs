@@ -0,0 +1,512 @@
+use crate::model::{
+    templates::ProfileTemplate,
+    templates_admin::{
+        AdminGetProfileTemplate, AdminListProfilesTemplate, AdminProfileFormTemplate,
+        AdminProfileVersionTemplate,
+    },
+};
+use crate::utils::{convert_markdown_to_html, is_http_link};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+/// Format of a Profile version. A version is the UTC datetime the Profile was saved,
+/// so versions are sorted chronologically when sorted as strings.
+pub const PROFILE_VERSION_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3fZ";
+
+/// Create a new Profile version from a datetime
+pub fn new_profile_version(now: DateTime<Utc>) -> String {
+    now.format(PROFILE_VERSION_FORMAT).to_string()
+}
+
+/// ProfileExperience
+/// A work or volunteer experience with fields:
+/// - period: Experience period. e.g. "2022 - Now"
+/// - role: Role in the organization
+/// - organization: Organization name
+/// - link: (Optional) Organization link
+/// - highlights: Experience highlights
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProfileExperience {
+    pub period: String,
+    pub role: String,
+    pub organization: String,
+    pub link: Option<String>,
+    pub highlights: Vec<String>,
+}
+
+/// ProfileSkill
+/// A skill with its short description
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProfileSkill {
+    pub name: String,
+    pub description: String,
+}
+
+/// ProfileLink
+/// A named link. e.g. certification or social media
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProfileLink {
+    pub name: String,
+    pub url: String,
+}
+
+/// Profile
+/// A saved version of the Profile page with fields:
+/// - version: Profile version. UTC datetime in `PROFILE_VERSION_FORMAT`
+/// - headline: Profile headline
+/// - bio: Profile biography in markdown
+/// - experiences: Work and volunteer experiences
+/// - skills: Skills
+/// - links: Links
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Profile {
+    pub version: String,
+    pub headline: String,
+    pub bio: String,
+    pub experiences: Vec<ProfileExperience>,
+    pub skills: Vec<ProfileSkill>,
+    pub links: Vec<ProfileLink>,
+}
+
+impl Profile {
+    /// Summarize the Profile for the audit log
+    pub fn audit_summary(&self) -> String {
+        format!(
+            "version={}; headline={}; bio={} chars; experiences={}; skills={}; links={}",
+            self.version,
+            self.headline,
+            self.bio.chars().count(),
+            self.experiences.len(),
+            self.skills.len(),
+            self.links.len()
+        )
+    }
+    /// Convert Profile to (Askama) ProfileTemplate
+    /// Markdown biography is rendered into HTML
+    pub fn to_template(&self) -> ProfileTemplate {
+        debug!(
+            "Profile: Constructing ProfileTemplate for Profile version {}",
+            &self.version
+        );
+        ProfileTemplate {
+            headline: self.headline.clone(),
+            bio: convert_markdown_to_html(&self.bio),
+            experiences: self.experiences.clone(),
+            skills: self.skills.clone(),
+            links: self.links.clone(),
+        }
+    }
+    /// Convert Profile to (Askama) AdminGetProfileTemplate
+    /// Used to preview a Profile version
+    pub fn to_admin_template(&self) -> AdminGetProfileTemplate {
+        AdminGetProfileTemplate {
+            version: self.version.clone(),
+            headline: self.headline.clone(),
+            bio: convert_markdown_to_html(&self.bio),
+            experiences: self.experiences.clone(),
+            skills: self.skills.clone(),
+            links: self.links.clone(),
+        }
+    }
+    /// Convert Profile to ProfileForm to be edited in the admin editor
+    pub fn to_form(&self) -> ProfileForm {
+        let experiences = self
+            .experiences
+            .iter()
+            .map(|experience| {
+                let mut header = vec![
+                    experience.period.clone(),
+                    experience.role.clone(),
+                    experience.organization.clone(),
+                ];
+                if let Some(link) = &experience.link {
+                    header.push(link.clone());
+                }
+                let mut lines = vec![header.join(" | ")];
+                lines.extend(
+                    experience
+                        .highlights
+                        .iter()
+                        .map(|highlight| format!("- {highlight}")),
+                );
+                lines.join("\n")
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n");
+        let skills = self
+            .skills
+            .iter()
+            .map(|skill| format!("{} | {}", skill.name, skill.description))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let links = self
+            .links
+            .iter()
+            .map(|link| format!("{} | {}", link.name, link.url))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        ProfileForm {
+            headline: self.headline.clone(),
+            bio: self.bio.clone(),
+            experiences,
+            skills,
+            links,
+        }
+    }
+    /// Calculate size of Profile in u32
+    /// Useful for weighing data size
+    pub fn data_size(&self) -> u32 {
+        let experiences_size: usize = self
+            .experiences
+            .iter()
+            .map(|experience| {
+                experience.period.len()
+                    + experience.role.len()
+                    + experience.organization.len()
+                    + experience.link.as_ref().map_or(0, |link| link.len())
+                    + experience
+                        .highlights
+                        .iter()
+                        .map(|val| val.len())
+                        .sum::<usize>()
+            })
+            .sum();
+        let skills_size: usize = self
+            .skills
+            .iter()
+            .map(|skill| skill.name.len() + skill.description.len())
+            .sum();
+        let links_size: usize = self
+            .links
+            .iter()
+            .map(|link| link.name.len() + link.url.len())
+            .sum();
+        (self.version.len()
+            + self.headline.len()
+            + self.bio.len()
+            + experiences_size
+            + skills_size
+            + links_size) as u32
+    }
+}
+
+/// Profiles
+/// Vector of Profile versions
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Profiles {
+    pub profiles: Vec<Profile>,
+}
+
+impl Profiles {
+    /// Convert Profiles to (Askama) AdminListProfilesTemplate
+    /// `latest_version` is marked as the live version
+    pub fn to_admin_list_template(&self, latest_version: &str) -> AdminListProfilesTemplate {
+        AdminListProfilesTemplate {
+            profiles: self
+                .profiles
+                .iter()
+                .map(|profile| AdminProfileVersionTemplate {
+                    version: profile.version.clone(),
+                    headline: profile.headline.clone(),
+                    is_latest: profile.version == latest_version,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// ProfileForm
+/// Raw Profile fields submitted from the admin profile editor.
+/// Use `validate` to turn it into a `Profile`.
+/// - experiences: Blocks separated by an empty line. The first line of a block is
+///   `period | role | organization | link` (link is optional) and the following
+///   lines are highlights starting with `-`.
+/// - skills: One `name | description` per line
+/// - links: One `name | url` per line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfileForm {
+    pub headline: String,
+    pub bio: String,
+    pub experiences: String,
+    pub skills: String,
+    pub links: String,
+}
+
+impl ProfileForm {
+    /// Validate ProfileForm and convert it into a `Profile` with `version`
+    /// Return all validation error messages if any field is invalid.
+    pub fn validate(&self, version: String) -> Result<Profile, Vec<String>> {
+        let mut errors = Vec::new();
+
+        let headline = self.headline.trim();
+        if headline.is_empty() {
+            errors.push("Profile headline is required.".to_string());
+        }
+        let experiences = parse_experiences(&self.experiences, &mut errors);
+        let skills = parse_skills(&self.skills, &mut errors);
+        let links = parse_links(&self.links, &mut errors);
+
+        if !errors.is_empty() {
+            debug!("ProfileForm: Profile is invalid. {:?}", errors);
+            return Err(errors);
+        }
+        Ok(Profile {
+            version,
+            headline: headline.to_string(),
+            bio: self.bio.trim().to_string(),
+            experiences,
+            skills,
+            links,
+        })
+    }
+    /// Convert ProfileForm to (Askama) AdminProfileFormTemplate
+    /// Used to render the admin profile editor with the submitted values
+    pub fn to_admin_template(&self) -> AdminProfileFormTemplate {
+        AdminProfileFormTemplate {
+            headline: self.headline.clone(),
+            bio: self.bio.clone(),
+            experiences: self.experiences.clone(),
+            skills: self.skills.clone(),
+            links: self.links.clone(),
+        }
+    }
+}
+
+/// Split a `name | value` line into trimmed name and value
+fn split_pair(line: &str) -> (String, String) {
+    match line.split_once('|') {
+        Some((name, value)) => (name.trim().to_string(), value.trim().to_string()),
+        None => (line.trim().to_string(), String::new()),
+    }
+}
+
+/// Parse experience blocks of a ProfileForm
+fn parse_experiences(raw: &str, errors: &mut Vec<String>) -> Vec<ProfileExperience> {
+    let mut blocks: Vec<Vec<&str>> = Vec::new();
+    let mut block: Vec<&str> = Vec::new();
+    for line in raw.lines().map(|line| line.trim()) {
+        if line.is_empty() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+            continue;
+        }
+        block.push(line);
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+
+    let mut experiences = Vec::new();
+    for (idx, block) in blocks.iter().enumerate() {
+        let number = idx + 1;
+        let header: Vec<&str> = block[0].split('|').map(|val| val.trim()).collect();
+        if !(3..=4).contains(&header.len()) || header[..3].iter().any(|val| val.is_empty()) {
+            errors.push(format!(
+                "Experience {number} must start with 'period | role | organization | link'."
+            ));
+            continue;
+        }
+        let link = header
+            .get(3)
+            .map(|val| val.to_string())
+            .filter(|val| !val.is_empty());
+        if !link.as_deref().is_none_or(is_http_link) {
+            errors.push(format!(
+                "Experience {number} link must start with http:// or https://."
+            ));
+        }
+        let mut highlights = Vec::new();
+        for line in &block[1..] {
+            match line.strip_prefix('-') {
+                Some(highlight) => highlights.push(highlight.trim().to_string()),
+                None => errors.push(format!(
+                    "Experience {number} highlight '{line}' must start with '-'."
+                )),
+            }
+        }
+        experiences.push(ProfileExperience {
+            period: header[0].to_string(),
+            role: header[1].to_string(),
+            organization: header[2].to_string(),
+            link,
+            highlights,
+        });
+    }
+    experiences
+}
+
+/// Parse skill lines of a ProfileForm
+fn parse_skills(raw: &str, errors: &mut Vec<String>) -> Vec<ProfileSkill> {
+    let mut skills = Vec::new();
+    for line in raw.lines().filter(|line| !line.trim().is_empty()) {
+        let (name, description) = split_pair(line);
+        if name.is_empty() {
+            errors.push(format!("Skill '{}' must have a name.", line.trim()));
+            continue;
+        }
+        skills.push(ProfileSkill { name, description });
+    }
+    skills
+}
+
+/// Parse link lines of a ProfileForm
+fn parse_links(raw: &str, errors: &mut Vec<String>) -> Vec<ProfileLink> {
+    let mut links = Vec::new();
+    for line in raw.lines().filter(|line| !line.trim().is_empty()) {
+        let (name, url) = split_pair(line);
+        if name.is_empty() || url.is_empty() || !is_http_link(&url) {
+            errors.push(format!(
+                "Link '{}' must be 'name | url' with an http(s) url.",
+                line.trim()
+            ));
+            continue;
+        }
+        links.push(ProfileLink { name, url });
+    }
+    links
+}
+
+/// ProfilesParams
+/// Axum parameters query for Profile history pagination
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProfilesParams {
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+}
+
+impl ProfilesParams {
+    /// Sanitize ProfilesParams by checking negative value and set unknown to
+    /// the default values.
+    pub fn sanitize(&self) -> Self {
+        let start = match self.start {
+            Some(val) if val >= 0 => val,
+            _ => {
+                debug!("ProfilesParams: set default start to 0");
+                0_i64
+            }
+        };
+        let end = match self.end {
+            Some(val) if val >= 0 => val,
+            _ => {
+                debug!("ProfilesParams: set default end to 20");
+                20_i64
+            }
+        };
+        Self {
+            start: Some(start),
+            end: Some(end),
+        }
+    }
+}
+
+/// ProfileCommandStatus
+/// Status of Profile Command Operations:
+/// - Stored
+/// - CacheInserted
+/// - CacheInvalidated
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProfileCommandStatus {
+    Stored,
+    CacheInserted,
+    CacheInvalidated,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sample_form() -> ProfileForm {
+        ProfileForm {
+            headline: " Husni - SRE ".to_string(),
+            bio: "Hi! I'm **Husni**".to_string(),
+            experiences: "2022 - Now | SRE | Accelbyte | https://accelbyte.io\n- Operate infra\n- Handle incidents\n\n2022 - 2022 | System Engineer | Tokopedia".to_string(),
+            skills: "Rust | Build internal tools\nLinux".to_string(),
+            links: "GitHub | https://github.com/husni-zuhdi".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_new_profile_version() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 19, 8, 30, 0).unwrap();
+        assert_eq!(new_profile_version(now), "2026-10-19T08:30:00.000Z");
+    }
+
+    #[test]
+    fn test_profile_form_validate() {
+        let profile = sample_form().validate("v1".to_string()).unwrap();
+        assert_eq!(profile.version, "v1");
+        assert_eq!(profile.headline, "Husni - SRE");
+        assert_eq!(profile.experiences.len(), 2);
+        assert_eq!(
+            profile.experiences[0].link,
+            Some("https://accelbyte.io".to_string())
+        );
+        assert_eq!(
+            profile.experiences[0].highlights,
+            vec!["Operate infra".to_string(), "Handle incidents".to_string()]
+        );
+        assert_eq!(profile.experiences[1].link, None);
+        assert_eq!(profile.skills[1].name, "Linux");
+        assert_eq!(profile.skills[1].description, "");
+        assert_eq!(profile.links[0].url, "https://github.com/husni-zuhdi");
+    }
+
+    #[test]
+    fn test_profile_form_validate_errors() {
+        let form = ProfileForm {
+            headline: " ".to_string(),
+            bio: "".to_string(),
+            experiences: "2022 | SRE\n- Operate infra\n\n2022 | SRE | Acme | ftp://acme\nOperate"
+                .to_string(),
+            skills: "| no name".to_string(),
+            links: "GitHub".to_string(),
+        };
+        let errors = form.validate("v1".to_string()).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                "Profile headline is required.".to_string(),
+                "Experience 1 must start with 'period | role | organization | link'.".to_string(),
+                "Experience 2 link must start with http:// or https://.".to_string(),
+                "Experience 2 highlight 'Operate' must start with '-'.".to_string(),
+                "Skill '| no name' must have a name.".to_string(),
+                "Link 'GitHub' must be 'name | url' with an http(s) url.".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_profile_to_form_round_trip() {
+        let profile = sample_form().validate("v1".to_string()).unwrap();
+        let form = profile.to_form();
+        assert_eq!(form.validate("v1".to_string()), Ok(profile));
+    }
+
+    #[test]
+    fn test_profile_to_template() {
+        let template = sample_form()
+            .validate("v1".to_string())
+            .unwrap()
+            .to_template();
+        assert_eq!(template.bio, "<p>Hi! I'm <strong>Husni</strong></p>");
+    }
+
+    #[test]
+    fn test_profiles_to_admin_list_template() {
+        let profile = sample_form().validate("v1".to_string()).unwrap();
+        let old_profile = Profile {
+            version: "v0".to_string(),
+            ..profile.clone()
+        };
+        let template = Profiles {
+            profiles: vec![profile, old_profile],
+        }
+        .to_admin_list_template("v1");
+        assert!(template.profiles[0].is_latest);
+        assert!(!template.profiles[1].is_latest);
+    }
+}
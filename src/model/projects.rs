@@ -0,0 +1,505 @@
+use crate::model::{
+    templates::{ProjectMetadataTemplate, ProjectTemplate, ProjectsTemplate},
+    templates_admin::{AdminListProjectsTemplate, AdminProjectTemplate},
+};
+use crate::utils::{
+    convert_markdown_to_html, convert_tags_string_to_vec, is_http_link, is_tags_matched,
+    remove_whitespace,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+use tracing::debug;
+
+/// ProjectStatus
+/// Status of a Project
+/// Can be:
+/// - Active: Project is actively developed
+/// - Maintained: Project only receives fixes
+/// - Archived: Project is no longer maintained
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProjectStatus {
+    #[default]
+    Active,
+    Maintained,
+    Archived,
+}
+
+impl ProjectStatus {
+    /// All Project statuses in display order
+    pub const ALL: [Self; 3] = [Self::Active, Self::Maintained, Self::Archived];
+}
+
+impl Display for ProjectStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            Self::Active => write!(f, "Active"),
+            Self::Maintained => write!(f, "Maintained"),
+            Self::Archived => write!(f, "Archived"),
+        }
+    }
+}
+
+impl FromStr for ProjectStatus {
+    type Err = String;
+
+    /// Parse Project status case-insensitively
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "active" => Ok(Self::Active),
+            "maintained" => Ok(Self::Maintained),
+            "archived" => Ok(Self::Archived),
+            _ => Err(format!("Project status '{}' is not valid.", s.trim())),
+        }
+    }
+}
+
+/// Project
+/// Project data with fields:
+/// - id: Project Identifier
+/// - name: Project Name
+/// - summary: Project one line summary
+/// - body: Project markdown body
+/// - repo_url: (Optional) Project repository link
+/// - status: Project status
+/// - tags: Project tech tags
+/// - position: Project ordering. Lower position is listed first
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Project {
+    pub id: i64,
+    pub name: String,
+    pub summary: String,
+    pub body: String,
+    pub repo_url: Option<String>,
+    pub status: ProjectStatus,
+    pub tags: Vec<String>,
+    pub position: i64,
+}
+
+impl Project {
+    /// Summarize the Project for the audit log
+    pub fn audit_summary(&self) -> String {
+        format!(
+            "name={}; status={}; repo_url={}; position={}; body={} chars; tags={}",
+            self.name,
+            self.status,
+            self.repo_url.as_deref().unwrap_or_default(),
+            self.position,
+            self.body.chars().count(),
+            self.tags.join(",")
+        )
+    }
+    /// Convert Project to (Askama) ProjectMetadataTemplate
+    pub fn to_metadata_template(&self) -> ProjectMetadataTemplate {
+        ProjectMetadataTemplate {
+            id: self.id,
+            name: self.name.clone(),
+            summary: self.summary.clone(),
+            repo_url: self.repo_url.clone().unwrap_or_default(),
+            status: self.status.to_string(),
+            tags: self.tags.clone(),
+        }
+    }
+    /// Convert Project to (Askama) ProjectTemplate
+    /// Markdown body is rendered into HTML
+    pub fn to_template(&self) -> ProjectTemplate {
+        debug!(
+            "Project: Constructing ProjectTemplate for Project Id {}",
+            &self.id
+        );
+        ProjectTemplate {
+            id: self.id,
+            name: self.name.clone(),
+            summary: self.summary.clone(),
+            body: convert_markdown_to_html(&self.body),
+            repo_url: self.repo_url.clone().unwrap_or_default(),
+            status: self.status.to_string(),
+            tags: self.tags.clone(),
+        }
+    }
+    /// Convert Project to (Askama) AdminProjectTemplate
+    pub fn to_admin_template(&self) -> AdminProjectTemplate {
+        debug!(
+            "Project: Constructing AdminProjectTemplate for Project Id {}",
+            self.id
+        );
+        AdminProjectTemplate {
+            id: self.id,
+            name: self.name.clone(),
+            summary: self.summary.clone(),
+            body: self.body.clone(),
+            repo_url: self.repo_url.clone().unwrap_or_default(),
+            status: self.status.to_string(),
+            tags: self.tags.join(","),
+            position: self.position.to_string(),
+        }
+    }
+    /// Calculate size of Project in u32
+    /// Useful for weighing data size
+    pub fn data_size(&self) -> u32 {
+        (size_of_val(&self.id)
+            + self.name.len()
+            + self.summary.len()
+            + self.body.len()
+            + size_of_val(&self.repo_url)
+            + size_of_val(&self.status)
+            + size_of_val(&self.tags)
+            + size_of_val(&self.position)) as u32
+    }
+}
+
+/// Projects
+/// Vector of Project
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Projects {
+    pub projects: Vec<Project>,
+}
+
+impl Projects {
+    /// Convert Projects to (Askama) ProjectsTemplate
+    /// `params` is used to mark the active status and tags filters.
+    pub fn to_template(&self, params: &ProjectsParams) -> ProjectsTemplate {
+        ProjectsTemplate {
+            projects: self
+                .projects
+                .iter()
+                .map(|project| project.to_metadata_template())
+                .collect(),
+            statuses: ProjectStatus::ALL
+                .iter()
+                .map(|status| status.to_string())
+                .collect(),
+            active_status: params
+                .status
+                .as_ref()
+                .map(|status| status.to_string())
+                .unwrap_or_default(),
+            active_tags: params
+                .tags
+                .as_deref()
+                .map(convert_tags_string_to_vec)
+                .unwrap_or_default(),
+        }
+    }
+    /// Convert Projects to (Askama) AdminListProjectsTemplate
+    pub fn to_admin_list_template(&self) -> AdminListProjectsTemplate {
+        AdminListProjectsTemplate {
+            projects: self
+                .projects
+                .iter()
+                .map(|project| project.to_admin_template())
+                .collect(),
+        }
+    }
+}
+
+/// ProjectForm
+/// Raw Project fields submitted from the admin add/edit forms.
+/// Use `validate` to turn it into a `Project`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectForm {
+    pub id: i64,
+    pub name: String,
+    pub summary: String,
+    pub body: String,
+    pub repo_url: String,
+    pub status: String,
+    pub tags: String,
+    pub position: String,
+}
+
+impl ProjectForm {
+    /// Validate ProjectForm and convert it into a `Project`
+    /// Return all validation error messages if any field is invalid:
+    /// - name and summary must not be empty
+    /// - repository link must be an http(s) link if set
+    /// - status must be one of `ProjectStatus`
+    /// - position must be a number if set
+    pub fn validate(&self) -> Result<Project, Vec<String>> {
+        let mut errors = Vec::new();
+
+        let name = self.name.trim();
+        if name.is_empty() {
+            errors.push("Project name is required.".to_string());
+        }
+        let summary = self.summary.trim();
+        if summary.is_empty() {
+            errors.push("Project summary is required.".to_string());
+        }
+        let repo_url = self.repo_url.trim();
+        if !is_http_link(repo_url) {
+            errors.push("Repository link must start with http:// or https://.".to_string());
+        }
+        let status = self.status.parse::<ProjectStatus>();
+        if let Err(err) = &status {
+            errors.push(err.clone());
+        }
+        let position = match self.position.trim() {
+            "" => Ok(0),
+            val => val.parse::<i64>(),
+        };
+        if position.is_err() {
+            errors.push(format!(
+                "Project position '{}' is not a number.",
+                self.position.trim()
+            ));
+        }
+
+        match (status, position) {
+            (Ok(status), Ok(position)) if errors.is_empty() => Ok(Project {
+                id: self.id,
+                name: name.to_string(),
+                summary: summary.to_string(),
+                body: self.body.clone(),
+                repo_url: Some(repo_url.to_string()).filter(|val| !val.is_empty()),
+                status,
+                tags: remove_whitespace(&self.tags)
+                    .split(',')
+                    .filter(|tag| !tag.is_empty())
+                    .map(|tag| tag.to_string())
+                    .collect(),
+                position,
+            }),
+            _ => {
+                debug!(
+                    "ProjectForm: Project Id {} is invalid. {:?}",
+                    self.id, errors
+                );
+                Err(errors)
+            }
+        }
+    }
+    /// Convert ProjectForm to (Askama) AdminProjectTemplate
+    /// Used to re-render the admin forms with the submitted values
+    pub fn to_admin_template(&self) -> AdminProjectTemplate {
+        AdminProjectTemplate {
+            id: self.id,
+            name: self.name.clone(),
+            summary: self.summary.clone(),
+            body: self.body.clone(),
+            repo_url: self.repo_url.clone(),
+            status: self.status.clone(),
+            tags: self.tags.clone(),
+            position: self.position.clone(),
+        }
+    }
+}
+
+/// ProjectsParams
+/// Axum parameters query for pagination and filters
+/// - start and end: pagination
+/// - status: (Optional) only return projects with this status
+/// - tags: (Optional) only return projects with any of these comma separated tech tags
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProjectsParams {
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+    pub status: Option<String>,
+    pub tags: Option<String>,
+}
+
+impl ProjectsParams {
+    /// Sanitize ProjectsParams by checking negative value and set unknown to
+    /// the default values. Invalid status and blank tags filters are dropped.
+    pub fn sanitize(&self) -> Self {
+        let start = match self.start {
+            Some(val) if val >= 0 => val,
+            _ => {
+                debug!("ProjectsParams: set default start to 0");
+                0_i64
+            }
+        };
+        let end = match self.end {
+            Some(val) if val >= 0 => val,
+            _ => {
+                debug!("ProjectsParams: set default end to 100");
+                100_i64
+            }
+        };
+        let status = self
+            .status
+            .as_ref()
+            .and_then(|val| val.parse::<ProjectStatus>().ok())
+            .map(|val| val.to_string());
+        let tags = self
+            .tags
+            .as_ref()
+            .map(|val| remove_whitespace(val))
+            .filter(|val| !val.is_empty());
+
+        Self {
+            start: Some(start),
+            end: Some(end),
+            status,
+            tags,
+        }
+    }
+    /// Check if a Project pass the status and tags filters
+    pub fn is_matched(&self, project: &Project) -> bool {
+        let status_matched = self
+            .status
+            .as_ref()
+            .is_none_or(|status| &project.status.to_string() == status);
+        let tags_matched = self
+            .tags
+            .as_ref()
+            .is_none_or(|tags| is_tags_matched(tags, &project.tags));
+        status_matched && tags_matched
+    }
+}
+
+/// ProjectCommandStatus
+/// Status of Project Command Operations:
+/// - Stored
+/// - Updated
+/// - Deleted
+/// - CacheInserted
+/// - CacheInvalidated
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProjectCommandStatus {
+    Stored,
+    Updated,
+    Deleted,
+    CacheInserted,
+    CacheInvalidated,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample_project() -> Project {
+        Project {
+            id: 1,
+            name: "Portfolio".to_string(),
+            summary: "Personal website".to_string(),
+            body: "# Portfolio".to_string(),
+            repo_url: Some("https://github.com/husni-zuhdi/husni-portfolio".to_string()),
+            status: ProjectStatus::Active,
+            tags: vec!["rust".to_string(), "htmx".to_string()],
+            position: 1,
+        }
+    }
+
+    #[test]
+    fn test_project_status_parse() {
+        assert_eq!(
+            " Archived ".parse::<ProjectStatus>(),
+            Ok(ProjectStatus::Archived)
+        );
+        assert_eq!(
+            "maintained".parse::<ProjectStatus>(),
+            Ok(ProjectStatus::Maintained)
+        );
+        assert!("done".parse::<ProjectStatus>().is_err());
+    }
+
+    #[test]
+    fn test_project_to_template() {
+        let template = sample_project().to_template();
+        assert_eq!(template.status, "Active");
+        assert!(template.body.contains("<h1>Portfolio</h1>"));
+        assert_eq!(template.tags, vec!["rust".to_string(), "htmx".to_string()]);
+    }
+
+    #[test]
+    fn test_project_to_admin_template() {
+        let template = sample_project().to_admin_template();
+        assert_eq!(template.body, "# Portfolio");
+        assert_eq!(template.tags, "rust,htmx");
+        assert_eq!(template.position, "1");
+    }
+
+    #[test]
+    fn test_project_audit_summary() {
+        assert_eq!(
+            sample_project().audit_summary(),
+            "name=Portfolio; status=Active; repo_url=https://github.com/husni-zuhdi/husni-portfolio; position=1; body=11 chars; tags=rust,htmx"
+        );
+    }
+
+    #[test]
+    fn test_project_form_validate() {
+        let form = ProjectForm {
+            id: 2,
+            name: " CLI ".to_string(),
+            summary: "A CLI".to_string(),
+            body: "Body".to_string(),
+            repo_url: "".to_string(),
+            status: "archived".to_string(),
+            tags: "rust, cli".to_string(),
+            position: "".to_string(),
+        };
+        let project = form.validate().unwrap();
+        assert_eq!(project.name, "CLI");
+        assert_eq!(project.repo_url, None);
+        assert_eq!(project.status, ProjectStatus::Archived);
+        assert_eq!(project.tags, vec!["rust".to_string(), "cli".to_string()]);
+        assert_eq!(project.position, 0);
+    }
+
+    #[test]
+    fn test_project_form_validate_errors() {
+        let form = ProjectForm {
+            id: 2,
+            name: "".to_string(),
+            summary: " ".to_string(),
+            body: "".to_string(),
+            repo_url: "github.com".to_string(),
+            status: "done".to_string(),
+            tags: "".to_string(),
+            position: "first".to_string(),
+        };
+        let errors = form.validate().unwrap_err();
+        assert_eq!(errors.len(), 5);
+        assert_eq!(errors[0], "Project name is required.");
+        assert_eq!(errors[3], "Project status 'done' is not valid.");
+    }
+
+    #[test]
+    fn test_projects_params_sanitize() {
+        let params = ProjectsParams {
+            start: Some(-1),
+            end: None,
+            status: Some("archived".to_string()),
+            tags: Some(" rust , go".to_string()),
+        }
+        .sanitize();
+        assert_eq!(params.start, Some(0));
+        assert_eq!(params.end, Some(100));
+        assert_eq!(params.status, Some("Archived".to_string()));
+        assert_eq!(params.tags, Some("rust,go".to_string()));
+
+        let params = ProjectsParams {
+            start: None,
+            end: None,
+            status: Some("done".to_string()),
+            tags: Some("  ".to_string()),
+        }
+        .sanitize();
+        assert_eq!(params.status, None);
+        assert_eq!(params.tags, None);
+    }
+
+    #[test]
+    fn test_projects_params_is_matched() {
+        let project = sample_project();
+        let mut params = ProjectsParams {
+            start: None,
+            end: None,
+            status: None,
+            tags: None,
+        };
+        assert!(params.is_matched(&project));
+
+        params.status = Some("Active".to_string());
+        assert!(params.is_matched(&project));
+        params.tags = Some("go,htmx".to_string());
+        assert!(params.is_matched(&project));
+        params.tags = Some("go".to_string());
+        assert!(!params.is_matched(&project));
+        params.tags = None;
+        params.status = Some("Archived".to_string());
+        assert!(!params.is_matched(&project));
+    }
+}
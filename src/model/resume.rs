@@ -0,0 +1,629 @@
+use crate::model::{templates::ResumeTemplate, templates_admin::AdminResumeFormTemplate};
+use crate::utils::is_http_link;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use tracing::debug;
+
+/// JSON Resume schema used by `/resume.json`
+pub const JSON_RESUME_SCHEMA: &str =
+    "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+
+/// Check a Resume date
+/// Resume dates follow JSON Resume ISO 8601 dates. e.g. 2022, 2022-04, or 2022-04-01
+fn is_resume_date(date: &str) -> bool {
+    let pattern = Regex::new(r"^\d{4}(-(0[1-9]|1[0-2])(-(0[1-9]|[12]\d|3[01]))?)?$").unwrap();
+    pattern.is_match(date)
+}
+
+/// ResumeBasics
+/// Basic information of the Resume owner
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResumeBasics {
+    pub name: String,
+    pub label: String,
+    pub email: String,
+    pub url: String,
+    pub summary: String,
+}
+
+/// ResumeWork
+/// A work experience with fields:
+/// - start_date: Start date
+/// - end_date: (Optional) End date. `None` means the work is ongoing
+/// - position: Position in the company
+/// - company: Company name
+/// - url: (Optional) Company link
+/// - highlights: Work highlights
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResumeWork {
+    pub start_date: String,
+    pub end_date: Option<String>,
+    pub position: String,
+    pub company: String,
+    pub url: Option<String>,
+    pub highlights: Vec<String>,
+}
+
+/// ResumeEducation
+/// An education with fields:
+/// - start_date: Start date
+/// - end_date: (Optional) End date. `None` means the education is ongoing
+/// - institution: Institution name
+/// - study_type: Study type. e.g. Bachelor
+/// - area: Study area. e.g. Nuclear Engineering
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResumeEducation {
+    pub start_date: String,
+    pub end_date: Option<String>,
+    pub institution: String,
+    pub study_type: String,
+    pub area: String,
+}
+
+/// ResumeCertificate
+/// A certification with fields:
+/// - date: Issued date
+/// - name: Certification name
+/// - issuer: Certification issuer
+/// - url: (Optional) Certification link
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResumeCertificate {
+    pub date: String,
+    pub name: String,
+    pub issuer: String,
+    pub url: Option<String>,
+}
+
+/// ResumeSkill
+/// A skill with fields:
+/// - name: Skill name
+/// - level: Skill level. e.g. Advanced
+/// - keywords: Skill keywords
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResumeSkill {
+    pub name: String,
+    pub level: String,
+    pub keywords: Vec<String>,
+}
+
+/// Resume
+/// Structured curriculum vitae
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Resume {
+    pub basics: ResumeBasics,
+    pub work: Vec<ResumeWork>,
+    pub education: Vec<ResumeEducation>,
+    pub certificates: Vec<ResumeCertificate>,
+    pub skills: Vec<ResumeSkill>,
+}
+
+/// Insert `val` into a JSON object if it's not empty
+fn insert_non_empty(object: &mut Map<String, Value>, key: &str, val: &str) {
+    if !val.is_empty() {
+        object.insert(key.to_string(), json!(val));
+    }
+}
+
+impl Resume {
+    /// Summarize the Resume for the audit log
+    pub fn audit_summary(&self) -> String {
+        format!(
+            "name={}; label={}; work={}; education={}; certificates={}; skills={}",
+            self.basics.name,
+            self.basics.label,
+            self.work.len(),
+            self.education.len(),
+            self.certificates.len(),
+            self.skills.len()
+        )
+    }
+    /// Convert Resume to (Askama) ResumeTemplate
+    pub fn to_template(&self) -> ResumeTemplate {
+        ResumeTemplate {
+            basics: self.basics.clone(),
+            work: self.work.clone(),
+            education: self.education.clone(),
+            certificates: self.certificates.clone(),
+            skills: self.skills.clone(),
+        }
+    }
+    /// Convert Resume into a JSON Resume document
+    /// Empty optional fields are omitted.
+    pub fn to_json_resume(&self) -> Value {
+        let mut basics = Map::new();
+        insert_non_empty(&mut basics, "name", &self.basics.name);
+        insert_non_empty(&mut basics, "label", &self.basics.label);
+        insert_non_empty(&mut basics, "email", &self.basics.email);
+        insert_non_empty(&mut basics, "url", &self.basics.url);
+        insert_non_empty(&mut basics, "summary", &self.basics.summary);
+
+        let work: Vec<Value> = self
+            .work
+            .iter()
+            .map(|work| {
+                let mut object = Map::new();
+                insert_non_empty(&mut object, "name", &work.company);
+                insert_non_empty(&mut object, "position", &work.position);
+                insert_non_empty(&mut object, "url", work.url.as_deref().unwrap_or_default());
+                insert_non_empty(&mut object, "startDate", &work.start_date);
+                insert_non_empty(
+                    &mut object,
+                    "endDate",
+                    work.end_date.as_deref().unwrap_or_default(),
+                );
+                object.insert("highlights".to_string(), json!(work.highlights));
+                Value::Object(object)
+            })
+            .collect();
+        let education: Vec<Value> = self
+            .education
+            .iter()
+            .map(|education| {
+                let mut object = Map::new();
+                insert_non_empty(&mut object, "institution", &education.institution);
+                insert_non_empty(&mut object, "area", &education.area);
+                insert_non_empty(&mut object, "studyType", &education.study_type);
+                insert_non_empty(&mut object, "startDate", &education.start_date);
+                insert_non_empty(
+                    &mut object,
+                    "endDate",
+                    education.end_date.as_deref().unwrap_or_default(),
+                );
+                Value::Object(object)
+            })
+            .collect();
+        let certificates: Vec<Value> = self
+            .certificates
+            .iter()
+            .map(|certificate| {
+                let mut object = Map::new();
+                insert_non_empty(&mut object, "name", &certificate.name);
+                insert_non_empty(&mut object, "date", &certificate.date);
+                insert_non_empty(&mut object, "issuer", &certificate.issuer);
+                insert_non_empty(
+                    &mut object,
+                    "url",
+                    certificate.url.as_deref().unwrap_or_default(),
+                );
+                Value::Object(object)
+            })
+            .collect();
+        let skills: Vec<Value> = self
+            .skills
+            .iter()
+            .map(|skill| {
+                let mut object = Map::new();
+                insert_non_empty(&mut object, "name", &skill.name);
+                insert_non_empty(&mut object, "level", &skill.level);
+                object.insert("keywords".to_string(), json!(skill.keywords));
+                Value::Object(object)
+            })
+            .collect();
+
+        json!({
+            "$schema": JSON_RESUME_SCHEMA,
+            "basics": basics,
+            "work": work,
+            "education": education,
+            "certificates": certificates,
+            "skills": skills,
+        })
+    }
+    /// Convert Resume to ResumeForm to be edited in the admin editor
+    pub fn to_form(&self) -> ResumeForm {
+        let work = self
+            .work
+            .iter()
+            .map(|work| {
+                let header = [
+                    work.start_date.clone(),
+                    work.end_date.clone().unwrap_or_default(),
+                    work.position.clone(),
+                    work.company.clone(),
+                    work.url.clone().unwrap_or_default(),
+                ];
+                let mut lines = vec![header.join(" | ")];
+                lines.extend(
+                    work.highlights
+                        .iter()
+                        .map(|highlight| format!("- {highlight}")),
+                );
+                lines.join("\n")
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n");
+        let education = self
+            .education
+            .iter()
+            .map(|education| {
+                [
+                    education.start_date.clone(),
+                    education.end_date.clone().unwrap_or_default(),
+                    education.institution.clone(),
+                    education.study_type.clone(),
+                    education.area.clone(),
+                ]
+                .join(" | ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let certificates = self
+            .certificates
+            .iter()
+            .map(|certificate| {
+                [
+                    certificate.date.clone(),
+                    certificate.name.clone(),
+                    certificate.issuer.clone(),
+                    certificate.url.clone().unwrap_or_default(),
+                ]
+                .join(" | ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let skills = self
+            .skills
+            .iter()
+            .map(|skill| {
+                format!(
+                    "{} | {} | {}",
+                    skill.name,
+                    skill.level,
+                    skill.keywords.join(", ")
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        ResumeForm {
+            name: self.basics.name.clone(),
+            label: self.basics.label.clone(),
+            email: self.basics.email.clone(),
+            url: self.basics.url.clone(),
+            summary: self.basics.summary.clone(),
+            work,
+            education,
+            certificates,
+            skills,
+        }
+    }
+    /// Calculate size of Resume in u32
+    /// Useful for weighing data size
+    pub fn data_size(&self) -> u32 {
+        // Resume is small enough to be weighed by its JSON size
+        serde_json::to_string(self).map_or(0, |val| val.len()) as u32
+    }
+}
+
+/// ResumeForm
+/// Raw Resume fields submitted from the admin resume editor.
+/// Use `validate` to turn it into a `Resume`.
+/// Dates are `YYYY`, `YYYY-MM`, or `YYYY-MM-DD`. Empty end dates mean ongoing.
+/// - work: Blocks separated by an empty line. The first line of a block is
+///   `start | end | position | company | url` and the following lines are
+///   highlights starting with `-`.
+/// - education: One `start | end | institution | study type | area` per line
+/// - certificates: One `date | name | issuer | url` per line
+/// - skills: One `name | level | keyword, keyword` per line
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResumeForm {
+    pub name: String,
+    pub label: String,
+    pub email: String,
+    pub url: String,
+    pub summary: String,
+    pub work: String,
+    pub education: String,
+    pub certificates: String,
+    pub skills: String,
+}
+
+impl ResumeForm {
+    /// Validate ResumeForm and convert it into a `Resume`
+    /// Return all validation error messages if any field is invalid.
+    pub fn validate(&self) -> Result<Resume, Vec<String>> {
+        let mut errors = Vec::new();
+
+        let name = self.name.trim();
+        if name.is_empty() {
+            errors.push("Resume name is required.".to_string());
+        }
+        let email = self.email.trim();
+        if !email.is_empty() && !email.contains('@') {
+            errors.push(format!("Resume email '{email}' is not valid."));
+        }
+        let url = self.url.trim();
+        if !is_http_link(url) {
+            errors.push("Resume link must start with http:// or https://.".to_string());
+        }
+        let work = parse_work(&self.work, &mut errors);
+        let education = parse_education(&self.education, &mut errors);
+        let certificates = parse_certificates(&self.certificates, &mut errors);
+        let skills = parse_skills(&self.skills, &mut errors);
+
+        if !errors.is_empty() {
+            debug!("ResumeForm: Resume is invalid. {:?}", errors);
+            return Err(errors);
+        }
+        Ok(Resume {
+            basics: ResumeBasics {
+                name: name.to_string(),
+                label: self.label.trim().to_string(),
+                email: email.to_string(),
+                url: url.to_string(),
+                summary: self.summary.trim().to_string(),
+            },
+            work,
+            education,
+            certificates,
+            skills,
+        })
+    }
+    /// Convert ResumeForm to (Askama) AdminResumeFormTemplate
+    /// Used to render the admin resume editor with the submitted values
+    pub fn to_admin_template(&self) -> AdminResumeFormTemplate {
+        AdminResumeFormTemplate {
+            name: self.name.clone(),
+            label: self.label.clone(),
+            email: self.email.clone(),
+            url: self.url.clone(),
+            summary: self.summary.clone(),
+            work: self.work.clone(),
+            education: self.education.clone(),
+            certificates: self.certificates.clone(),
+            skills: self.skills.clone(),
+        }
+    }
+}
+
+/// Split a line by `|` into `size` trimmed fields
+/// Return `None` if the line doesn't have exactly `size` fields
+fn split_fields(line: &str, size: usize) -> Option<Vec<String>> {
+    let fields: Vec<String> = line.split('|').map(|val| val.trim().to_string()).collect();
+    (fields.len() == size).then_some(fields)
+}
+
+/// Check a start date and an optional end date of a Resume entry
+fn check_dates(entry: &str, start: &str, end: &str, errors: &mut Vec<String>) {
+    if !is_resume_date(start) {
+        errors.push(format!("{entry} start date '{start}' is not a valid date."));
+    }
+    if !end.is_empty() && !is_resume_date(end) {
+        errors.push(format!("{entry} end date '{end}' is not a valid date."));
+    }
+}
+
+/// Check an optional link of a Resume entry
+fn check_link(entry: &str, link: &str, errors: &mut Vec<String>) -> Option<String> {
+    if !is_http_link(link) {
+        errors.push(format!("{entry} link must start with http:// or https://."));
+    }
+    Some(link.to_string()).filter(|val| !val.is_empty())
+}
+
+/// Parse work blocks of a ResumeForm
+fn parse_work(raw: &str, errors: &mut Vec<String>) -> Vec<ResumeWork> {
+    let mut blocks: Vec<Vec<&str>> = Vec::new();
+    let mut block: Vec<&str> = Vec::new();
+    for line in raw.lines().map(|line| line.trim()) {
+        if line.is_empty() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+            continue;
+        }
+        block.push(line);
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+
+    let mut work = Vec::new();
+    for (idx, block) in blocks.iter().enumerate() {
+        let entry = format!("Work {}", idx + 1);
+        let Some(header) = split_fields(block[0], 5) else {
+            errors.push(format!(
+                "{entry} must start with 'start | end | position | company | url'."
+            ));
+            continue;
+        };
+        check_dates(&entry, &header[0], &header[1], errors);
+        if header[2].is_empty() || header[3].is_empty() {
+            errors.push(format!("{entry} must have a position and a company."));
+        }
+        let url = check_link(&entry, &header[4], errors);
+        let mut highlights = Vec::new();
+        for line in &block[1..] {
+            match line.strip_prefix('-') {
+                Some(highlight) => highlights.push(highlight.trim().to_string()),
+                None => errors.push(format!("{entry} highlight '{line}' must start with '-'.")),
+            }
+        }
+        work.push(ResumeWork {
+            start_date: header[0].clone(),
+            end_date: Some(header[1].clone()).filter(|val| !val.is_empty()),
+            position: header[2].clone(),
+            company: header[3].clone(),
+            url,
+            highlights,
+        });
+    }
+    work
+}
+
+/// Parse education lines of a ResumeForm
+fn parse_education(raw: &str, errors: &mut Vec<String>) -> Vec<ResumeEducation> {
+    let mut education = Vec::new();
+    let lines = raw.lines().filter(|line| !line.trim().is_empty());
+    for (idx, line) in lines.enumerate() {
+        let entry = format!("Education {}", idx + 1);
+        let Some(fields) = split_fields(line, 5) else {
+            errors.push(format!(
+                "{entry} must be 'start | end | institution | study type | area'."
+            ));
+            continue;
+        };
+        check_dates(&entry, &fields[0], &fields[1], errors);
+        if fields[2].is_empty() {
+            errors.push(format!("{entry} must have an institution."));
+        }
+        education.push(ResumeEducation {
+            start_date: fields[0].clone(),
+            end_date: Some(fields[1].clone()).filter(|val| !val.is_empty()),
+            institution: fields[2].clone(),
+            study_type: fields[3].clone(),
+            area: fields[4].clone(),
+        });
+    }
+    education
+}
+
+/// Parse certificate lines of a ResumeForm
+fn parse_certificates(raw: &str, errors: &mut Vec<String>) -> Vec<ResumeCertificate> {
+    let mut certificates = Vec::new();
+    let lines = raw.lines().filter(|line| !line.trim().is_empty());
+    for (idx, line) in lines.enumerate() {
+        let entry = format!("Certificate {}", idx + 1);
+        let Some(fields) = split_fields(line, 4) else {
+            errors.push(format!("{entry} must be 'date | name | issuer | url'."));
+            continue;
+        };
+        if !is_resume_date(&fields[0]) {
+            errors.push(format!(
+                "{entry} date '{}' is not a valid date.",
+                &fields[0]
+            ));
+        }
+        if fields[1].is_empty() {
+            errors.push(format!("{entry} must have a name."));
+        }
+        let url = check_link(&entry, &fields[3], errors);
+        certificates.push(ResumeCertificate {
+            date: fields[0].clone(),
+            name: fields[1].clone(),
+            issuer: fields[2].clone(),
+            url,
+        });
+    }
+    certificates
+}
+
+/// Parse skill lines of a ResumeForm
+fn parse_skills(raw: &str, errors: &mut Vec<String>) -> Vec<ResumeSkill> {
+    let mut skills = Vec::new();
+    let lines = raw.lines().filter(|line| !line.trim().is_empty());
+    for (idx, line) in lines.enumerate() {
+        let entry = format!("Skill {}", idx + 1);
+        let Some(fields) = split_fields(line, 3) else {
+            errors.push(format!(
+                "{entry} must be 'name | level | keyword, keyword'."
+            ));
+            continue;
+        };
+        if fields[0].is_empty() {
+            errors.push(format!("{entry} must have a name."));
+        }
+        skills.push(ResumeSkill {
+            name: fields[0].clone(),
+            level: fields[1].clone(),
+            keywords: fields[2]
+                .split(',')
+                .map(|val| val.trim().to_string())
+                .filter(|val| !val.is_empty())
+                .collect(),
+        });
+    }
+    skills
+}
+
+/// ResumeCommandStatus
+/// Status of Resume Command Operations:
+/// - Stored
+/// - CacheInserted
+/// - CacheInvalidated
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ResumeCommandStatus {
+    Stored,
+    CacheInserted,
+    CacheInvalidated,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample_form() -> ResumeForm {
+        ResumeForm {
+            name: "Husni Zuhdi".to_string(),
+            label: "Site Reliability Engineer".to_string(),
+            email: "husni@example.com".to_string(),
+            url: "https://husni.dev".to_string(),
+            summary: "SRE".to_string(),
+            work: "2022-04 | | SRE | Accelbyte | https://accelbyte.io\n- Operate infra".to_string(),
+            education: "2016 | 2021 | Gadjah Mada University | Bachelor | Nuclear Engineering"
+                .to_string(),
+            certificates: "2023-01 | CKA | CNCF | ".to_string(),
+            skills: "Kubernetes | Advanced | GKE, EKS".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_resume_form_validate() {
+        let resume = sample_form().validate().unwrap();
+        assert_eq!(resume.basics.name, "Husni Zuhdi");
+        assert_eq!(resume.work[0].end_date, None);
+        assert_eq!(resume.work[0].highlights, vec!["Operate infra".to_string()]);
+        assert_eq!(resume.education[0].end_date, Some("2021".to_string()));
+        assert_eq!(resume.certificates[0].url, None);
+        assert_eq!(
+            resume.skills[0].keywords,
+            vec!["GKE".to_string(), "EKS".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_resume_form_validate_errors() {
+        let form = ResumeForm {
+            name: "".to_string(),
+            email: "husni".to_string(),
+            work: "April 2022 | | SRE | Accelbyte | accelbyte.io".to_string(),
+            education: "2016 | 2021 | UGM".to_string(),
+            certificates: "2023-13-45 | CKA | CNCF | ".to_string(),
+            skills: " | | ".to_string(),
+            ..Default::default()
+        };
+        let errors = form.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                "Resume name is required.".to_string(),
+                "Resume email 'husni' is not valid.".to_string(),
+                "Work 1 start date 'April 2022' is not a valid date.".to_string(),
+                "Work 1 link must start with http:// or https://.".to_string(),
+                "Education 1 must be 'start | end | institution | study type | area'.".to_string(),
+                "Certificate 1 date '2023-13-45' is not a valid date.".to_string(),
+                "Skill 1 must have a name.".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resume_to_form_round_trip() {
+        let resume = sample_form().validate().unwrap();
+        assert_eq!(resume.to_form().validate(), Ok(resume));
+    }
+
+    #[test]
+    fn test_resume_to_json_resume() {
+        let json_resume = sample_form().validate().unwrap().to_json_resume();
+        assert_eq!(json_resume["$schema"], JSON_RESUME_SCHEMA);
+        assert_eq!(json_resume["basics"]["label"], "Site Reliability Engineer");
+        assert_eq!(json_resume["work"][0]["name"], "Accelbyte");
+        assert_eq!(json_resume["work"][0]["startDate"], "2022-04");
+        assert!(json_resume["work"][0].get("endDate").is_none());
+        assert_eq!(json_resume["education"][0]["studyType"], "Bachelor");
+        assert!(json_resume["certificates"][0].get("url").is_none());
+        assert_eq!(json_resume["skills"][0]["keywords"][1], "EKS");
+    }
+}
@@ -29,6 +29,10 @@ impl Tag {
     pub const fn data_size(&self) -> u32 {
         (size_of_val(&self.id) + size_of_val(&self.name)) as u32
     }
+    /// Summarize the Tag for the audit log
+    pub fn audit_summary(&self) -> String {
+        format!("name={}", self.name)
+    }
 }
 
 /// Tags
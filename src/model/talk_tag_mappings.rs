@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+/// Talk Tag Mapping
+/// Corelate a talk id with a tag id
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TalkTagMapping {
+    pub talk_id: i64,
+    pub tag_id: i64,
+}
+
+impl TalkTagMapping {
+    /// Calculate size of TalkTagMapping in u32
+    /// Useful for weighing data size
+    pub const fn data_size(&self) -> u32 {
+        (size_of_val(&self.talk_id) + size_of_val(&self.tag_id)) as u32
+    }
+}
+
+/// Talk Tag Mappings
+/// Vector of talk id and it's tag id
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TalkTagMappings {
+    pub maps: Vec<TalkTagMapping>,
+}
+
+/// TalkTagMappingCommandStatus
+/// Status of Talk Tag Mapping Command Operations:
+/// - Stored
+/// - Deleted
+/// - CacheInserted
+/// - CacheInvalidated
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TalkTagMappingCommandStatus {
+    Stored,
+    Deleted,
+    CacheInserted,
+    CacheInvalidated,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_talk_tag_mapping_data_size() {
+        let mapping = TalkTagMapping {
+            talk_id: 1,
+            tag_id: 2,
+        };
+        assert!(mapping.data_size() > 0);
+    }
+}
@@ -1,24 +1,76 @@
 use crate::model::{
-    templates::{TalkTemplate, TalksTemplate},
+    templates::{TalkTemplate, TalkYearTemplate, TalksTemplate},
     templates_admin::{AdminListTalksTemplate, AdminTalkTemplate},
 };
+use crate::utils::{convert_tags_string_to_vec, is_http_link, is_tags_matched, remove_whitespace};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::debug;
 
+/// Date format used to store and render Talk dates
+pub const TALK_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Date formats accepted when parsing free-form Talk dates.
+/// The first one is the canonical `TALK_DATE_FORMAT`, the rest are
+/// formats found in talks created before dates were validated.
+const TALK_DATE_LEGACY_FORMATS: [&str; 5] = [
+    TALK_DATE_FORMAT,
+    "%Y/%m/%d",
+    "%B %d, %Y",
+    "%d %B %Y",
+    "%b %d, %Y",
+];
+
+/// Numeric day-first date formats found in legacy talks.
+/// They could also be month-first, so they are only accepted when the day
+/// cannot be a month.
+const TALK_DATE_DAY_FIRST_FORMATS: [&str; 2] = ["%d-%m-%Y", "%d/%m/%Y"];
+
+/// iCalendar product identifier of the talks calendar feed
+const TALK_CALENDAR_PRODID: &str = "-//Husni Naufal Zuhdi//Talks//EN";
+/// iCalendar content lines should not be longer than 75 octets
+const ICAL_LINE_LIMIT: usize = 75;
+
+/// Parse a Talk date String into `NaiveDate`
+/// Try `TALK_DATE_FORMAT` first then fallback to the legacy formats.
+/// Return None if the date cannot be parsed or it is ambiguous,
+/// e.g. `03/04/2024` could be 3 April or March 4.
+pub fn parse_talk_date(date: &str) -> Option<NaiveDate> {
+    let trimmed = date.trim();
+    TALK_DATE_LEGACY_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(trimmed, fmt).ok())
+        .or_else(|| {
+            TALK_DATE_DAY_FIRST_FORMATS
+                .iter()
+                .find_map(|fmt| NaiveDate::parse_from_str(trimmed, fmt).ok())
+                .filter(|val| val.day() > 12 || val.day() == val.month())
+        })
+}
+
 /// Talk
 /// Talk data with fields:
 /// - id: Talk Identifier
 /// - name: Talk Name
+/// - date: Talk Date
+/// - invalid_date: (Optional) Stored date that cannot be parsed. `date` is
+///   the default date while it is set, until the date is fixed in the admin.
 /// - media_link: (Optional) Talk media (video/record) link
-/// - org_link: (Optional) Talk organisation link
+/// - org_id: (Optional) Talk organisation identifier
+/// - org_name: (Optional) Talk organisation name, resolved from `org_id`
+/// - org_link: (Optional) Talk organisation link, resolved from `org_id`
+/// - tags: Talk tag names, resolved from `talk_tag_mapping`
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Talk {
     pub id: i64,
     pub name: String,
-    pub date: String,
+    pub date: NaiveDate,
+    pub invalid_date: Option<String>,
     pub media_link: Option<String>,
+    pub org_id: Option<i64>,
     pub org_name: Option<String>,
     pub org_link: Option<String>,
+    pub tags: Vec<String>,
 }
 
 impl Talk {
@@ -28,10 +80,11 @@ impl Talk {
         TalkTemplate {
             id: self.id,
             name: self.name.clone(),
-            date: self.date.clone(),
+            date: self.formatted_date(),
             media_link: self.media_link.clone().unwrap(),
             org_name: self.org_name.clone().unwrap(),
             org_link: self.org_link.clone().unwrap(),
+            tags: self.tags.clone(),
         }
     }
     /// Convert Talk to (Askama) AdminTalkTemplate
@@ -43,21 +96,94 @@ impl Talk {
         AdminTalkTemplate {
             id: self.id,
             name: self.name.clone(),
-            date: self.date.clone(),
+            date: self.formatted_date(),
+            invalid_date: self.invalid_date.is_some(),
             media_link: self.media_link.clone().unwrap(),
+            org_id: self.org_id.unwrap_or_default(),
             org_name: self.org_name.clone().unwrap(),
             org_link: self.org_link.clone().unwrap(),
+            tags: self.tags.clone(),
         }
     }
+    /// Get the non-empty media and organisation links of the Talk
+    pub fn links(&self) -> Vec<String> {
+        [&self.media_link, &self.org_link]
+            .into_iter()
+            .flatten()
+            .filter(|link| !link.trim().is_empty())
+            .cloned()
+            .collect()
+    }
+    /// Summarize the Talk for the audit log
+    pub fn audit_summary(&self) -> String {
+        format!(
+            "name={}; date={}; media_link={}; org_id={}; tags={}",
+            self.name,
+            self.formatted_date(),
+            self.media_link.as_deref().unwrap_or_default(),
+            self.org_id.map(|val| val.to_string()).unwrap_or_default(),
+            self.tags.join(",")
+        )
+    }
+    /// Format Talk date with `TALK_DATE_FORMAT`
+    /// Return the stored date as is if it cannot be parsed.
+    pub fn formatted_date(&self) -> String {
+        match &self.invalid_date {
+            Some(raw_date) => raw_date.clone(),
+            None => self.date.format(TALK_DATE_FORMAT).to_string(),
+        }
+    }
+    /// Check if the stored Talk date could be parsed
+    pub fn is_date_valid(&self) -> bool {
+        self.invalid_date.is_none()
+    }
+    /// Check if Talk happen today or later
+    pub fn is_upcoming(&self, today: NaiveDate) -> bool {
+        self.date >= today
+    }
+    /// Convert Talk to iCalendar VEVENT content lines
+    /// `dtstamp` is the time the calendar is generated.
+    pub fn to_ical_event(&self, dtstamp: DateTime<Utc>) -> Vec<String> {
+        let non_empty = |val: &Option<String>| val.clone().filter(|val| !val.is_empty());
+        let org_name = non_empty(&self.org_name);
+        let org_link = non_empty(&self.org_link);
+        let link = non_empty(&self.media_link).or_else(|| org_link.clone());
+        let end_date = self.date.succ_opt().unwrap_or(self.date);
+
+        let mut lines = vec![
+            "BEGIN:VEVENT".to_string(),
+            format!("UID:talk-{}@husni-portfolio", self.id),
+            format!("DTSTAMP:{}", dtstamp.format("%Y%m%dT%H%M%SZ")),
+            format!("DTSTART;VALUE=DATE:{}", self.date.format("%Y%m%d")),
+            format!("DTEND;VALUE=DATE:{}", end_date.format("%Y%m%d")),
+            format!("SUMMARY:{}", escape_ical_text(&self.name)),
+        ];
+        if let Some(org_name) = &org_name {
+            lines.push(format!("LOCATION:{}", escape_ical_text(org_name)));
+            let description = match &org_link {
+                Some(org_link) => format!("Talk at {org_name} ({org_link})"),
+                None => format!("Talk at {org_name}"),
+            };
+            lines.push(format!("DESCRIPTION:{}", escape_ical_text(&description)));
+        }
+        if let Some(link) = link {
+            lines.push(format!("URL:{link}"));
+        }
+        lines.push("END:VEVENT".to_string());
+        lines
+    }
     /// Calculate size of Talks in u32
     /// Useful for weighing data size
     pub const fn data_size(&self) -> u32 {
         (size_of_val(&self.id)
             + size_of_val(&self.name)
             + size_of_val(&self.date)
+            + size_of_val(&self.invalid_date)
+            + size_of_val(&self.org_id)
             + size_of_val(&self.org_name)
             + size_of_val(&self.org_link)
-            + size_of_val(&self.media_link)) as u32
+            + size_of_val(&self.media_link)
+            + size_of_val(&self.tags)) as u32
     }
     /// Sanitize media and org part of Talk by set default empty value if None
     pub fn sanitize_talk_media_org(&self) -> Self {
@@ -79,11 +205,106 @@ impl Talk {
         Self {
             id: self.id,
             name: self.name.clone(),
-            date: self.date.clone(),
+            date: self.date,
+            invalid_date: self.invalid_date.clone(),
             media_link,
+            org_id: self.org_id,
             org_name,
             org_link,
+            tags: self.tags.clone(),
+        }
+    }
+}
+
+/// TalkForm
+/// Raw Talk fields submitted from the admin add/edit forms.
+/// Use `validate` to turn it into a `Talk`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TalkForm {
+    pub id: i64,
+    pub name: String,
+    pub date: String,
+    pub media_link: String,
+    pub org_id: String,
+    pub tags: Vec<String>,
+}
+
+impl TalkForm {
+    /// Validate TalkForm and convert it into a `Talk`
+    /// Return all validation error messages if any field is invalid:
+    /// - name must not be empty
+    /// - date must follow `TALK_DATE_FORMAT`
+    /// - media link must be an http(s) link if set
+    /// - organisation must be empty or a valid organisation id
+    pub fn validate(&self) -> Result<Talk, Vec<String>> {
+        let mut errors = Vec::new();
+
+        let name = self.name.trim();
+        if name.is_empty() {
+            errors.push("Talk name is required.".to_string());
+        }
+
+        let date = NaiveDate::parse_from_str(self.date.trim(), TALK_DATE_FORMAT);
+        if date.is_err() {
+            errors.push(format!(
+                "Talk date '{}' is not a valid date. Use YYYY-MM-DD.",
+                self.date.trim()
+            ));
+        }
+
+        let media_link = self.media_link.trim();
+        if !is_http_link(media_link) {
+            errors.push("Media link must start with http:// or https://.".to_string());
+        }
+
+        let org_id = self.parse_org_id();
+        if org_id.is_err() {
+            errors.push(format!(
+                "Organization '{}' is not valid.",
+                self.org_id.trim()
+            ));
+        }
+
+        match date {
+            Ok(date) if errors.is_empty() => Ok(Talk {
+                id: self.id,
+                name: name.to_string(),
+                date,
+                invalid_date: None,
+                media_link: Some(media_link.to_string()),
+                org_id: org_id.unwrap_or_default(),
+                org_name: None,
+                org_link: None,
+                tags: self.tags.clone(),
+            }),
+            _ => {
+                debug!("TalkForm: Talk Id {} is invalid. {:?}", self.id, errors);
+                Err(errors)
+            }
+        }
+    }
+    /// Convert TalkForm to (Askama) AdminTalkTemplate
+    /// Used to re-render the admin forms with the submitted values
+    pub fn to_admin_template(&self) -> AdminTalkTemplate {
+        AdminTalkTemplate {
+            id: self.id,
+            name: self.name.clone(),
+            date: self.date.clone(),
+            invalid_date: false,
+            media_link: self.media_link.clone(),
+            org_id: self.parse_org_id().ok().flatten().unwrap_or_default(),
+            org_name: String::new(),
+            org_link: String::new(),
+            tags: self.tags.clone(),
+        }
+    }
+    /// Parse submitted organisation id. Empty value means no organisation.
+    fn parse_org_id(&self) -> Result<Option<i64>, std::num::ParseIntError> {
+        let org_id = self.org_id.trim();
+        if org_id.is_empty() {
+            return Ok(None);
         }
+        org_id.parse::<i64>().map(Some)
     }
 }
 
@@ -104,11 +325,78 @@ impl Talks {
         }
     }
     /// Convert Talks to (Askama) TalksTemplate
-    pub fn to_template(&self) -> TalksTemplate {
+    /// Talks happen on `today` or later are listed as upcoming (soonest first).
+    /// The rest are listed as past talks grouped by year (latest first).
+    /// Talks with an invalid date are left out until it is fixed.
+    /// `params` is used to mark the active year, organisation, and tags filters.
+    pub fn to_template(&self, today: NaiveDate, params: &TalksParams) -> TalksTemplate {
+        let mut upcoming: Vec<&Talk> = self
+            .talks
+            .iter()
+            .filter(|talk| talk.is_date_valid() && talk.is_upcoming(today))
+            .collect();
+        upcoming.sort_by_key(|talk| talk.date);
+
+        let mut past: Vec<&Talk> = self
+            .talks
+            .iter()
+            .filter(|talk| talk.is_date_valid() && !talk.is_upcoming(today))
+            .collect();
+        past.sort_by_key(|talk| std::cmp::Reverse(talk.date));
+
+        let mut past_years: Vec<TalkYearTemplate> = Vec::new();
+        for talk in past {
+            let year = talk.date.year();
+            match past_years.last_mut() {
+                Some(group) if group.year == year => group.talks.push(talk.to_template()),
+                _ => past_years.push(TalkYearTemplate {
+                    year,
+                    talks: vec![talk.to_template()],
+                }),
+            }
+        }
+
         TalksTemplate {
-            talks: self.talks.iter().map(|talk| talk.to_template()).collect(),
+            upcoming: upcoming.iter().map(|talk| talk.to_template()).collect(),
+            past: past_years,
+            active_year: params.year,
+            active_org: params.org.clone().unwrap_or_default(),
+            active_tags: params
+                .tags
+                .as_deref()
+                .map(convert_tags_string_to_vec)
+                .unwrap_or_default(),
         }
     }
+    /// Convert Talks to an iCalendar (RFC 5545) feed
+    /// Each Talk is an all-day VEVENT ordered by date. Talks with an invalid
+    /// date are left out. `dtstamp` is the time the calendar is generated.
+    pub fn to_ical(&self, dtstamp: DateTime<Utc>) -> String {
+        let mut talks: Vec<&Talk> = self
+            .talks
+            .iter()
+            .filter(|talk| talk.is_date_valid())
+            .collect();
+        talks.sort_by_key(|talk| (talk.date, talk.id));
+
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            format!("PRODID:{TALK_CALENDAR_PRODID}"),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+            "X-WR-CALNAME:Husni Naufal Zuhdi Talks".to_string(),
+        ];
+        for talk in talks {
+            lines.extend(talk.to_ical_event(dtstamp));
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        lines
+            .iter()
+            .map(|line| fold_ical_line(line) + "\r\n")
+            .collect()
+    }
     /// Convert Talks to (Askama) AdminListTalksTemplate
     pub fn to_admin_list_template(&self) -> AdminListTalksTemplate {
         AdminListTalksTemplate {
@@ -121,17 +409,60 @@ impl Talks {
     }
 }
 
+/// Escape iCalendar TEXT value
+/// Backslash, semicolon, comma, and newline have to be escaped.
+fn escape_ical_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Fold iCalendar content line longer than `ICAL_LINE_LIMIT` octets
+/// Continuation lines start with a single space.
+fn fold_ical_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut line_len = 0;
+    for ch in line.chars() {
+        if line_len + ch.len_utf8() > ICAL_LINE_LIMIT {
+            folded.push_str("\r\n ");
+            line_len = 1;
+        }
+        folded.push(ch);
+        line_len += ch.len_utf8();
+    }
+    folded
+}
+
 /// TalksParams
-/// Axum parameters query for pagination
+/// Axum parameters query for pagination and filters
+/// - start and end: pagination
+/// - year: (Optional) only return talks in this year
+/// - org: (Optional) only return talks from this organisation name
+/// - tags: (Optional) only return talks with any of these comma separated tag names
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct TalksParams {
     pub start: Option<i64>,
     pub end: Option<i64>,
+    pub year: Option<i32>,
+    pub org: Option<String>,
+    pub tags: Option<String>,
 }
 
 impl TalksParams {
+    /// TalksParams of every Talk without pagination or filters
+    pub fn all() -> Self {
+        Self {
+            start: Some(0),
+            end: Some(i64::MAX),
+            year: None,
+            org: None,
+            tags: None,
+        }
+    }
     /// Sanitize TalksParams by checking negative value and set unknown to
-    /// the default values
+    /// the default values. Blank organisation and tags filters are dropped.
     pub fn sanitize(&self) -> Self {
         let start = match self.start {
             Some(val) if val >= 0 => val,
@@ -147,12 +478,46 @@ impl TalksParams {
                 100_i64
             }
         };
+        let year = match self.year {
+            Some(val) if val > 0 => Some(val),
+            Some(val) => {
+                debug!("TalkParams: ignore invalid year {val}");
+                None
+            }
+            None => None,
+        };
+        let org = self
+            .org
+            .as_ref()
+            .map(|val| val.trim().to_string())
+            .filter(|val| !val.is_empty());
+        let tags = self
+            .tags
+            .as_ref()
+            .map(|val| remove_whitespace(val))
+            .filter(|val| !val.is_empty());
 
         Self {
             start: Some(start),
             end: Some(end),
+            year,
+            org,
+            tags,
         }
     }
+    /// Check if a Talk pass the year, organisation, and tags filters
+    pub fn is_matched(&self, talk: &Talk) -> bool {
+        let year_matched = self.year.is_none_or(|year| talk.date.year() == year);
+        let org_matched = self
+            .org
+            .as_ref()
+            .is_none_or(|org| talk.org_name.as_ref() == Some(org));
+        let tags_matched = self
+            .tags
+            .as_ref()
+            .is_none_or(|tags| is_tags_matched(tags, &talk.tags));
+        year_matched && org_matched && tags_matched
+    }
 }
 
 /// TalkCommandStatus
@@ -182,10 +547,13 @@ mod test {
         Talk {
             id: 1,
             name: "Talk 1".to_string(),
-            date: "2024-01-01".to_string(),
+            date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            invalid_date: None,
             media_link: Some("https://example.com/media".to_string()),
+            org_id: Some(1),
             org_name: Some("Org".to_string()),
             org_link: Some("https://example.com/org".to_string()),
+            tags: vec!["rust".to_string()],
         }
     }
 
@@ -193,10 +561,13 @@ mod test {
         Talk {
             id: 2,
             name: "Talk 2".to_string(),
-            date: "2024-02-02".to_string(),
+            date: NaiveDate::from_ymd_opt(2024, 2, 2).unwrap(),
+            invalid_date: None,
             media_link: None,
+            org_id: None,
             org_name: None,
             org_link: None,
+            tags: Vec::new(),
         }
     }
 
@@ -211,18 +582,67 @@ mod test {
         assert_eq!(template.org_link, "https://example.com/org");
     }
 
+    #[test]
+    fn test_talk_links() {
+        assert_eq!(
+            sample_talk().links(),
+            vec![
+                "https://example.com/media".to_string(),
+                "https://example.com/org".to_string()
+            ]
+        );
+        assert!(talk_with_empty_media_org().links().is_empty());
+        assert!(talk_with_empty_media_org()
+            .sanitize_talk_media_org()
+            .links()
+            .is_empty());
+    }
+
     #[test]
     fn test_talk_to_admin_template() {
         let talk = sample_talk();
         let template = talk.to_admin_template();
         assert_eq!(template.id, talk.id);
         assert_eq!(template.name, talk.name);
-        assert_eq!(template.date, talk.date);
+        assert_eq!(template.date, "2024-01-01");
         assert_eq!(template.media_link, talk.media_link.unwrap());
         assert_eq!(template.org_name, talk.org_name.unwrap());
         assert_eq!(template.org_link, talk.org_link.unwrap());
     }
 
+    #[test]
+    fn test_talk_with_invalid_date() {
+        let talk = Talk {
+            date: NaiveDate::default(),
+            invalid_date: Some("sometime in 2022".to_string()),
+            ..sample_talk()
+        };
+        assert!(!talk.is_date_valid());
+        let template = talk.to_admin_template();
+        assert_eq!(template.date, "sometime in 2022");
+        assert!(template.invalid_date);
+
+        // Left out of the public talks and calendar
+        let talks = Talks {
+            talks: vec![talk, talk_with_empty_media_org()],
+        }
+        .sanitize();
+        let today = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let params = TalksParams {
+            start: None,
+            end: None,
+            year: None,
+            org: None,
+            tags: None,
+        };
+        let template = talks.to_template(today, &params);
+        assert_eq!(template.upcoming.len(), 1);
+        assert!(template.past.is_empty());
+        let calendar = talks.to_ical(sample_dtstamp());
+        assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 1);
+        assert_eq!(talks.to_admin_list_template().talks.len(), 2);
+    }
+
     #[test]
     fn test_talk_sanitize_talk_media_org_fills_empty_values() {
         let sanitized = talk_with_empty_media_org().sanitize_talk_media_org();
@@ -231,7 +651,7 @@ mod test {
         assert_eq!(sanitized.org_link, Some(String::new()));
         assert_eq!(sanitized.id, 2);
         assert_eq!(sanitized.name, "Talk 2");
-        assert_eq!(sanitized.date, "2024-02-02");
+        assert_eq!(sanitized.date, talk_with_empty_media_org().date);
     }
 
     #[test]
@@ -264,15 +684,129 @@ mod test {
         assert_eq!(sanitized.talks[1].org_link, Some(String::new()));
     }
 
+    fn talk_on(id: i64, year: i32, month: u32, day: u32) -> Talk {
+        Talk {
+            id,
+            name: format!("Talk {id}"),
+            date: NaiveDate::from_ymd_opt(year, month, day).unwrap(),
+            invalid_date: None,
+            media_link: None,
+            org_id: Some(id),
+            org_name: Some(format!("Org {id}")),
+            org_link: None,
+            tags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_talk_date_canonical_format() {
+        assert_eq!(
+            parse_talk_date("2022-07-02"),
+            NaiveDate::from_ymd_opt(2022, 7, 2)
+        );
+    }
+
+    #[test]
+    fn test_parse_talk_date_legacy_formats() {
+        let expected = NaiveDate::from_ymd_opt(2022, 7, 2);
+        assert_eq!(parse_talk_date("2022/07/02"), expected);
+        assert_eq!(parse_talk_date("July 02, 2022"), expected);
+        assert_eq!(parse_talk_date("2 July 2022"), expected);
+        assert_eq!(parse_talk_date("Jul 2, 2022"), expected);
+        assert_eq!(parse_talk_date("  2022-07-02 "), expected);
+
+        let expected = NaiveDate::from_ymd_opt(2022, 7, 25);
+        assert_eq!(parse_talk_date("25-07-2022"), expected);
+        assert_eq!(parse_talk_date("25/07/2022"), expected);
+        // Same date whether day-first or month-first
+        assert_eq!(
+            parse_talk_date("07/07/2022"),
+            NaiveDate::from_ymd_opt(2022, 7, 7)
+        );
+    }
+
+    #[test]
+    fn test_parse_talk_date_ambiguous() {
+        // 3 April or March 4
+        assert_eq!(parse_talk_date("03/04/2024"), None);
+        assert_eq!(parse_talk_date("03-04-2024"), None);
+        assert_eq!(parse_talk_date("12/01/2024"), None);
+    }
+
+    #[test]
+    fn test_parse_talk_date_unparsable() {
+        assert_eq!(parse_talk_date(""), None);
+        assert_eq!(parse_talk_date("sometime in 2022"), None);
+        assert_eq!(parse_talk_date("2022-13-45"), None);
+    }
+
+    #[test]
+    fn test_talk_is_upcoming() {
+        let today = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert!(sample_talk().is_upcoming(today));
+        assert!(talk_with_empty_media_org().is_upcoming(today));
+        assert!(!talk_on(3, 2023, 12, 31).is_upcoming(today));
+    }
+
     #[test]
     fn test_talks_to_template() {
+        let params = TalksParams {
+            start: None,
+            end: None,
+            year: Some(2024),
+            org: Some("Org".to_string()),
+            tags: None,
+        };
         let template = Talks {
             talks: vec![sample_talk()],
         }
-        .to_template();
-        assert_eq!(template.talks.len(), 1);
-        assert_eq!(template.talks[0].id, 1);
-        assert_eq!(template.talks[0].name, "Talk 1");
+        .to_template(NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(), &params);
+        assert_eq!(template.upcoming.len(), 1);
+        assert_eq!(template.upcoming[0].id, 1);
+        assert_eq!(template.upcoming[0].name, "Talk 1");
+        assert!(template.past.is_empty());
+        assert_eq!(template.active_year, Some(2024));
+        assert_eq!(template.active_org, "Org");
+    }
+
+    #[test]
+    fn test_talks_to_template_splits_upcoming_and_past_by_year() {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let talks = Talks {
+            talks: vec![
+                talk_on(1, 2022, 3, 1),
+                talk_on(2, 2023, 5, 1),
+                talk_on(3, 2024, 12, 1),
+                talk_on(4, 2023, 11, 1),
+                talk_on(5, 2024, 6, 1),
+                talk_on(6, 2024, 2, 1),
+            ],
+        }
+        .sanitize();
+        let template = talks.to_template(
+            today,
+            &TalksParams {
+                start: None,
+                end: None,
+                year: None,
+                org: None,
+                tags: None,
+            },
+        );
+
+        let upcoming_ids: Vec<i64> = template.upcoming.iter().map(|t| t.id).collect();
+        assert_eq!(upcoming_ids, vec![5, 3]);
+
+        let years: Vec<i32> = template.past.iter().map(|group| group.year).collect();
+        assert_eq!(years, vec![2024, 2023, 2022]);
+        let past_ids: Vec<Vec<i64>> = template
+            .past
+            .iter()
+            .map(|group| group.talks.iter().map(|t| t.id).collect())
+            .collect();
+        assert_eq!(past_ids, vec![vec![6], vec![4, 2], vec![1]]);
+        assert_eq!(template.active_year, None);
+        assert_eq!(template.active_org, "");
     }
 
     #[test]
@@ -290,27 +824,47 @@ mod test {
         let params = TalksParams {
             start: None,
             end: None,
+            year: None,
+            org: None,
+            tags: None,
         };
         assert_eq!(
             params.sanitize(),
             TalksParams {
                 start: Some(0),
-                end: Some(100)
+                end: Some(100),
+                year: None,
+                org: None,
+                tags: None,
             }
         );
     }
 
+    #[test]
+    fn test_talks_params_all() {
+        let sanitized = TalksParams::all().sanitize();
+        assert_eq!(sanitized.start, Some(0));
+        assert_eq!(sanitized.end, Some(i64::MAX));
+        assert!(sanitized.is_matched(&sample_talk()));
+    }
+
     #[test]
     fn test_talks_params_sanitize_negative_values() {
         let params = TalksParams {
             start: Some(-5),
             end: Some(-1),
+            year: None,
+            org: None,
+            tags: None,
         };
         assert_eq!(
             params.sanitize(),
             TalksParams {
                 start: Some(0),
-                end: Some(100)
+                end: Some(100),
+                year: None,
+                org: None,
+                tags: None,
             }
         );
     }
@@ -320,12 +874,18 @@ mod test {
         let params = TalksParams {
             start: Some(5),
             end: Some(20),
+            year: None,
+            org: None,
+            tags: None,
         };
         assert_eq!(
             params.sanitize(),
             TalksParams {
                 start: Some(5),
-                end: Some(20)
+                end: Some(20),
+                year: None,
+                org: None,
+                tags: None,
             }
         );
     }
@@ -335,13 +895,211 @@ mod test {
         let params = TalksParams {
             start: Some(5),
             end: None,
+            year: None,
+            org: None,
+            tags: None,
         };
         assert_eq!(
             params.sanitize(),
             TalksParams {
                 start: Some(5),
-                end: Some(100)
+                end: Some(100),
+                year: None,
+                org: None,
+                tags: None,
             }
         );
     }
+
+    #[test]
+    fn test_talks_params_sanitize_filters() {
+        let params = TalksParams {
+            start: None,
+            end: None,
+            year: Some(2024),
+            org: Some("  Org  ".to_string()),
+            tags: Some(" rust,   dev ".to_string()),
+        };
+        let sanitized = params.sanitize();
+        assert_eq!(sanitized.year, Some(2024));
+        assert_eq!(sanitized.org, Some("Org".to_string()));
+        assert_eq!(sanitized.tags, Some("rust,dev".to_string()));
+    }
+
+    #[test]
+    fn test_talks_params_sanitize_drops_invalid_filters() {
+        let params = TalksParams {
+            start: None,
+            end: None,
+            year: Some(-2024),
+            org: Some("   ".to_string()),
+            tags: Some("  ".to_string()),
+        };
+        let sanitized = params.sanitize();
+        assert_eq!(sanitized.year, None);
+        assert_eq!(sanitized.org, None);
+        assert_eq!(sanitized.tags, None);
+    }
+
+    #[test]
+    fn test_talks_params_is_matched() {
+        let talk = sample_talk();
+        let mut params = TalksParams {
+            start: None,
+            end: None,
+            year: None,
+            org: None,
+            tags: None,
+        };
+        assert!(params.is_matched(&talk));
+
+        params.year = Some(2024);
+        assert!(params.is_matched(&talk));
+        params.org = Some("Org".to_string());
+        assert!(params.is_matched(&talk));
+
+        params.org = Some("Another Org".to_string());
+        assert!(!params.is_matched(&talk));
+        params.org = None;
+        params.year = Some(2023);
+        assert!(!params.is_matched(&talk));
+        params.year = None;
+        params.tags = Some("go,rust".to_string());
+        assert!(params.is_matched(&talk));
+        params.tags = Some("go".to_string());
+        assert!(!params.is_matched(&talk));
+        assert!(!TalksParams {
+            start: None,
+            end: None,
+            year: None,
+            org: Some("Org".to_string()),
+            tags: None,
+        }
+        .is_matched(&talk_with_empty_media_org()));
+    }
+
+    fn valid_talk_form() -> TalkForm {
+        TalkForm {
+            id: 3,
+            name: " Talk 3 ".to_string(),
+            date: "2024-03-03".to_string(),
+            media_link: "https://media.com".to_string(),
+            org_id: "1".to_string(),
+            tags: vec!["rust".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_talk_form_validate() {
+        let talk = valid_talk_form().validate().unwrap();
+        assert_eq!(talk.id, 3);
+        assert_eq!(talk.name, "Talk 3");
+        assert_eq!(talk.date, NaiveDate::from_ymd_opt(2024, 3, 3).unwrap());
+        assert_eq!(talk.media_link, Some("https://media.com".to_string()));
+        assert_eq!(talk.org_id, Some(1));
+        assert_eq!(talk.org_name, None);
+        assert_eq!(talk.org_link, None);
+        assert_eq!(talk.tags, vec!["rust".to_string()]);
+    }
+
+    #[test]
+    fn test_talk_form_validate_errors() {
+        let form = TalkForm {
+            id: 3,
+            name: "  ".to_string(),
+            date: "03/03/2024".to_string(),
+            media_link: "media.com".to_string(),
+            org_id: "Org".to_string(),
+            tags: Vec::new(),
+        };
+        let errors = form.validate().unwrap_err();
+        assert_eq!(errors.len(), 4);
+        assert_eq!(errors[0], "Talk name is required.");
+        assert!(errors[1].contains("03/03/2024"));
+        assert_eq!(errors[3], "Organization 'Org' is not valid.");
+    }
+
+    #[test]
+    fn test_talk_form_to_admin_template_keeps_raw_values() {
+        let form = TalkForm {
+            date: "not a date".to_string(),
+            ..valid_talk_form()
+        };
+        let template = form.to_admin_template();
+        assert_eq!(template.name, " Talk 3 ");
+        assert_eq!(template.date, "not a date");
+        assert_eq!(template.org_id, 1);
+    }
+
+    fn sample_dtstamp() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-01-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_talk_to_ical_event() {
+        let lines = sample_talk().to_ical_event(sample_dtstamp());
+        assert_eq!(
+            lines,
+            vec![
+                "BEGIN:VEVENT",
+                "UID:talk-1@husni-portfolio",
+                "DTSTAMP:20240101T100000Z",
+                "DTSTART;VALUE=DATE:20240101",
+                "DTEND;VALUE=DATE:20240102",
+                "SUMMARY:Talk 1",
+                "LOCATION:Org",
+                "DESCRIPTION:Talk at Org (https://example.com/org)",
+                "URL:https://example.com/media",
+                "END:VEVENT",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_talk_to_ical_event_without_media_and_org() {
+        let lines = talk_with_empty_media_org()
+            .sanitize_talk_media_org()
+            .to_ical_event(sample_dtstamp());
+        assert!(!lines.iter().any(|line| line.starts_with("LOCATION")));
+        assert!(!lines.iter().any(|line| line.starts_with("DESCRIPTION")));
+        assert!(!lines.iter().any(|line| line.starts_with("URL")));
+    }
+
+    #[test]
+    fn test_talks_to_ical() {
+        let calendar = Talks {
+            talks: vec![talk_with_empty_media_org(), sample_talk()],
+        }
+        .to_ical(sample_dtstamp());
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(calendar.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 2);
+        let first = calendar.find("UID:talk-1@").unwrap();
+        let second = calendar.find("UID:talk-2@").unwrap();
+        assert!(first < second, "events should be ordered by date");
+    }
+
+    #[test]
+    fn test_escape_ical_text() {
+        assert_eq!(
+            escape_ical_text("Rust; Axum, and\nHTMX \\o/"),
+            "Rust\\; Axum\\, and\\nHTMX \\\\o/"
+        );
+    }
+
+    #[test]
+    fn test_fold_ical_line() {
+        let short = "SUMMARY:short";
+        assert_eq!(fold_ical_line(short), short);
+
+        let long = format!("SUMMARY:{}", "a".repeat(100));
+        let folded = fold_ical_line(&long);
+        let lines: Vec<&str> = folded.split("\r\n").collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].len(), ICAL_LINE_LIMIT);
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(folded.replace("\r\n ", ""), long);
+    }
 }
@@ -1,8 +1,28 @@
+use crate::model::profiles::{ProfileExperience, ProfileLink, ProfileSkill};
+use crate::model::resume::{
+    ResumeBasics, ResumeCertificate, ResumeEducation, ResumeSkill, ResumeWork,
+};
 use askama::Template;
 
 #[derive(Template, Debug)]
 #[template(path = "profile.html")]
-pub struct ProfileTemplate;
+pub struct ProfileTemplate {
+    pub headline: String,
+    pub bio: String,
+    pub experiences: Vec<ProfileExperience>,
+    pub skills: Vec<ProfileSkill>,
+    pub links: Vec<ProfileLink>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "resume.html")]
+pub struct ResumeTemplate {
+    pub basics: ResumeBasics,
+    pub work: Vec<ResumeWork>,
+    pub education: Vec<ResumeEducation>,
+    pub certificates: Vec<ResumeCertificate>,
+    pub skills: Vec<ResumeSkill>,
+}
 
 #[derive(Template, Debug)]
 #[template(path = "blogs.html")]
@@ -28,6 +48,18 @@ pub struct BlogTemplate {
     pub tags: Vec<String>,
 }
 
+/// Render only the content block of `blog.html`
+/// Used by the admin blog editor preview
+#[derive(Template, Debug)]
+#[template(path = "blog.html", block = "content")]
+pub struct BlogPreviewTemplate {
+    pub id: i64,
+    pub name: String,
+    pub filename: String,
+    pub body: String,
+    pub tags: Vec<String>,
+}
+
 #[derive(Template, Debug)]
 #[template(path = "version.html")]
 pub struct VersionTemplate {
@@ -40,6 +72,16 @@ pub struct VersionTemplate {
 #[derive(Template, Debug)]
 #[template(path = "talks.html")]
 pub struct TalksTemplate {
+    pub upcoming: Vec<TalkTemplate>,
+    pub past: Vec<TalkYearTemplate>,
+    pub active_year: Option<i32>,
+    pub active_org: String,
+    pub active_tags: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct TalkYearTemplate {
+    pub year: i32,
     pub talks: Vec<TalkTemplate>,
 }
 
@@ -51,15 +93,92 @@ pub struct TalkTemplate {
     pub media_link: String,
     pub org_name: String,
     pub org_link: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "projects.html")]
+pub struct ProjectsTemplate {
+    pub projects: Vec<ProjectMetadataTemplate>,
+    pub statuses: Vec<String>,
+    pub active_status: String,
+    pub active_tags: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct ProjectMetadataTemplate {
+    pub id: i64,
+    pub name: String,
+    pub summary: String,
+    pub repo_url: String,
+    pub status: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "project.html")]
+pub struct ProjectTemplate {
+    pub id: i64,
+    pub name: String,
+    pub summary: String,
+    pub body: String,
+    pub repo_url: String,
+    pub status: String,
+    pub tags: Vec<String>,
 }
 
 #[derive(Template, Debug)]
 #[template(path = "auth/login.html")]
-pub struct LoginTemplate;
+pub struct LoginTemplate {
+    pub oidc_enabled: bool,
+    pub email_link_enabled: bool,
+}
 
 #[derive(Template, Debug)]
 #[template(path = "auth/login_retry.html")]
-pub struct LoginRetryTemplate;
+pub struct LoginRetryTemplate {
+    pub error: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "auth/login_totp.html")]
+pub struct LoginTotpTemplate {
+    pub error: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "auth/login_oidc.html")]
+pub struct LoginOidcTemplate {
+    pub error: String,
+    pub totp: bool,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "auth/login_email_sent.html")]
+pub struct LoginEmailSentTemplate {
+    pub error: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "auth/login_email.html")]
+pub struct LoginEmailTemplate {
+    pub token: String,
+    pub error: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "auth/login_reset.html")]
+pub struct LoginResetTemplate {
+    pub token: String,
+    pub error: String,
+    pub min_length: usize,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "auth/login_reset_result.html")]
+pub struct LoginResetResultTemplate {
+    pub errors: Vec<String>,
+}
 
 #[derive(Template, Debug)]
 #[template(path = "auth/login_success.html")]
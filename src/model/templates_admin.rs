@@ -1,10 +1,56 @@
-use crate::model::{tags::Tag, templates::BlogMetadataTemplate};
+use crate::model::{
+    audit_logs::AuditLog,
+    auth::{LoginActivity, Session, User},
+    dashboard::{CacheEntryCount, RecentUpdate, TagUsage},
+    profiles::{ProfileExperience, ProfileLink, ProfileSkill},
+    tags::Tag,
+    templates::BlogMetadataTemplate,
+};
 use askama::Template;
 
 #[derive(Template, Debug)]
 #[template(path = "admin/admin.html")]
 pub struct AdminTemplate {}
 
+#[derive(Template, Debug)]
+#[template(path = "admin/dashboard/content.html")]
+pub struct AdminDashboardContentTemplate {
+    pub blogs: i64,
+    pub talks: i64,
+    pub tags: i64,
+    pub projects: i64,
+    pub drafts: i64,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/dashboard/tags.html")]
+pub struct AdminDashboardTagsTemplate {
+    pub most_used: Vec<TagUsage>,
+    pub unused: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/dashboard/recent.html")]
+pub struct AdminDashboardRecentTemplate {
+    pub recent_updates: Vec<RecentUpdate>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/dashboard/cache.html")]
+pub struct AdminDashboardCacheTemplate {
+    pub is_enabled: bool,
+    pub hits: u64,
+    pub misses: u64,
+    pub hit_ratio: String,
+    pub entries: Vec<CacheEntryCount>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/dashboard/logins.html")]
+pub struct AdminDashboardLoginsTemplate {
+    pub login_activities: Vec<LoginActivity>,
+}
+
 #[derive(Template, Debug)]
 #[template(path = "admin/talks/talks.html")]
 pub struct AdminTalksTemplate {}
@@ -12,14 +58,19 @@ pub struct AdminTalksTemplate {}
 #[derive(Template, Debug)]
 #[template(path = "admin/talks/get_add_talk.html")]
 pub struct AdminGetAddTalkTemplate {
-    pub id: i64,
-    pub date: String,
+    pub talk: AdminTalkTemplate,
+    pub organizations: Vec<AdminOrganizationTemplate>,
+    pub avail_tags: Vec<String>,
+    pub errors: Vec<String>,
 }
 
 #[derive(Template, Debug)]
 #[template(path = "admin/talks/get_edit_talk.html")]
 pub struct AdminGetEditTalkTemplate {
     pub talk: AdminTalkTemplate,
+    pub organizations: Vec<AdminOrganizationTemplate>,
+    pub avail_tags: Vec<String>,
+    pub errors: Vec<String>,
 }
 
 #[derive(Template, Debug)]
@@ -45,9 +96,361 @@ pub struct AdminTalkTemplate {
     pub id: i64,
     pub name: String,
     pub date: String,
+    pub invalid_date: bool,
     pub media_link: String,
+    pub org_id: i64,
     pub org_name: String,
     pub org_link: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/talks/organizations/organizations.html")]
+pub struct AdminOrganizationsTemplate {}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/talks/organizations/get_add_organization.html")]
+pub struct AdminGetAddOrganizationTemplate {
+    pub organization: AdminOrganizationTemplate,
+    pub errors: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/talks/organizations/get_edit_organization.html")]
+pub struct AdminGetEditOrganizationTemplate {
+    pub organization: AdminOrganizationTemplate,
+    pub errors: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/talks/organizations/get_delete_organization.html")]
+pub struct AdminGetDeleteOrganizationTemplate {
+    pub id: i64,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/talks/organizations/get_organization.html")]
+pub struct AdminGetOrganizationTemplate {
+    pub organization: AdminOrganizationTemplate,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/talks/organizations/list_organizations.html")]
+pub struct AdminListOrganizationsTemplate {
+    pub organizations: Vec<AdminOrganizationTemplate>,
+}
+
+#[derive(Debug)]
+pub struct AdminOrganizationTemplate {
+    pub id: i64,
+    pub name: String,
+    pub url: String,
+    pub logo: String,
+    pub description: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/projects/projects.html")]
+pub struct AdminProjectsTemplate {}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/projects/get_add_project.html")]
+pub struct AdminGetAddProjectTemplate {
+    pub project: AdminProjectTemplate,
+    pub statuses: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/projects/get_edit_project.html")]
+pub struct AdminGetEditProjectTemplate {
+    pub project: AdminProjectTemplate,
+    pub statuses: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/projects/get_delete_project.html")]
+pub struct AdminGetDeleteProjectTemplate {
+    pub id: i64,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/projects/get_project.html")]
+pub struct AdminGetProjectTemplate {
+    pub project: AdminProjectTemplate,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/projects/list_projects.html")]
+pub struct AdminListProjectsTemplate {
+    pub projects: Vec<AdminProjectTemplate>,
+}
+
+#[derive(Debug)]
+pub struct AdminProjectTemplate {
+    pub id: i64,
+    pub name: String,
+    pub summary: String,
+    pub body: String,
+    pub repo_url: String,
+    pub status: String,
+    pub tags: String,
+    pub position: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/profile/profile.html")]
+pub struct AdminProfileTemplate {}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/profile/get_edit_profile.html")]
+pub struct AdminGetEditProfileTemplate {
+    pub profile: AdminProfileFormTemplate,
+    pub notice: String,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct AdminProfileFormTemplate {
+    pub headline: String,
+    pub bio: String,
+    pub experiences: String,
+    pub skills: String,
+    pub links: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/profile/get_profile.html")]
+pub struct AdminGetProfileTemplate {
+    pub version: String,
+    pub headline: String,
+    pub bio: String,
+    pub experiences: Vec<ProfileExperience>,
+    pub skills: Vec<ProfileSkill>,
+    pub links: Vec<ProfileLink>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/profile/list_profiles.html")]
+pub struct AdminListProfilesTemplate {
+    pub profiles: Vec<AdminProfileVersionTemplate>,
+}
+
+#[derive(Debug)]
+pub struct AdminProfileVersionTemplate {
+    pub version: String,
+    pub headline: String,
+    pub is_latest: bool,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/resume/resume.html")]
+pub struct AdminResumeTemplate {}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/resume/get_edit_resume.html")]
+pub struct AdminGetEditResumeTemplate {
+    pub resume: AdminResumeFormTemplate,
+    pub notice: String,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct AdminResumeFormTemplate {
+    pub name: String,
+    pub label: String,
+    pub email: String,
+    pub url: String,
+    pub summary: String,
+    pub work: String,
+    pub education: String,
+    pub certificates: String,
+    pub skills: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/media/media.html")]
+pub struct AdminMediaTemplate {
+    pub max_size: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/media/list_media.html")]
+pub struct AdminListMediaTemplate {
+    pub media_files: Vec<AdminMediaFileTemplate>,
+    pub notice: String,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct AdminMediaFileTemplate {
+    pub key: String,
+    pub filename: String,
+    pub content_type: String,
+    pub size: String,
+    pub created_at: String,
+    pub url: String,
+    pub is_image: bool,
+    pub markdown_snippet: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/media/get_delete_media.html")]
+pub struct AdminGetDeleteMediaTemplate {
+    pub key: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/links/links.html")]
+pub struct AdminLinkChecksTemplate {
+    pub interval: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/links/list_links.html")]
+pub struct AdminListLinkChecksTemplate {
+    pub checked_count: usize,
+    pub broken_count: usize,
+    pub last_checked_at: String,
+    pub sources: Vec<AdminBrokenLinkSourceTemplate>,
+    pub notice: String,
+}
+
+#[derive(Debug)]
+pub struct AdminBrokenLinkSourceTemplate {
+    pub source_type: String,
+    pub source_id: i64,
+    pub source_name: String,
+    pub links: Vec<AdminBrokenLinkTemplate>,
+}
+
+#[derive(Debug)]
+pub struct AdminBrokenLinkTemplate {
+    pub url: String,
+    pub detail: String,
+    pub checked_at: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/audit/audit.html")]
+pub struct AdminAuditLogsTemplate {
+    pub entity_types: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/audit/list_audit.html")]
+pub struct AdminListAuditLogsTemplate {
+    pub audit_logs: Vec<AuditLog>,
+    pub is_truncated: bool,
+    pub export_query: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/users/users.html")]
+pub struct AdminUsersTemplate {}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/users/list_users.html")]
+pub struct AdminListUsersTemplate {
+    pub users: Vec<User>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/users/get_user.html")]
+pub struct AdminGetUserTemplate {
+    pub id: String,
+    pub email: String,
+    pub role: String,
+    pub totp_enabled: bool,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/users/get_add_user.html")]
+pub struct AdminGetAddUserTemplate {
+    pub email: String,
+    pub role: String,
+    pub roles: Vec<String>,
+    pub password_min_length: usize,
+    pub errors: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/users/get_edit_user.html")]
+pub struct AdminGetEditUserTemplate {
+    pub id: String,
+    pub email: String,
+    pub role: String,
+    pub roles: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/users/get_delete_user.html")]
+pub struct AdminGetDeleteUserTemplate {
+    pub id: String,
+    pub email: String,
+    pub error: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/users/get_reset_user_totp.html")]
+pub struct AdminGetResetUserTotpTemplate {
+    pub id: String,
+    pub email: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/account/account.html")]
+pub struct AdminAccountTemplate {
+    pub email: String,
+    pub role: String,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/account/get_account_password.html")]
+pub struct AdminGetAccountPasswordTemplate {
+    pub password_min_length: usize,
+    pub notice: String,
+    pub errors: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/account/get_account_totp.html")]
+pub struct AdminGetAccountTotpTemplate {
+    pub enabled: bool,
+    pub recovery_codes_left: usize,
+    pub notice: String,
+    pub errors: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/account/get_account_sessions.html")]
+pub struct AdminGetAccountSessionsTemplate {
+    pub sessions: Vec<Session>,
+    pub current_session_id: String,
+    pub notice: String,
+    pub errors: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/account/get_account_logins.html")]
+pub struct AdminGetAccountLoginsTemplate {
+    pub login_activities: Vec<LoginActivity>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/account/get_account_totp_setup.html")]
+pub struct AdminGetAccountTotpSetupTemplate {
+    pub qr_svg: String,
+    pub secret: String,
+    pub errors: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/account/get_account_totp_recovery_codes.html")]
+pub struct AdminGetAccountTotpRecoveryCodesTemplate {
+    pub recovery_codes: Vec<String>,
 }
 
 #[derive(Template, Debug)]
@@ -65,7 +468,12 @@ pub struct AdminListBlogsTemplate {
 #[template(path = "admin/blogs/get_add_blog.html")]
 pub struct AdminGetAddBlogTemplate {
     pub id: i64,
+    pub name: String,
+    pub body: String,
+    pub blog_tags: Vec<String>,
     pub avail_tags: Vec<String>,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
 }
 
 #[derive(Template, Debug)]
@@ -76,6 +484,16 @@ pub struct AdminGetEditBlogTemplate {
     pub body: String,
     pub blog_tags: Vec<String>,
     pub avail_tags: Vec<String>,
+    pub draft_updated_at: Option<String>,
+    pub notice: String,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+#[derive(Template, Debug)]
+#[template(path = "admin/blogs/blog_draft_status.html")]
+pub struct AdminBlogDraftStatusTemplate {
+    pub updated_at: Option<String>,
 }
 
 #[derive(Template, Debug)]
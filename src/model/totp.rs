@@ -0,0 +1,259 @@
+use ring::digest::{digest, SHA256};
+use ring::hmac::{sign, Key, HMAC_SHA1_FOR_LEGACY_USE_ONLY};
+use serde::{Deserialize, Serialize};
+use urlencoding::encode;
+
+/// Issuer shown by authenticator apps
+pub const TOTP_ISSUER: &str = "Husni Portfolio";
+/// Number of digits of a TOTP code
+pub const TOTP_DIGITS: u32 = 6;
+/// Seconds a TOTP code is valid for
+pub const TOTP_PERIOD: i64 = 30;
+/// Number of bytes of a generated TOTP secret (160 bits as advised by RFC 4226)
+pub const TOTP_SECRET_LENGTH: usize = 20;
+/// Number of recovery codes generated on enrollment
+pub const RECOVERY_CODES_COUNT: usize = 10;
+/// Seconds a password-verified login can wait for its TOTP code
+pub const TOTP_CHALLENGE_TTL: usize = 300;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// UserTotp
+/// TOTP two-factor authentication of a User with fields:
+/// - user_id: User Identifier
+/// - encrypted_secret: TOTP secret encrypted with the TOTP encryption key
+/// - enabled: False until the enrollment is confirmed with a code
+/// - last_step: Time step of the last accepted code. Older codes are rejected as replays
+/// - recovery_codes: SHA-256 hashes of the unused recovery codes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserTotp {
+    pub user_id: String,
+    pub encrypted_secret: String,
+    pub enabled: bool,
+    pub last_step: Option<i64>,
+    pub recovery_codes: Vec<String>,
+}
+
+impl UserTotp {
+    /// Consume a recovery code
+    /// Return false if the code is unknown or was already used.
+    pub fn use_recovery_code(&mut self, code: &str) -> bool {
+        let hashed_code = hash_recovery_code(code);
+        let count = self.recovery_codes.len();
+        self.recovery_codes.retain(|val| *val != hashed_code);
+        self.recovery_codes.len() < count
+    }
+}
+
+/// TotpChallengeClaims
+/// Claims of the short-lived token issued once the password of a User with
+/// TOTP enabled is verified. The JWT is only issued after the TOTP code.
+/// - sub: Id of the User
+/// - remember: "remember me" was checked on the password step
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TotpChallengeClaims {
+    pub sub: String,
+    #[serde(default)]
+    pub remember: bool,
+    pub exp: usize,
+    pub iat: usize,
+}
+
+/// UserTotpCommandStatus
+/// Status of UserTotp Command Operations:
+/// - Stored
+/// - Deleted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum UserTotpCommandStatus {
+    Stored,
+    Deleted,
+}
+
+/// Encode bytes to unpadded RFC 4648 base32 as expected by authenticator apps
+pub fn base32_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(5) {
+        let mut buffer = [0u8; 5];
+        buffer[..chunk.len()].copy_from_slice(chunk);
+        let bits = buffer
+            .iter()
+            .fold(0u64, |acc, val| (acc << 8) | *val as u64);
+        let chars = (chunk.len() * 8).div_ceil(5);
+        for i in 0..chars {
+            let index = (bits >> (35 - i * 5)) & 0x1f;
+            encoded.push(BASE32_ALPHABET[index as usize] as char);
+        }
+    }
+    encoded
+}
+
+/// Decode RFC 4648 base32, ignoring case, whitespace and padding
+pub fn base32_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut buffer = 0u64;
+    let mut bits = 0;
+    for val in encoded
+        .chars()
+        .filter(|val| !val.is_whitespace() && *val != '=')
+    {
+        let index = BASE32_ALPHABET
+            .iter()
+            .position(|char| *char as char == val.to_ascii_uppercase())?;
+        buffer = (buffer << 5) | index as u64;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+/// Time step of a unix time
+pub fn totp_step(unix_time: i64) -> i64 {
+    unix_time.div_euclid(TOTP_PERIOD)
+}
+
+/// Compute the RFC 6238 TOTP code (HMAC-SHA1) of a time step
+pub fn totp_code(secret: &[u8], step: i64, digits: u32) -> String {
+    let key = Key::new(HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret);
+    let tag = sign(&key, &step.to_be_bytes());
+    let hash = tag.as_ref();
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    format!(
+        "{:0width$}",
+        binary % 10u32.pow(digits),
+        width = digits as usize
+    )
+}
+
+/// Verify a TOTP code allowing one step of clock drift on each side
+/// Codes of steps up to `last_step` were already used and are rejected.
+/// Return the matched step to be stored as the new `last_step`.
+pub fn verify_totp_code(
+    secret: &[u8],
+    code: &str,
+    unix_time: i64,
+    last_step: Option<i64>,
+) -> Option<i64> {
+    let code: String = code.chars().filter(|val| !val.is_whitespace()).collect();
+    if code.len() != TOTP_DIGITS as usize || !code.chars().all(|val| val.is_ascii_digit()) {
+        return None;
+    }
+    let step = totp_step(unix_time);
+    (step - 1..=step + 1)
+        .filter(|val| last_step.is_none_or(|last_step| *val > last_step))
+        .find(|val| totp_code(secret, *val, TOTP_DIGITS) == code)
+}
+
+/// Build the `otpauth://` URI to be scanned by authenticator apps
+pub fn totp_uri(secret: &[u8], email: &str) -> String {
+    format!(
+        "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        encode(TOTP_ISSUER),
+        encode(email),
+        base32_encode(secret),
+        encode(TOTP_ISSUER),
+        TOTP_DIGITS,
+        TOTP_PERIOD
+    )
+}
+
+/// Hash a recovery code to be stored
+/// Codes are compared ignoring case, whitespace and dashes.
+pub fn hash_recovery_code(code: &str) -> String {
+    let normalized: String = code
+        .chars()
+        .filter(|val| !val.is_whitespace() && *val != '-')
+        .collect::<String>()
+        .to_lowercase();
+    digest(&SHA256, normalized.as_bytes())
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_base32() {
+        assert_eq!(base32_encode(b"foobar"), "MZXW6YTBOI");
+        assert_eq!(base32_encode(b"12345678901234567890").len(), 32);
+        assert_eq!(
+            base32_decode("mzxw 6ytb oi======"),
+            Some(b"foobar".to_vec())
+        );
+        assert_eq!(base32_decode("MZXW1"), None);
+
+        let secret = [7u8; TOTP_SECRET_LENGTH];
+        assert_eq!(
+            base32_decode(&base32_encode(&secret)),
+            Some(secret.to_vec())
+        );
+    }
+
+    #[test]
+    fn test_totp_code() {
+        // RFC 6238 Appendix B test vectors of SHA1
+        let secret = b"12345678901234567890";
+        assert_eq!(totp_code(secret, totp_step(59), 8), "94287082");
+        assert_eq!(totp_code(secret, totp_step(1111111109), 8), "07081804");
+        assert_eq!(totp_code(secret, totp_step(1234567890), 8), "89005924");
+        assert_eq!(totp_code(secret, totp_step(2000000000), 8), "69279037");
+    }
+
+    #[test]
+    fn test_verify_totp_code() {
+        let secret = b"12345678901234567890";
+        let now = 1111111109;
+        let step = totp_step(now);
+        let code = totp_code(secret, step, TOTP_DIGITS);
+        let previous_code = totp_code(secret, step - 1, TOTP_DIGITS);
+
+        assert_eq!(verify_totp_code(secret, &code, now, None), Some(step));
+        assert_eq!(
+            verify_totp_code(secret, &format!(" {code} "), now, None),
+            Some(step)
+        );
+        assert_eq!(
+            verify_totp_code(secret, &previous_code, now, None),
+            Some(step - 1)
+        );
+        assert_eq!(verify_totp_code(secret, &code, now, Some(step)), None);
+        assert_eq!(verify_totp_code(secret, &code, now + 120, None), None);
+        assert_eq!(verify_totp_code(secret, "12345", now, None), None);
+        assert_eq!(verify_totp_code(secret, "abcdef", now, None), None);
+    }
+
+    #[test]
+    fn test_totp_uri() {
+        assert_eq!(
+            totp_uri(b"foobar", "me@example.com"),
+            "otpauth://totp/Husni%20Portfolio:me%40example.com?secret=MZXW6YTBOI&issuer=Husni%20Portfolio&algorithm=SHA1&digits=6&period=30"
+        );
+    }
+
+    #[test]
+    fn test_use_recovery_code() {
+        let mut user_totp = UserTotp {
+            user_id: "user-1".to_string(),
+            encrypted_secret: String::new(),
+            enabled: true,
+            last_step: None,
+            recovery_codes: vec![hash_recovery_code("abcde-12345")],
+        };
+        assert!(!user_totp.use_recovery_code("abcde-54321"));
+        assert!(user_totp.use_recovery_code(" ABCDE12345 "));
+        assert!(user_totp.recovery_codes.is_empty());
+        assert!(!user_totp.use_recovery_code("abcde-12345"));
+    }
+}
@@ -0,0 +1,20 @@
+use crate::model::audit_logs::{AuditLog, AuditLogCommandStatus, AuditLogs, AuditLogsParams};
+use async_trait::async_trait;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(AuditLogDisplayRepo);
+clone_trait_object!(AuditLogOperationRepo);
+
+#[async_trait]
+pub trait AuditLogDisplayRepo: DynClone {
+    async fn find_audit_logs(
+        &self,
+        params: AuditLogsParams,
+        limit: Option<i64>,
+    ) -> Option<AuditLogs>;
+}
+
+#[async_trait]
+pub trait AuditLogOperationRepo: DynClone {
+    async fn add(&mut self, audit_log: AuditLog) -> Option<AuditLogCommandStatus>;
+}
@@ -1,4 +1,9 @@
-use crate::model::auth::{Session, SessionCommandStatus, User, UserCommandStatus};
+use crate::model::auth::{
+    EmailToken, EmailTokenCommandStatus, LoginActivity, LoginActivityCommandStatus, LoginLockout,
+    LoginLockoutCommandStatus, Session, SessionCommandStatus, User, UserCommandStatus, UserRole,
+    Users,
+};
+use crate::model::totp::{UserTotp, UserTotpCommandStatus};
 use async_trait::async_trait;
 use dyn_clone::{clone_trait_object, DynClone};
 
@@ -8,22 +13,63 @@ clone_trait_object!(AuthRepo);
 pub trait AuthRepo: DynClone {
     async fn find_user_by_id(&self, id: String) -> Option<User>;
     async fn find_user_by_email(&self, email: String) -> Option<User>;
-    async fn add_user(&self, id: String, email: String, hpass: String)
-        -> Option<UserCommandStatus>;
+    async fn find_users(&self) -> Option<Users>;
+    async fn add_user(
+        &self,
+        id: String,
+        email: String,
+        hpass: String,
+        role: UserRole,
+    ) -> Option<UserCommandStatus>;
     async fn update_user(
         &self,
         id: String,
         email: Option<String>,
         hpass: Option<String>,
+        role: Option<UserRole>,
     ) -> Option<UserCommandStatus>;
     async fn delete_user(&self, id: String) -> Option<UserCommandStatus>;
+    async fn find_user_totp(&self, user_id: String) -> Option<UserTotp>;
+    async fn upsert_user_totp(&self, user_totp: UserTotp) -> Option<UserTotpCommandStatus>;
+    async fn delete_user_totp(&self, user_id: String) -> Option<UserTotpCommandStatus>;
     async fn find_session(&self, id: String) -> Option<Session>;
-    async fn add_session(
+    async fn find_user_sessions(&self, user_id: String) -> Option<Vec<Session>>;
+    async fn add_session(&self, session: Session) -> Option<SessionCommandStatus>;
+    async fn update_session_last_seen(
+        &self,
+        id: String,
+        last_seen_at: String,
+    ) -> Option<SessionCommandStatus>;
+    async fn extend_session(
         &self,
         id: String,
-        user_id: String,
-        token: String,
         expire: String,
+        last_seen_at: String,
     ) -> Option<SessionCommandStatus>;
     async fn delete_session(&self, id: String) -> Option<SessionCommandStatus>;
+    async fn delete_user_sessions(&self, user_id: String) -> Option<SessionCommandStatus>;
+    async fn delete_expired_sessions(&self, now: String) -> Option<SessionCommandStatus>;
+    async fn add_login_activity(
+        &self,
+        login_activity: LoginActivity,
+    ) -> Option<LoginActivityCommandStatus>;
+    async fn find_login_activities(&self, limit: i64) -> Option<Vec<LoginActivity>>;
+    async fn find_email_login_activities(
+        &self,
+        email: String,
+        limit: i64,
+    ) -> Option<Vec<LoginActivity>>;
+    async fn find_login_lockout(&self, email: String) -> Option<LoginLockout>;
+    async fn add_failed_login(&self, email: String) -> Option<u64>;
+    async fn update_login_lockout_until(
+        &self,
+        email: String,
+        locked_until: String,
+    ) -> Option<LoginLockoutCommandStatus>;
+    async fn delete_login_lockout(&self, email: String) -> Option<LoginLockoutCommandStatus>;
+    async fn find_email_token(&self, id: String) -> Option<EmailToken>;
+    async fn add_email_token(&self, email_token: EmailToken) -> Option<EmailTokenCommandStatus>;
+    async fn consume_email_token(&self, id: String) -> Option<EmailToken>;
+    async fn delete_user_email_tokens(&self, user_id: String) -> Option<EmailTokenCommandStatus>;
+    async fn delete_expired_email_tokens(&self, now: String) -> Option<EmailTokenCommandStatus>;
 }
@@ -0,0 +1,17 @@
+use crate::model::blog_drafts::{BlogDraft, BlogDraftCommandStatus};
+use async_trait::async_trait;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(BlogDraftDisplayRepo);
+clone_trait_object!(BlogDraftOperationRepo);
+
+#[async_trait]
+pub trait BlogDraftDisplayRepo: DynClone {
+    async fn find(&self, blog_id: i64, user_id: String) -> Option<BlogDraft>;
+}
+
+#[async_trait]
+pub trait BlogDraftOperationRepo: DynClone {
+    async fn save(&mut self, blog_draft: BlogDraft) -> Option<BlogDraftCommandStatus>;
+    async fn delete(&mut self, blog_id: i64, user_id: String) -> Option<BlogDraftCommandStatus>;
+}
@@ -0,0 +1,18 @@
+use crate::model::dashboard::{CacheStats, ContentStats, RecentUpdate, TagUsages};
+use async_trait::async_trait;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(DashboardDisplayRepo);
+clone_trait_object!(DashboardCacheRepo);
+
+#[async_trait]
+pub trait DashboardDisplayRepo: DynClone {
+    async fn find_content_stats(&self) -> Option<ContentStats>;
+    async fn find_tag_usages(&self) -> Option<TagUsages>;
+    async fn find_recent_updates(&self, limit: i64) -> Option<Vec<RecentUpdate>>;
+}
+
+#[async_trait]
+pub trait DashboardCacheRepo: DynClone {
+    async fn find_cache_stats(&self) -> Option<CacheStats>;
+}
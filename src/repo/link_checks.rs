@@ -0,0 +1,16 @@
+use crate::model::link_checks::{LinkCheckCommandStatus, LinkChecks};
+use async_trait::async_trait;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(LinkCheckDisplayRepo);
+clone_trait_object!(LinkCheckOperationRepo);
+
+#[async_trait]
+pub trait LinkCheckDisplayRepo: DynClone {
+    async fn find_link_checks(&self) -> Option<LinkChecks>;
+}
+
+#[async_trait]
+pub trait LinkCheckOperationRepo: DynClone {
+    async fn replace(&mut self, link_checks: LinkChecks) -> Option<LinkCheckCommandStatus>;
+}
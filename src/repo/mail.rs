@@ -0,0 +1,11 @@
+use crate::model::mail::{Mail, MailCommandStatus};
+use async_trait::async_trait;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(MailRepo);
+
+/// Transport of outgoing emails
+#[async_trait]
+pub trait MailRepo: DynClone {
+    async fn send(&self, mail: Mail) -> Option<MailCommandStatus>;
+}
@@ -0,0 +1,33 @@
+use crate::model::media::{MediaCommandStatus, MediaFile, MediaFiles, MediaFilesParams};
+use async_trait::async_trait;
+use bytes::Bytes;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(MediaDisplayRepo);
+clone_trait_object!(MediaOperationRepo);
+clone_trait_object!(MediaStorageRepo);
+
+#[async_trait]
+pub trait MediaDisplayRepo: DynClone {
+    async fn find(&self, key: String) -> Option<MediaFile>;
+    async fn find_media_files(&self, params: MediaFilesParams) -> Option<MediaFiles>;
+}
+
+#[async_trait]
+pub trait MediaOperationRepo: DynClone {
+    async fn add(&mut self, media_file: MediaFile) -> Option<MediaCommandStatus>;
+    async fn delete(&mut self, key: String) -> Option<MediaCommandStatus>;
+}
+
+/// Storage backend of media files content
+#[async_trait]
+pub trait MediaStorageRepo: DynClone {
+    async fn upload(
+        &mut self,
+        key: String,
+        content_type: String,
+        data: Bytes,
+    ) -> Option<MediaCommandStatus>;
+    async fn download(&self, key: String) -> Option<Bytes>;
+    async fn remove(&mut self, key: String) -> Option<MediaCommandStatus>;
+}
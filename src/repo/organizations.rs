@@ -0,0 +1,36 @@
+use crate::model::organizations::{
+    Organization, OrganizationCommandStatus, Organizations, OrganizationsParams,
+};
+use async_trait::async_trait;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(OrganizationDisplayRepo);
+clone_trait_object!(OrganizationOperationRepo);
+
+#[async_trait]
+pub trait OrganizationDisplayRepo: DynClone {
+    async fn find(&self, id: i64) -> Option<Organization>;
+    async fn find_organizations(&self, params: OrganizationsParams) -> Option<Organizations>;
+}
+
+#[async_trait]
+pub trait OrganizationOperationRepo: DynClone {
+    async fn get_new_id(&self) -> Option<i64>;
+    async fn add(
+        &mut self,
+        id: i64,
+        name: String,
+        url: Option<String>,
+        logo: Option<String>,
+        description: Option<String>,
+    ) -> Option<OrganizationCommandStatus>;
+    async fn update(
+        &mut self,
+        id: i64,
+        name: String,
+        url: Option<String>,
+        logo: Option<String>,
+        description: Option<String>,
+    ) -> Option<OrganizationCommandStatus>;
+    async fn delete(&mut self, id: i64) -> Option<OrganizationCommandStatus>;
+}
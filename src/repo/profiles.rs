@@ -0,0 +1,25 @@
+use crate::model::profiles::{Profile, ProfileCommandStatus, Profiles, ProfilesParams};
+use async_trait::async_trait;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(ProfileDisplayRepo);
+clone_trait_object!(ProfileOperationRepo);
+clone_trait_object!(ProfileCacheOperationRepo);
+
+#[async_trait]
+pub trait ProfileDisplayRepo: DynClone {
+    async fn find(&self, version: String) -> Option<Profile>;
+    async fn find_latest(&self) -> Option<Profile>;
+    async fn find_profiles(&self, params: ProfilesParams) -> Option<Profiles>;
+}
+
+#[async_trait]
+pub trait ProfileOperationRepo: DynClone {
+    async fn add(&mut self, profile: Profile) -> Option<ProfileCommandStatus>;
+}
+
+#[async_trait]
+pub trait ProfileCacheOperationRepo: DynClone {
+    async fn insert(&mut self, profile: Profile) -> Option<ProfileCommandStatus>;
+    async fn invalidate(&mut self, version: String) -> Option<ProfileCommandStatus>;
+}
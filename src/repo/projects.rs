@@ -0,0 +1,27 @@
+use crate::model::projects::{Project, ProjectCommandStatus, Projects, ProjectsParams};
+use async_trait::async_trait;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(ProjectDisplayRepo);
+clone_trait_object!(ProjectOperationRepo);
+clone_trait_object!(ProjectCacheOperationRepo);
+
+#[async_trait]
+pub trait ProjectDisplayRepo: DynClone {
+    async fn find(&self, id: i64) -> Option<Project>;
+    async fn find_projects(&self, params: ProjectsParams) -> Option<Projects>;
+}
+
+#[async_trait]
+pub trait ProjectOperationRepo: DynClone {
+    async fn get_new_id(&self) -> Option<i64>;
+    async fn add(&mut self, project: Project) -> Option<ProjectCommandStatus>;
+    async fn update(&mut self, project: Project) -> Option<ProjectCommandStatus>;
+    async fn delete(&mut self, id: i64) -> Option<ProjectCommandStatus>;
+}
+
+#[async_trait]
+pub trait ProjectCacheOperationRepo: DynClone {
+    async fn insert(&mut self, project: Project) -> Option<ProjectCommandStatus>;
+    async fn invalidate(&mut self, id: i64) -> Option<ProjectCommandStatus>;
+}
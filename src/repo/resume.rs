@@ -0,0 +1,23 @@
+use crate::model::resume::{Resume, ResumeCommandStatus};
+use async_trait::async_trait;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(ResumeDisplayRepo);
+clone_trait_object!(ResumeOperationRepo);
+clone_trait_object!(ResumeCacheOperationRepo);
+
+#[async_trait]
+pub trait ResumeDisplayRepo: DynClone {
+    async fn find(&self) -> Option<Resume>;
+}
+
+#[async_trait]
+pub trait ResumeOperationRepo: DynClone {
+    async fn save(&mut self, resume: Resume) -> Option<ResumeCommandStatus>;
+}
+
+#[async_trait]
+pub trait ResumeCacheOperationRepo: DynClone {
+    async fn insert(&mut self, resume: Resume) -> Option<ResumeCommandStatus>;
+    async fn invalidate(&mut self) -> Option<ResumeCommandStatus>;
+}
@@ -0,0 +1,35 @@
+use crate::model::talk_tag_mappings::{TalkTagMappingCommandStatus, TalkTagMappings};
+use async_trait::async_trait;
+use dyn_clone::{clone_trait_object, DynClone};
+
+clone_trait_object!(TalkTagMappingDisplayRepo);
+clone_trait_object!(TalkTagMappingOperationRepo);
+clone_trait_object!(TalkTagMappingCacheOperationRepo);
+
+#[async_trait]
+pub trait TalkTagMappingDisplayRepo: DynClone {
+    async fn find_by_talk_id(&self, talk_id: i64) -> Option<TalkTagMappings>;
+    async fn find_by_tag_id(&self, tag_id: i64) -> Option<TalkTagMappings>;
+}
+
+#[async_trait]
+pub trait TalkTagMappingOperationRepo: DynClone {
+    async fn add(&mut self, talk_id: i64, tag_id: i64) -> Option<TalkTagMappingCommandStatus>;
+    async fn delete_by_talk_id(&mut self, talk_id: i64) -> Option<TalkTagMappingCommandStatus>;
+    async fn delete_by_talk_id_and_tag_id(
+        &mut self,
+        talk_id: i64,
+        tag_id: i64,
+    ) -> Option<TalkTagMappingCommandStatus>;
+}
+
+#[async_trait]
+pub trait TalkTagMappingCacheOperationRepo: DynClone {
+    async fn insert(&mut self, talk_id: i64, tag_id: i64) -> Option<TalkTagMappingCommandStatus>;
+    async fn invalidate(
+        &mut self,
+        talk_id: i64,
+        tag_id: i64,
+    ) -> Option<TalkTagMappingCommandStatus>;
+    async fn invalidate_by_talk_id(&mut self, talk_id: i64) -> Option<TalkTagMappingCommandStatus>;
+}
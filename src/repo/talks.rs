@@ -1,5 +1,6 @@
 use crate::model::talks::{Talk, TalkCommandStatus, Talks, TalksParams};
 use async_trait::async_trait;
+use chrono::NaiveDate;
 use dyn_clone::{clone_trait_object, DynClone};
 
 clone_trait_object!(TalkDisplayRepo);
@@ -19,19 +20,17 @@ pub trait TalkOperationRepo: DynClone {
         &mut self,
         id: i64,
         name: String,
-        date: String,
+        date: NaiveDate,
         media_link: Option<String>,
-        org_name: Option<String>,
-        org_link: Option<String>,
+        org_id: Option<i64>,
     ) -> Option<TalkCommandStatus>;
     async fn update(
         &mut self,
         id: i64,
         name: Option<String>,
-        date: Option<String>,
+        date: Option<NaiveDate>,
         media_link: Option<String>,
-        org_name: Option<String>,
-        org_link: Option<String>,
+        org_id: Option<i64>,
     ) -> Option<TalkCommandStatus>;
     async fn delete(&mut self, id: i64) -> Option<TalkCommandStatus>;
 }
@@ -40,4 +39,7 @@ pub trait TalkOperationRepo: DynClone {
 pub trait TalkCacheOperationRepo: DynClone {
     async fn insert(&mut self, talk: Talk) -> Option<TalkCommandStatus>;
     async fn invalidate(&mut self, id: i64) -> Option<TalkCommandStatus>;
+    async fn find_calendar(&self) -> Option<String>;
+    async fn insert_calendar(&mut self, calendar: String) -> Option<TalkCommandStatus>;
+    async fn invalidate_calendar(&mut self) -> Option<TalkCommandStatus>;
 }
@@ -1,16 +1,29 @@
 use crate::handler::{
     admin::{
+        account::{displays as acd, operations as aco},
+        audit::displays as aud,
         blogs::{
             displays as bd, operations as bo,
             tags::{displays as btd, operations as bto},
         },
         displays as add,
-        talks::{displays as td, operations as to},
+        links::{displays as ld, operations as lo},
+        media::{displays as md, operations as mo},
+        profile::{displays as prd, operations as pro},
+        projects::{displays as pd, operations as po},
+        resume::{displays as rd, operations as ro},
+        talks::{
+            displays as td, operations as to,
+            organizations::{displays as tod, operations as too},
+        },
+        users::{displays as ud, operations as uo},
     },
-    auth::{displays as ad, operations as ao},
+    auth::{displays as ad, guard::require_auth, operations as ao, session::refresh_access_token},
 };
-use crate::handler::{blogs, profile, status, talks, version};
+use crate::handler::{blogs, media, profile, projects, resume, status, talks, version};
 use crate::model::axum::AppState;
+use axum::extract::DefaultBodyLimit;
+use axum::middleware::from_fn_with_state;
 use axum::routing::get_service;
 use axum::{
     routing::{delete, get, post, put},
@@ -44,18 +57,35 @@ pub fn main_route(app_state: AppState) -> Router {
 
     let login_rate_limited = Router::new()
         .route("/login", post(ao::post_login))
+        .route("/login/totp", post(ao::post_login_totp))
+        .route("/login/email", post(ao::post_login_email))
+        .route("/login/email/verify", post(ao::post_login_email_verify))
+        .route("/login/reset", post(ao::post_login_reset))
+        .route("/login/oidc", get(ao::get_login_oidc))
+        .route("/login/oidc/callback", get(ao::get_login_oidc_callback))
         .layer(GovernorLayer::new(governor_conf));
 
+    // Reissue short-lived access tokens of active sessions
+    let session_refreshed = Router::new()
+        .route("/logout", delete(ao::delete_logout))
+        .nest("/admin", admin_route(app_state.clone()))
+        .layer(from_fn_with_state(app_state.clone(), refresh_access_token));
+
     Router::new()
         .route("/", get(profile::get_profile))
         .route("/version", get(version::get_version))
+        .route("/resume", get(resume::get_resume))
+        .route("/resume.json", get(resume::get_resume_json))
+        .route("/media/{key}", get(media::get_media))
         .route("/login", get(ad::get_login))
+        .route("/login/email/verify", get(ad::get_login_email))
+        .route("/login/reset", get(ad::get_login_reset))
         .merge(login_rate_limited)
-        .route("/logout", delete(ao::delete_logout))
+        .merge(session_refreshed)
         .route("/etc/passwd", get(status::get_418_i_am_a_teapot))
         .nest("/blogs", blogs_route())
         .nest("/talks", talks_route())
-        .nest("/admin", admin_route())
+        .nest("/projects", projects_route())
         .nest_service("/statics", get_service(ServeDir::new("./statics/favicon/")))
         .nest_service(
             "/theme.js",
@@ -77,14 +107,44 @@ fn blogs_route() -> Router<AppState> {
 }
 
 fn talks_route() -> Router<AppState> {
-    Router::new().route("/", get(talks::get_talks))
+    Router::new()
+        .route("/", get(talks::get_talks))
+        .route("/calendar.ics", get(talks::get_talks_calendar))
 }
 
-fn admin_route() -> Router<AppState> {
+fn projects_route() -> Router<AppState> {
+    Router::new()
+        .route("/", get(projects::get_projects))
+        .route("/{project_id}", get(projects::get_project))
+}
+
+/// Every path under `/admin`, including unknown ones, requires an active
+/// Session, and the CSRF token for requests changing state. See `require_auth`.
+fn admin_route(app_state: AppState) -> Router<AppState> {
     Router::new()
         .route("/", get(add::get_base_admin))
+        .nest("/dashboard", admin_dashboard_route())
         .nest("/talks", admin_talks_route())
         .nest("/blogs", admin_blogs_route())
+        .nest("/projects", admin_projects_route())
+        .nest("/profile", admin_profile_route())
+        .nest("/resume", admin_resume_route())
+        .nest("/media", admin_media_route())
+        .nest("/links", admin_links_route())
+        .nest("/audit", admin_audit_route())
+        .nest("/users", admin_users_route())
+        .nest("/account", admin_account_route())
+        .fallback(status::get_404_not_found)
+        .layer(from_fn_with_state(app_state, require_auth))
+}
+
+fn admin_dashboard_route() -> Router<AppState> {
+    Router::new()
+        .route("/content", get(add::get_admin_dashboard_content))
+        .route("/tags", get(add::get_admin_dashboard_tags))
+        .route("/recent", get(add::get_admin_dashboard_recent))
+        .route("/cache", get(add::get_admin_dashboard_cache))
+        .route("/logins", get(add::get_admin_dashboard_logins))
 }
 
 fn admin_talks_route() -> Router<AppState> {
@@ -98,6 +158,132 @@ fn admin_talks_route() -> Router<AppState> {
         .route("/{talk_id}/edit", put(to::put_edit_admin_talk))
         .route("/{talk_id}/delete", get(td::get_delete_admin_talk))
         .route("/{talk_id}/delete", delete(to::delete_delete_admin_talk))
+        .nest("/organizations", admin_talks_organizations_route())
+}
+
+fn admin_talks_organizations_route() -> Router<AppState> {
+    Router::new()
+        .route("/", get(tod::get_base_admin_organizations))
+        .route("/list", get(tod::get_admin_organizations_list))
+        .route("/add", get(tod::get_add_admin_organization))
+        .route("/add", post(too::post_add_admin_organization))
+        .route("/{organization_id}", get(tod::get_admin_organization))
+        .route(
+            "/{organization_id}/edit",
+            get(tod::get_edit_admin_organization),
+        )
+        .route(
+            "/{organization_id}/edit",
+            put(too::put_edit_admin_organization),
+        )
+        .route(
+            "/{organization_id}/delete",
+            get(tod::get_delete_admin_organization),
+        )
+        .route(
+            "/{organization_id}/delete",
+            delete(too::delete_delete_admin_organization),
+        )
+}
+
+fn admin_profile_route() -> Router<AppState> {
+    Router::new()
+        .route("/", get(prd::get_base_admin_profile))
+        .route("/edit", get(prd::get_edit_admin_profile))
+        .route("/edit", post(pro::post_edit_admin_profile))
+        .route("/history", get(prd::get_admin_profile_history))
+        .route("/{profile_version}", get(prd::get_admin_profile))
+        .route(
+            "/{profile_version}/rollback",
+            post(pro::post_rollback_admin_profile),
+        )
+}
+
+fn admin_resume_route() -> Router<AppState> {
+    Router::new()
+        .route("/", get(rd::get_base_admin_resume))
+        .route("/edit", get(rd::get_edit_admin_resume))
+        .route("/edit", post(ro::post_edit_admin_resume))
+}
+
+fn admin_media_route() -> Router<AppState> {
+    Router::new()
+        .route("/", get(md::get_base_admin_media))
+        .route("/list", get(md::get_admin_media_list))
+        // Upload size is checked while reading the file against MEDIA_MAX_SIZE_BYTES
+        .route(
+            "/upload",
+            post(mo::post_upload_admin_media).layer(DefaultBodyLimit::disable()),
+        )
+        .route("/{key}/delete", get(md::get_delete_admin_media))
+        .route("/{key}/delete", delete(mo::delete_delete_admin_media))
+}
+
+fn admin_links_route() -> Router<AppState> {
+    Router::new()
+        .route("/", get(ld::get_base_admin_links))
+        .route("/list", get(ld::get_admin_links_list))
+        .route("/check", post(lo::post_check_admin_links))
+}
+
+fn admin_audit_route() -> Router<AppState> {
+    Router::new()
+        .route("/", get(aud::get_base_admin_audit))
+        .route("/list", get(aud::get_admin_audit_list))
+        .route("/export", get(aud::get_admin_audit_export))
+}
+
+fn admin_users_route() -> Router<AppState> {
+    Router::new()
+        .route("/", get(ud::get_base_admin_users))
+        .route("/list", get(ud::get_admin_users_list))
+        .route("/add", get(ud::get_add_admin_user))
+        .route("/add", post(uo::post_add_admin_user))
+        .route("/{user_id}", get(ud::get_admin_user))
+        .route("/{user_id}/edit", get(ud::get_edit_admin_user))
+        .route("/{user_id}/edit", put(uo::put_edit_admin_user))
+        .route("/{user_id}/delete", get(ud::get_delete_admin_user))
+        .route("/{user_id}/delete", delete(uo::delete_delete_admin_user))
+        .route("/{user_id}/totp", get(ud::get_reset_admin_user_totp))
+        .route("/{user_id}/totp", delete(uo::delete_reset_admin_user_totp))
+}
+
+fn admin_account_route() -> Router<AppState> {
+    Router::new()
+        .route("/", get(acd::get_base_admin_account))
+        .route("/password", get(acd::get_admin_account_password))
+        .route("/password", post(aco::post_admin_account_password))
+        .route("/totp", get(acd::get_admin_account_totp))
+        .route("/totp/setup", post(aco::post_admin_account_totp_setup))
+        .route("/totp/enable", post(aco::post_admin_account_totp_enable))
+        .route(
+            "/totp/recovery-codes",
+            post(aco::post_admin_account_totp_recovery_codes),
+        )
+        .route("/totp/disable", post(aco::post_admin_account_totp_disable))
+        .route("/logins", get(acd::get_admin_account_logins))
+        .route("/sessions", get(acd::get_admin_account_sessions))
+        .route("/sessions", delete(aco::delete_admin_account_sessions))
+        .route(
+            "/sessions/{session_id}",
+            delete(aco::delete_admin_account_session),
+        )
+}
+
+fn admin_projects_route() -> Router<AppState> {
+    Router::new()
+        .route("/", get(pd::get_base_admin_projects))
+        .route("/list", get(pd::get_admin_projects_list))
+        .route("/add", get(pd::get_add_admin_project))
+        .route("/add", post(po::post_add_admin_project))
+        .route("/{project_id}", get(pd::get_admin_project))
+        .route("/{project_id}/edit", get(pd::get_edit_admin_project))
+        .route("/{project_id}/edit", put(po::put_edit_admin_project))
+        .route("/{project_id}/delete", get(pd::get_delete_admin_project))
+        .route(
+            "/{project_id}/delete",
+            delete(po::delete_delete_admin_project),
+        )
 }
 
 fn admin_blogs_route() -> Router<AppState> {
@@ -106,9 +292,13 @@ fn admin_blogs_route() -> Router<AppState> {
         .route("/list", get(bd::get_admin_blogs_list))
         .route("/add", get(bd::get_add_admin_blog))
         .route("/add", post(bo::post_add_admin_blog))
+        .route("/preview", post(bo::post_preview_admin_blog))
         .route("/{blog_id}", get(bd::get_admin_blog))
         .route("/{blog_id}/edit", get(bd::get_edit_admin_blog))
         .route("/{blog_id}/edit", put(bo::put_edit_admin_blog))
+        .route("/{blog_id}/draft", get(bd::get_admin_blog_draft))
+        .route("/{blog_id}/draft", put(bo::put_admin_blog_draft))
+        .route("/{blog_id}/draft", delete(bo::delete_admin_blog_draft))
         .route("/{blog_id}/delete", get(bd::get_delete_admin_blog))
         .route("/{blog_id}/delete", delete(bo::delete_delete_admin_blog))
         .nest("/tags", admin_blogs_tags_route())
@@ -127,3 +317,398 @@ fn admin_blogs_tags_route() -> Router<AppState> {
         .route("/{tag_id}/delete", get(btd::get_delete_admin_tag))
         .route("/{tag_id}/delete", delete(bto::delete_delete_admin_tag))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::Config;
+    use crate::handler::auth::csrf::CSRF_HEADER_NAME;
+    use crate::handler::auth::session::create_session;
+    use crate::handler::HX_REQUEST;
+    use crate::model::audit_logs::{AuditLogsParams, AUDIT_ENTITY_ORGANIZATION, AUDIT_ENTITY_TALK};
+    use crate::model::auth::{User, UserRole};
+    use crate::repo::audit_logs::AuditLogDisplayRepo;
+    use crate::repo::auth::AuthRepo;
+    use crate::repo::organizations::OrganizationOperationRepo;
+    use crate::state::state_factory;
+    use axum::body::{to_bytes, Body};
+    use axum::extract::ConnectInfo;
+    use axum::http::header::{COOKIE, LOCATION};
+    use axum::http::{HeaderName, Method, Request, StatusCode};
+    use std::net::SocketAddr;
+    use tower::ServiceExt;
+
+    async fn app_state() -> AppState {
+        let mut config = Config::default();
+        config.secrets.database_url = ":memory:".to_string();
+        state_factory(config).await
+    }
+
+    /// Store `user-1` and return the cookie of its Session
+    async fn session_cookie(app_state: &AppState) -> String {
+        let auth_uc = app_state.auth_db_usecase.lock().await.clone().unwrap();
+        auth_uc
+            .add_user(
+                "user-1".to_string(),
+                "me@example.com".to_string(),
+                String::new(),
+                UserRole::Owner,
+            )
+            .await
+            .unwrap();
+        let user: User = auth_uc.find_user_by_id("user-1".to_string()).await.unwrap();
+        let tokens = create_session(app_state, &user, false, String::new(), String::new())
+            .await
+            .unwrap();
+        format!("_csrf_token=abc; token={}", tokens.access_token)
+    }
+
+    /// Method and path of every route nested in `admin_route`
+    /// Path parameters are filled with `1`.
+    const ADMIN_ROUTES: &[(Method, &str)] = &[
+        (Method::GET, "/admin"),
+        (Method::GET, "/admin/dashboard/content"),
+        (Method::GET, "/admin/dashboard/tags"),
+        (Method::GET, "/admin/dashboard/recent"),
+        (Method::GET, "/admin/dashboard/cache"),
+        (Method::GET, "/admin/dashboard/logins"),
+        (Method::GET, "/admin/talks"),
+        (Method::GET, "/admin/talks/list"),
+        (Method::GET, "/admin/talks/add"),
+        (Method::POST, "/admin/talks/add"),
+        (Method::GET, "/admin/talks/1"),
+        (Method::GET, "/admin/talks/1/edit"),
+        (Method::PUT, "/admin/talks/1/edit"),
+        (Method::GET, "/admin/talks/1/delete"),
+        (Method::DELETE, "/admin/talks/1/delete"),
+        (Method::GET, "/admin/blogs"),
+        (Method::GET, "/admin/blogs/list"),
+        (Method::GET, "/admin/blogs/add"),
+        (Method::POST, "/admin/blogs/add"),
+        (Method::POST, "/admin/blogs/preview"),
+        (Method::GET, "/admin/blogs/1"),
+        (Method::GET, "/admin/blogs/1/edit"),
+        (Method::PUT, "/admin/blogs/1/edit"),
+        (Method::GET, "/admin/blogs/1/draft"),
+        (Method::PUT, "/admin/blogs/1/draft"),
+        (Method::DELETE, "/admin/blogs/1/draft"),
+        (Method::GET, "/admin/blogs/1/delete"),
+        (Method::DELETE, "/admin/blogs/1/delete"),
+        (Method::GET, "/admin/projects"),
+        (Method::GET, "/admin/projects/list"),
+        (Method::GET, "/admin/projects/add"),
+        (Method::POST, "/admin/projects/add"),
+        (Method::GET, "/admin/projects/1"),
+        (Method::GET, "/admin/projects/1/edit"),
+        (Method::PUT, "/admin/projects/1/edit"),
+        (Method::GET, "/admin/projects/1/delete"),
+        (Method::DELETE, "/admin/projects/1/delete"),
+        (Method::GET, "/admin/profile"),
+        (Method::GET, "/admin/profile/edit"),
+        (Method::POST, "/admin/profile/edit"),
+        (Method::GET, "/admin/profile/history"),
+        (Method::GET, "/admin/profile/1"),
+        (Method::POST, "/admin/profile/1/rollback"),
+        (Method::GET, "/admin/resume"),
+        (Method::GET, "/admin/resume/edit"),
+        (Method::POST, "/admin/resume/edit"),
+        (Method::GET, "/admin/media"),
+        (Method::GET, "/admin/media/list"),
+        (Method::POST, "/admin/media/upload"),
+        (Method::GET, "/admin/media/1/delete"),
+        (Method::DELETE, "/admin/media/1/delete"),
+        (Method::GET, "/admin/links"),
+        (Method::GET, "/admin/links/list"),
+        (Method::POST, "/admin/links/check"),
+        (Method::GET, "/admin/audit"),
+        (Method::GET, "/admin/audit/list"),
+        (Method::GET, "/admin/audit/export"),
+        (Method::GET, "/admin/users"),
+        (Method::GET, "/admin/users/list"),
+        (Method::GET, "/admin/users/add"),
+        (Method::POST, "/admin/users/add"),
+        (Method::GET, "/admin/users/1"),
+        (Method::GET, "/admin/users/1/edit"),
+        (Method::PUT, "/admin/users/1/edit"),
+        (Method::GET, "/admin/users/1/delete"),
+        (Method::DELETE, "/admin/users/1/delete"),
+        (Method::GET, "/admin/users/1/totp"),
+        (Method::DELETE, "/admin/users/1/totp"),
+        (Method::GET, "/admin/account"),
+        (Method::GET, "/admin/account/password"),
+        (Method::POST, "/admin/account/password"),
+        (Method::GET, "/admin/account/totp"),
+        (Method::POST, "/admin/account/totp/setup"),
+        (Method::POST, "/admin/account/totp/enable"),
+        (Method::POST, "/admin/account/totp/recovery-codes"),
+        (Method::POST, "/admin/account/totp/disable"),
+        (Method::GET, "/admin/account/logins"),
+        (Method::GET, "/admin/account/sessions"),
+        (Method::DELETE, "/admin/account/sessions"),
+        (Method::DELETE, "/admin/account/sessions/1"),
+        (Method::GET, "/admin/talks/organizations"),
+        (Method::GET, "/admin/talks/organizations/list"),
+        (Method::GET, "/admin/talks/organizations/add"),
+        (Method::POST, "/admin/talks/organizations/add"),
+        (Method::GET, "/admin/talks/organizations/1"),
+        (Method::GET, "/admin/talks/organizations/1/edit"),
+        (Method::PUT, "/admin/talks/organizations/1/edit"),
+        (Method::GET, "/admin/talks/organizations/1/delete"),
+        (Method::DELETE, "/admin/talks/organizations/1/delete"),
+        (Method::GET, "/admin/blogs/tags"),
+        (Method::GET, "/admin/blogs/tags/list"),
+        (Method::GET, "/admin/blogs/tags/search"),
+        (Method::GET, "/admin/blogs/tags/add"),
+        (Method::POST, "/admin/blogs/tags/add"),
+        (Method::GET, "/admin/blogs/tags/1"),
+        (Method::GET, "/admin/blogs/tags/1/edit"),
+        (Method::PUT, "/admin/blogs/tags/1/edit"),
+        (Method::GET, "/admin/blogs/tags/1/delete"),
+        (Method::DELETE, "/admin/blogs/tags/1/delete"),
+    ];
+
+    fn request(method: &Method, path: &str, headers: &[(HeaderName, &str)]) -> Request<Body> {
+        let mut builder = Request::builder().method(method).uri(path);
+        for (name, value) in headers {
+            builder = builder.header(name, *value);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_admin_routes_require_session() {
+        let app = main_route(app_state().await);
+        // Unknown paths and methods are rejected too, so routes missing from
+        // ADMIN_ROUTES are still protected
+        let unknown_routes = [
+            (Method::GET, "/admin/unknown"),
+            (Method::POST, "/admin/blogs/1/unknown"),
+            (Method::PATCH, "/admin/talks/1/edit"),
+            (Method::DELETE, "/admin/users/list"),
+        ];
+        for (method, path) in ADMIN_ROUTES.iter().chain(unknown_routes.iter()) {
+            // Browsers are sent to the login page
+            let response = app
+                .clone()
+                .oneshot(request(method, path, &[]))
+                .await
+                .unwrap();
+            assert!(response.status().is_redirection(), "{method} {path}");
+            assert_eq!(response.headers()[LOCATION], "/login", "{method} {path}");
+
+            let response = app
+                .clone()
+                .oneshot(request(method, path, &[(HX_REQUEST, "true")]))
+                .await
+                .unwrap();
+            assert_eq!(
+                response.status(),
+                StatusCode::UNAUTHORIZED,
+                "{method} {path}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_routes_require_csrf_token() {
+        let app_state = app_state().await;
+        let cookie = session_cookie(&app_state).await;
+        let app = main_route(app_state);
+        for (method, path) in ADMIN_ROUTES {
+            if *method == Method::GET {
+                continue;
+            }
+            let response = app
+                .clone()
+                .oneshot(request(method, path, &[(COOKIE, &cookie)]))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN, "{method} {path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_route_auth_user() {
+        let app_state = app_state().await;
+        let cookie = session_cookie(&app_state).await;
+        let app = main_route(app_state);
+
+        let response = app
+            .clone()
+            .oneshot(request(
+                &Method::GET,
+                "/admin/account",
+                &[(COOKIE, &cookie)],
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(String::from_utf8(body.to_vec())
+            .unwrap()
+            .contains("me@example.com"));
+
+        let headers = [(COOKIE, cookie.as_str()), (CSRF_HEADER_NAME, "abc")];
+        let mut preview = request(&Method::POST, "/admin/blogs/preview", &headers);
+        *preview.body_mut() = Body::from("blog_id=1&blog_name=Hello&blog_body=World");
+        let response = app.oneshot(preview).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_admin_talk_with_invalid_date() {
+        let path = std::env::temp_dir().join(format!("talks-test-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let database_url = path.to_str().unwrap().to_string();
+        let mut config = Config::default();
+        config.secrets.database_url = database_url.clone();
+        let app_state = state_factory(config).await;
+        let cookie = session_cookie(&app_state).await;
+
+        // Store a Talk then break its date like a legacy free-form date
+        let mut talk_db_uc = app_state.talk_db_usecase.lock().await.clone().unwrap();
+        let id = 1;
+        talk_db_uc
+            .talk_operation_repo
+            .add(
+                id,
+                "Broken Talk".to_string(),
+                chrono::NaiveDate::from_ymd_opt(2022, 7, 2).unwrap(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let db = libsql::Builder::new_local(&database_url)
+            .build()
+            .await
+            .unwrap();
+        db.connect()
+            .unwrap()
+            .execute(
+                "UPDATE talks SET date = 'sometime in 2022' WHERE id = ?1",
+                [id],
+            )
+            .await
+            .unwrap();
+
+        let app = main_route(app_state);
+        let get_body = |path: String| {
+            let app = app.clone();
+            let cookie = cookie.clone();
+            async move {
+                let response = app
+                    .oneshot(request(&Method::GET, &path, &[(COOKIE, &cookie)]))
+                    .await
+                    .unwrap();
+                assert_eq!(response.status(), StatusCode::OK, "{path}");
+                let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                String::from_utf8(body.to_vec()).unwrap()
+            }
+        };
+
+        // Listed with a warning and editable in the admin
+        let list = get_body("/admin/talks/list".to_string()).await;
+        assert!(list.contains("Invalid date 'sometime in 2022'"));
+        let edit = get_body(format!("/admin/talks/{id}/edit")).await;
+        assert!(edit.contains("Broken Talk"));
+        assert!(edit.contains("Stored date 'sometime in 2022' is invalid."));
+        // Left out of the public talks
+        assert!(!get_body("/talks".to_string()).await.contains("Broken Talk"));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn test_talks_calendar_has_every_talk() {
+        let app_state = app_state().await;
+        let mut talk_db_uc = app_state.talk_db_usecase.lock().await.clone().unwrap();
+        // More talks than the default pagination of TalksParams
+        for id in 1..=101 {
+            talk_db_uc
+                .talk_operation_repo
+                .add(
+                    id,
+                    format!("Talk {id}"),
+                    chrono::NaiveDate::from_ymd_opt(2022, 7, 2).unwrap(),
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        let response = main_route(app_state)
+            .oneshot(request(&Method::GET, "/talks/calendar.ics", &[]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let calendar = String::from_utf8(body.to_vec()).unwrap();
+        assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 101);
+    }
+
+    #[tokio::test]
+    async fn test_admin_organization_edit_is_audited() {
+        let app_state = app_state().await;
+        let cookie = session_cookie(&app_state).await;
+
+        let mut organization_db_uc = app_state
+            .organization_db_usecase
+            .lock()
+            .await
+            .clone()
+            .unwrap();
+        organization_db_uc
+            .add(1, "Org".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let mut talk_db_uc = app_state.talk_db_usecase.lock().await.clone().unwrap();
+        talk_db_uc
+            .talk_operation_repo
+            .add(
+                1,
+                "Org Talk".to_string(),
+                chrono::NaiveDate::from_ymd_opt(2022, 7, 2).unwrap(),
+                None,
+                Some(1),
+            )
+            .await
+            .unwrap();
+
+        let headers = [(COOKIE, cookie.as_str()), (CSRF_HEADER_NAME, "abc")];
+        let mut edit = request(&Method::PUT, "/admin/talks/organizations/1/edit", &headers);
+        *edit.body_mut() = Body::from(
+            "organization_id=1&organization_name=New%20Org&organization_url=https%3A%2F%2Fexample.com",
+        );
+        let addr: SocketAddr = "10.0.0.1:5000".parse().unwrap();
+        edit.extensions_mut().insert(ConnectInfo(addr));
+        let response = main_route(app_state.clone()).oneshot(edit).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let audit_log_db_uc = app_state.audit_log_db_usecase.lock().await.clone().unwrap();
+        let audit_logs = audit_log_db_uc
+            .find_audit_logs(AuditLogsParams::default(), None)
+            .await
+            .unwrap()
+            .audit_logs;
+        let organization_log = audit_logs
+            .iter()
+            .find(|val| val.entity_type == AUDIT_ENTITY_ORGANIZATION)
+            .unwrap();
+        assert_eq!(organization_log.user_id, "user-1");
+        assert_eq!(organization_log.client_ip, "10.0.0.1");
+        assert!(organization_log.before.contains("name=Org;"));
+        assert!(organization_log.after.contains("name=New Org;"));
+        let talk_log = audit_logs
+            .iter()
+            .find(|val| val.entity_type == AUDIT_ENTITY_TALK)
+            .unwrap();
+        assert_eq!(talk_log.entity_id, "1");
+        assert_eq!(talk_log.before, "org_id=1; org_name=Org; org_link=");
+        assert_eq!(
+            talk_log.after,
+            "org_id=1; org_name=New Org; org_link=https://example.com"
+        );
+    }
+}
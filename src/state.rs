@@ -1,19 +1,36 @@
 use crate::cache::inmemory::InMemoryCache;
-use crate::config::Config;
+use crate::config::{Config, MailTransport, MediaStorage};
 use crate::database::turso::TursoDatabase;
+use crate::mailer::{maildir::MaildirMailer, smtp::SmtpMailer};
 use crate::model::axum::AppState;
 use crate::model::blogs::BlogsParams;
+use crate::model::projects::ProjectsParams;
 use crate::model::tags::TagsListParams;
 use crate::model::talks::TalksParams;
 use crate::repo::blog_tag_mappings::BlogTagMappingDisplayRepo;
 use crate::repo::blogs::BlogDisplayRepo;
+use crate::repo::projects::{ProjectCacheOperationRepo, ProjectDisplayRepo};
 use crate::repo::tags::TagDisplayRepo;
+use crate::repo::talk_tag_mappings::TalkTagMappingDisplayRepo;
 use crate::repo::talks::TalkDisplayRepo;
+use crate::storage::{gcs::GcsStorage, local::LocalStorage};
+use crate::usecase::audit_logs::AuditLogDBUseCase;
 use crate::usecase::auth::AuthDBUseCase;
+use crate::usecase::blog_drafts::BlogDraftDBUseCase;
 use crate::usecase::blog_tag_mappings::{BlogTagMappingCacheUseCase, BlogTagMappingDBUseCase};
 use crate::usecase::blogs::{BlogCacheUseCase, BlogDBUseCase};
+use crate::usecase::dashboard::{DashboardCacheUseCase, DashboardDBUseCase};
+use crate::usecase::link_checks::LinkCheckDBUseCase;
+use crate::usecase::mail::MailUseCase;
+use crate::usecase::media::{MediaDBUseCase, MediaStorageUseCase};
+use crate::usecase::organizations::OrganizationDBUseCase;
+use crate::usecase::profiles::{ProfileCacheUseCase, ProfileDBUseCase};
+use crate::usecase::projects::{ProjectCacheUseCase, ProjectDBUseCase};
+use crate::usecase::resume::{ResumeCacheUseCase, ResumeDBUseCase};
 use crate::usecase::tags::{TagCacheUseCase, TagDBUseCase};
+use crate::usecase::talk_tag_mappings::{TalkTagMappingCacheUseCase, TalkTagMappingDBUseCase};
 use crate::usecase::talks::{TalkCacheUseCase, TalkDBUseCase};
+use lettre::message::Mailbox;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tracing::{info, warn};
@@ -24,6 +41,8 @@ struct DBUsecases {
     talk: TalkDBUseCase,
     tag: TagDBUseCase,
     btm: BlogTagMappingDBUseCase,
+    ttm: TalkTagMappingDBUseCase,
+    project: ProjectDBUseCase,
 }
 #[derive(Debug)]
 struct CacheUsecases {
@@ -31,6 +50,8 @@ struct CacheUsecases {
     talk: TalkCacheUseCase,
     tag: TagCacheUseCase,
     btm: BlogTagMappingCacheUseCase,
+    ttm: TalkTagMappingCacheUseCase,
+    project: ProjectCacheUseCase,
 }
 
 /// Pre-fill InMemory cache before application starting
@@ -40,9 +61,12 @@ async fn prefill_inmemory_cache(db_usecases: DBUsecases, mut cache_usecases: Cac
         .find_talks(TalksParams {
             start: None,
             end: None,
+            year: None,
+            org: None,
+            tags: None,
         })
         .await;
-    match talks_opt {
+    match talks_opt.clone() {
         Some(talks) => {
             info!("Inserting talks cache");
             for talk in talks.talks {
@@ -114,6 +138,32 @@ async fn prefill_inmemory_cache(db_usecases: DBUsecases, mut cache_usecases: Cac
         }
     }
 
+    let talk_ids: Vec<i64> = talks_opt
+        .map(|talks| talks.talks.iter().map(|t| t.id).collect())
+        .unwrap_or_default();
+    for id in talk_ids {
+        let ttms_opt = db_usecases.ttm.find_by_talk_id(id).await;
+
+        match ttms_opt {
+            Some(ttms) => {
+                info!("Inserting talk tag mapping for talk id {} into cache", id);
+                for val in ttms.maps {
+                    let insert_opt = cache_usecases
+                        .ttm
+                        .operation
+                        .insert(val.talk_id, val.tag_id)
+                        .await;
+                    if insert_opt.is_none() {
+                        warn!("Failed to insert talk tag mapping with talk_id {} and tag_id {} into cache", val.talk_id, val.tag_id);
+                    }
+                }
+            }
+            None => {
+                warn!("Talk Tag Mapping for talk id {} in database are empty", id);
+            }
+        }
+    }
+
     let blog_ids: Vec<i64> = blogs_opt.unwrap().iter().map(|b| b.id).collect();
     for id in blog_ids {
         let btms_opt = db_usecases.btm.find_by_blog_id(id).await;
@@ -137,6 +187,31 @@ async fn prefill_inmemory_cache(db_usecases: DBUsecases, mut cache_usecases: Cac
             }
         }
     }
+
+    let projects_opt = db_usecases
+        .project
+        .find_projects(ProjectsParams {
+            start: None,
+            end: None,
+            status: None,
+            tags: None,
+        })
+        .await;
+    match projects_opt {
+        Some(projects) => {
+            info!("Inserting projects cache");
+            for project in projects.projects {
+                let id = project.id;
+                let insert_opt = cache_usecases.project.insert(project).await;
+                if insert_opt.is_none() {
+                    warn!("Failed to insert project id {} into cache", id);
+                }
+            }
+        }
+        None => {
+            warn!("Projects in database are empty");
+        }
+    }
 }
 
 /// Create In-Memory cache usecases
@@ -147,6 +222,11 @@ async fn create_inmemory_cache_usecases(
     Option<TalkCacheUseCase>,
     Option<TagCacheUseCase>,
     Option<BlogTagMappingCacheUseCase>,
+    Option<TalkTagMappingCacheUseCase>,
+    Option<ProjectCacheUseCase>,
+    Option<ProfileCacheUseCase>,
+    Option<ResumeCacheUseCase>,
+    Option<DashboardCacheUseCase>,
 ) {
     info!("Building In Memory usecases.");
     let cache_repo = InMemoryCache::new(config.cache_ttl.unwrap());
@@ -165,11 +245,89 @@ async fn create_inmemory_cache_usecases(
         )),
         Some(BlogTagMappingCacheUseCase::new(
             Box::new(cache_repo.clone()),
-            Box::new(cache_repo),
+            Box::new(cache_repo.clone()),
         )),
+        Some(TalkTagMappingCacheUseCase::new(
+            Box::new(cache_repo.clone()),
+            Box::new(cache_repo.clone()),
+        )),
+        Some(ProjectCacheUseCase::new(
+            Box::new(cache_repo.clone()),
+            Box::new(cache_repo.clone()),
+        )),
+        Some(ProfileCacheUseCase::new(
+            Box::new(cache_repo.clone()),
+            Box::new(cache_repo.clone()),
+        )),
+        Some(ResumeCacheUseCase::new(
+            Box::new(cache_repo.clone()),
+            Box::new(cache_repo.clone()),
+        )),
+        Some(DashboardCacheUseCase::new(Box::new(cache_repo))),
     )
 }
 
+/// Create media storage usecase
+/// `gcs` requires MEDIA_BUCKET. Otherwise, the media library is disabled.
+async fn create_media_storage_usecase(config: Config) -> Option<MediaStorageUseCase> {
+    match config.media_storage {
+        MediaStorage::Local => {
+            info!("Building Local media storage usecase.");
+            let storage_repo = LocalStorage::new(config.media_path).await;
+            Some(MediaStorageUseCase::new(Box::new(storage_repo)))
+        }
+        MediaStorage::Gcs => match config.media_bucket {
+            Some(bucket) => {
+                info!("Building GCS media storage usecase.");
+                let storage_repo = GcsStorage::new(bucket).await;
+                Some(MediaStorageUseCase::new(Box::new(storage_repo)))
+            }
+            None => {
+                warn!("MEDIA_BUCKET is not set. Media library is disabled.");
+                None
+            }
+        },
+    }
+}
+
+/// Create mail usecase
+/// Requires MAIL_FROM, and SMTP_HOST for `smtp`. Otherwise, emails are disabled.
+async fn create_mail_usecase(config: Config) -> Option<MailUseCase> {
+    let transport = config.mail_transport?;
+    let from = match config.mail_from.map(|from| from.parse::<Mailbox>()) {
+        Some(Ok(from)) => from,
+        Some(Err(err)) => {
+            warn!("MAIL_FROM is not a valid address. Emails are disabled. {err}");
+            return None;
+        }
+        None => {
+            warn!("MAIL_FROM is not set. Emails are disabled.");
+            return None;
+        }
+    };
+    match transport {
+        MailTransport::Smtp => match config.smtp_host {
+            Some(host) => {
+                info!("Building SMTP mail usecase.");
+                let credentials = config
+                    .smtp_username
+                    .map(|username| (username, config.secrets.smtp_password.unwrap_or_default()));
+                let mail_repo = SmtpMailer::new(from, host, config.smtp_port, credentials);
+                Some(MailUseCase::new(Box::new(mail_repo)))
+            }
+            None => {
+                warn!("SMTP_HOST is not set. Emails are disabled.");
+                None
+            }
+        },
+        MailTransport::File => {
+            info!("Building Maildir mail usecase.");
+            let mail_repo = MaildirMailer::new(from, config.mail_path).await;
+            Some(MailUseCase::new(Box::new(mail_repo)))
+        }
+    }
+}
+
 /// Create SQLite database usecases
 async fn create_sqlite_db_usecases(
     config: Config,
@@ -179,6 +337,16 @@ async fn create_sqlite_db_usecases(
     Option<TagDBUseCase>,
     Option<BlogTagMappingDBUseCase>,
     Option<AuthDBUseCase>,
+    Option<OrganizationDBUseCase>,
+    Option<TalkTagMappingDBUseCase>,
+    Option<ProjectDBUseCase>,
+    Option<ProfileDBUseCase>,
+    Option<ResumeDBUseCase>,
+    Option<MediaDBUseCase>,
+    Option<BlogDraftDBUseCase>,
+    Option<LinkCheckDBUseCase>,
+    Option<DashboardDBUseCase>,
+    Option<AuditLogDBUseCase>,
 ) {
     info!("Building SQLite usecases.");
     let db_repo = TursoDatabase::new(
@@ -204,7 +372,44 @@ async fn create_sqlite_db_usecases(
             Box::new(db_repo.clone()),
             Box::new(db_repo.clone()),
         )),
-        Some(AuthDBUseCase::new(Box::new(db_repo))),
+        Some(AuthDBUseCase::new(Box::new(db_repo.clone()))),
+        Some(OrganizationDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(TalkTagMappingDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(ProjectDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(ProfileDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(ResumeDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(MediaDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(BlogDraftDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(LinkCheckDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(DashboardDBUseCase::new(Box::new(db_repo.clone()))),
+        Some(AuditLogDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo),
+        )),
     )
 }
 
@@ -217,6 +422,16 @@ async fn create_turso_db_usecases(
     Option<TagDBUseCase>,
     Option<BlogTagMappingDBUseCase>,
     Option<AuthDBUseCase>,
+    Option<OrganizationDBUseCase>,
+    Option<TalkTagMappingDBUseCase>,
+    Option<ProjectDBUseCase>,
+    Option<ProfileDBUseCase>,
+    Option<ResumeDBUseCase>,
+    Option<MediaDBUseCase>,
+    Option<BlogDraftDBUseCase>,
+    Option<LinkCheckDBUseCase>,
+    Option<DashboardDBUseCase>,
+    Option<AuditLogDBUseCase>,
 ) {
     info!("Building Turso usecases.");
     let db_repo = TursoDatabase::new(
@@ -242,7 +457,44 @@ async fn create_turso_db_usecases(
             Box::new(db_repo.clone()),
             Box::new(db_repo.clone()),
         )),
-        Some(AuthDBUseCase::new(Box::new(db_repo))),
+        Some(AuthDBUseCase::new(Box::new(db_repo.clone()))),
+        Some(OrganizationDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(TalkTagMappingDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(ProjectDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(ProfileDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(ResumeDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(MediaDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(BlogDraftDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(LinkCheckDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo.clone()),
+        )),
+        Some(DashboardDBUseCase::new(Box::new(db_repo.clone()))),
+        Some(AuditLogDBUseCase::new(
+            Box::new(db_repo.clone()),
+            Box::new(db_repo),
+        )),
     )
 }
 
@@ -255,8 +507,24 @@ async fn create_turso_db_usecases(
 /// - TagDBUseCase
 /// - BlogTagMappingDBUseCase
 /// - AuthDBUseCase
+/// - OrganizationDBUseCase
+/// - TalkTagMappingDBUseCase
+/// - ProjectDBUseCase
+/// - ProfileDBUseCase
+/// - ResumeDBUseCase
+/// - MediaDBUseCase
+/// - BlogDraftDBUseCase
+/// - LinkCheckDBUseCase
+/// - DashboardDBUseCase
+/// - AuditLogDBUseCase
 /// - TalkCacheUseCase
 /// - TagCacheUseCase
+/// - TalkTagMappingCacheUseCase
+/// - ProjectCacheUseCase
+/// - ProfileCacheUseCase
+/// - ResumeCacheUseCase
+/// - DashboardCacheUseCase
+/// - MediaStorageUseCase
 ///
 /// To have a fully function portfolio web-app, it's sugessted to enable
 /// all usecases.
@@ -272,23 +540,51 @@ pub async fn state_factory(config: Config) -> AppState {
         config.data_source == "turso" && config.secrets.turso_auth_token.is_some();
     let cache_is_enabled = config.cache_type.is_some();
 
-    let (blog_db_uc, talk_db_uc, tag_db_uc, btm_db_uc, auth_db_uc) =
-        if data_source_is_configured_turso {
-            create_turso_db_usecases(config.clone()).await
-        } else if data_source_is_configured_sqlite {
-            create_sqlite_db_usecases(config.clone()).await
-        } else {
-            (None, None, None, None, None)
-        };
+    let (
+        blog_db_uc,
+        talk_db_uc,
+        tag_db_uc,
+        btm_db_uc,
+        auth_db_uc,
+        organization_db_uc,
+        ttm_db_uc,
+        project_db_uc,
+        profile_db_uc,
+        resume_db_uc,
+        media_db_uc,
+        blog_draft_db_uc,
+        link_check_db_uc,
+        dashboard_db_uc,
+        audit_log_db_uc,
+    ) = if data_source_is_configured_turso {
+        create_turso_db_usecases(config.clone()).await
+    } else if data_source_is_configured_sqlite {
+        create_sqlite_db_usecases(config.clone()).await
+    } else {
+        (
+            None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None,
+        )
+    };
 
     if blog_db_uc.is_none() {
         panic!("In version 0.3.5+, we drop the memory database support. Please use SQLite or Turso Database.");
     }
 
-    let (blog_cache_uc, talk_cache_uc, tag_cache_uc, btm_cache_uc) = if cache_is_enabled {
+    let (
+        blog_cache_uc,
+        talk_cache_uc,
+        tag_cache_uc,
+        btm_cache_uc,
+        ttm_cache_uc,
+        project_cache_uc,
+        profile_cache_uc,
+        resume_cache_uc,
+        dashboard_cache_uc,
+    ) = if cache_is_enabled {
         create_inmemory_cache_usecases(config.clone()).await
     } else {
-        (None, None, None, None)
+        (None, None, None, None, None, None, None, None, None)
     };
 
     if cache_is_enabled {
@@ -298,12 +594,16 @@ pub async fn state_factory(config: Config) -> AppState {
                 talk: talk_db_uc.clone().unwrap(),
                 tag: tag_db_uc.clone().unwrap(),
                 btm: btm_db_uc.clone().unwrap(),
+                ttm: ttm_db_uc.clone().unwrap(),
+                project: project_db_uc.clone().unwrap(),
             },
             CacheUsecases {
                 blog: blog_cache_uc.clone().unwrap(),
                 talk: talk_cache_uc.clone().unwrap(),
                 tag: tag_cache_uc.clone().unwrap(),
                 btm: btm_cache_uc.clone().unwrap(),
+                ttm: ttm_cache_uc.clone().unwrap(),
+                project: project_cache_uc.clone().unwrap(),
             },
         )
         .await;
@@ -314,10 +614,29 @@ pub async fn state_factory(config: Config) -> AppState {
     let tag_db_usecase = Arc::new(Mutex::new(tag_db_uc));
     let blog_tag_mapping_db_usecase = Arc::new(Mutex::new(btm_db_uc));
     let auth_db_usecase = Arc::new(Mutex::new(auth_db_uc));
+    let organization_db_usecase = Arc::new(Mutex::new(organization_db_uc));
+    let talk_tag_mapping_db_usecase = Arc::new(Mutex::new(ttm_db_uc));
+    let project_db_usecase = Arc::new(Mutex::new(project_db_uc));
+    let profile_db_usecase = Arc::new(Mutex::new(profile_db_uc));
+    let resume_db_usecase = Arc::new(Mutex::new(resume_db_uc));
+    let media_db_usecase = Arc::new(Mutex::new(media_db_uc));
+    let blog_draft_db_usecase = Arc::new(Mutex::new(blog_draft_db_uc));
+    let link_check_db_usecase = Arc::new(Mutex::new(link_check_db_uc));
+    let dashboard_db_usecase = Arc::new(Mutex::new(dashboard_db_uc));
+    let audit_log_db_usecase = Arc::new(Mutex::new(audit_log_db_uc));
     let talk_cache_usecase = Arc::new(Mutex::new(talk_cache_uc));
     let tag_cache_usecase = Arc::new(Mutex::new(tag_cache_uc));
     let blog_cache_usecase = Arc::new(Mutex::new(blog_cache_uc));
     let blog_tag_mapping_cache_usecase = Arc::new(Mutex::new(btm_cache_uc));
+    let talk_tag_mapping_cache_usecase = Arc::new(Mutex::new(ttm_cache_uc));
+    let project_cache_usecase = Arc::new(Mutex::new(project_cache_uc));
+    let profile_cache_usecase = Arc::new(Mutex::new(profile_cache_uc));
+    let resume_cache_usecase = Arc::new(Mutex::new(resume_cache_uc));
+    let dashboard_cache_usecase = Arc::new(Mutex::new(dashboard_cache_uc));
+    let media_storage_usecase = Arc::new(Mutex::new(
+        create_media_storage_usecase(config.clone()).await,
+    ));
+    let mail_usecase = Arc::new(Mutex::new(create_mail_usecase(config.clone()).await));
 
     AppState {
         config,
@@ -326,10 +645,27 @@ pub async fn state_factory(config: Config) -> AppState {
         tag_db_usecase,
         blog_tag_mapping_db_usecase,
         auth_db_usecase,
+        organization_db_usecase,
+        talk_tag_mapping_db_usecase,
+        project_db_usecase,
+        profile_db_usecase,
+        resume_db_usecase,
+        media_db_usecase,
+        blog_draft_db_usecase,
+        link_check_db_usecase,
+        dashboard_db_usecase,
+        audit_log_db_usecase,
         talk_cache_usecase,
         tag_cache_usecase,
         blog_cache_usecase,
         blog_tag_mapping_cache_usecase,
+        talk_tag_mapping_cache_usecase,
+        project_cache_usecase,
+        profile_cache_usecase,
+        resume_cache_usecase,
+        dashboard_cache_usecase,
+        media_storage_usecase,
+        mail_usecase,
     }
 }
 
@@ -348,11 +684,31 @@ mod test {
         let tag_uc = state.tag_db_usecase.lock().await.take();
         let blogtag_uc = state.blog_tag_mapping_db_usecase.lock().await.take();
         let auth_uc = state.auth_db_usecase.lock().await.take();
+        let organization_uc = state.organization_db_usecase.lock().await.take();
+        let talktag_uc = state.talk_tag_mapping_db_usecase.lock().await.take();
+        let project_uc = state.project_db_usecase.lock().await.take();
+        let profile_uc = state.profile_db_usecase.lock().await.take();
+        let resume_uc = state.resume_db_usecase.lock().await.take();
+        let media_uc = state.media_db_usecase.lock().await.take();
+        let blog_draft_uc = state.blog_draft_db_usecase.lock().await.take();
+        let link_check_uc = state.link_check_db_usecase.lock().await.take();
+        let dashboard_uc = state.dashboard_db_usecase.lock().await.take();
+        let audit_log_uc = state.audit_log_db_usecase.lock().await.take();
 
         assert!(talk_uc.is_some(), "TalkDBUseCase is None");
         assert!(tag_uc.is_some(), "TagDBUseCase is None");
         assert!(blogtag_uc.is_some(), "BlogTagMappingDBUseCase is None");
         assert!(auth_uc.is_some(), "AuthDBUseCase is None");
+        assert!(organization_uc.is_some(), "OrganizationDBUseCase is None");
+        assert!(talktag_uc.is_some(), "TalkTagMappingDBUseCase is None");
+        assert!(project_uc.is_some(), "ProjectDBUseCase is None");
+        assert!(profile_uc.is_some(), "ProfileDBUseCase is None");
+        assert!(resume_uc.is_some(), "ResumeDBUseCase is None");
+        assert!(media_uc.is_some(), "MediaDBUseCase is None");
+        assert!(blog_draft_uc.is_some(), "BlogDraftDBUseCase is None");
+        assert!(link_check_uc.is_some(), "LinkCheckDBUseCase is None");
+        assert!(dashboard_uc.is_some(), "DashboardDBUseCase is None");
+        assert!(audit_log_uc.is_some(), "AuditLogDBUseCase is None");
     }
 
     //#[tokio::test]
@@ -0,0 +1,100 @@
+use crate::model::media::MediaCommandStatus;
+use crate::repo::media::MediaStorageRepo;
+use async_trait::async_trait;
+use bytes::Bytes;
+use google_cloud_storage::client::{Storage, StorageControl};
+use tracing::{debug, error, info, warn};
+
+/// Store media files in a Google Cloud Storage (GCS) bucket
+#[derive(Clone)]
+pub struct GcsStorage {
+    pub client: Storage,
+    pub control: StorageControl,
+    pub bucket: String,
+}
+
+impl GcsStorage {
+    /// Create new GcsStorage for a bucket
+    pub async fn new(bucket: String) -> Self {
+        info!("Setting up GCS Storage for bucket {}", &bucket);
+        let client = Storage::builder()
+            .build()
+            .await
+            .expect("Failed to build GCS client");
+        let control = StorageControl::builder()
+            .build()
+            .await
+            .expect("Failed to build GCS control client");
+        Self {
+            client,
+            control,
+            bucket: format!("projects/_/buckets/{}", &bucket),
+        }
+    }
+}
+
+#[async_trait]
+impl MediaStorageRepo for GcsStorage {
+    async fn upload(
+        &mut self,
+        key: String,
+        content_type: String,
+        data: Bytes,
+    ) -> Option<MediaCommandStatus> {
+        debug!(
+            "Uploading {} ({}) into {}",
+            &key, &content_type, &self.bucket
+        );
+        let res = self
+            .client
+            .write_object(&self.bucket, &key, data)
+            .set_content_type(content_type)
+            .send_unbuffered()
+            .await;
+        match res {
+            Ok(_) => Some(MediaCommandStatus::Uploaded),
+            Err(err) => {
+                error!("Failed to upload media object {}. {}", &key, err);
+                None
+            }
+        }
+    }
+    async fn download(&self, key: String) -> Option<Bytes> {
+        debug!("Downloading {} from {}", &key, &self.bucket);
+        let mut reader = match self.client.read_object(&self.bucket, &key).send().await {
+            Ok(reader) => reader,
+            Err(err) => {
+                warn!("Failed to read media object {}. {}", &key, err);
+                return None;
+            }
+        };
+        let mut contents = Vec::new();
+        while let Some(chunk) = reader.next().await {
+            match chunk {
+                Ok(chunk) => contents.extend_from_slice(&chunk),
+                Err(err) => {
+                    error!("Failed to read media object {} chunk. {}", &key, err);
+                    return None;
+                }
+            }
+        }
+        Some(Bytes::from(contents))
+    }
+    async fn remove(&mut self, key: String) -> Option<MediaCommandStatus> {
+        debug!("Removing {} from {}", &key, &self.bucket);
+        let res = self
+            .control
+            .delete_object()
+            .set_bucket(&self.bucket)
+            .set_object(&key)
+            .send()
+            .await;
+        match res {
+            Ok(()) => Some(MediaCommandStatus::Removed),
+            Err(err) => {
+                error!("Failed to remove media object {}. {}", &key, err);
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::model::media::{is_valid_media_key, MediaCommandStatus};
+use crate::repo::media::MediaStorageRepo;
+use async_trait::async_trait;
+use bytes::Bytes;
+use std::path::PathBuf;
+use tracing::{debug, error, info, warn};
+
+/// Store media files in a local directory
+#[derive(Clone)]
+pub struct LocalStorage {
+    pub path: PathBuf,
+}
+
+impl LocalStorage {
+    /// Create new LocalStorage and its directory if it doesn't exist yet
+    pub async fn new(path: String) -> Self {
+        info!("Setting up Local Storage at {}", &path);
+        tokio::fs::create_dir_all(&path)
+            .await
+            .expect("Failed to create media directory.");
+        Self {
+            path: PathBuf::from(path),
+        }
+    }
+    /// Path of a media file. Return `None` for an invalid key
+    fn file_path(&self, key: &str) -> Option<PathBuf> {
+        if !is_valid_media_key(key) {
+            warn!("Media key {} is not valid.", key);
+            return None;
+        }
+        Some(self.path.join(key))
+    }
+}
+
+#[async_trait]
+impl MediaStorageRepo for LocalStorage {
+    async fn upload(
+        &mut self,
+        key: String,
+        content_type: String,
+        data: Bytes,
+    ) -> Option<MediaCommandStatus> {
+        let path = self.file_path(&key)?;
+        debug!("Writing {} ({}) into {:?}", &key, &content_type, &path);
+        match tokio::fs::write(&path, data).await {
+            Ok(()) => Some(MediaCommandStatus::Uploaded),
+            Err(err) => {
+                error!("Failed to write media file {:?}. {}", &path, err);
+                None
+            }
+        }
+    }
+    async fn download(&self, key: String) -> Option<Bytes> {
+        let path = self.file_path(&key)?;
+        debug!("Reading {:?}", &path);
+        match tokio::fs::read(&path).await {
+            Ok(data) => Some(Bytes::from(data)),
+            Err(err) => {
+                warn!("Failed to read media file {:?}. {}", &path, err);
+                None
+            }
+        }
+    }
+    async fn remove(&mut self, key: String) -> Option<MediaCommandStatus> {
+        let path = self.file_path(&key)?;
+        debug!("Removing {:?}", &path);
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Some(MediaCommandStatus::Removed),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                warn!("Media file {:?} was already removed.", &path);
+                Some(MediaCommandStatus::Removed)
+            }
+            Err(err) => {
+                error!("Failed to remove media file {:?}. {}", &path, err);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_local_storage_upload_download_and_remove() {
+        let path = std::env::temp_dir().join(format!("media-test-{}", std::process::id()));
+        let mut storage = LocalStorage::new(path.to_string_lossy().to_string()).await;
+        let key = "20261019120000000-cat.txt".to_string();
+
+        let res = storage
+            .upload(key.clone(), "text/plain".to_string(), Bytes::from("meow"))
+            .await;
+        assert_eq!(res, Some(MediaCommandStatus::Uploaded));
+        assert_eq!(
+            storage.download(key.clone()).await,
+            Some(Bytes::from("meow"))
+        );
+
+        let res = storage.remove(key.clone()).await;
+        assert_eq!(res, Some(MediaCommandStatus::Removed));
+        assert_eq!(storage.download(key).await, None);
+
+        // Keys are never resolved outside of the storage directory
+        assert_eq!(storage.download("../secret".to_string()).await, None);
+        let _ = tokio::fs::remove_dir_all(path).await;
+    }
+}
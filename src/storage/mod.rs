@@ -0,0 +1,2 @@
+pub mod gcs;
+pub mod local;
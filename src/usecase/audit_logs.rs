@@ -0,0 +1,54 @@
+use crate::model::audit_logs::{AuditLog, AuditLogCommandStatus, AuditLogs, AuditLogsParams};
+use crate::repo::audit_logs::*;
+use async_trait::async_trait;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct AuditLogDBUseCase {
+    pub audit_log_display_repo: Box<dyn AuditLogDisplayRepo + Send + Sync>,
+    pub audit_log_operation_repo: Box<dyn AuditLogOperationRepo + Send + Sync>,
+}
+
+impl Debug for dyn AuditLogDisplayRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AuditLogDisplayRepo")
+    }
+}
+
+impl Debug for dyn AuditLogOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AuditLogOperationRepo")
+    }
+}
+
+#[async_trait]
+impl AuditLogDisplayRepo for AuditLogDBUseCase {
+    async fn find_audit_logs(
+        &self,
+        params: AuditLogsParams,
+        limit: Option<i64>,
+    ) -> Option<AuditLogs> {
+        self.audit_log_display_repo
+            .find_audit_logs(params, limit)
+            .await
+    }
+}
+
+#[async_trait]
+impl AuditLogOperationRepo for AuditLogDBUseCase {
+    async fn add(&mut self, audit_log: AuditLog) -> Option<AuditLogCommandStatus> {
+        self.audit_log_operation_repo.add(audit_log).await
+    }
+}
+
+impl AuditLogDBUseCase {
+    pub fn new(
+        audit_log_display_repo: Box<dyn AuditLogDisplayRepo + Send + Sync>,
+        audit_log_operation_repo: Box<dyn AuditLogOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            audit_log_display_repo,
+            audit_log_operation_repo,
+        }
+    }
+}
@@ -1,4 +1,9 @@
-use crate::model::auth::{Session, SessionCommandStatus, User, UserCommandStatus};
+use crate::model::auth::{
+    EmailToken, EmailTokenCommandStatus, LoginActivity, LoginActivityCommandStatus, LoginLockout,
+    LoginLockoutCommandStatus, Session, SessionCommandStatus, User, UserCommandStatus, UserRole,
+    Users,
+};
+use crate::model::totp::{UserTotp, UserTotpCommandStatus};
 use crate::repo::auth::AuthRepo;
 use async_trait::async_trait;
 use core::fmt::Debug;
@@ -22,40 +27,127 @@ impl AuthRepo for AuthDBUseCase {
     async fn find_user_by_email(&self, email: String) -> Option<User> {
         self.auth_repo.find_user_by_email(email).await
     }
+    async fn find_users(&self) -> Option<Users> {
+        self.auth_repo.find_users().await
+    }
     async fn add_user(
         &self,
         id: String,
         email: String,
         hpass: String,
+        role: UserRole,
     ) -> Option<UserCommandStatus> {
-        self.auth_repo.add_user(id, email, hpass).await
+        self.auth_repo.add_user(id, email, hpass, role).await
     }
     async fn update_user(
         &self,
         id: String,
         email: Option<String>,
         hpass: Option<String>,
+        role: Option<UserRole>,
     ) -> Option<UserCommandStatus> {
-        self.auth_repo.update_user(id, email, hpass).await
+        self.auth_repo.update_user(id, email, hpass, role).await
     }
     async fn delete_user(&self, id: String) -> Option<UserCommandStatus> {
         self.auth_repo.delete_user(id).await
     }
+    async fn find_user_totp(&self, user_id: String) -> Option<UserTotp> {
+        self.auth_repo.find_user_totp(user_id).await
+    }
+    async fn upsert_user_totp(&self, user_totp: UserTotp) -> Option<UserTotpCommandStatus> {
+        self.auth_repo.upsert_user_totp(user_totp).await
+    }
+    async fn delete_user_totp(&self, user_id: String) -> Option<UserTotpCommandStatus> {
+        self.auth_repo.delete_user_totp(user_id).await
+    }
     async fn find_session(&self, id: String) -> Option<Session> {
         self.auth_repo.find_session(id).await
     }
-    async fn add_session(
+    async fn find_user_sessions(&self, user_id: String) -> Option<Vec<Session>> {
+        self.auth_repo.find_user_sessions(user_id).await
+    }
+    async fn add_session(&self, session: Session) -> Option<SessionCommandStatus> {
+        self.auth_repo.add_session(session).await
+    }
+    async fn update_session_last_seen(
+        &self,
+        id: String,
+        last_seen_at: String,
+    ) -> Option<SessionCommandStatus> {
+        self.auth_repo
+            .update_session_last_seen(id, last_seen_at)
+            .await
+    }
+    async fn extend_session(
         &self,
         id: String,
-        user_id: String,
-        token: String,
         expire: String,
+        last_seen_at: String,
     ) -> Option<SessionCommandStatus> {
-        self.auth_repo.add_session(id, user_id, token, expire).await
+        self.auth_repo
+            .extend_session(id, expire, last_seen_at)
+            .await
     }
     async fn delete_session(&self, id: String) -> Option<SessionCommandStatus> {
         self.auth_repo.delete_session(id).await
     }
+    async fn delete_user_sessions(&self, user_id: String) -> Option<SessionCommandStatus> {
+        self.auth_repo.delete_user_sessions(user_id).await
+    }
+    async fn delete_expired_sessions(&self, now: String) -> Option<SessionCommandStatus> {
+        self.auth_repo.delete_expired_sessions(now).await
+    }
+    async fn add_login_activity(
+        &self,
+        login_activity: LoginActivity,
+    ) -> Option<LoginActivityCommandStatus> {
+        self.auth_repo.add_login_activity(login_activity).await
+    }
+    async fn find_login_activities(&self, limit: i64) -> Option<Vec<LoginActivity>> {
+        self.auth_repo.find_login_activities(limit).await
+    }
+    async fn find_email_login_activities(
+        &self,
+        email: String,
+        limit: i64,
+    ) -> Option<Vec<LoginActivity>> {
+        self.auth_repo
+            .find_email_login_activities(email, limit)
+            .await
+    }
+    async fn find_login_lockout(&self, email: String) -> Option<LoginLockout> {
+        self.auth_repo.find_login_lockout(email).await
+    }
+    async fn add_failed_login(&self, email: String) -> Option<u64> {
+        self.auth_repo.add_failed_login(email).await
+    }
+    async fn update_login_lockout_until(
+        &self,
+        email: String,
+        locked_until: String,
+    ) -> Option<LoginLockoutCommandStatus> {
+        self.auth_repo
+            .update_login_lockout_until(email, locked_until)
+            .await
+    }
+    async fn delete_login_lockout(&self, email: String) -> Option<LoginLockoutCommandStatus> {
+        self.auth_repo.delete_login_lockout(email).await
+    }
+    async fn find_email_token(&self, id: String) -> Option<EmailToken> {
+        self.auth_repo.find_email_token(id).await
+    }
+    async fn add_email_token(&self, email_token: EmailToken) -> Option<EmailTokenCommandStatus> {
+        self.auth_repo.add_email_token(email_token).await
+    }
+    async fn consume_email_token(&self, id: String) -> Option<EmailToken> {
+        self.auth_repo.consume_email_token(id).await
+    }
+    async fn delete_user_email_tokens(&self, user_id: String) -> Option<EmailTokenCommandStatus> {
+        self.auth_repo.delete_user_email_tokens(user_id).await
+    }
+    async fn delete_expired_email_tokens(&self, now: String) -> Option<EmailTokenCommandStatus> {
+        self.auth_repo.delete_expired_email_tokens(now).await
+    }
 }
 
 impl AuthDBUseCase {
@@ -0,0 +1,53 @@
+use crate::model::blog_drafts::{BlogDraft, BlogDraftCommandStatus};
+use crate::repo::blog_drafts::*;
+use async_trait::async_trait;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct BlogDraftDBUseCase {
+    pub blog_draft_display_repo: Box<dyn BlogDraftDisplayRepo + Send + Sync>,
+    pub blog_draft_operation_repo: Box<dyn BlogDraftOperationRepo + Send + Sync>,
+}
+
+impl Debug for dyn BlogDraftDisplayRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BlogDraftDisplayRepo")
+    }
+}
+
+impl Debug for dyn BlogDraftOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BlogDraftOperationRepo")
+    }
+}
+
+#[async_trait]
+impl BlogDraftDisplayRepo for BlogDraftDBUseCase {
+    async fn find(&self, blog_id: i64, user_id: String) -> Option<BlogDraft> {
+        self.blog_draft_display_repo.find(blog_id, user_id).await
+    }
+}
+
+#[async_trait]
+impl BlogDraftOperationRepo for BlogDraftDBUseCase {
+    async fn save(&mut self, blog_draft: BlogDraft) -> Option<BlogDraftCommandStatus> {
+        self.blog_draft_operation_repo.save(blog_draft).await
+    }
+    async fn delete(&mut self, blog_id: i64, user_id: String) -> Option<BlogDraftCommandStatus> {
+        self.blog_draft_operation_repo
+            .delete(blog_id, user_id)
+            .await
+    }
+}
+
+impl BlogDraftDBUseCase {
+    pub fn new(
+        blog_draft_display_repo: Box<dyn BlogDraftDisplayRepo + Send + Sync>,
+        blog_draft_operation_repo: Box<dyn BlogDraftOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            blog_draft_display_repo,
+            blog_draft_operation_repo,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::model::dashboard::{CacheStats, ContentStats, RecentUpdate, TagUsages};
+use crate::repo::dashboard::*;
+use async_trait::async_trait;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct DashboardDBUseCase {
+    pub dashboard_display_repo: Box<dyn DashboardDisplayRepo + Send + Sync>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DashboardCacheUseCase {
+    pub dashboard_cache_repo: Box<dyn DashboardCacheRepo + Send + Sync>,
+}
+
+impl Debug for dyn DashboardDisplayRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DashboardDisplayRepo")
+    }
+}
+
+impl Debug for dyn DashboardCacheRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DashboardCacheRepo")
+    }
+}
+
+#[async_trait]
+impl DashboardDisplayRepo for DashboardDBUseCase {
+    async fn find_content_stats(&self) -> Option<ContentStats> {
+        self.dashboard_display_repo.find_content_stats().await
+    }
+    async fn find_tag_usages(&self) -> Option<TagUsages> {
+        self.dashboard_display_repo.find_tag_usages().await
+    }
+    async fn find_recent_updates(&self, limit: i64) -> Option<Vec<RecentUpdate>> {
+        self.dashboard_display_repo.find_recent_updates(limit).await
+    }
+}
+
+#[async_trait]
+impl DashboardCacheRepo for DashboardCacheUseCase {
+    async fn find_cache_stats(&self) -> Option<CacheStats> {
+        self.dashboard_cache_repo.find_cache_stats().await
+    }
+}
+
+impl DashboardDBUseCase {
+    pub fn new(dashboard_display_repo: Box<dyn DashboardDisplayRepo + Send + Sync>) -> Self {
+        Self {
+            dashboard_display_repo,
+        }
+    }
+}
+
+impl DashboardCacheUseCase {
+    pub fn new(dashboard_cache_repo: Box<dyn DashboardCacheRepo + Send + Sync>) -> Self {
+        Self {
+            dashboard_cache_repo,
+        }
+    }
+}
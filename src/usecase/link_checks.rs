@@ -0,0 +1,48 @@
+use crate::model::link_checks::{LinkCheckCommandStatus, LinkChecks};
+use crate::repo::link_checks::*;
+use async_trait::async_trait;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct LinkCheckDBUseCase {
+    pub link_check_display_repo: Box<dyn LinkCheckDisplayRepo + Send + Sync>,
+    pub link_check_operation_repo: Box<dyn LinkCheckOperationRepo + Send + Sync>,
+}
+
+impl Debug for dyn LinkCheckDisplayRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LinkCheckDisplayRepo")
+    }
+}
+
+impl Debug for dyn LinkCheckOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "LinkCheckOperationRepo")
+    }
+}
+
+#[async_trait]
+impl LinkCheckDisplayRepo for LinkCheckDBUseCase {
+    async fn find_link_checks(&self) -> Option<LinkChecks> {
+        self.link_check_display_repo.find_link_checks().await
+    }
+}
+
+#[async_trait]
+impl LinkCheckOperationRepo for LinkCheckDBUseCase {
+    async fn replace(&mut self, link_checks: LinkChecks) -> Option<LinkCheckCommandStatus> {
+        self.link_check_operation_repo.replace(link_checks).await
+    }
+}
+
+impl LinkCheckDBUseCase {
+    pub fn new(
+        link_check_display_repo: Box<dyn LinkCheckDisplayRepo + Send + Sync>,
+        link_check_operation_repo: Box<dyn LinkCheckOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            link_check_display_repo,
+            link_check_operation_repo,
+        }
+    }
+}
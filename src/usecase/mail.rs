@@ -0,0 +1,28 @@
+use crate::model::mail::{Mail, MailCommandStatus};
+use crate::repo::mail::MailRepo;
+use async_trait::async_trait;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct MailUseCase {
+    pub mail_repo: Box<dyn MailRepo + Send + Sync>,
+}
+
+impl Debug for dyn MailRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MailRepo")
+    }
+}
+
+#[async_trait]
+impl MailRepo for MailUseCase {
+    async fn send(&self, mail: Mail) -> Option<MailCommandStatus> {
+        self.mail_repo.send(mail).await
+    }
+}
+
+impl MailUseCase {
+    pub fn new(mail_repo: Box<dyn MailRepo + Send + Sync>) -> Self {
+        Self { mail_repo }
+    }
+}
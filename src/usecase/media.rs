@@ -0,0 +1,92 @@
+use crate::model::media::{MediaCommandStatus, MediaFile, MediaFiles, MediaFilesParams};
+use crate::repo::media::*;
+use async_trait::async_trait;
+use bytes::Bytes;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct MediaDBUseCase {
+    pub media_display_repo: Box<dyn MediaDisplayRepo + Send + Sync>,
+    pub media_operation_repo: Box<dyn MediaOperationRepo + Send + Sync>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MediaStorageUseCase {
+    pub media_storage_repo: Box<dyn MediaStorageRepo + Send + Sync>,
+}
+
+impl Debug for dyn MediaDisplayRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MediaDisplayRepo")
+    }
+}
+
+impl Debug for dyn MediaOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MediaOperationRepo")
+    }
+}
+
+impl Debug for dyn MediaStorageRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MediaStorageRepo")
+    }
+}
+
+#[async_trait]
+impl MediaDisplayRepo for MediaDBUseCase {
+    async fn find(&self, key: String) -> Option<MediaFile> {
+        self.media_display_repo.find(key).await
+    }
+    async fn find_media_files(&self, params: MediaFilesParams) -> Option<MediaFiles> {
+        self.media_display_repo.find_media_files(params).await
+    }
+}
+
+#[async_trait]
+impl MediaOperationRepo for MediaDBUseCase {
+    async fn add(&mut self, media_file: MediaFile) -> Option<MediaCommandStatus> {
+        self.media_operation_repo.add(media_file).await
+    }
+    async fn delete(&mut self, key: String) -> Option<MediaCommandStatus> {
+        self.media_operation_repo.delete(key).await
+    }
+}
+
+impl MediaDBUseCase {
+    pub fn new(
+        media_display_repo: Box<dyn MediaDisplayRepo + Send + Sync>,
+        media_operation_repo: Box<dyn MediaOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            media_display_repo,
+            media_operation_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl MediaStorageRepo for MediaStorageUseCase {
+    async fn upload(
+        &mut self,
+        key: String,
+        content_type: String,
+        data: Bytes,
+    ) -> Option<MediaCommandStatus> {
+        self.media_storage_repo
+            .upload(key, content_type, data)
+            .await
+    }
+    async fn download(&self, key: String) -> Option<Bytes> {
+        self.media_storage_repo.download(key).await
+    }
+    async fn remove(&mut self, key: String) -> Option<MediaCommandStatus> {
+        self.media_storage_repo.remove(key).await
+    }
+}
+
+impl MediaStorageUseCase {
+    pub fn new(media_storage_repo: Box<dyn MediaStorageRepo + Send + Sync>) -> Self {
+        Self { media_storage_repo }
+    }
+}
@@ -1,5 +1,16 @@
+pub mod audit_logs;
 pub mod auth;
+pub mod blog_drafts;
 pub mod blog_tag_mappings;
 pub mod blogs;
+pub mod dashboard;
+pub mod link_checks;
+pub mod mail;
+pub mod media;
+pub mod organizations;
+pub mod profiles;
+pub mod projects;
+pub mod resume;
 pub mod tags;
+pub mod talk_tag_mappings;
 pub mod talks;
@@ -0,0 +1,82 @@
+use crate::model::organizations::{
+    Organization, OrganizationCommandStatus, Organizations, OrganizationsParams,
+};
+use crate::repo::organizations::*;
+use async_trait::async_trait;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct OrganizationDBUseCase {
+    pub organization_display_repo: Box<dyn OrganizationDisplayRepo + Send + Sync>,
+    pub organization_operation_repo: Box<dyn OrganizationOperationRepo + Send + Sync>,
+}
+
+impl Debug for dyn OrganizationDisplayRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OrganizationDisplayRepo")
+    }
+}
+
+impl Debug for dyn OrganizationOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OrganizationOperationRepo")
+    }
+}
+
+#[async_trait]
+impl OrganizationDisplayRepo for OrganizationDBUseCase {
+    async fn find(&self, id: i64) -> Option<Organization> {
+        self.organization_display_repo.find(id).await
+    }
+    async fn find_organizations(&self, params: OrganizationsParams) -> Option<Organizations> {
+        self.organization_display_repo
+            .find_organizations(params)
+            .await
+    }
+}
+
+#[async_trait]
+impl OrganizationOperationRepo for OrganizationDBUseCase {
+    async fn get_new_id(&self) -> Option<i64> {
+        self.organization_operation_repo.get_new_id().await
+    }
+    async fn add(
+        &mut self,
+        id: i64,
+        name: String,
+        url: Option<String>,
+        logo: Option<String>,
+        description: Option<String>,
+    ) -> Option<OrganizationCommandStatus> {
+        self.organization_operation_repo
+            .add(id, name, url, logo, description)
+            .await
+    }
+    async fn update(
+        &mut self,
+        id: i64,
+        name: String,
+        url: Option<String>,
+        logo: Option<String>,
+        description: Option<String>,
+    ) -> Option<OrganizationCommandStatus> {
+        self.organization_operation_repo
+            .update(id, name, url, logo, description)
+            .await
+    }
+    async fn delete(&mut self, id: i64) -> Option<OrganizationCommandStatus> {
+        self.organization_operation_repo.delete(id).await
+    }
+}
+
+impl OrganizationDBUseCase {
+    pub fn new(
+        organization_display_repo: Box<dyn OrganizationDisplayRepo + Send + Sync>,
+        organization_operation_repo: Box<dyn OrganizationOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            organization_display_repo,
+            organization_operation_repo,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::model::profiles::{Profile, ProfileCommandStatus, Profiles, ProfilesParams};
+use crate::repo::profiles::*;
+use async_trait::async_trait;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct ProfileDBUseCase {
+    pub profile_display_repo: Box<dyn ProfileDisplayRepo + Send + Sync>,
+    pub profile_operation_repo: Box<dyn ProfileOperationRepo + Send + Sync>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProfileCacheUseCase {
+    pub profile_display_repo: Box<dyn ProfileDisplayRepo + Send + Sync>,
+    pub profile_operation_repo: Box<dyn ProfileCacheOperationRepo + Send + Sync>,
+}
+
+impl Debug for dyn ProfileDisplayRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProfileDisplayRepo")
+    }
+}
+
+impl Debug for dyn ProfileOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProfileOperationRepo")
+    }
+}
+
+impl Debug for dyn ProfileCacheOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProfileCacheOperationRepo")
+    }
+}
+
+#[async_trait]
+impl ProfileDisplayRepo for ProfileDBUseCase {
+    async fn find(&self, version: String) -> Option<Profile> {
+        self.profile_display_repo.find(version).await
+    }
+    async fn find_latest(&self) -> Option<Profile> {
+        self.profile_display_repo.find_latest().await
+    }
+    async fn find_profiles(&self, params: ProfilesParams) -> Option<Profiles> {
+        self.profile_display_repo.find_profiles(params).await
+    }
+}
+
+#[async_trait]
+impl ProfileOperationRepo for ProfileDBUseCase {
+    async fn add(&mut self, profile: Profile) -> Option<ProfileCommandStatus> {
+        self.profile_operation_repo.add(profile).await
+    }
+}
+
+impl ProfileDBUseCase {
+    pub fn new(
+        profile_display_repo: Box<dyn ProfileDisplayRepo + Send + Sync>,
+        profile_operation_repo: Box<dyn ProfileOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            profile_display_repo,
+            profile_operation_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl ProfileDisplayRepo for ProfileCacheUseCase {
+    async fn find(&self, version: String) -> Option<Profile> {
+        self.profile_display_repo.find(version).await
+    }
+    async fn find_latest(&self) -> Option<Profile> {
+        self.profile_display_repo.find_latest().await
+    }
+    async fn find_profiles(&self, params: ProfilesParams) -> Option<Profiles> {
+        self.profile_display_repo.find_profiles(params).await
+    }
+}
+
+#[async_trait]
+impl ProfileCacheOperationRepo for ProfileCacheUseCase {
+    async fn insert(&mut self, profile: Profile) -> Option<ProfileCommandStatus> {
+        self.profile_operation_repo.insert(profile).await
+    }
+    async fn invalidate(&mut self, version: String) -> Option<ProfileCommandStatus> {
+        self.profile_operation_repo.invalidate(version).await
+    }
+}
+
+impl ProfileCacheUseCase {
+    pub fn new(
+        profile_display_repo: Box<dyn ProfileDisplayRepo + Send + Sync>,
+        profile_operation_repo: Box<dyn ProfileCacheOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            profile_display_repo,
+            profile_operation_repo,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::model::projects::{Project, ProjectCommandStatus, Projects, ProjectsParams};
+use crate::repo::projects::*;
+use async_trait::async_trait;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct ProjectDBUseCase {
+    pub project_display_repo: Box<dyn ProjectDisplayRepo + Send + Sync>,
+    pub project_operation_repo: Box<dyn ProjectOperationRepo + Send + Sync>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProjectCacheUseCase {
+    pub project_display_repo: Box<dyn ProjectDisplayRepo + Send + Sync>,
+    pub project_operation_repo: Box<dyn ProjectCacheOperationRepo + Send + Sync>,
+}
+
+impl Debug for dyn ProjectDisplayRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProjectDisplayRepo")
+    }
+}
+
+impl Debug for dyn ProjectOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProjectOperationRepo")
+    }
+}
+
+impl Debug for dyn ProjectCacheOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProjectCacheOperationRepo")
+    }
+}
+
+#[async_trait]
+impl ProjectDisplayRepo for ProjectDBUseCase {
+    async fn find(&self, id: i64) -> Option<Project> {
+        self.project_display_repo.find(id).await
+    }
+    async fn find_projects(&self, params: ProjectsParams) -> Option<Projects> {
+        self.project_display_repo.find_projects(params).await
+    }
+}
+
+#[async_trait]
+impl ProjectOperationRepo for ProjectDBUseCase {
+    async fn get_new_id(&self) -> Option<i64> {
+        self.project_operation_repo.get_new_id().await
+    }
+    async fn add(&mut self, project: Project) -> Option<ProjectCommandStatus> {
+        self.project_operation_repo.add(project).await
+    }
+    async fn update(&mut self, project: Project) -> Option<ProjectCommandStatus> {
+        self.project_operation_repo.update(project).await
+    }
+    async fn delete(&mut self, id: i64) -> Option<ProjectCommandStatus> {
+        self.project_operation_repo.delete(id).await
+    }
+}
+
+impl ProjectDBUseCase {
+    pub fn new(
+        project_display_repo: Box<dyn ProjectDisplayRepo + Send + Sync>,
+        project_operation_repo: Box<dyn ProjectOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            project_display_repo,
+            project_operation_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl ProjectDisplayRepo for ProjectCacheUseCase {
+    async fn find(&self, id: i64) -> Option<Project> {
+        self.project_display_repo.find(id).await
+    }
+    async fn find_projects(&self, params: ProjectsParams) -> Option<Projects> {
+        self.project_display_repo.find_projects(params).await
+    }
+}
+
+#[async_trait]
+impl ProjectCacheOperationRepo for ProjectCacheUseCase {
+    async fn insert(&mut self, project: Project) -> Option<ProjectCommandStatus> {
+        self.project_operation_repo.insert(project).await
+    }
+    async fn invalidate(&mut self, id: i64) -> Option<ProjectCommandStatus> {
+        self.project_operation_repo.invalidate(id).await
+    }
+}
+
+impl ProjectCacheUseCase {
+    pub fn new(
+        project_display_repo: Box<dyn ProjectDisplayRepo + Send + Sync>,
+        project_operation_repo: Box<dyn ProjectCacheOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            project_display_repo,
+            project_operation_repo,
+        }
+    }
+}
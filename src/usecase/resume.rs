@@ -0,0 +1,89 @@
+use crate::model::resume::{Resume, ResumeCommandStatus};
+use crate::repo::resume::*;
+use async_trait::async_trait;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct ResumeDBUseCase {
+    pub resume_display_repo: Box<dyn ResumeDisplayRepo + Send + Sync>,
+    pub resume_operation_repo: Box<dyn ResumeOperationRepo + Send + Sync>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ResumeCacheUseCase {
+    pub resume_display_repo: Box<dyn ResumeDisplayRepo + Send + Sync>,
+    pub resume_operation_repo: Box<dyn ResumeCacheOperationRepo + Send + Sync>,
+}
+
+impl Debug for dyn ResumeDisplayRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ResumeDisplayRepo")
+    }
+}
+
+impl Debug for dyn ResumeOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ResumeOperationRepo")
+    }
+}
+
+impl Debug for dyn ResumeCacheOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ResumeCacheOperationRepo")
+    }
+}
+
+#[async_trait]
+impl ResumeDisplayRepo for ResumeDBUseCase {
+    async fn find(&self) -> Option<Resume> {
+        self.resume_display_repo.find().await
+    }
+}
+
+#[async_trait]
+impl ResumeOperationRepo for ResumeDBUseCase {
+    async fn save(&mut self, resume: Resume) -> Option<ResumeCommandStatus> {
+        self.resume_operation_repo.save(resume).await
+    }
+}
+
+impl ResumeDBUseCase {
+    pub fn new(
+        resume_display_repo: Box<dyn ResumeDisplayRepo + Send + Sync>,
+        resume_operation_repo: Box<dyn ResumeOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            resume_display_repo,
+            resume_operation_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl ResumeDisplayRepo for ResumeCacheUseCase {
+    async fn find(&self) -> Option<Resume> {
+        self.resume_display_repo.find().await
+    }
+}
+
+#[async_trait]
+impl ResumeCacheOperationRepo for ResumeCacheUseCase {
+    async fn insert(&mut self, resume: Resume) -> Option<ResumeCommandStatus> {
+        self.resume_operation_repo.insert(resume).await
+    }
+    async fn invalidate(&mut self) -> Option<ResumeCommandStatus> {
+        self.resume_operation_repo.invalidate().await
+    }
+}
+
+impl ResumeCacheUseCase {
+    pub fn new(
+        resume_display_repo: Box<dyn ResumeDisplayRepo + Send + Sync>,
+        resume_operation_repo: Box<dyn ResumeCacheOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self {
+            resume_display_repo,
+            resume_operation_repo,
+        }
+    }
+}
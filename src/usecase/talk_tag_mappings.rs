@@ -0,0 +1,108 @@
+use crate::model::talk_tag_mappings::{TalkTagMappingCommandStatus, TalkTagMappings};
+use crate::repo::talk_tag_mappings::*;
+use async_trait::async_trait;
+use core::fmt::Debug;
+
+#[derive(Clone, Debug)]
+pub struct TalkTagMappingDBUseCase {
+    pub display: Box<dyn TalkTagMappingDisplayRepo + Send + Sync>,
+    pub operation: Box<dyn TalkTagMappingOperationRepo + Send + Sync>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TalkTagMappingCacheUseCase {
+    pub display: Box<dyn TalkTagMappingDisplayRepo + Send + Sync>,
+    pub operation: Box<dyn TalkTagMappingCacheOperationRepo + Send + Sync>,
+}
+
+impl Debug for dyn TalkTagMappingDisplayRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TalkTagMappingDisplayRepo")
+    }
+}
+
+impl Debug for dyn TalkTagMappingOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TalkTagMappingOperationRepo")
+    }
+}
+
+impl Debug for dyn TalkTagMappingCacheOperationRepo + Send + Sync {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TalkTagMappingCacheOperationRepo")
+    }
+}
+
+#[async_trait]
+impl TalkTagMappingDisplayRepo for TalkTagMappingDBUseCase {
+    async fn find_by_talk_id(&self, talk_id: i64) -> Option<TalkTagMappings> {
+        self.display.find_by_talk_id(talk_id).await
+    }
+    async fn find_by_tag_id(&self, tag_id: i64) -> Option<TalkTagMappings> {
+        self.display.find_by_tag_id(tag_id).await
+    }
+}
+
+#[async_trait]
+impl TalkTagMappingOperationRepo for TalkTagMappingDBUseCase {
+    async fn add(&mut self, talk_id: i64, tag_id: i64) -> Option<TalkTagMappingCommandStatus> {
+        self.operation.add(talk_id, tag_id).await
+    }
+    async fn delete_by_talk_id(&mut self, talk_id: i64) -> Option<TalkTagMappingCommandStatus> {
+        self.operation.delete_by_talk_id(talk_id).await
+    }
+    async fn delete_by_talk_id_and_tag_id(
+        &mut self,
+        talk_id: i64,
+        tag_id: i64,
+    ) -> Option<TalkTagMappingCommandStatus> {
+        self.operation
+            .delete_by_talk_id_and_tag_id(talk_id, tag_id)
+            .await
+    }
+}
+
+#[async_trait]
+impl TalkTagMappingDisplayRepo for TalkTagMappingCacheUseCase {
+    async fn find_by_talk_id(&self, talk_id: i64) -> Option<TalkTagMappings> {
+        self.display.find_by_talk_id(talk_id).await
+    }
+    async fn find_by_tag_id(&self, tag_id: i64) -> Option<TalkTagMappings> {
+        self.display.find_by_tag_id(tag_id).await
+    }
+}
+
+#[async_trait]
+impl TalkTagMappingCacheOperationRepo for TalkTagMappingCacheUseCase {
+    async fn insert(&mut self, talk_id: i64, tag_id: i64) -> Option<TalkTagMappingCommandStatus> {
+        self.operation.insert(talk_id, tag_id).await
+    }
+    async fn invalidate(
+        &mut self,
+        talk_id: i64,
+        tag_id: i64,
+    ) -> Option<TalkTagMappingCommandStatus> {
+        self.operation.invalidate(talk_id, tag_id).await
+    }
+    async fn invalidate_by_talk_id(&mut self, talk_id: i64) -> Option<TalkTagMappingCommandStatus> {
+        self.operation.invalidate_by_talk_id(talk_id).await
+    }
+}
+
+impl TalkTagMappingDBUseCase {
+    pub fn new(
+        display: Box<dyn TalkTagMappingDisplayRepo + Send + Sync>,
+        operation: Box<dyn TalkTagMappingOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self { display, operation }
+    }
+}
+
+impl TalkTagMappingCacheUseCase {
+    pub fn new(
+        display: Box<dyn TalkTagMappingDisplayRepo + Send + Sync>,
+        operation: Box<dyn TalkTagMappingCacheOperationRepo + Send + Sync>,
+    ) -> Self {
+        Self { display, operation }
+    }
+}
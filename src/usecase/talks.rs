@@ -1,6 +1,7 @@
 use crate::model::talks::{Talk, TalkCommandStatus, Talks, TalksParams};
 use crate::repo::talks::*;
 use async_trait::async_trait;
+use chrono::NaiveDate;
 use core::fmt::Debug;
 
 #[derive(Clone, Debug)]
@@ -52,26 +53,24 @@ impl TalkOperationRepo for TalkDBUseCase {
         &mut self,
         id: i64,
         name: String,
-        date: String,
+        date: NaiveDate,
         media_link: Option<String>,
-        org_name: Option<String>,
-        org_link: Option<String>,
+        org_id: Option<i64>,
     ) -> Option<TalkCommandStatus> {
         self.talk_operation_repo
-            .add(id, name, date, media_link, org_name, org_link)
+            .add(id, name, date, media_link, org_id)
             .await
     }
     async fn update(
         &mut self,
         id: i64,
         name: Option<String>,
-        date: Option<String>,
+        date: Option<NaiveDate>,
         media_link: Option<String>,
-        org_name: Option<String>,
-        org_link: Option<String>,
+        org_id: Option<i64>,
     ) -> Option<TalkCommandStatus> {
         self.talk_operation_repo
-            .update(id, name, date, media_link, org_name, org_link)
+            .update(id, name, date, media_link, org_id)
             .await
     }
     async fn delete(&mut self, id: i64) -> Option<TalkCommandStatus> {
@@ -109,6 +108,15 @@ impl TalkCacheOperationRepo for TalkCacheUseCase {
     async fn invalidate(&mut self, id: i64) -> Option<TalkCommandStatus> {
         self.talk_operation_repo.invalidate(id).await
     }
+    async fn find_calendar(&self) -> Option<String> {
+        self.talk_operation_repo.find_calendar().await
+    }
+    async fn insert_calendar(&mut self, calendar: String) -> Option<TalkCommandStatus> {
+        self.talk_operation_repo.insert_calendar(calendar).await
+    }
+    async fn invalidate_calendar(&mut self) -> Option<TalkCommandStatus> {
+        self.talk_operation_repo.invalidate_calendar().await
+    }
 }
 
 impl TalkCacheUseCase {
@@ -1,3 +1,4 @@
+use crate::model::media::rewrite_media_images;
 use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
 
 /// Capitalize the first character in s.
@@ -25,9 +26,28 @@ pub fn convert_tags_string_to_vec(s: &str) -> Vec<String> {
     s.split(',').map(std::string::ToString::to_string).collect()
 }
 
+/// Check if any of comma separated `tags_filter` is in `tags`
+/// Shared tags filter of Blogs and Talks. Empty filter match everything.
+#[must_use]
+pub fn is_tags_matched(tags_filter: &str, tags: &[String]) -> bool {
+    let tags_filter = remove_whitespace(tags_filter);
+    tags_filter.is_empty()
+        || tags_filter
+            .split(',')
+            .any(|tag| tags.iter().any(|val| val == tag))
+}
+
+/// Check if a link is empty or an http(s) link
+/// Used to validate optional links submitted from admin forms
+#[must_use]
+pub fn is_http_link(link: &str) -> bool {
+    link.is_empty() || link.starts_with("http://") || link.starts_with("https://")
+}
+
 /// Process Markdown
 /// take String of markdown body and convert into html with Askama Options
 /// return String of converted markdown in html
+/// Images from the media store are rewritten into responsive markup.
 ///
 /// # Panics
 /// panic if failed to convert markdown to html with allow dangerous html option
@@ -45,12 +65,14 @@ pub fn convert_markdown_to_html(body_md: &str) -> String {
     )
     .unwrap();
 
-    ammonia::Builder::default()
+    let html = ammonia::Builder::default()
         .link_rel(None)
         .url_relative(ammonia::UrlRelative::PassThrough)
         .add_clean_content_tags(&["script", "style"])
         .clean(&html)
-        .to_string()
+        .to_string();
+
+    rewrite_media_images(&html)
 }
 
 #[cfg(test)]
@@ -74,6 +96,25 @@ mod test {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_is_http_link() {
+        assert!(is_http_link(""));
+        assert!(is_http_link("http://example.com"));
+        assert!(is_http_link("https://example.com"));
+        assert!(!is_http_link("example.com"));
+        assert!(!is_http_link("javascript:alert(1)"));
+    }
+
+    #[test]
+    fn test_is_tags_matched() {
+        let tags = vec!["rust".to_string(), "devops".to_string()];
+        assert!(is_tags_matched("", &tags));
+        assert!(is_tags_matched("rust", &tags));
+        assert!(is_tags_matched("go, devops", &tags));
+        assert!(!is_tags_matched("go,k8s", &tags));
+        assert!(!is_tags_matched("rust", &[]));
+    }
+
     #[test]
     fn test_convert_markdown_to_html_header() {
         let header = r"
@@ -100,4 +141,14 @@ mod test {
         let result = convert_markdown_to_html(&text);
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_convert_markdown_to_html_media_image() {
+        let body = "![cat](/media/20261019120000000-cat@400x200.png)";
+        let result = convert_markdown_to_html(body);
+        assert!(result.starts_with("<p><picture><source type=\"image/webp\""));
+        assert!(result.contains(
+            "<img src=\"/media/20261019120000000-cat@400x200.png\" alt=\"cat\" width=\"400\" height=\"200\""
+        ));
+    }
 }